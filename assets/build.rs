@@ -9,6 +9,10 @@ use std::env;
 use std::fs;
 use std::path::Path;
 
+/// Maximum condition nesting depth. Must match `oab_battle::types::MAX_CONDITION_DEPTH`.
+const MAX_CONDITION_DEPTH: usize = 4;
+const MAX_ABILITY_EFFECTS: usize = 4;
+
 // ── JSON schema types (build-time only) ──────────────────────────────────────
 
 #[derive(Deserialize)]
@@ -23,6 +27,12 @@ struct JsonCard {
     shop_abilities: Vec<JsonAbility>,
     #[serde(default)]
     battle_abilities: Vec<JsonAbility>,
+    #[serde(default)]
+    base_statuses: Vec<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    keywords: Vec<String>,
 }
 
 #[derive(Deserialize)]
@@ -44,6 +54,20 @@ struct JsonAbility {
     #[serde(default)]
     conditions: Vec<serde_json::Value>,
     max_triggers: Option<u32>,
+    #[serde(default)]
+    then: Vec<JsonEffect>,
+    // OnHealthBelow: `{ "type": "Absolute" | "Percent", "data": n }`
+    threshold: Option<JsonThreshold>,
+    // Battle-only limits
+    max_triggers_per_round: Option<u32>,
+    cooldown: Option<u32>,
+}
+
+#[derive(Deserialize, Clone)]
+struct JsonThreshold {
+    #[serde(rename = "type")]
+    threshold_type: String,
+    data: i32,
 }
 
 #[derive(Deserialize, Clone)]
@@ -53,13 +77,22 @@ struct JsonEffect {
     // SpawnUnit
     card_id: Option<u32>,
     spawn_location: Option<String>,
-    // Damage
-    amount: Option<i32>,
-    // ModifyStats
-    health: Option<i32>,
-    attack: Option<i32>,
-    // Damage, ModifyStats, Destroy
+    // SpawnUnit: copies to spawn (default 1) and "Own" | "Opposing" board (battle only)
+    count: Option<u8>,
+    side: Option<String>,
+    // Damage, Heal, GainMana: a number or a `{ term, factor }` value expression
+    amount: Option<serde_json::Value>,
+    // ModifyStats; SpawnUnit "Fixed" stats (plain numbers)
+    health: Option<serde_json::Value>,
+    attack: Option<serde_json::Value>,
+    // GrantStatusThisBattle, GrantStatusPermanent, RemoveStatusPermanent
+    status: Option<String>,
+    // Damage, ModifyStats, Destroy, status effects, Mimic, Steal, Silence
     target: Option<JsonTarget>,
+    // Resurrect: "MostRecent" | "Strongest" and "Base" | "Current"
+    // SpawnUnit stats: "Base" | "Fixed" | "InheritBuffs"
+    pick: Option<String>,
+    stats: Option<String>,
 }
 
 #[derive(Deserialize, Clone)]
@@ -119,10 +152,39 @@ fn escape_rust_string(s: &str) -> String {
     s.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
-fn require_i32(value: Option<i32>, card_id: u32, ability_name: &str, field: &str) -> i32 {
-    value.unwrap_or_else(|| {
+fn require_value(
+    value: &Option<serde_json::Value>,
+    lane: AbilityLane,
+    card_id: u32,
+    ability_name: &str,
+    field: &str,
+) {
+    let value = value.as_ref().unwrap_or_else(|| {
         panic!("Card {card_id} ability '{ability_name}' missing required '{field}' field")
-    })
+    });
+    if value.is_i64() {
+        return;
+    }
+    let term = &value["term"];
+    assert!(
+        value["factor"].is_i64(),
+        "Card {card_id} ability '{ability_name}' '{field}' must be a number or have an integer 'factor'"
+    );
+    match term["type"].as_str() {
+        Some("Stat") | Some("UnitCount") | Some("TagCount") => {
+            if term["type"] == "TagCount" {
+                require_tag(&term["data"], card_id, ability_name);
+            }
+            let scope = extract_scope(&term["data"], card_id, ability_name);
+            if lane == AbilityLane::Shop {
+                validate_shop_scope(&scope, card_id, ability_name, "value");
+            }
+        }
+        Some("TriggerCount") if lane == AbilityLane::Battle => {}
+        other => panic!(
+            "Card {card_id} ability '{ability_name}' '{field}' has unsupported value term {other:?}"
+        ),
+    }
 }
 
 fn require_target<'a>(
@@ -136,6 +198,42 @@ fn require_target<'a>(
     })
 }
 
+fn require_status<'a>(value: &'a Option<String>, card_id: u32, ability_name: &str) -> &'a str {
+    let status = value.as_deref().unwrap_or_else(|| {
+        panic!("Card {card_id} ability '{ability_name}' missing required 'status' field")
+    });
+    validate_status(status, card_id, ability_name);
+    status
+}
+
+fn validate_status(status: &str, card_id: u32, context: &str) {
+    match status {
+        "Shield" | "Poison" | "Guard" => {}
+        other => panic!("Card {card_id} {context} has unknown status '{other}'"),
+    }
+}
+
+fn validate_tag(tag: &str, card_id: u32, context: &str) {
+    match tag {
+        "Beast" | "Insect" | "Machine" | "Undead" | "Elemental" => {}
+        other => panic!("Card {card_id} {context} has unknown tag '{other}'"),
+    }
+}
+
+fn validate_keyword(keyword: &str, card_id: u32, context: &str) {
+    match keyword {
+        "FirstStrike" | "Trample" | "Windfury" | "Reach" => {}
+        other => panic!("Card {card_id} {context} has unknown keyword '{other}'"),
+    }
+}
+
+fn require_tag(data: &serde_json::Value, card_id: u32, ability_name: &str) {
+    let tag = data["tag"].as_str().unwrap_or_else(|| {
+        panic!("Card {card_id} ability '{ability_name}' target/matcher missing tag")
+    });
+    validate_tag(tag, card_id, ability_name);
+}
+
 fn extract_scope(data: &serde_json::Value, card_id: u32, ability_name: &str) -> String {
     data["scope"]
         .as_str()
@@ -163,6 +261,8 @@ fn validate_target(
 ) {
     match target.target_type.as_str() {
         "All" | "Position" | "Random" | "Standard" => {}
+        "AllWithTag" | "RandomWithTag" => require_tag(&target.data, card_id, ability_name),
+        "PreviousTargets" => return,
         "Adjacent" if lane == AbilityLane::Battle => {}
         "Adjacent" => panic!(
             "Card {card_id} ability '{ability_name}' effect '{effect_type}' uses shop-incompatible target Adjacent"
@@ -190,7 +290,10 @@ fn validate_matcher(
     let data = &matcher["data"];
 
     match matcher_type {
-        "StatValueCompare" | "UnitCount" | "IsPosition" => {
+        "StatValueCompare" | "UnitCount" | "IsPosition" | "HasTag" | "TagCount" => {
+            if matcher_type == "HasTag" || matcher_type == "TagCount" {
+                require_tag(data, card_id, ability_name);
+            }
             let scope = extract_scope(data, card_id, ability_name);
             if lane == AbilityLane::Shop {
                 validate_shop_scope(&scope, card_id, ability_name, "matcher");
@@ -210,6 +313,14 @@ fn validate_matcher(
                 );
             }
         }
+        "MissingHealthCompare" => {
+            if lane == AbilityLane::Shop {
+                panic!(
+                    "Card {card_id} ability '{ability_name}' uses shop-incompatible matcher MissingHealthCompare"
+                );
+            }
+            let _ = extract_scope(data, card_id, ability_name);
+        }
         "StatStatCompare" => {
             if lane == AbilityLane::Shop {
                 panic!(
@@ -234,205 +345,411 @@ fn validate_condition(
     lane: AbilityLane,
     card_id: u32,
     ability_name: &str,
+    depth: usize,
 ) {
+    if depth > MAX_CONDITION_DEPTH {
+        panic!(
+            "Card {card_id} ability '{ability_name}' has conditions nested deeper than {MAX_CONDITION_DEPTH}"
+        );
+    }
     let condition_type = condition["type"].as_str().unwrap_or_else(|| {
         panic!("Card {card_id} ability '{ability_name}' has condition missing 'type'")
     });
     match condition_type {
         "Is" => validate_matcher(&condition["data"], lane, card_id, ability_name),
-        "AnyOf" => {
-            for matcher in condition["data"].as_array().unwrap_or_else(|| {
-                panic!("Card {card_id} ability '{ability_name}' AnyOf must be an array")
+        "AnyOf" | "AllOf" => {
+            for child in condition["data"].as_array().unwrap_or_else(|| {
+                panic!("Card {card_id} ability '{ability_name}' {condition_type} must be an array")
             }) {
-                validate_matcher(matcher, lane, card_id, ability_name);
+                validate_condition(child, lane, card_id, ability_name, depth + 1);
             }
         }
+        "Not" => validate_condition(&condition["data"], lane, card_id, ability_name, depth + 1),
         other => {
             panic!("Card {card_id} ability '{ability_name}' has unknown condition type '{other}'")
         }
     }
 }
 
-fn normalize_shop_ability(
+fn validate_effect_steps(ability: &JsonAbility, card_id: u32, ability_label: &str) {
+    assert!(
+        ability.then.len() < MAX_ABILITY_EFFECTS,
+        "Card {card_id} ability '{ability_label}' has more than {MAX_ABILITY_EFFECTS} effects"
+    );
+    if ability
+        .effect
+        .target
+        .as_ref()
+        .is_some_and(|target| target.target_type == "PreviousTargets")
+    {
+        panic!(
+            "Card {card_id} ability '{ability_label}' targets PreviousTargets in its first effect"
+        );
+    }
+}
+
+fn validate_spawn(
+    effect: &JsonEffect,
+    lane: AbilityLane,
     card_id: u32,
-    ability: JsonAbility,
     ability_label: &str,
     all_card_ids: &BTreeSet<u32>,
-) -> JsonAbility {
-    match ability.trigger.as_str() {
-        "OnBuy" | "OnSell" | "OnShopStart" | "AfterLoss" | "AfterWin" | "AfterDraw" => {}
-        other => panic!(
-            "Card {card_id} ability '{}' uses shop lane with invalid trigger '{other}'",
-            ability_label
-        ),
+) {
+    let spawn_id = effect.card_id.unwrap_or_else(|| {
+        panic!("Card {card_id} ability '{ability_label}' SpawnUnit missing card_id")
+    });
+    assert!(
+        all_card_ids.contains(&spawn_id),
+        "Card {card_id} ability '{ability_label}' SpawnUnit references missing card_id {spawn_id}"
+    );
+    assert!(
+        effect.count.unwrap_or(1) >= 1,
+        "Card {card_id} ability '{ability_label}' SpawnUnit count must be at least 1"
+    );
+    match (lane, effect.side.as_deref()) {
+        (_, None | Some("Own")) | (AbilityLane::Battle, Some("Opposing")) => {}
+        (_, other) => {
+            panic!(
+                "Card {card_id} ability '{ability_label}' SpawnUnit has unsupported side {other:?}"
+            )
+        }
     }
+    match effect.stats.as_deref() {
+        None | Some("Base") | Some("InheritBuffs") => {}
+        Some("Fixed") => {
+            let fixed = |value: &Option<serde_json::Value>, field: &str| {
+                value.as_ref().and_then(|v| v.as_i64()).unwrap_or_else(|| {
+                    panic!(
+                        "Card {card_id} ability '{ability_label}' SpawnUnit Fixed stats need a number '{field}'"
+                    )
+                })
+            };
+            fixed(&effect.attack, "attack");
+            assert!(
+                fixed(&effect.health, "health") > 0,
+                "Card {card_id} ability '{ability_label}' SpawnUnit Fixed health must be positive"
+            );
+        }
+        other => {
+            panic!("Card {card_id} ability '{ability_label}' SpawnUnit has unsupported stats {other:?}")
+        }
+    }
+}
 
-    if ability.effect.effect_type == "ModifyStats" {
+fn validate_shop_effect(
+    effect: &JsonEffect,
+    card_id: u32,
+    ability_label: &str,
+    all_card_ids: &BTreeSet<u32>,
+) {
+    if effect.effect_type == "ModifyStats" {
         panic!(
             "Card {card_id} ability '{}' uses shop-incompatible effect ModifyStats",
             ability_label
         );
     }
 
-    match ability.effect.effect_type.as_str() {
+    match effect.effect_type.as_str() {
         "ModifyStatsPermanent" => {
-            let _ = require_i32(ability.effect.health, card_id, ability_label, "health");
-            let _ = require_i32(ability.effect.attack, card_id, ability_label, "attack");
-            let target = require_target(
-                &ability.effect.target,
+            require_value(
+                &effect.health,
+                AbilityLane::Shop,
+                card_id,
+                ability_label,
+                "health",
+            );
+            require_value(
+                &effect.attack,
+                AbilityLane::Shop,
                 card_id,
                 ability_label,
-                &ability.effect.effect_type,
+                "attack",
             );
+            let target =
+                require_target(&effect.target, card_id, ability_label, &effect.effect_type);
             validate_target(
                 target,
                 AbilityLane::Shop,
                 card_id,
                 ability_label,
-                &ability.effect.effect_type,
+                &effect.effect_type,
             );
         }
         "SpawnUnit" => {
-            let spawn_id = ability.effect.card_id.unwrap_or_else(|| {
-                panic!(
-                    "Card {card_id} ability '{}' SpawnUnit missing card_id",
-                    ability_label
-                )
-            });
-            assert!(
-                all_card_ids.contains(&spawn_id),
-                "Card {} ability '{}' SpawnUnit references missing card_id {}",
+            validate_spawn(
+                effect,
+                AbilityLane::Shop,
                 card_id,
                 ability_label,
-                spawn_id
+                all_card_ids,
             );
         }
-        "Destroy" => {
-            let target = require_target(
-                &ability.effect.target,
+        "Heal" => {
+            require_value(
+                &effect.amount,
+                AbilityLane::Shop,
+                card_id,
+                ability_label,
+                "amount",
+            );
+            let target =
+                require_target(&effect.target, card_id, ability_label, &effect.effect_type);
+            validate_target(
+                target,
+                AbilityLane::Shop,
                 card_id,
                 ability_label,
-                &ability.effect.effect_type,
+                &effect.effect_type,
             );
+        }
+        "Destroy" | "Silence" => {
+            let target =
+                require_target(&effect.target, card_id, ability_label, &effect.effect_type);
             validate_target(
                 target,
                 AbilityLane::Shop,
                 card_id,
                 ability_label,
-                &ability.effect.effect_type,
+                &effect.effect_type,
             );
         }
         "GainMana" => {
-            let _ = require_i32(ability.effect.amount, card_id, ability_label, "amount");
+            require_value(
+                &effect.amount,
+                AbilityLane::Shop,
+                card_id,
+                ability_label,
+                "amount",
+            );
+        }
+        "GrantStatusPermanent" | "RemoveStatusPermanent" => {
+            let _ = require_status(&effect.status, card_id, ability_label);
+            let target =
+                require_target(&effect.target, card_id, ability_label, &effect.effect_type);
+            validate_target(
+                target,
+                AbilityLane::Shop,
+                card_id,
+                ability_label,
+                &effect.effect_type,
+            );
         }
         other => panic!(
             "Card {card_id} ability '{}' uses shop-incompatible effect '{other}'",
             ability_label
         ),
     }
-
-    for condition in &ability.conditions {
-        validate_condition(condition, AbilityLane::Shop, card_id, ability_label);
-    }
-
-    ability
 }
 
-fn normalize_battle_ability(
+fn normalize_shop_ability(
     card_id: u32,
     ability: JsonAbility,
     ability_label: &str,
     all_card_ids: &BTreeSet<u32>,
 ) -> JsonAbility {
     match ability.trigger.as_str() {
-        "OnStart" | "OnFaint" | "OnAllyFaint" | "OnHurt" | "OnSpawn" | "OnAllySpawn"
-        | "OnEnemySpawn" | "BeforeUnitAttack" | "AfterUnitAttack" | "BeforeAnyAttack"
-        | "AfterAnyAttack" => {}
-        "OnBuy" | "OnSell" | "OnShopStart" | "AfterLoss" | "AfterWin" | "AfterDraw" => panic!(
-            "Card {card_id} ability '{}' uses battle lane with shop trigger '{}'",
-            ability_label, ability.trigger
-        ),
-        _ => panic!(
-            "Card {card_id} ability '{}' has unknown trigger '{}'",
-            ability_label, ability.trigger
+        "OnBuy" | "OnSell" | "OnShopStart" | "AfterLoss" | "AfterWin" | "AfterDraw" => {}
+        other => panic!(
+            "Card {card_id} ability '{}' uses shop lane with invalid trigger '{other}'",
+            ability_label
         ),
     }
+    assert!(
+        ability.threshold.is_none(),
+        "Card {card_id} ability '{ability_label}' has a threshold on a shop trigger"
+    );
+    assert!(
+        ability.max_triggers_per_round.is_none() && ability.cooldown.is_none(),
+        "Card {card_id} ability '{ability_label}' has battle-only limits on a shop trigger"
+    );
 
-    match ability.effect.effect_type.as_str() {
-        "Damage" => {
-            let _ = require_i32(ability.effect.amount, card_id, ability_label, "amount");
-            let target = require_target(
-                &ability.effect.target,
+    validate_effect_steps(&ability, card_id, ability_label);
+    for effect in core::iter::once(&ability.effect).chain(&ability.then) {
+        validate_shop_effect(effect, card_id, ability_label, all_card_ids);
+    }
+
+    for condition in &ability.conditions {
+        validate_condition(condition, AbilityLane::Shop, card_id, ability_label, 1);
+    }
+
+    ability
+}
+
+fn validate_battle_effect(
+    effect: &JsonEffect,
+    card_id: u32,
+    ability_label: &str,
+    all_card_ids: &BTreeSet<u32>,
+) {
+    match effect.effect_type.as_str() {
+        "Damage" | "Heal" => {
+            require_value(
+                &effect.amount,
+                AbilityLane::Battle,
                 card_id,
                 ability_label,
-                &ability.effect.effect_type,
+                "amount",
             );
+            let target =
+                require_target(&effect.target, card_id, ability_label, &effect.effect_type);
             validate_target(
                 target,
                 AbilityLane::Battle,
                 card_id,
                 ability_label,
-                &ability.effect.effect_type,
+                &effect.effect_type,
             );
         }
         "ModifyStats" | "ModifyStatsPermanent" => {
-            let _ = require_i32(ability.effect.health, card_id, ability_label, "health");
-            let _ = require_i32(ability.effect.attack, card_id, ability_label, "attack");
-            let target = require_target(
-                &ability.effect.target,
+            require_value(
+                &effect.health,
+                AbilityLane::Battle,
                 card_id,
                 ability_label,
-                &ability.effect.effect_type,
+                "health",
             );
+            require_value(
+                &effect.attack,
+                AbilityLane::Battle,
+                card_id,
+                ability_label,
+                "attack",
+            );
+            let target =
+                require_target(&effect.target, card_id, ability_label, &effect.effect_type);
             validate_target(
                 target,
                 AbilityLane::Battle,
                 card_id,
                 ability_label,
-                &ability.effect.effect_type,
+                &effect.effect_type,
             );
         }
         "Destroy" => {
-            let target = require_target(
-                &ability.effect.target,
-                card_id,
-                ability_label,
-                &ability.effect.effect_type,
-            );
+            let target =
+                require_target(&effect.target, card_id, ability_label, &effect.effect_type);
             validate_target(
                 target,
                 AbilityLane::Battle,
                 card_id,
                 ability_label,
-                &ability.effect.effect_type,
+                &effect.effect_type,
             );
         }
         "SpawnUnit" => {
-            let spawn_id = ability.effect.card_id.unwrap_or_else(|| {
-                panic!(
-                    "Card {card_id} ability '{}' SpawnUnit missing card_id",
-                    ability_label
-                )
-            });
+            validate_spawn(
+                effect,
+                AbilityLane::Battle,
+                card_id,
+                ability_label,
+                all_card_ids,
+            );
+        }
+        "Resurrect" => {
+            assert!(
+                matches!(effect.pick.as_deref(), Some("MostRecent" | "Strongest")),
+                "Card {card_id} ability '{ability_label}' Resurrect pick must be MostRecent or Strongest"
+            );
             assert!(
-                all_card_ids.contains(&spawn_id),
-                "Card {} ability '{}' SpawnUnit references missing card_id {}",
+                matches!(effect.stats.as_deref(), Some("Base" | "Current")),
+                "Card {card_id} ability '{ability_label}' Resurrect stats must be Base or Current"
+            );
+        }
+        "Mimic" | "Steal" | "Silence" => {
+            let target =
+                require_target(&effect.target, card_id, ability_label, &effect.effect_type);
+            validate_target(
+                target,
+                AbilityLane::Battle,
                 card_id,
                 ability_label,
-                spawn_id
+                &effect.effect_type,
             );
         }
         "GainMana" => {
-            let _ = require_i32(ability.effect.amount, card_id, ability_label, "amount");
+            require_value(
+                &effect.amount,
+                AbilityLane::Battle,
+                card_id,
+                ability_label,
+                "amount",
+            );
+        }
+        "GrantStatusThisBattle" | "GrantStatusPermanent" | "RemoveStatusPermanent" => {
+            let _ = require_status(&effect.status, card_id, ability_label);
+            let target =
+                require_target(&effect.target, card_id, ability_label, &effect.effect_type);
+            validate_target(
+                target,
+                AbilityLane::Battle,
+                card_id,
+                ability_label,
+                &effect.effect_type,
+            );
         }
         other => panic!(
             "Card {card_id} ability '{}' has unsupported battle effect '{other}'",
             ability_label
         ),
     }
+}
+
+fn normalize_battle_ability(
+    card_id: u32,
+    ability: JsonAbility,
+    ability_label: &str,
+    all_card_ids: &BTreeSet<u32>,
+) -> JsonAbility {
+    match ability.trigger.as_str() {
+        "OnStart" | "OnFaint" | "OnAllyFaint" | "OnHurt" | "OnSpawn" | "OnAllySpawn"
+        | "OnEnemySpawn" | "BeforeUnitAttack" | "AfterUnitAttack" | "BeforeAnyAttack"
+        | "AfterAnyAttack" | "OnKill" | "OnAllyHurt" | "OnEnemyFaint" | "OnBattleEnd" => {
+            assert!(
+                ability.threshold.is_none(),
+                "Card {card_id} ability '{ability_label}' has a threshold on trigger '{}'",
+                ability.trigger
+            );
+        }
+        "OnHealthBelow" => {
+            let threshold = ability.threshold.as_ref().unwrap_or_else(|| {
+                panic!("Card {card_id} ability '{ability_label}' OnHealthBelow missing threshold")
+            });
+            match threshold.threshold_type.as_str() {
+                "Absolute" => assert!(
+                    i16::try_from(threshold.data).is_ok(),
+                    "Card {card_id} ability '{ability_label}' threshold out of range"
+                ),
+                "Percent" => assert!(
+                    (0..=100).contains(&threshold.data),
+                    "Card {card_id} ability '{ability_label}' threshold percent out of range"
+                ),
+                other => panic!(
+                    "Card {card_id} ability '{ability_label}' has unknown threshold type '{other}'"
+                ),
+            }
+        }
+        "OnBuy" | "OnSell" | "OnShopStart" | "AfterLoss" | "AfterWin" | "AfterDraw" => panic!(
+            "Card {card_id} ability '{}' uses battle lane with shop trigger '{}'",
+            ability_label, ability.trigger
+        ),
+        _ => panic!(
+            "Card {card_id} ability '{}' has unknown trigger '{}'",
+            ability_label, ability.trigger
+        ),
+    }
+
+    assert!(
+        ability.cooldown != Some(0),
+        "Card {card_id} ability '{ability_label}' cooldown must be at least 1"
+    );
+
+    validate_effect_steps(&ability, card_id, ability_label);
+    for effect in core::iter::once(&ability.effect).chain(&ability.then) {
+        validate_battle_effect(effect, card_id, ability_label, all_card_ids);
+    }
 
     for condition in &ability.conditions {
-        validate_condition(condition, AbilityLane::Battle, card_id, ability_label);
+        validate_condition(condition, AbilityLane::Battle, card_id, ability_label, 1);
     }
 
     ability
@@ -465,8 +782,14 @@ fn normalize_card_abilities(
     (shop, battle)
 }
 
-fn gen_battle_trigger(trigger: &str) -> String {
-    format!("AbilityTrigger::{trigger}")
+fn gen_battle_trigger(ability: &JsonAbility) -> String {
+    match &ability.threshold {
+        Some(threshold) => format!(
+            "AbilityTrigger::{} {{ threshold: HealthThreshold::{}({}) }}",
+            ability.trigger, threshold.threshold_type, threshold.data
+        ),
+        None => format!("AbilityTrigger::{}", ability.trigger),
+    }
 }
 
 fn gen_shop_trigger(trigger: &str) -> String {
@@ -502,6 +825,20 @@ fn gen_battle_target(target: &JsonTarget) -> String {
             let scope = target.data["scope"].as_str().unwrap();
             format!("AbilityTarget::Adjacent {{ scope: TargetScope::{scope} }}")
         }
+        "PreviousTargets" => "AbilityTarget::PreviousTargets".to_string(),
+        "AllWithTag" => {
+            let scope = target.data["scope"].as_str().unwrap();
+            let tag = target.data["tag"].as_str().unwrap();
+            format!("AbilityTarget::AllWithTag {{ scope: TargetScope::{scope}, tag: Tag::{tag} }}")
+        }
+        "RandomWithTag" => {
+            let scope = target.data["scope"].as_str().unwrap();
+            let tag = target.data["tag"].as_str().unwrap();
+            let count = target.data["count"].as_u64().unwrap();
+            format!(
+                "AbilityTarget::RandomWithTag {{ scope: TargetScope::{scope}, tag: Tag::{tag}, count: {count} }}"
+            )
+        }
         other => panic!("Unknown battle target type: {other}"),
     }
 }
@@ -531,41 +868,123 @@ fn gen_shop_target(target: &JsonTarget) -> String {
                 "ShopTarget::Standard {{ scope: ShopScope::{scope}, stat: StatType::{stat}, order: SortOrder::{order}, count: {count} }}"
             )
         }
+        "PreviousTargets" => "ShopTarget::PreviousTargets".to_string(),
+        "AllWithTag" => {
+            let scope = target.data["scope"].as_str().unwrap();
+            let tag = target.data["tag"].as_str().unwrap();
+            format!("ShopTarget::AllWithTag {{ scope: ShopScope::{scope}, tag: Tag::{tag} }}")
+        }
+        "RandomWithTag" => {
+            let scope = target.data["scope"].as_str().unwrap();
+            let tag = target.data["tag"].as_str().unwrap();
+            let count = target.data["count"].as_u64().unwrap();
+            format!(
+                "ShopTarget::RandomWithTag {{ scope: ShopScope::{scope}, tag: Tag::{tag}, count: {count} }}"
+            )
+        }
         other => panic!("Unknown shop target type: {other}"),
     }
 }
 
+fn gen_battle_value(val: &serde_json::Value) -> String {
+    if let Some(n) = val.as_i64() {
+        return format!("ValueExpr::Const({n})");
+    }
+    let term = &val["term"];
+    let term = match term["type"].as_str().unwrap() {
+        "Stat" => {
+            let scope = term["data"]["scope"].as_str().unwrap();
+            let stat = term["data"]["stat"].as_str().unwrap();
+            format!("ValueTerm::Stat {{ scope: TargetScope::{scope}, stat: StatType::{stat} }}")
+        }
+        "UnitCount" => {
+            let scope = term["data"]["scope"].as_str().unwrap();
+            format!("ValueTerm::UnitCount {{ scope: TargetScope::{scope} }}")
+        }
+        "TagCount" => {
+            let scope = term["data"]["scope"].as_str().unwrap();
+            let tag = term["data"]["tag"].as_str().unwrap();
+            format!("ValueTerm::TagCount {{ scope: TargetScope::{scope}, tag: Tag::{tag} }}")
+        }
+        "TriggerCount" => "ValueTerm::TriggerCount".to_string(),
+        other => panic!("Unknown battle value term: {other}"),
+    };
+    let factor = val["factor"].as_i64().unwrap();
+    format!("ValueExpr::Scaled {{ term: {term}, factor: {factor} }}")
+}
+
+fn gen_shop_value(val: &serde_json::Value) -> String {
+    if let Some(n) = val.as_i64() {
+        return format!("ShopValueExpr::Const({n})");
+    }
+    let term = &val["term"];
+    let term = match term["type"].as_str().unwrap() {
+        "Stat" => {
+            let scope = term["data"]["scope"].as_str().unwrap();
+            let stat = term["data"]["stat"].as_str().unwrap();
+            format!("ShopValueTerm::Stat {{ scope: ShopScope::{scope}, stat: StatType::{stat} }}")
+        }
+        "UnitCount" => {
+            let scope = term["data"]["scope"].as_str().unwrap();
+            format!("ShopValueTerm::UnitCount {{ scope: ShopScope::{scope} }}")
+        }
+        "TagCount" => {
+            let scope = term["data"]["scope"].as_str().unwrap();
+            let tag = term["data"]["tag"].as_str().unwrap();
+            format!("ShopValueTerm::TagCount {{ scope: ShopScope::{scope}, tag: Tag::{tag} }}")
+        }
+        other => panic!("Unknown shop value term: {other}"),
+    };
+    let factor = val["factor"].as_i64().unwrap();
+    format!("ShopValueExpr::Scaled {{ term: {term}, factor: {factor} }}")
+}
+
 fn gen_battle_effect(effect: &JsonEffect) -> String {
     match effect.effect_type.as_str() {
         "GainMana" => {
-            let amount = effect.amount.unwrap();
+            let amount = gen_battle_value(effect.amount.as_ref().unwrap());
             format!("AbilityEffect::GainMana {{ amount: {amount} }}")
         }
         "SpawnUnit" => {
             let card_id = effect.card_id.unwrap();
-            let spawn_loc = match effect.spawn_location.as_deref() {
-                Some("Front") => "SpawnLocation::Front",
-                Some("Back") => "SpawnLocation::Back",
-                _ => "SpawnLocation::DeathPosition",
-            };
-            format!("AbilityEffect::SpawnUnit {{ card_id: CardId({card_id}), spawn_location: {spawn_loc} }}")
+            let spawn_loc = gen_spawn_location(effect);
+            let count = effect.count.unwrap_or(1);
+            let side = effect.side.as_deref().unwrap_or("Own");
+            let stats = gen_spawn_stats(effect);
+            format!(
+                "AbilityEffect::SpawnUnit {{ card_id: CardId({card_id}), spawn_location: {spawn_loc}, count: {count}, side: SpawnSide::{side}, stats: {stats} }}"
+            )
+        }
+        "Resurrect" => {
+            let pick = effect.pick.as_ref().unwrap();
+            let stats = effect.stats.as_ref().unwrap();
+            let spawn_loc = gen_spawn_location(effect);
+            format!(
+                "AbilityEffect::Resurrect {{ pick: ResurrectPick::{pick}, stats: ResurrectStats::{stats}, spawn_location: {spawn_loc} }}"
+            )
         }
-        "Damage" => {
-            let amount = effect.amount.unwrap();
+        "Mimic" | "Steal" | "Silence" => {
+            let effect_type = &effect.effect_type;
             let target = gen_battle_target(effect.target.as_ref().unwrap());
-            format!("AbilityEffect::Damage {{ amount: {amount}, target: {target} }}")
+            format!("AbilityEffect::{effect_type} {{ target: {target} }}")
+        }
+        "Damage" | "Heal" => {
+            let effect_type = &effect.effect_type;
+            let amount = gen_battle_value(effect.amount.as_ref().unwrap());
+            let target = gen_battle_target(effect.target.as_ref().unwrap());
+            format!("AbilityEffect::{effect_type} {{ amount: {amount}, target: {target} }}")
         }
         "ModifyStats" => {
-            let health = effect.health.unwrap();
-            let attack = effect.attack.unwrap();
+            let health = gen_battle_value(effect.health.as_ref().unwrap());
+            let attack = gen_battle_value(effect.attack.as_ref().unwrap());
             let target = gen_battle_target(effect.target.as_ref().unwrap());
             format!(
                 "AbilityEffect::ModifyStats {{ health: {health}, attack: {attack}, target: {target} }}"
             )
         }
         "ModifyStatsPermanent" => {
-            let health = effect.health.unwrap();
-            let attack = effect.attack.unwrap();
+            let health = gen_battle_value(effect.health.as_ref().unwrap());
+            let attack = gen_battle_value(effect.attack.as_ref().unwrap());
             let target = gen_battle_target(effect.target.as_ref().unwrap());
             format!(
                 "AbilityEffect::ModifyStatsPermanent {{ health: {health}, attack: {attack}, target: {target} }}"
@@ -575,36 +994,75 @@ fn gen_battle_effect(effect: &JsonEffect) -> String {
             let target = gen_battle_target(effect.target.as_ref().unwrap());
             format!("AbilityEffect::Destroy {{ target: {target} }}")
         }
+        "GrantStatusThisBattle" | "GrantStatusPermanent" | "RemoveStatusPermanent" => {
+            let effect_type = &effect.effect_type;
+            let status = effect.status.as_ref().unwrap();
+            let target = gen_battle_target(effect.target.as_ref().unwrap());
+            format!("AbilityEffect::{effect_type} {{ status: Status::{status}, target: {target} }}")
+        }
         other => panic!("Unknown battle effect type: {other}"),
     }
 }
 
+fn gen_spawn_location(effect: &JsonEffect) -> &'static str {
+    match effect.spawn_location.as_deref() {
+        Some("Front") => "SpawnLocation::Front",
+        Some("Back") => "SpawnLocation::Back",
+        Some("AdjacentToSource") => "SpawnLocation::AdjacentToSource",
+        _ => "SpawnLocation::DeathPosition",
+    }
+}
+
+fn gen_spawn_stats(effect: &JsonEffect) -> String {
+    match effect.stats.as_deref() {
+        Some("Fixed") => {
+            let attack = effect.attack.as_ref().unwrap().as_i64().unwrap();
+            let health = effect.health.as_ref().unwrap().as_i64().unwrap();
+            format!("SpawnStats::Fixed {{ attack: {attack}, health: {health} }}")
+        }
+        Some("InheritBuffs") => "SpawnStats::InheritBuffs".into(),
+        _ => "SpawnStats::Base".into(),
+    }
+}
+
 fn gen_shop_effect(effect: &JsonEffect) -> String {
     match effect.effect_type.as_str() {
         "GainMana" => {
-            let amount = effect.amount.unwrap();
+            let amount = gen_shop_value(effect.amount.as_ref().unwrap());
             format!("ShopEffect::GainMana {{ amount: {amount} }}")
         }
         "SpawnUnit" => {
             let card_id = effect.card_id.unwrap();
-            let spawn_loc = match effect.spawn_location.as_deref() {
-                Some("Front") => "SpawnLocation::Front",
-                Some("Back") => "SpawnLocation::Back",
-                _ => "SpawnLocation::DeathPosition",
-            };
-            format!("ShopEffect::SpawnUnit {{ card_id: CardId({card_id}), spawn_location: {spawn_loc} }}")
+            let spawn_loc = gen_spawn_location(effect);
+            let count = effect.count.unwrap_or(1);
+            let stats = gen_spawn_stats(effect);
+            format!(
+                "ShopEffect::SpawnUnit {{ card_id: CardId({card_id}), spawn_location: {spawn_loc}, count: {count}, stats: {stats} }}"
+            )
         }
         "ModifyStatsPermanent" => {
-            let health = effect.health.unwrap();
-            let attack = effect.attack.unwrap();
+            let health = gen_shop_value(effect.health.as_ref().unwrap());
+            let attack = gen_shop_value(effect.attack.as_ref().unwrap());
             let target = gen_shop_target(effect.target.as_ref().unwrap());
             format!(
                 "ShopEffect::ModifyStatsPermanent {{ health: {health}, attack: {attack}, target: {target} }}"
             )
         }
-        "Destroy" => {
+        "Destroy" | "Silence" => {
+            let effect_type = &effect.effect_type;
             let target = gen_shop_target(effect.target.as_ref().unwrap());
-            format!("ShopEffect::Destroy {{ target: {target} }}")
+            format!("ShopEffect::{effect_type} {{ target: {target} }}")
+        }
+        "Heal" => {
+            let amount = gen_shop_value(effect.amount.as_ref().unwrap());
+            let target = gen_shop_target(effect.target.as_ref().unwrap());
+            format!("ShopEffect::Heal {{ amount: {amount}, target: {target} }}")
+        }
+        "GrantStatusPermanent" | "RemoveStatusPermanent" => {
+            let effect_type = &effect.effect_type;
+            let status = effect.status.as_ref().unwrap();
+            let target = gen_shop_target(effect.target.as_ref().unwrap());
+            format!("ShopEffect::{effect_type} {{ status: Status::{status}, target: {target} }}")
         }
         other => panic!("Unknown shop effect type: {other}"),
     }
@@ -660,6 +1118,28 @@ fn gen_battle_matcher(val: &serde_json::Value) -> String {
             let index = data["index"].as_i64().unwrap();
             format!("Matcher::IsPosition {{ scope: TargetScope::{scope}, index: {index} }}")
         }
+        "MissingHealthCompare" => {
+            let scope = data["scope"].as_str().unwrap();
+            let op = data["op"].as_str().unwrap();
+            let value = data["value"].as_i64().unwrap();
+            format!(
+                "Matcher::MissingHealthCompare {{ scope: TargetScope::{scope}, op: CompareOp::{op}, value: {value} }}"
+            )
+        }
+        "HasTag" => {
+            let scope = data["scope"].as_str().unwrap();
+            let tag = data["tag"].as_str().unwrap();
+            format!("Matcher::HasTag {{ scope: TargetScope::{scope}, tag: Tag::{tag} }}")
+        }
+        "TagCount" => {
+            let scope = data["scope"].as_str().unwrap();
+            let tag = data["tag"].as_str().unwrap();
+            let op = data["op"].as_str().unwrap();
+            let value = data["value"].as_i64().unwrap();
+            format!(
+                "Matcher::TagCount {{ scope: TargetScope::{scope}, tag: Tag::{tag}, op: CompareOp::{op}, value: {value} }}"
+            )
+        }
         other => panic!("Unknown battle matcher type: {other}"),
     }
 }
@@ -690,6 +1170,20 @@ fn gen_shop_matcher(val: &serde_json::Value) -> String {
             let index = data["index"].as_i64().unwrap();
             format!("ShopMatcher::IsPosition {{ scope: ShopScope::{scope}, index: {index} }}")
         }
+        "HasTag" => {
+            let scope = data["scope"].as_str().unwrap();
+            let tag = data["tag"].as_str().unwrap();
+            format!("ShopMatcher::HasTag {{ scope: ShopScope::{scope}, tag: Tag::{tag} }}")
+        }
+        "TagCount" => {
+            let scope = data["scope"].as_str().unwrap();
+            let tag = data["tag"].as_str().unwrap();
+            let op = data["op"].as_str().unwrap();
+            let value = data["value"].as_i64().unwrap();
+            format!(
+                "ShopMatcher::TagCount {{ scope: ShopScope::{scope}, tag: Tag::{tag}, op: CompareOp::{op}, value: {value} }}"
+            )
+        }
         other => panic!("Unknown shop matcher type: {other}"),
     }
 }
@@ -701,14 +1195,18 @@ fn gen_battle_condition(val: &serde_json::Value) -> String {
             let matcher = gen_battle_matcher(&val["data"]);
             format!("Condition::Is({matcher})")
         }
-        "AnyOf" => {
-            let matchers: Vec<String> = val["data"]
+        "AnyOf" | "AllOf" => {
+            let children: Vec<String> = val["data"]
                 .as_array()
                 .unwrap()
                 .iter()
-                .map(gen_battle_matcher)
+                .map(gen_battle_condition)
                 .collect();
-            format!("Condition::AnyOf(vec![{}])", matchers.join(", "))
+            format!("Condition::{ctype}(vec![{}])", children.join(", "))
+        }
+        "Not" => {
+            let child = gen_battle_condition(&val["data"]);
+            format!("Condition::Not(alloc::boxed::Box::new({child}))")
         }
         other => panic!("Unknown battle condition type: {other}"),
     }
@@ -721,21 +1219,25 @@ fn gen_shop_condition(val: &serde_json::Value) -> String {
             let matcher = gen_shop_matcher(&val["data"]);
             format!("ShopCondition::Is({matcher})")
         }
-        "AnyOf" => {
-            let matchers: Vec<String> = val["data"]
+        "AnyOf" | "AllOf" => {
+            let children: Vec<String> = val["data"]
                 .as_array()
                 .unwrap()
                 .iter()
-                .map(gen_shop_matcher)
+                .map(gen_shop_condition)
                 .collect();
-            format!("ShopCondition::AnyOf(vec![{}])", matchers.join(", "))
+            format!("ShopCondition::{ctype}(vec![{}])", children.join(", "))
+        }
+        "Not" => {
+            let child = gen_shop_condition(&val["data"]);
+            format!("ShopCondition::Not(alloc::boxed::Box::new({child}))")
         }
         other => panic!("Unknown shop condition type: {other}"),
     }
 }
 
 fn gen_battle_ability(ability: &JsonAbility) -> String {
-    let trigger = gen_battle_trigger(&ability.trigger);
+    let trigger = gen_battle_trigger(ability);
     let effect = gen_battle_effect(&ability.effect);
     let conditions: Vec<String> = ability
         .conditions
@@ -751,6 +1253,14 @@ fn gen_battle_ability(ability: &JsonAbility) -> String {
         Some(n) => format!("Some({n})"),
         None => "None".to_string(),
     };
+    let then: Vec<String> = ability.then.iter().map(gen_battle_effect).collect();
+    let then_str = format!("vec![{}]", then.join(", "));
+    let gen_limit = |limit: Option<u32>| match limit {
+        Some(n) => format!("Some({n})"),
+        None => "None".to_string(),
+    };
+    let max_triggers_per_round = gen_limit(ability.max_triggers_per_round);
+    let cooldown = gen_limit(ability.cooldown);
 
     format!(
         r#"Ability {{
@@ -758,6 +1268,9 @@ fn gen_battle_ability(ability: &JsonAbility) -> String {
                     effect: {effect},
                     conditions: {conditions_str},
                     max_triggers: {max_triggers},
+                    then: {then_str},
+                    max_triggers_per_round: {max_triggers_per_round},
+                    cooldown: {cooldown},
                 }}"#
     )
 }
@@ -775,6 +1288,8 @@ fn gen_shop_ability(ability: &JsonAbility) -> String {
         Some(n) => format!("Some({n})"),
         None => "None".to_string(),
     };
+    let then: Vec<String> = ability.then.iter().map(gen_shop_effect).collect();
+    let then_str = format!("vec![{}]", then.join(", "));
 
     format!(
         r#"ShopAbility {{
@@ -782,10 +1297,38 @@ fn gen_shop_ability(ability: &JsonAbility) -> String {
                     effect: {effect},
                     conditions: {conditions_str},
                     max_triggers: {max_triggers},
+                    then: {then_str},
                 }}"#
     )
 }
 
+fn gen_status_mask(statuses: &[String]) -> String {
+    if statuses.is_empty() {
+        "StatusMask::empty()".to_string()
+    } else {
+        let entries: Vec<String> = statuses.iter().map(|s| format!("Status::{s}")).collect();
+        format!("StatusMask::from_statuses(&[{}])", entries.join(", "))
+    }
+}
+
+fn gen_tag_mask(tags: &[String]) -> String {
+    if tags.is_empty() {
+        "TagMask::empty()".to_string()
+    } else {
+        let entries: Vec<String> = tags.iter().map(|t| format!("Tag::{t}")).collect();
+        format!("TagMask::from_tags(&[{}])", entries.join(", "))
+    }
+}
+
+fn gen_keyword_mask(keywords: &[String]) -> String {
+    if keywords.is_empty() {
+        "KeywordMask::empty()".to_string()
+    } else {
+        let entries: Vec<String> = keywords.iter().map(|k| format!("Keyword::{k}")).collect();
+        format!("KeywordMask::from_keywords(&[{}])", entries.join(", "))
+    }
+}
+
 fn gen_card(
     card: &JsonCard,
     shop_abilities: &[JsonAbility],
@@ -817,6 +1360,10 @@ fn gen_card(
         )
     };
 
+    let base_statuses_str = gen_status_mask(&card.base_statuses);
+    let tags_str = gen_tag_mask(&card.tags);
+    let keywords_str = gen_keyword_mask(&card.keywords);
+
     format!(
         r#"        UnitCard {{
             id: CardId({id}),
@@ -825,6 +1372,9 @@ fn gen_card(
             economy: EconomyStats {{ play_cost: {cost}, burn_value: {burn} }},
             shop_abilities: {shop_abilities_str},
             battle_abilities: {battle_abilities_str},
+            base_statuses: {base_statuses_str},
+            tags: {tags_str},
+            keywords: {keywords_str},
         }}"#
     )
 }
@@ -882,6 +1432,18 @@ fn main() {
         }
     }
 
+    for card in &cards {
+        for status in &card.base_statuses {
+            validate_status(status, card.id, "base_statuses");
+        }
+        for tag in &card.tags {
+            validate_tag(tag, card.id, "tags");
+        }
+        for keyword in &card.keywords {
+            validate_keyword(keyword, card.id, "keywords");
+        }
+    }
+
     let split_abilities: Vec<(Vec<JsonAbility>, Vec<JsonAbility>)> = cards
         .iter()
         .map(|card| normalize_card_abilities(card, &card_id_set))
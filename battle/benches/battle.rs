@@ -0,0 +1,207 @@
+//! Battle resolution benchmarks over boards drafted from the bundled card sets.
+//!
+//! Run with `cargo bench -p oab-battle`. Each group also prints the heap
+//! allocations per battle of both paths. `benches/BASELINE.md` records the
+//! results before and after triggers started resolving by ability reference.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use oab_assets::{cards, sets};
+use oab_battle::battle::{resolve_battle_outcome, resolve_battle_with_modifiers, CombatUnit};
+use oab_battle::rng::{BattleRng, XorShiftRng};
+use oab_battle::rules::BattleRules;
+use oab_battle::types::{CardId, UnitCard};
+
+const MATCHUPS: u64 = 64;
+
+/// The system allocator, counting every allocation.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Average allocations `resolve` makes per battle, not counting the board
+/// clones it is handed.
+fn allocations_per_battle(
+    matchups: &[Matchup],
+    mut resolve: impl FnMut(Vec<CombatUnit>, Vec<CombatUnit>, &mut XorShiftRng),
+) -> f64 {
+    let mut total = 0;
+    for m in matchups {
+        let (player, enemy) = (m.player.clone(), m.enemy.clone());
+        let mut rng = XorShiftRng::seed_from_u64(m.seed);
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        resolve(player, enemy, &mut rng);
+        total += ALLOCATIONS.load(Ordering::Relaxed) - before;
+    }
+    total as f64 / matchups.len() as f64
+}
+
+struct Matchup {
+    player: Vec<CombatUnit>,
+    enemy: Vec<CombatUnit>,
+    seed: u64,
+}
+
+fn card_pool() -> BTreeMap<CardId, UnitCard> {
+    cards::get_all().into_iter().map(|c| (c.id, c)).collect()
+}
+
+/// Full boards drafted from each set's draftable cards.
+fn drafted_matchups(pool: &BTreeMap<CardId, UnitCard>) -> Vec<Matchup> {
+    let board_size = BattleRules::default().board_size as usize;
+    let drafts: Vec<Vec<&UnitCard>> = sets::get_all()
+        .iter()
+        .map(|set| {
+            set.cards
+                .iter()
+                .filter(|entry| entry.rarity > 0)
+                .filter_map(|entry| pool.get(&entry.card_id))
+                .collect()
+        })
+        .filter(|draft: &Vec<&UnitCard>| !draft.is_empty())
+        .collect();
+
+    (0..MATCHUPS)
+        .map(|seed| {
+            let mut rng = XorShiftRng::seed_from_u64(seed);
+            let draft = &drafts[seed as usize % drafts.len()];
+            let board = |rng: &mut XorShiftRng| -> Vec<CombatUnit> {
+                (0..board_size)
+                    .map(|_| CombatUnit::from_card(draft[rng.gen_range(draft.len())].clone()))
+                    .collect()
+            };
+            let player = board(&mut rng);
+            let enemy = board(&mut rng);
+            Matchup {
+                player,
+                enemy,
+                seed,
+            }
+        })
+        .collect()
+}
+
+/// Boards of the cards with the most battle abilities, where trigger
+/// resolution dominates.
+fn trigger_heavy_matchups(pool: &BTreeMap<CardId, UnitCard>) -> Vec<Matchup> {
+    let board_size = BattleRules::default().board_size as usize;
+    let mut busiest: Vec<&UnitCard> = pool.values().collect();
+    busiest.sort_by_key(|c| core::cmp::Reverse(c.battle_abilities.len()));
+    let busiest = &busiest[..busiest.len().min(board_size * 4)];
+
+    (0..MATCHUPS)
+        .map(|seed| {
+            let mut rng = XorShiftRng::seed_from_u64(seed);
+            let board = |rng: &mut XorShiftRng| -> Vec<CombatUnit> {
+                (0..board_size)
+                    .map(|_| CombatUnit::from_card(busiest[rng.gen_range(busiest.len())].clone()))
+                    .collect()
+            };
+            let player = board(&mut rng);
+            let enemy = board(&mut rng);
+            Matchup {
+                player,
+                enemy,
+                seed,
+            }
+        })
+        .collect()
+}
+
+fn bench_matchups(c: &mut Criterion, name: &str, matchups: &[Matchup]) {
+    let pool = card_pool();
+    let rules = BattleRules::default();
+    let event_log_allocs = allocations_per_battle(matchups, |player, enemy, rng| {
+        black_box(resolve_battle_with_modifiers(
+            player,
+            enemy,
+            rng,
+            &pool,
+            &rules,
+            &[],
+        ));
+    });
+    let outcome_allocs = allocations_per_battle(matchups, |player, enemy, rng| {
+        black_box(resolve_battle_outcome(
+            player,
+            enemy,
+            rng,
+            &pool,
+            &rules,
+            &[],
+        ));
+    });
+    println!(
+        "{name}: {event_log_allocs:.1} allocations per battle (event_log), \
+         {outcome_allocs:.1} (outcome)"
+    );
+
+    let mut group = c.benchmark_group(name);
+
+    group.bench_function("event_log", |b| {
+        b.iter(|| {
+            for m in matchups {
+                let mut rng = XorShiftRng::seed_from_u64(m.seed);
+                black_box(resolve_battle_with_modifiers(
+                    m.player.clone(),
+                    m.enemy.clone(),
+                    &mut rng,
+                    &pool,
+                    &rules,
+                    &[],
+                ));
+            }
+        })
+    });
+    group.bench_function("outcome", |b| {
+        b.iter(|| {
+            for m in matchups {
+                let mut rng = XorShiftRng::seed_from_u64(m.seed);
+                black_box(resolve_battle_outcome(
+                    m.player.clone(),
+                    m.enemy.clone(),
+                    &mut rng,
+                    &pool,
+                    &rules,
+                    &[],
+                ));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn drafted(c: &mut Criterion) {
+    let matchups = drafted_matchups(&card_pool());
+    bench_matchups(c, "drafted_boards", &matchups);
+}
+
+fn trigger_heavy(c: &mut Criterion) {
+    let matchups = trigger_heavy_matchups(&card_pool());
+    bench_matchups(c, "trigger_heavy_boards", &matchups);
+}
+
+criterion_group!(benches, drafted, trigger_heavy);
+criterion_main!(benches);
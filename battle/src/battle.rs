@@ -2,20 +2,27 @@
 //!
 //! This module handles combat resolution between player and enemy units.
 
+use alloc::borrow::Cow;
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
-use crate::limits::{BattleLimits, LimitReason};
+use crate::limits::{BattleLimits, FatigueRule, LimitReason};
+use crate::modifiers::{clash_multiplier, BattleModifier};
 use crate::rng::BattleRng;
+use crate::rules::{BattleRules, TiebreakPolicy};
+use crate::sink::{BattleOutcome, EventSink, SnapshotNotice};
+use crate::trace::{self, Combinator, Operand, Operands, SkipReason, TraceEntry};
 use alloc::collections::BTreeMap;
 
 use crate::types::{
     Ability, AbilityEffect, AbilityTarget, AbilityTrigger, CardId, CompareOp, Condition,
-    CountValue, IndexValue, ManaDelta, ManaValue, Matcher, SignedIndex, SortOrder, SpawnLocation,
-    StatType, StatValue, TargetScope, UnitCard,
+    CountValue, IndexValue, Keyword, KeywordMask, LimitValue, ManaDelta, ManaValue, Matcher,
+    ResurrectPick, ResurrectStats, SignedIndex, SortOrder, SpawnLocation, SpawnSide, SpawnStats,
+    StatType, StatValue, Status, StatusMask, TagMask, TargetScope, UnitCard, ValueExpr, ValueTerm,
+    MAX_ABILITY_EFFECTS, MAX_CONDITION_DEPTH,
 };
 
 #[cfg(feature = "std")]
@@ -82,6 +89,8 @@ pub struct UnitView {
     pub attack: StatValue,
     pub health: StatValue,
     pub battle_abilities: Vec<Ability>,
+    /// Active statuses at the time of the snapshot.
+    pub statuses: StatusMask,
 }
 
 #[derive(
@@ -112,11 +121,17 @@ pub enum CombatEvent {
     Clash {
         p_dmg: StatValue,
         e_dmg: StatValue,
+        player_instance_id: UnitInstanceId,
+        enemy_instance_id: UnitInstanceId,
     },
+    /// A front's health after a clash swing. `damage` is the health it lost
+    /// to `source_instance_id`, the opposing front, in that swing.
     DamageTaken {
         target_instance_id: UnitInstanceId,
         team: Team,
         remaining_hp: StatValue,
+        source_instance_id: UnitInstanceId,
+        damage: StatValue,
     },
     UnitDeath {
         team: Team,
@@ -131,6 +146,12 @@ pub enum CombatEvent {
         damage: StatValue,
         remaining_hp: StatValue,
     },
+    AbilityHeal {
+        source_instance_id: UnitInstanceId,
+        target_instance_id: UnitInstanceId,
+        amount: StatValue,
+        new_health: StatValue,
+    },
     AbilityModifyStats {
         source_instance_id: UnitInstanceId,
         target_instance_id: UnitInstanceId,
@@ -161,6 +182,77 @@ pub enum CombatEvent {
         spawned_unit: UnitView,
         new_board_state: Vec<UnitView>,
     },
+    StatusApplied {
+        target_instance_id: UnitInstanceId,
+        status: Status,
+        permanent: bool,
+    },
+    StatusRemoved {
+        target_instance_id: UnitInstanceId,
+        status: Status,
+        permanent: bool,
+    },
+    StatusConsumed {
+        target_instance_id: UnitInstanceId,
+        status: Status,
+    },
+    /// A First Strike front landed its hit before the opposing front struck back.
+    FirstStrike {
+        source_instance_id: UnitInstanceId,
+        target_instance_id: UnitInstanceId,
+    },
+    /// Excess clash damage carried past the opposing front into the next
+    /// enemy. Only recorded when the hit lands; `damage` is the health lost.
+    Trample {
+        source_instance_id: UnitInstanceId,
+        target_instance_id: UnitInstanceId,
+        damage: StatValue,
+        remaining_hp: StatValue,
+    },
+    /// A Windfury front is about to take its second swing this round.
+    Windfury {
+        source_instance_id: UnitInstanceId,
+    },
+    /// A Reach unit in slot 1 hit the opposing front. Only recorded when the
+    /// hit lands; `damage` is the health lost.
+    Reach {
+        source_instance_id: UnitInstanceId,
+        target_instance_id: UnitInstanceId,
+        damage: StatValue,
+        remaining_hp: StatValue,
+    },
+    /// A unit became a copy of another unit (Mimic).
+    UnitTransform {
+        team: Team,
+        transformed_unit: UnitView,
+        new_board_state: Vec<UnitView>,
+    },
+    /// An enemy unit switched sides (Steal) and now fights for `team` under
+    /// `new_instance_id`.
+    UnitStolen {
+        source_instance_id: UnitInstanceId,
+        old_instance_id: UnitInstanceId,
+        new_instance_id: UnitInstanceId,
+        team: Team,
+        player_board_state: Vec<UnitView>,
+        enemy_board_state: Vec<UnitView>,
+    },
+    /// A unit lost all of its abilities for the rest of the battle (Silence).
+    UnitSilenced {
+        source_instance_id: UnitInstanceId,
+        target_instance_id: UnitInstanceId,
+    },
+    /// A battlefield modifier is in effect for both teams. Emitted once per
+    /// modifier before the start phase.
+    ModifierActive {
+        modifier: BattleModifier,
+    },
+    /// Fatigue hit a front unit at the end of a stalled round.
+    FatigueDamage {
+        target_instance_id: UnitInstanceId,
+        damage: StatValue,
+        remaining_hp: StatValue,
+    },
     LimitExceeded {
         losing_team: Option<Team>,
         reason: LimitReason,
@@ -200,19 +292,20 @@ struct TriggerPriority {
 
 /// Trigger struct with support for location-based spawning.
 /// All captured triggers fire regardless of source liveness (stack semantics).
+/// The ability is referenced by source and index, and looked up through the
+/// `TriggerRegistry` when the trigger resolves.
 #[derive(Debug)]
 struct PendingTrigger {
     source_id: UnitInstanceId,
     team: Team,
-    effect: AbilityEffect,
     priority: TriggerPriority,
     spawn_index_override: Option<usize>,
     trigger_target_id: Option<UnitInstanceId>,
-    conditions: Vec<Condition>,
     /// Index of this ability in the source unit's abilities vec (for tracking trigger counts)
     ability_index: usize,
-    /// Max triggers allowed for this ability (None = unlimited)
-    max_triggers: Option<CountValue>,
+    /// The source's `ability_epoch` when captured, so a trigger queued before
+    /// the source changed its abilities still resolves the ability it captured.
+    ability_epoch: CountValue,
 }
 
 #[derive(Debug, Clone, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
@@ -226,9 +319,39 @@ pub struct CombatUnit {
     pub card_id: crate::types::CardId,
     pub attack_buff: StatValue,
     pub health_buff: StatValue,
+    /// Health from the card definition. Max health is this plus `health_buff`.
+    pub base_health: StatValue,
     pub play_cost: ManaValue,
     /// Tracks how many times each ability has triggered this battle (indexed by ability position)
     pub ability_trigger_counts: Vec<CountValue>,
+    /// Tracks how many times each ability has triggered this round (reset as each round begins)
+    pub ability_round_trigger_counts: Vec<CountValue>,
+    /// Tracks how many activations of each ability passed their conditions, for cooldowns
+    pub ability_activation_counts: Vec<CountValue>,
+    /// Statuses from the card definition.
+    pub base_statuses: StatusMask,
+    /// Statuses carried in from the board unit (granted in earlier shops/battles).
+    pub permanent_statuses: StatusMask,
+    /// Statuses granted during this battle only.
+    pub battle_statuses: StatusMask,
+    /// Statuses used up during this battle (e.g. a Shield that blocked a hit).
+    pub consumed_statuses: StatusMask,
+    /// Tribes from the card definition.
+    pub tags: TagMask,
+    /// Combat keywords from the card definition.
+    pub keywords: KeywordMask,
+    /// Unit that last damaged this one; credited with the kill if it faints.
+    pub last_damaged_by: Option<UnitInstanceId>,
+    /// Health before the first hit since hurt triggers were last captured.
+    /// Used to detect health-threshold crossings.
+    pub hurt_from_health: Option<StatValue>,
+    /// Abilities were removed (Silence); triggers already queued for this unit are dropped.
+    pub silenced: bool,
+    /// Bumped whenever `abilities` is replaced during a battle. Battle-only
+    /// bookkeeping, never encoded.
+    #[codec(skip)]
+    #[cfg_attr(feature = "std", serde(skip))]
+    ability_epoch: CountValue,
 }
 
 impl CombatUnit {
@@ -243,9 +366,111 @@ impl CombatUnit {
             card_id: card.id,
             attack_buff: 0,
             health_buff: 0,
+            base_health: card.stats.health,
             play_cost: card.economy.play_cost,
             ability_trigger_counts: vec![0; ability_count],
+            ability_round_trigger_counts: vec![0; ability_count],
+            ability_activation_counts: vec![0; ability_count],
+            base_statuses: card.base_statuses,
+            permanent_statuses: StatusMask::empty(),
+            battle_statuses: StatusMask::empty(),
+            consumed_statuses: StatusMask::empty(),
+            tags: card.tags,
+            keywords: card.keywords,
+            last_damaged_by: None,
+            hurt_from_health: None,
+            silenced: false,
+            ability_epoch: 0,
+        }
+    }
+
+    /// Removes every ability from this unit. Used by the Silence effect and
+    /// for board units silenced in the shop.
+    pub fn silence(&mut self) {
+        self.abilities.clear();
+        self.ability_trigger_counts.clear();
+        self.ability_round_trigger_counts.clear();
+        self.ability_activation_counts.clear();
+        self.silenced = true;
+        self.ability_epoch = self.ability_epoch.wrapping_add(1);
+    }
+
+    /// The per-battle or per-round allowance the ability at `index` has used up, if any.
+    fn trigger_limit_reached(
+        &self,
+        index: usize,
+        max_triggers: Option<CountValue>,
+        max_triggers_per_round: Option<CountValue>,
+    ) -> Option<SkipReason> {
+        let reached = |counts: &[CountValue], max: Option<CountValue>| {
+            max.is_some_and(|max| counts.get(index).copied().unwrap_or(0) >= max)
+        };
+        if reached(&self.ability_trigger_counts, max_triggers) {
+            Some(SkipReason::MaxTriggers)
+        } else if reached(&self.ability_round_trigger_counts, max_triggers_per_round) {
+            Some(SkipReason::MaxTriggersPerRound)
+        } else {
+            None
+        }
+    }
+
+    /// Counts an activation of the ability at `index` and reports whether its
+    /// cooldown lets this one fire.
+    fn advance_cooldown(&mut self, index: usize, cooldown: CountValue) -> bool {
+        let Some(count) = self.ability_activation_counts.get_mut(index) else {
+            return true;
+        };
+        *count = count.saturating_add(1);
+        *count % cooldown.max(1) == 0
+    }
+
+    /// Clears per-battle bookkeeping for a unit that re-enters the board.
+    fn reset_ability_counters(&mut self) {
+        let ability_count = self.abilities.len();
+        self.ability_trigger_counts = vec![0; ability_count];
+        self.ability_round_trigger_counts = vec![0; ability_count];
+        self.ability_activation_counts = vec![0; ability_count];
+        self.last_damaged_by = None;
+        self.hurt_from_health = None;
+    }
+
+    /// Brings a fallen unit back to life with the requested stats.
+    fn revive(&mut self, stats: ResurrectStats) {
+        if stats == ResurrectStats::Base {
+            self.attack_buff = 0;
+            self.health_buff = 0;
+            self.permanent_statuses = StatusMask::empty();
+            self.battle_statuses = StatusMask::empty();
+            self.consumed_statuses = StatusMask::empty();
+        }
+        self.health = self.max_health().max(1);
+        self.reset_ability_counters();
+    }
+
+    /// Turns this unit into a copy of `model`, keeping its own identity and
+    /// granted statuses. A fallen model is copied at full max health.
+    /// A silenced unit stays silenced and copies no abilities.
+    fn become_copy_of(&mut self, model: &CombatUnit) {
+        self.card_id = model.card_id;
+        self.attack = model.attack;
+        self.attack_buff = model.attack_buff;
+        self.health_buff = model.health_buff;
+        self.base_health = model.base_health;
+        self.health = if model.is_alive() {
+            model.health
+        } else {
+            model.max_health().max(1)
+        };
+        self.play_cost = model.play_cost;
+        if !self.silenced {
+            self.abilities = model.abilities.clone();
+            self.ability_epoch = self.ability_epoch.wrapping_add(1);
         }
+        self.base_statuses = model.base_statuses;
+        self.consumed_statuses = StatusMask::empty();
+        self.tags = model.tags;
+        self.keywords = model.keywords;
+        self.reset_ability_counters();
     }
 
     fn to_view(&self, card_pool: &BTreeMap<CardId, UnitCard>) -> UnitView {
@@ -260,6 +485,7 @@ impl CombatUnit {
             attack: self.effective_attack(),
             health: self.effective_health(),
             battle_abilities: self.abilities.clone(),
+            statuses: self.active_statuses(),
         }
     }
 
@@ -271,6 +497,18 @@ impl CombatUnit {
         self.health.max(0)
     }
 
+    /// Buffs raise max health; damage does not lower it.
+    pub fn max_health(&self) -> StatValue {
+        self.base_health.saturating_add(self.health_buff).max(0)
+    }
+
+    /// Health lost to damage (max health minus current health).
+    pub fn missing_health(&self) -> StatValue {
+        self.max_health()
+            .saturating_sub(self.effective_health())
+            .max(0)
+    }
+
     pub fn is_alive(&self) -> bool {
         self.health > 0
     }
@@ -279,39 +517,256 @@ impl CombatUnit {
         let actual_damage = amount.max(0);
         self.health = self.health.saturating_sub(actual_damage);
     }
+
+    /// Active statuses: (base | permanent | battle) - consumed.
+    pub fn active_statuses(&self) -> StatusMask {
+        self.base_statuses
+            .union(&self.permanent_statuses)
+            .union(&self.battle_statuses)
+            .difference(&self.consumed_statuses)
+    }
+
+    pub fn has_status(&self, status: Status) -> bool {
+        self.active_statuses().contains(status)
+    }
+
+    pub fn has_keyword(&self, keyword: Keyword) -> bool {
+        self.keywords.contains(keyword)
+    }
+
+    /// Record an incoming hit from `source`. Call before the damage is applied.
+    fn note_hit(&mut self, source: UnitInstanceId) {
+        self.last_damaged_by = Some(source);
+        self.hurt_from_health.get_or_insert(self.health);
+    }
+
+    /// Consume an active Shield, returning true if the hit was blocked.
+    fn try_block_with_shield<S: EventSink>(&mut self, events: &mut S) -> bool {
+        if !self.has_status(Status::Shield) {
+            return false;
+        }
+        self.consumed_statuses.insert(Status::Shield);
+        events.record(CombatEvent::StatusConsumed {
+            target_instance_id: self.instance_id,
+            status: Status::Shield,
+        });
+        true
+    }
 }
 
 // ==========================================
 // TRIGGER REGISTRY (Observer/Pub-Sub)
 // ==========================================
 
-/// Maps trigger types to subscribed units for O(1) trigger relevance checks.
+/// Counts subscribers per trigger type for O(1) trigger relevance checks.
 /// Unused triggers cost zero — no iteration over non-subscribing units.
+///
+/// Queued triggers read their ability from the source unit when they resolve.
+/// Before a unit replaces its abilities or takes a new instance id, its old
+/// list is retired here so triggers it already queued still find it.
+#[derive(Clone)]
 struct TriggerRegistry {
-    buckets: [Vec<UnitInstanceId>; crate::types::TRIGGER_VARIANT_COUNT],
+    subscribers: [u16; crate::types::TRIGGER_VARIANT_COUNT],
+    retired: BTreeMap<(UnitInstanceId, CountValue), Vec<Ability>>,
 }
 
 impl TriggerRegistry {
     fn new() -> Self {
         Self {
-            buckets: core::array::from_fn(|_| Vec::new()),
+            subscribers: [0; crate::types::TRIGGER_VARIANT_COUNT],
+            retired: BTreeMap::new(),
         }
     }
 
     fn register_unit(&mut self, unit: &CombatUnit) {
         for ability in &unit.abilities {
-            self.buckets[ability.trigger.index()].push(unit.instance_id);
+            let count = &mut self.subscribers[ability.trigger.index()];
+            *count = count.saturating_add(1);
         }
     }
 
-    fn unregister_unit(&mut self, instance_id: UnitInstanceId) {
-        for bucket in &mut self.buckets {
-            bucket.retain(|id| *id != instance_id);
+    /// Call before the unit loses or replaces the abilities it registered.
+    fn unregister_unit(&mut self, unit: &CombatUnit) {
+        for ability in &unit.abilities {
+            let count = &mut self.subscribers[ability.trigger.index()];
+            *count = count.saturating_sub(1);
         }
     }
 
     fn has_subscribers(&self, trigger: AbilityTrigger) -> bool {
-        !self.buckets[trigger.index()].is_empty()
+        self.subscribers[trigger.index()] > 0
+    }
+
+    fn retire_abilities(&mut self, unit: &CombatUnit) {
+        if !unit.abilities.is_empty() {
+            self.retired
+                .entry((unit.instance_id, unit.ability_epoch))
+                .or_insert_with(|| unit.abilities.clone());
+        }
+    }
+
+    /// The ability `trigger` captured, given its source as it is now.
+    fn captured_ability<'a>(
+        &'a self,
+        trigger: &PendingTrigger,
+        source: Option<&'a CombatUnit>,
+    ) -> Option<&'a Ability> {
+        match source {
+            Some(unit) if unit.ability_epoch == trigger.ability_epoch => {
+                unit.abilities.get(trigger.ability_index)
+            }
+            _ => self
+                .retired
+                .get(&(trigger.source_id, trigger.ability_epoch))?
+                .get(trigger.ability_index),
+        }
+    }
+}
+
+// ==========================================
+// UNIT LISTS
+// ==========================================
+
+/// One team's units in order, with an index from instance ID to position so
+/// lookups by ID don't scan the list. Units only enter and leave through
+/// `push`, `insert` and `remove`, which keep the index in sync; instance IDs
+/// must not change while a unit is in the list.
+#[derive(Debug, Clone, Default)]
+struct UnitList {
+    units: Vec<CombatUnit>,
+    /// Position + 1 of each unit, by the team-less part of its instance ID.
+    /// 0 means the ID is not in the list.
+    slots: Vec<u16>,
+}
+
+impl UnitList {
+    fn key(instance_id: UnitInstanceId) -> usize {
+        usize::from(instance_id.raw() & !UnitId::ENEMY_MASK)
+    }
+
+    /// Points the index at every unit from `from` onwards.
+    fn reindex_from(&mut self, from: usize) {
+        for (idx, unit) in self.units.iter().enumerate().skip(from) {
+            let key = Self::key(unit.instance_id);
+            if key >= self.slots.len() {
+                self.slots.resize(key + 1, 0);
+            }
+            self.slots[key] = (idx + 1) as u16;
+        }
+    }
+
+    fn push(&mut self, unit: CombatUnit) {
+        self.units.push(unit);
+        self.reindex_from(self.units.len() - 1);
+    }
+
+    fn insert(&mut self, idx: usize, unit: CombatUnit) {
+        self.units.insert(idx, unit);
+        self.reindex_from(idx);
+    }
+
+    fn remove(&mut self, idx: usize) -> CombatUnit {
+        let unit = self.units.remove(idx);
+        self.slots[Self::key(unit.instance_id)] = 0;
+        self.reindex_from(idx);
+        unit
+    }
+
+    fn position(&self, instance_id: UnitInstanceId) -> Option<usize> {
+        let idx = usize::from(*self.slots.get(Self::key(instance_id))?).checked_sub(1)?;
+        debug_assert_eq!(self.units[idx].instance_id, instance_id);
+        Some(idx)
+    }
+
+    fn find(&self, instance_id: UnitInstanceId) -> Option<&CombatUnit> {
+        self.position(instance_id).map(|idx| &self.units[idx])
+    }
+
+    fn find_mut(&mut self, instance_id: UnitInstanceId) -> Option<&mut CombatUnit> {
+        self.position(instance_id).map(|idx| &mut self.units[idx])
+    }
+}
+
+impl From<Vec<CombatUnit>> for UnitList {
+    fn from(units: Vec<CombatUnit>) -> Self {
+        let mut list = Self {
+            units,
+            slots: Vec::new(),
+        };
+        list.reindex_from(0);
+        list
+    }
+}
+
+impl core::ops::Deref for UnitList {
+    type Target = [CombatUnit];
+
+    fn deref(&self) -> &[CombatUnit] {
+        &self.units
+    }
+}
+
+impl core::ops::DerefMut for UnitList {
+    fn deref_mut(&mut self) -> &mut [CombatUnit] {
+        &mut self.units
+    }
+}
+
+// ==========================================
+// GRAVEYARD
+// ==========================================
+
+/// Fallen units of each team, in the order they died. Lives for the whole
+/// battle so effects can read and revive units that fell in earlier phases.
+#[derive(Debug, Clone, Default)]
+struct Graveyard {
+    player: UnitList,
+    enemy: UnitList,
+}
+
+impl Graveyard {
+    fn team_mut(&mut self, team: Team) -> &mut UnitList {
+        match team {
+            Team::Player => &mut self.player,
+            Team::Enemy => &mut self.enemy,
+        }
+    }
+
+    fn push(&mut self, unit: CombatUnit) {
+        self.team_mut(unit.team).push(unit);
+    }
+
+    fn find(&self, instance_id: UnitInstanceId) -> Option<&CombatUnit> {
+        let fallen = if instance_id.is_player() {
+            &self.player
+        } else {
+            &self.enemy
+        };
+        fallen.find(instance_id)
+    }
+
+    fn find_mut(&mut self, instance_id: UnitInstanceId) -> Option<&mut CombatUnit> {
+        let fallen = if instance_id.is_player() {
+            &mut self.player
+        } else {
+            &mut self.enemy
+        };
+        fallen.find_mut(instance_id)
+    }
+
+    /// Removes and returns the fallen unit of `team` chosen by `pick`.
+    fn take(&mut self, team: Team, pick: ResurrectPick) -> Option<CombatUnit> {
+        let fallen = self.team_mut(team);
+        let index = match pick {
+            ResurrectPick::MostRecent => fallen.len().checked_sub(1),
+            // `max_by_key` keeps the last maximum, so ties go to the most recent.
+            ResurrectPick::Strongest => fallen
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, u)| u.effective_attack())
+                .map(|(i, _)| i),
+        }?;
+        Some(fallen.remove(index))
     }
 }
 
@@ -328,27 +783,42 @@ fn capture_triggers_for_unit(
     trigger_type: AbilityTrigger,
     trigger_target_id: Option<UnitInstanceId>,
     spawn_index_override: Option<usize>,
-) -> Vec<PendingTrigger> {
-    let mut triggers = Vec::new();
-    for (sub_idx, ability) in unit.abilities.iter().enumerate() {
-        if ability.trigger != trigger_type {
-            continue;
-        }
-        if let Some(max) = ability.max_triggers {
-            if unit
-                .ability_trigger_counts
-                .get(sub_idx)
-                .copied()
-                .unwrap_or(0)
-                >= max
-            {
-                continue;
-            }
-        }
-        triggers.push(PendingTrigger {
+) -> impl Iterator<Item = PendingTrigger> + '_ {
+    capture_matching_triggers(
+        unit,
+        unit_position,
+        team,
+        move |trigger| *trigger == trigger_type,
+        trigger_target_id,
+        spawn_index_override,
+    )
+}
+
+/// Like [`capture_triggers_for_unit`], for parameterised triggers matched by a predicate.
+fn capture_matching_triggers<'a>(
+    unit: &'a CombatUnit,
+    unit_position: usize,
+    team: Team,
+    matches: impl Fn(&AbilityTrigger) -> bool + 'a,
+    trigger_target_id: Option<UnitInstanceId>,
+    spawn_index_override: Option<usize>,
+) -> impl Iterator<Item = PendingTrigger> + 'a {
+    unit.abilities
+        .iter()
+        .enumerate()
+        .filter(move |(sub_idx, ability)| {
+            matches(&ability.trigger)
+                && unit
+                    .trigger_limit_reached(
+                        *sub_idx,
+                        ability.max_triggers,
+                        ability.max_triggers_per_round,
+                    )
+                    .is_none()
+        })
+        .map(move |(sub_idx, _)| PendingTrigger {
             source_id: unit.instance_id,
             team,
-            effect: ability.effect.clone(),
             priority: TriggerPriority {
                 attack: unit.effective_attack(),
                 health: unit.effective_health(),
@@ -358,169 +828,630 @@ fn capture_triggers_for_unit(
             },
             spawn_index_override,
             trigger_target_id,
-            conditions: ability.conditions.clone(),
             ability_index: sub_idx,
-            max_triggers: ability.max_triggers,
-        });
-    }
-    triggers
+            ability_epoch: unit.ability_epoch,
+        })
 }
 
 /// Find a unit by instance ID and return its position and a reference.
-fn find_unit_with_position(
-    id: UnitInstanceId,
-    units: &[CombatUnit],
-) -> Option<(usize, &CombatUnit)> {
-    units.iter().enumerate().find(|(_, u)| u.instance_id == id)
+fn find_unit_with_position(id: UnitInstanceId, units: &UnitList) -> Option<(usize, &CombatUnit)> {
+    units.position(id).map(|idx| (idx, &units[idx]))
 }
 
-// ==========================================
-// MAIN BATTLE RESOLVER
-// ==========================================
-
-pub fn resolve_battle<R: BattleRng>(
-    mut player_units: Vec<CombatUnit>,
-    mut enemy_units: Vec<CombatUnit>,
-    rng: &mut R,
-    card_pool: &BTreeMap<CardId, UnitCard>,
-    board_size: usize,
-) -> Vec<CombatEvent> {
-    let mut events = Vec::new();
-    let mut limits = BattleLimits::new();
-
-    // Assign stable instance IDs and teams if not already set
-    // Note: This assumes the input units might have placeholder IDs/teams.
-    for unit in &mut player_units {
-        unit.instance_id = limits.generate_instance_id(Team::Player);
-        unit.team = Team::Player;
-    }
-    for unit in &mut enemy_units {
-        unit.instance_id = limits.generate_instance_id(Team::Enemy);
-        unit.team = Team::Enemy;
-    }
-
-    // Build trigger registry from initial boards
-    let mut registry = TriggerRegistry::new();
-    for unit in player_units.iter() {
-        registry.register_unit(unit);
-    }
-    for unit in enemy_units.iter() {
-        registry.register_unit(unit);
+/// Capture OnHurt and OnHealthBelow for a damaged unit and OnAllyHurt for the
+/// rest of its board. Runs before the death check, so fatally-damaged units
+/// still react to being hurt.
+fn capture_hurt_triggers(
+    hurt_id: UnitInstanceId,
+    attacker_id: Option<UnitInstanceId>,
+    player_units: &mut UnitList,
+    enemy_units: &mut UnitList,
+    registry: &TriggerRegistry,
+    queue: &mut Vec<PendingTrigger>,
+) {
+    let (units, team) = if hurt_id.is_player() {
+        (player_units, Team::Player)
+    } else {
+        (enemy_units, Team::Enemy)
+    };
+    let Some(idx) = units.position(hurt_id) else {
+        return;
+    };
+    let health_before = units[idx].hurt_from_health.take();
+    let unit = &units[idx];
+    let spawn_override = if unit.health <= 0 { Some(idx) } else { None };
+
+    if registry.has_subscribers(AbilityTrigger::OnHurt) {
+        queue.extend(capture_triggers_for_unit(
+            unit,
+            idx,
+            team,
+            AbilityTrigger::OnHurt,
+            attacker_id,
+            spawn_override,
+        ));
     }
 
-    // 1. Start of Battle Phase
-    limits.reset_phase_counters();
-    if execute_phase(
-        BattlePhase::Start,
-        &mut player_units,
-        &mut enemy_units,
-        &mut events,
-        rng,
-        &mut limits,
-        card_pool,
-        None,
-        &mut registry,
-        board_size,
-    )
-    .is_err()
-        || limits.is_exceeded()
-    {
-        return finalize_with_limit_exceeded(&mut events, &limits);
+    // Thresholds fire only for survivors whose health crossed them with this damage.
+    if let Some(before) = health_before.filter(|_| unit.is_alive()) {
+        let max_health = unit.max_health();
+        queue.extend(capture_matching_triggers(
+            unit,
+            idx,
+            team,
+            |trigger| match trigger {
+                AbilityTrigger::OnHealthBelow { threshold } => {
+                    !threshold.is_reached(before, max_health)
+                        && threshold.is_reached(unit.health, max_health)
+                }
+                _ => false,
+            },
+            attacker_id,
+            None,
+        ));
     }
 
-    // 2. Main Loop
-    while !player_units.is_empty() && !enemy_units.is_empty() {
-        if limits.record_round().is_err() {
-            return finalize_with_limit_exceeded(&mut events, &limits);
-        }
-
-        // Capture front unit IDs before any triggers fire this round
-        let pre_clash = ClashContext::capture(&player_units, &enemy_units);
-
-        // Before Attack — clash context = who is about to clash
-        limits.reset_phase_counters();
-        if execute_phase(
-            BattlePhase::BeforeAttack,
-            &mut player_units,
-            &mut enemy_units,
-            &mut events,
-            rng,
-            &mut limits,
-            card_pool,
-            Some(pre_clash),
-            &mut registry,
-            board_size,
-        )
-        .is_err()
-            || limits.is_exceeded()
-        {
-            return finalize_with_limit_exceeded(&mut events, &limits);
-        }
-
-        // The Clash (Attack) — all after-attack triggers are captured eagerly
-        // inside resolve_hurt_and_faint_loop before the death check.
-        limits.reset_phase_counters();
-        if execute_phase(
-            BattlePhase::Attack,
-            &mut player_units,
-            &mut enemy_units,
-            &mut events,
-            rng,
-            &mut limits,
-            card_pool,
-            None,
-            &mut registry,
-            board_size,
-        )
-        .is_err()
-            || limits.is_exceeded()
-        {
-            return finalize_with_limit_exceeded(&mut events, &limits);
+    if registry.has_subscribers(AbilityTrigger::OnAllyHurt) {
+        for (i, ally) in units.iter().enumerate() {
+            if ally.instance_id == hurt_id {
+                continue;
+            }
+            queue.extend(capture_triggers_for_unit(
+                ally,
+                i,
+                team,
+                AbilityTrigger::OnAllyHurt,
+                Some(hurt_id),
+                if ally.health <= 0 { Some(i) } else { None },
+            ));
         }
     }
-
-    // 3. Battle End
-    let _ = execute_phase(
-        BattlePhase::End,
-        &mut player_units,
-        &mut enemy_units,
-        &mut events,
-        rng,
-        &mut limits,
-        card_pool,
-        None,
-        &mut registry,
-        board_size,
-    );
-    events
 }
 
-/// Extract total next-shop mana delta for a team from battle events.
-pub fn shop_mana_delta_from_events(events: &[CombatEvent], team: Team) -> i16 {
-    let mut total = 0i16;
-    for event in events {
-        if let CombatEvent::AbilityGainMana {
-            team: event_team,
-            amount,
-            ..
-        } = event
-        {
-            if *event_team == team {
-                total = total.saturating_add(*amount as i16);
+/// Capture death reactions for units removed by a death check, then move them
+/// to the graveyard: OnKill for the enemy that dealt the fatal damage, OnFaint
+/// for the fallen unit, OnAllyFaint for its surviving allies and OnEnemyFaint
+/// for surviving enemies.
+fn capture_death_triggers(
+    dead_player: FallenUnits,
+    dead_enemy: FallenUnits,
+    player_units: &UnitList,
+    enemy_units: &UnitList,
+    registry: &mut TriggerRegistry,
+    graveyard: &mut Graveyard,
+    queue: &mut Vec<PendingTrigger>,
+) {
+    // Killers may have fallen in the same death check, so look them up before
+    // anything moves to the graveyard.
+    if registry.has_subscribers(AbilityTrigger::OnKill) {
+        for (_, victim) in dead_player.iter().chain(dead_enemy.iter()) {
+            let Some(killer_id) = victim.last_damaged_by else {
+                continue;
+            };
+            if killer_id.is_player() == victim.instance_id.is_player() {
+                continue;
+            }
+            let killer = find_unit_with_position(killer_id, player_units)
+                .or_else(|| find_unit_with_position(killer_id, enemy_units))
+                .map(|(idx, unit)| (idx, unit, None))
+                .or_else(|| {
+                    dead_player
+                        .iter()
+                        .chain(dead_enemy.iter())
+                        .find(|(_, u)| u.instance_id == killer_id)
+                        .map(|(idx, unit)| (*idx, unit, Some(*idx)))
+                });
+            if let Some((idx, unit, spawn_override)) = killer {
+                queue.extend(capture_triggers_for_unit(
+                    unit,
+                    idx,
+                    unit.team,
+                    AbilityTrigger::OnKill,
+                    Some(victim.instance_id),
+                    spawn_override,
+                ));
             }
         }
     }
-    total
-}
 
-/// Convenience helper for the local player's next-shop mana delta.
-pub fn player_shop_mana_delta_from_events(events: &[CombatEvent]) -> i16 {
-    shop_mana_delta_from_events(events, Team::Player)
-}
-
-/// Extract total permanent stat deltas for a team's units from battle events.
-pub fn permanent_stat_deltas_from_events(
-    events: &[CombatEvent],
+    for (dead, team, allies, enemies) in [
+        (dead_player, Team::Player, player_units, enemy_units),
+        (dead_enemy, Team::Enemy, enemy_units, player_units),
+    ] {
+        for (idx, dead_unit) in dead {
+            let dead_id = dead_unit.instance_id;
+            if registry.has_subscribers(AbilityTrigger::OnFaint) {
+                queue.extend(capture_triggers_for_unit(
+                    &dead_unit,
+                    idx,
+                    team,
+                    AbilityTrigger::OnFaint,
+                    Some(dead_id),
+                    Some(idx),
+                ));
+            }
+            registry.unregister_unit(&dead_unit);
+            if registry.has_subscribers(AbilityTrigger::OnAllyFaint) {
+                for (s_idx, survivor) in allies.iter().enumerate() {
+                    queue.extend(capture_triggers_for_unit(
+                        survivor,
+                        s_idx,
+                        team,
+                        AbilityTrigger::OnAllyFaint,
+                        Some(dead_id),
+                        Some(idx),
+                    ));
+                }
+            }
+            if registry.has_subscribers(AbilityTrigger::OnEnemyFaint) {
+                for (s_idx, survivor) in enemies.iter().enumerate() {
+                    queue.extend(capture_triggers_for_unit(
+                        survivor,
+                        s_idx,
+                        survivor.team,
+                        AbilityTrigger::OnEnemyFaint,
+                        Some(dead_id),
+                        None,
+                    ));
+                }
+            }
+            graveyard.push(dead_unit);
+        }
+    }
+}
+
+// ==========================================
+// MAIN BATTLE RESOLVER
+// ==========================================
+
+pub fn resolve_battle<R: BattleRng>(
+    player_units: Vec<CombatUnit>,
+    enemy_units: Vec<CombatUnit>,
+    rng: &mut R,
+    card_pool: &BTreeMap<CardId, UnitCard>,
+    rules: &BattleRules,
+) -> Vec<CombatEvent> {
+    resolve_battle_with_modifiers(player_units, enemy_units, rng, card_pool, rules, &[])
+}
+
+/// Resolve a battle with battlefield modifiers applied to both teams.
+/// Modifiers that change units are applied to the starting boards and to the
+/// card pool, so spawned and resurrected units follow them too.
+pub fn resolve_battle_with_modifiers<R: BattleRng>(
+    player_units: Vec<CombatUnit>,
+    enemy_units: Vec<CombatUnit>,
+    rng: &mut R,
+    card_pool: &BTreeMap<CardId, UnitCard>,
+    rules: &BattleRules,
+    modifiers: &[BattleModifier],
+) -> Vec<CombatEvent> {
+    let mut events = Vec::new();
+    resolve_battle_into(
+        player_units,
+        enemy_units,
+        rng,
+        card_pool,
+        rules,
+        modifiers,
+        &mut events,
+    );
+    events
+}
+
+/// Resolve a battle for its outcome only. Draws the same RNG values and
+/// reaches the same result as `resolve_battle_with_modifiers`, without
+/// keeping an event log.
+pub fn resolve_battle_outcome<R: BattleRng>(
+    player_units: Vec<CombatUnit>,
+    enemy_units: Vec<CombatUnit>,
+    rng: &mut R,
+    card_pool: &BTreeMap<CardId, UnitCard>,
+    rules: &BattleRules,
+    modifiers: &[BattleModifier],
+) -> BattleOutcome {
+    let mut outcome = BattleOutcome::default();
+    resolve_battle_into(
+        player_units,
+        enemy_units,
+        rng,
+        card_pool,
+        rules,
+        modifiers,
+        &mut outcome,
+    );
+    outcome
+}
+
+/// Resolve a battle, reporting every event to `events`.
+pub fn resolve_battle_into<R: BattleRng, S: EventSink>(
+    player_units: Vec<CombatUnit>,
+    enemy_units: Vec<CombatUnit>,
+    rng: &mut R,
+    card_pool: &BTreeMap<CardId, UnitCard>,
+    rules: &BattleRules,
+    modifiers: &[BattleModifier],
+    events: &mut S,
+) {
+    BattleSimulator::new(player_units, enemy_units, rng, card_pool, rules, modifiers)
+        .run_into(events);
+}
+
+/// A battle that runs one step at a time. Each step is one phase; a round
+/// is `BeforeAttack`, `Attack` and `Fatigue`. Between steps `snapshot` shows
+/// the full state of both boards, and the events and final result match
+/// `resolve_battle_with_modifiers` for the same inputs and RNG.
+pub struct BattleSimulator<'a, R: BattleRng> {
+    state: BattleState,
+    rng: R,
+    card_pool: Cow<'a, BTreeMap<CardId, UnitCard>>,
+    modifiers: Vec<BattleModifier>,
+    /// Events recorded while setting up, reported with the first step.
+    opening: Vec<CombatEvent>,
+}
+
+impl<'a, R: BattleRng> BattleSimulator<'a, R> {
+    /// Set up a battle. Modifiers are applied to both boards and the card
+    /// pool; nothing has been resolved yet.
+    pub fn new(
+        player_units: Vec<CombatUnit>,
+        enemy_units: Vec<CombatUnit>,
+        rng: R,
+        card_pool: &'a BTreeMap<CardId, UnitCard>,
+        rules: &BattleRules,
+        modifiers: &[BattleModifier],
+    ) -> Self {
+        let mut opening = Vec::new();
+        let state = BattleState::new(player_units, enemy_units, rules, modifiers, &mut opening);
+        Self {
+            state,
+            rng,
+            card_pool: modified_card_pool(card_pool, modifiers),
+            modifiers: modifiers.to_vec(),
+            opening,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state.is_finished()
+    }
+
+    /// What the next step runs.
+    pub fn next_step(&self) -> BattleStep {
+        self.state.step
+    }
+
+    /// Run the next step and return its events, or None once the battle is
+    /// over.
+    pub fn step(&mut self) -> Option<Vec<CombatEvent>> {
+        let mut events = Vec::new();
+        self.step_into(&mut events).then_some(events)
+    }
+
+    /// Run the next step, reporting its events to `events`. Returns false
+    /// once the battle is over. The step that ends the battle also calls
+    /// `events.finish`.
+    pub fn step_into<S: EventSink>(&mut self, events: &mut S) -> bool {
+        if self.state.is_finished() {
+            return false;
+        }
+        for event in self.opening.drain(..) {
+            events.record(event);
+        }
+        self.state
+            .advance(&mut self.rng, &self.card_pool, &self.modifiers, events);
+        if self.state.is_finished() {
+            events.finish(&self.state.player_units, &self.state.enemy_units);
+        }
+        true
+    }
+
+    /// Run the rest of the battle and return its events.
+    pub fn run_to_end(&mut self) -> Vec<CombatEvent> {
+        let mut events = Vec::new();
+        self.run_into(&mut events);
+        events
+    }
+
+    /// Run the rest of the battle, reporting every event to `events`.
+    pub fn run_into<S: EventSink>(&mut self, events: &mut S) {
+        while self.step_into(events) {}
+    }
+
+    /// Both boards and graveyards as they stand between steps.
+    pub fn snapshot(&self) -> BattleSnapshot {
+        BattleSnapshot {
+            round: self.state.limits.total_rounds,
+            next_step: self.state.step,
+            player_units: self.state.player_units.to_vec(),
+            enemy_units: self.state.enemy_units.to_vec(),
+            player_graveyard: self.state.graveyard.player.to_vec(),
+            enemy_graveyard: self.state.graveyard.enemy.to_vec(),
+        }
+    }
+
+    /// The RNG, for callers that keep drawing from it after the battle.
+    pub fn into_rng(self) -> R {
+        self.rng
+    }
+}
+
+/// The full state of a battle between two steps.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BattleSnapshot {
+    /// Rounds started so far.
+    pub round: LimitValue,
+    pub next_step: BattleStep,
+    pub player_units: Vec<CombatUnit>,
+    pub enemy_units: Vec<CombatUnit>,
+    /// Fallen units in the order they died.
+    pub player_graveyard: Vec<CombatUnit>,
+    pub enemy_graveyard: Vec<CombatUnit>,
+}
+
+/// The card pool as `modifiers` change it, so spawned and resurrected units
+/// follow the modifiers too.
+pub(crate) fn modified_card_pool<'a>(
+    card_pool: &'a BTreeMap<CardId, UnitCard>,
+    modifiers: &[BattleModifier],
+) -> Cow<'a, BTreeMap<CardId, UnitCard>> {
+    if !modifiers.iter().any(BattleModifier::alters_cards) {
+        return Cow::Borrowed(card_pool);
+    }
+    let mut pool = card_pool.clone();
+    for card in pool.values_mut() {
+        for modifier in modifiers {
+            modifier.apply_to_card(card);
+        }
+    }
+    Cow::Owned(pool)
+}
+
+/// What a paused battle runs next. A battle advances one phase at a time;
+/// a round is `BeforeAttack`, `Attack` and `Fatigue`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum BattleStep {
+    Start,
+    BeforeAttack,
+    Attack,
+    Fatigue,
+    End,
+    Finished,
+}
+
+/// A battle paused between phases. No triggers are queued between phases,
+/// so apart from the RNG this is everything the rest of the battle depends on.
+#[derive(Clone)]
+pub(crate) struct BattleState {
+    player_units: UnitList,
+    enemy_units: UnitList,
+    graveyard: Graveyard,
+    registry: TriggerRegistry,
+    limits: BattleLimits,
+    fatigue: FatigueRule,
+    board_size: usize,
+    step: BattleStep,
+}
+
+impl BattleState {
+    /// Set up a battle: apply the modifiers to both boards, announce them and
+    /// assign instance IDs.
+    pub(crate) fn new<S: EventSink>(
+        mut player_units: Vec<CombatUnit>,
+        mut enemy_units: Vec<CombatUnit>,
+        rules: &BattleRules,
+        modifiers: &[BattleModifier],
+        events: &mut S,
+    ) -> Self {
+        let mut limits = BattleLimits::new(rules);
+
+        for unit in player_units.iter_mut().chain(enemy_units.iter_mut()) {
+            for modifier in modifiers {
+                modifier.apply_to_unit(unit);
+            }
+        }
+        for modifier in modifiers {
+            events.record(CombatEvent::ModifierActive {
+                modifier: *modifier,
+            });
+        }
+        let fatigue = modifiers
+            .iter()
+            .find_map(BattleModifier::fatigue_rule)
+            .unwrap_or(rules.fatigue);
+
+        // Assign stable instance IDs and teams if not already set
+        // Note: This assumes the input units might have placeholder IDs/teams.
+        for unit in &mut player_units {
+            unit.instance_id = limits.generate_instance_id(Team::Player);
+            unit.team = Team::Player;
+        }
+        for unit in &mut enemy_units {
+            unit.instance_id = limits.generate_instance_id(Team::Enemy);
+            unit.team = Team::Enemy;
+        }
+
+        // Build trigger registry from initial boards
+        let mut registry = TriggerRegistry::new();
+        for unit in player_units.iter() {
+            registry.register_unit(unit);
+        }
+        for unit in enemy_units.iter() {
+            registry.register_unit(unit);
+        }
+
+        Self {
+            player_units: player_units.into(),
+            enemy_units: enemy_units.into(),
+            graveyard: Graveyard::default(),
+            registry,
+            limits,
+            fatigue,
+            board_size: rules.board_size as usize,
+            step: BattleStep::Start,
+        }
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.step == BattleStep::Finished
+    }
+
+    /// Run the next step. A step that hits a battle limit ends the battle.
+    pub(crate) fn advance<R: BattleRng, S: EventSink>(
+        &mut self,
+        rng: &mut R,
+        card_pool: &BTreeMap<CardId, UnitCard>,
+        modifiers: &[BattleModifier],
+        events: &mut S,
+    ) {
+        let outcome = match self.step {
+            BattleStep::Start => {
+                self.run_phase(BattlePhase::Start, None, 1, rng, card_pool, events)
+            }
+            BattleStep::BeforeAttack => {
+                if self.limits.record_round().is_err() {
+                    Err(())
+                } else {
+                    for unit in self
+                        .player_units
+                        .iter_mut()
+                        .chain(self.enemy_units.iter_mut())
+                    {
+                        unit.ability_round_trigger_counts.fill(0);
+                    }
+                    // Capture front unit IDs before any triggers fire this round
+                    let pre_clash = ClashContext::capture(&self.player_units, &self.enemy_units);
+                    self.run_phase(
+                        BattlePhase::BeforeAttack,
+                        Some(pre_clash),
+                        1,
+                        rng,
+                        card_pool,
+                        events,
+                    )
+                }
+            }
+            // The Clash — all after-attack triggers are captured eagerly
+            // inside resolve_hurt_and_faint_loop before the death check.
+            BattleStep::Attack => {
+                let round_multiplier = clash_multiplier(modifiers, self.limits.total_rounds);
+                self.run_phase(
+                    BattlePhase::Attack,
+                    None,
+                    round_multiplier,
+                    rng,
+                    card_pool,
+                    events,
+                )
+            }
+            // Fatigue — wears down both fronts once the battle has stalled.
+            BattleStep::Fatigue => {
+                self.limits.reset_phase_counters();
+                apply_fatigue(
+                    &self.fatigue,
+                    &mut self.player_units,
+                    &mut self.enemy_units,
+                    &mut self.graveyard,
+                    events,
+                    rng,
+                    &mut self.limits,
+                    card_pool,
+                    &mut self.registry,
+                    self.board_size,
+                )
+            }
+            BattleStep::End => self.run_phase(BattlePhase::End, None, 1, rng, card_pool, events),
+            BattleStep::Finished => return,
+        };
+
+        if outcome.is_err() || self.limits.is_exceeded() {
+            finalize_with_limit_exceeded(events, &self.limits);
+            self.step = BattleStep::Finished;
+            return;
+        }
+
+        let both_standing = !self.player_units.is_empty() && !self.enemy_units.is_empty();
+        self.step = match self.step {
+            BattleStep::Start | BattleStep::Fatigue if both_standing => BattleStep::BeforeAttack,
+            BattleStep::Start | BattleStep::Fatigue => BattleStep::End,
+            BattleStep::BeforeAttack => BattleStep::Attack,
+            BattleStep::Attack => BattleStep::Fatigue,
+            BattleStep::End | BattleStep::Finished => BattleStep::Finished,
+        };
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn run_phase<R: BattleRng, S: EventSink>(
+        &mut self,
+        phase: BattlePhase,
+        clash_context: Option<ClashContext>,
+        clash_multiplier: StatValue,
+        rng: &mut R,
+        card_pool: &BTreeMap<CardId, UnitCard>,
+        events: &mut S,
+    ) -> Result<(), ()> {
+        self.limits.reset_phase_counters();
+        execute_phase(
+            phase,
+            &mut self.player_units,
+            &mut self.enemy_units,
+            &mut self.graveyard,
+            events,
+            rng,
+            &mut self.limits,
+            card_pool,
+            clash_context,
+            clash_multiplier,
+            &mut self.registry,
+            self.board_size,
+        )
+    }
+
+    /// Encodes everything the rest of the battle depends on, so two states
+    /// with equal keys play out the same. Retired abilities are left out:
+    /// they only serve triggers that are still queued.
+    #[cfg(feature = "std")]
+    pub(crate) fn memo_key(&self) -> Vec<u8> {
+        (
+            &self.player_units[..],
+            &self.enemy_units[..],
+            &self.graveyard.player[..],
+            &self.graveyard.enemy[..],
+            &self.limits,
+            &self.registry.subscribers,
+            self.step,
+        )
+            .encode()
+    }
+}
+
+/// Extract total next-shop mana delta for a team from battle events.
+pub fn shop_mana_delta_from_events(events: &[CombatEvent], team: Team) -> i16 {
+    let mut total = 0i16;
+    for event in events {
+        if let CombatEvent::AbilityGainMana {
+            team: event_team,
+            amount,
+            ..
+        } = event
+        {
+            if *event_team == team {
+                total = total.saturating_add(*amount as i16);
+            }
+        }
+    }
+    total
+}
+
+/// Convenience helper for the local player's next-shop mana delta.
+pub fn player_shop_mana_delta_from_events(events: &[CombatEvent]) -> i16 {
+    shop_mana_delta_from_events(events, Team::Player)
+}
+
+/// Extract total permanent stat deltas for a team's units from battle events.
+pub fn permanent_stat_deltas_from_events(
+    events: &[CombatEvent],
     team: Team,
 ) -> BTreeMap<UnitId, (StatValue, StatValue)> {
     let mut deltas: BTreeMap<UnitId, (StatValue, StatValue)> = BTreeMap::new();
@@ -553,119 +1484,316 @@ pub fn player_permanent_stat_deltas_from_events(
     permanent_stat_deltas_from_events(events, Team::Player)
 }
 
-fn finalize_with_limit_exceeded(
-    events: &mut Vec<CombatEvent>,
-    limits: &BattleLimits,
-) -> Vec<CombatEvent> {
+/// Extract net permanent status changes for a team's units from battle events.
+///
+/// Returns `(granted, removed)` per unit; the later event wins when a status is
+/// both granted and removed. Apply as `(perm | granted) - removed`.
+pub fn permanent_status_deltas_from_events(
+    events: &[CombatEvent],
+    team: Team,
+) -> BTreeMap<UnitId, (StatusMask, StatusMask)> {
+    let mut deltas: BTreeMap<UnitId, (StatusMask, StatusMask)> = BTreeMap::new();
+    for event in events {
+        let (target_instance_id, status, granted) = match event {
+            CombatEvent::StatusApplied {
+                target_instance_id,
+                status,
+                permanent: true,
+            } => (target_instance_id, status, true),
+            CombatEvent::StatusRemoved {
+                target_instance_id,
+                status,
+                permanent: true,
+            } => (target_instance_id, status, false),
+            _ => continue,
+        };
+
+        if target_instance_id.is_player() != (team == Team::Player) {
+            continue;
+        }
+
+        let entry = deltas
+            .entry(*target_instance_id)
+            .or_insert((StatusMask::empty(), StatusMask::empty()));
+        if granted {
+            entry.0.insert(*status);
+            entry.1.remove(*status);
+        } else {
+            entry.0.remove(*status);
+            entry.1.insert(*status);
+        }
+    }
+    deltas
+}
+
+/// Convenience helper for permanent status changes applied to the local player's board.
+pub fn player_permanent_status_deltas_from_events(
+    events: &[CombatEvent],
+) -> BTreeMap<UnitId, (StatusMask, StatusMask)> {
+    permanent_status_deltas_from_events(events, Team::Player)
+}
+
+fn finalize_with_limit_exceeded<S: EventSink>(events: &mut S, limits: &BattleLimits) {
     let losing_team = limits.limit_exceeded_by;
 
     if let Some(reason) = &limits.limit_exceeded_reason {
-        events.push(CombatEvent::LimitExceeded {
+        events.record(CombatEvent::LimitExceeded {
             losing_team,
             reason: reason.clone(),
         });
     }
 
-    events.push(CombatEvent::BattleEnd {
+    events.record(CombatEvent::BattleEnd {
         result: match losing_team {
             Some(Team::Player) => BattleResult::Defeat,
             Some(Team::Enemy) => BattleResult::Victory,
             None => BattleResult::Draw,
         },
     });
-    core::mem::take(events)
 }
 
-// ==========================================
-// CORE LOGIC: RECURSIVE TRIGGER RESOLUTION
-// ==========================================
-
-/// Resolves triggers depth-first.
-/// If a trigger causes a state change (Death), we resolve reactions IMMEDIATELY.
+/// End-of-round fatigue: both fronts take the round's fatigue damage, ignoring
+/// Shield and crediting no killer. Hurt and faint reactions resolve as for
+/// ability damage.
 #[allow(clippy::too_many_arguments)]
-fn resolve_trigger_queue<R: BattleRng>(
-    queue: &mut Vec<PendingTrigger>,
-    player_units: &mut Vec<CombatUnit>,
-    enemy_units: &mut Vec<CombatUnit>,
-    graveyard: &mut Vec<CombatUnit>,
-    events: &mut Vec<CombatEvent>,
+fn apply_fatigue<R: BattleRng, S: EventSink>(
+    fatigue: &FatigueRule,
+    player_units: &mut UnitList,
+    enemy_units: &mut UnitList,
+    graveyard: &mut Graveyard,
+    events: &mut S,
     rng: &mut R,
     limits: &mut BattleLimits,
     card_pool: &BTreeMap<CardId, UnitCard>,
     registry: &mut TriggerRegistry,
     board_size: usize,
 ) -> Result<(), ()> {
-    // Trigger Priority:
-    // 1. Attack (Highest First) -> Ascending Sort
-    // 2. Health (Highest First) -> Ascending Sort
-    // 3. Position (Front First) -> Descending Sort (Index 1 < Index 0 so 0 ends up at back)
-    // 4. Random Tiebreaker      -> Per-unit value based on seed + unit ID (fair cross-team tiebreaker)
-    // 5. Ability Order          -> Descending Sort (First ability ends up at back)
-    // Pop takes from the end.
-    //
-    // IMPORTANT: Tiebreaker comes BEFORE ability_order so that once a unit "wins" priority,
-    // ALL of its abilities resolve before moving to the next unit. This prevents interleaving
-    // abilities from different units with the same stats/position.
-
-    // Assign tiebreaker to each trigger based on seed + unit identity.
-    // We rotate the ID before XORing to ensure the team bit (bit 31) mixes with
-    // different bits of the random base, preventing consistent team bias.
-    let random_base = rng.next_u32() as u16;
-    for trigger in queue.iter_mut() {
-        // Rotate ID by 8 bits so team bit (15) moves to bit 7, mixing fairly with random_base
-        trigger.priority.tiebreaker = random_base ^ trigger.source_id.raw().rotate_left(8);
+    let Some(damage) = fatigue.damage_for_round(limits.total_rounds) else {
+        return Ok(());
+    };
+    if player_units.is_empty() || enemy_units.is_empty() {
+        return Ok(());
     }
 
-    queue.sort_by(|a, b| {
-        a.priority
-            .attack
-            .cmp(&b.priority.attack)
-            .then(a.priority.health.cmp(&b.priority.health))
-            .then(b.priority.unit_position.cmp(&a.priority.unit_position))
-            .then(a.priority.tiebreaker.cmp(&b.priority.tiebreaker))
-            .then(b.priority.ability_order.cmp(&a.priority.ability_order))
-    });
-
-    // 2. Iterate — all captured triggers fire (stack semantics).
-    while let Some(trigger) = queue.pop() {
-        // A. Trigger Count Check + B. Condition Check (single lookup scope)
-        {
-            // Look up source on the board, or in the graveyard if dead.
-            let source = find_unit(trigger.source_id, player_units, enemy_units).or_else(|| {
-                graveyard
-                    .iter()
-                    .find(|u| u.instance_id == trigger.source_id)
-            });
+    let mut hurt_ids = Vec::new();
+    for units in [&mut *player_units, &mut *enemy_units] {
+        let front = &mut units[0];
+        front.hurt_from_health.get_or_insert(front.health);
+        front.last_damaged_by = None;
+        front.health = front.health.saturating_sub(damage);
+        hurt_ids.push(front.instance_id);
+        events.record(CombatEvent::FatigueDamage {
+            target_instance_id: front.instance_id,
+            damage,
+            remaining_hp: front.health,
+        });
+    }
 
-            // Check max_triggers (if source is found; dead/gone units are allowed)
-            if let Some(max) = trigger.max_triggers {
-                if let Some(unit) = source {
-                    if unit
-                        .ability_trigger_counts
-                        .get(trigger.ability_index)
-                        .copied()
-                        .unwrap_or(0)
-                        >= max
-                    {
-                        continue;
-                    }
-                }
+    let mut queue = Vec::new();
+    for hurt_id in hurt_ids {
+        capture_hurt_triggers(
+            hurt_id,
+            None,
+            player_units,
+            enemy_units,
+            registry,
+            &mut queue,
+        );
+    }
+    let (dead_player, dead_enemy) =
+        execute_death_check_phase(player_units, enemy_units, events, card_pool);
+    capture_death_triggers(
+        dead_player,
+        dead_enemy,
+        player_units,
+        enemy_units,
+        registry,
+        graveyard,
+        &mut queue,
+    );
+    if queue.is_empty() {
+        return Ok(());
+    }
+    resolve_trigger_queue(
+        &mut queue,
+        player_units,
+        enemy_units,
+        graveyard,
+        events,
+        rng,
+        limits,
+        card_pool,
+        registry,
+        board_size,
+    )
+}
+
+// ==========================================
+// CORE LOGIC: RECURSIVE TRIGGER RESOLUTION
+// ==========================================
+
+/// The bits of the seeded tiebreak base that decide the order of `queue`.
+/// Two triggers from different sources that tie on attack, health and
+/// position are ordered by the highest bit where their rotated IDs differ.
+fn decisive_tiebreak_bits(queue: &[PendingTrigger]) -> u16 {
+    let mut bits = 0u16;
+    for (i, a) in queue.iter().enumerate() {
+        for b in &queue[i + 1..] {
+            let tied = a.priority.attack == b.priority.attack
+                && a.priority.health == b.priority.health
+                && a.priority.unit_position == b.priority.unit_position;
+            let diff = a.source_id.raw().rotate_left(8) ^ b.source_id.raw().rotate_left(8);
+            if tied && diff != 0 {
+                bits |= 1 << (15 - diff.leading_zeros());
+            }
+        }
+    }
+    bits
+}
+
+fn trace_skip(trigger: &PendingTrigger, reason: SkipReason) {
+    trace::record(|| TraceEntry::TriggerSkipped {
+        source: trigger.source_id,
+        ability_index: trigger.ability_index,
+        reason,
+    });
+}
+
+/// Resolves triggers depth-first.
+/// If a trigger causes a state change (Death), we resolve reactions IMMEDIATELY.
+#[allow(clippy::too_many_arguments)]
+fn resolve_trigger_queue<R: BattleRng, S: EventSink>(
+    queue: &mut Vec<PendingTrigger>,
+    player_units: &mut UnitList,
+    enemy_units: &mut UnitList,
+    graveyard: &mut Graveyard,
+    events: &mut S,
+    rng: &mut R,
+    limits: &mut BattleLimits,
+    card_pool: &BTreeMap<CardId, UnitCard>,
+    registry: &mut TriggerRegistry,
+    board_size: usize,
+) -> Result<(), ()> {
+    // Trigger Priority:
+    // 1. Attack (Highest First) -> Ascending Sort
+    // 2. Health (Highest First) -> Ascending Sort
+    // 3. Position (Front First) -> Descending Sort (Index 1 < Index 0 so 0 ends up at back)
+    // 4. Random Tiebreaker      -> Per-unit value based on seed + unit ID (fair cross-team tiebreaker)
+    // 5. Ability Order          -> Descending Sort (First ability ends up at back)
+    // Pop takes from the end.
+    //
+    // IMPORTANT: Tiebreaker comes BEFORE ability_order so that once a unit "wins" priority,
+    // ALL of its abilities resolve before moving to the next unit. This prevents interleaving
+    // abilities from different units with the same stats/position.
+
+    match limits.rules.tiebreak {
+        TiebreakPolicy::Seeded => {
+            // Assign tiebreaker to each trigger based on seed + unit identity.
+            // We rotate the ID before XORing to ensure the team bit (bit 31) mixes with
+            // different bits of the random base, preventing consistent team bias.
+            let random_base = rng.tiebreak_base(|| decisive_tiebreak_bits(queue));
+            for trigger in queue.iter_mut() {
+                // Rotate ID by 8 bits so team bit (15) moves to bit 7, mixing fairly with random_base
+                trigger.priority.tiebreaker = random_base ^ trigger.source_id.raw().rotate_left(8);
+            }
+        }
+        TiebreakPolicy::PlayerFirst => {
+            // Pop takes from the end, so the higher value resolves first.
+            for trigger in queue.iter_mut() {
+                trigger.priority.tiebreaker = trigger.source_id.is_player() as u16;
+            }
+        }
+    }
+
+    queue.sort_by(|a, b| {
+        a.priority
+            .attack
+            .cmp(&b.priority.attack)
+            .then(a.priority.health.cmp(&b.priority.health))
+            .then(b.priority.unit_position.cmp(&a.priority.unit_position))
+            .then(a.priority.tiebreaker.cmp(&b.priority.tiebreaker))
+            .then(b.priority.ability_order.cmp(&a.priority.ability_order))
+    });
+    for trigger in queue.iter().rev() {
+        trace::record(|| TraceEntry::TriggerQueued {
+            source: trigger.source_id,
+            ability_index: trigger.ability_index,
+            attack: trigger.priority.attack,
+            health: trigger.priority.health,
+            position: trigger.priority.unit_position,
+            tiebreaker: trigger.priority.tiebreaker,
+            ability_order: trigger.priority.ability_order,
+        });
+    }
+
+    // 2. Iterate — all captured triggers fire (stack semantics).
+    while let Some(trigger) = queue.pop() {
+        // A. Trigger Count Check + B. Condition Check (single lookup scope)
+        let (steps, cooldown) = {
+            // Look up source on the board, or in the graveyard if dead.
+            let source = find_unit(trigger.source_id, player_units, enemy_units)
+                .or_else(|| graveyard.find(trigger.source_id));
+
+            // A source silenced after this trigger was queued loses it.
+            if source.is_some_and(|unit| unit.silenced) {
+                trace_skip(&trigger, SkipReason::Silenced);
+                continue;
+            }
+
+            let Some(ability) = registry.captured_ability(&trigger, source) else {
+                trace_skip(&trigger, SkipReason::AbilityGone);
+                continue;
+            };
+
+            // Check max_triggers and max_triggers_per_round
+            // (if source is found; dead/gone units are allowed)
+            if let Some(reason) = source.and_then(|unit| {
+                unit.trigger_limit_reached(
+                    trigger.ability_index,
+                    ability.max_triggers,
+                    ability.max_triggers_per_round,
+                )
+            }) {
+                trace_skip(&trigger, reason);
+                continue;
             }
 
             // Check conditions
-            if !trigger.conditions.is_empty() {
+            if !ability.conditions.is_empty() {
                 let Some(source) = source else {
-                    continue; // Source gone entirely — skip
+                    // Source gone entirely — skip
+                    trace_skip(&trigger, SkipReason::SourceGone);
+                    continue;
                 };
 
                 if !evaluate_condition(
-                    &trigger.conditions,
+                    &ability.conditions,
                     source,
                     player_units,
                     enemy_units,
                     rng,
                     trigger.trigger_target_id,
                 ) {
+                    trace_skip(&trigger, SkipReason::ConditionsFailed);
+                    continue;
+                }
+            }
+
+            (
+                (1 + ability.then.len()).min(MAX_ABILITY_EFFECTS),
+                ability.cooldown,
+            )
+        };
+
+        // Check cooldown — counts this activation on the source (board or graveyard)
+        if let Some(cooldown) = cooldown {
+            let source = match find_unit_mut(trigger.source_id, player_units, enemy_units) {
+                Some(unit) => Some(unit),
+                None => graveyard.find_mut(trigger.source_id),
+            };
+            if let Some(unit) = source {
+                if !unit.advance_cooldown(trigger.ability_index, cooldown) {
+                    trace_skip(&trigger, SkipReason::Cooldown);
                     continue;
                 }
             }
@@ -673,121 +1801,99 @@ fn resolve_trigger_queue<R: BattleRng>(
 
         // C. Emit Trigger Event
         limits.record_trigger(trigger.team)?;
-        events.push(CombatEvent::AbilityTrigger {
+        trace::record(|| TraceEntry::TriggerFired {
+            source: trigger.source_id,
+            ability_index: trigger.ability_index,
+        });
+        events.record(CombatEvent::AbilityTrigger {
             source_instance_id: trigger.source_id,
             ability_index: trigger.ability_index as IndexValue,
         });
 
         // D. Increment trigger count for this ability (if unit is still alive)
+        let mut trigger_count: CountValue = 1;
         if let Some(unit) = find_unit_mut(trigger.source_id, player_units, enemy_units) {
             if let Some(count) = unit.ability_trigger_counts.get_mut(trigger.ability_index) {
                 *count += 1;
+                trigger_count = *count;
+            }
+            if let Some(count) = unit
+                .ability_round_trigger_counts
+                .get_mut(trigger.ability_index)
+            {
+                *count += 1;
             }
+        } else if let Some(unit) = graveyard.find(trigger.source_id) {
+            trigger_count = unit
+                .ability_trigger_counts
+                .get(trigger.ability_index)
+                .copied()
+                .unwrap_or(0)
+                .saturating_add(1);
         }
 
-        // F. Apply Effect
-        let damaged_ids = apply_ability_effect(
-            trigger.source_id,
-            trigger.team,
-            &trigger.effect,
-            player_units,
-            enemy_units,
-            graveyard,
-            events,
-            rng,
-            limits,
-            trigger.spawn_index_override,
-            trigger.trigger_target_id,
-            card_pool,
-            registry,
-            board_size,
-        )?;
+        // F. Apply Effects — every step resolves before reactions and the death check.
+        let mut damaged_ids = Vec::new();
+        let mut previous_targets = Vec::new();
+        for step in 0..steps {
+            let Some(effect) = captured_step(
+                &trigger,
+                step,
+                player_units,
+                enemy_units,
+                graveyard,
+                registry,
+            ) else {
+                break;
+            };
+            let outcome = apply_ability_effect(
+                trigger.source_id,
+                trigger.team,
+                &effect,
+                player_units,
+                enemy_units,
+                graveyard,
+                events,
+                rng,
+                limits,
+                trigger.spawn_index_override,
+                trigger.trigger_target_id,
+                &previous_targets,
+                trigger_count,
+                card_pool,
+                registry,
+                board_size,
+            )?;
+            damaged_ids.extend(outcome.damaged);
+            previous_targets = outcome.hit;
+        }
 
         let mut reaction_queue = Vec::new();
 
         // Capture OnHurt BEFORE death check so fatally-damaged units still fire.
-        if registry.has_subscribers(AbilityTrigger::OnHurt) {
-            for unit_id in damaged_ids {
-                let (idx, team, unit) =
-                    if let Some((i, u)) = find_unit_with_position(unit_id, player_units) {
-                        (i, Team::Player, u)
-                    } else if let Some((i, u)) = find_unit_with_position(unit_id, enemy_units) {
-                        (i, Team::Enemy, u)
-                    } else {
-                        continue;
-                    };
-
-                let is_fatal = unit.health <= 0;
-                reaction_queue.extend(capture_triggers_for_unit(
-                    unit,
-                    idx,
-                    team,
-                    AbilityTrigger::OnHurt,
-                    Some(trigger.source_id),
-                    if is_fatal { Some(idx) } else { None },
-                ));
-            }
+        for unit_id in damaged_ids {
+            capture_hurt_triggers(
+                unit_id,
+                Some(trigger.source_id),
+                player_units,
+                enemy_units,
+                registry,
+                &mut reaction_queue,
+            );
         }
 
         // Death check — dead units move to graveyard
         let (dead_player, dead_enemy) =
             execute_death_check_phase(player_units, enemy_units, events, card_pool);
-
-        // OnFaint and OnAllyFaint from dead lists
-        for (idx, dead_unit) in dead_player {
-            let dead_id = dead_unit.instance_id;
-            if registry.has_subscribers(AbilityTrigger::OnFaint) {
-                reaction_queue.extend(capture_triggers_for_unit(
-                    &dead_unit,
-                    idx,
-                    Team::Player,
-                    AbilityTrigger::OnFaint,
-                    Some(dead_id),
-                    Some(idx),
-                ));
-            }
-            registry.unregister_unit(dead_id);
-            if registry.has_subscribers(AbilityTrigger::OnAllyFaint) {
-                for (s_idx, survivor) in player_units.iter().enumerate() {
-                    reaction_queue.extend(capture_triggers_for_unit(
-                        survivor,
-                        s_idx,
-                        Team::Player,
-                        AbilityTrigger::OnAllyFaint,
-                        Some(dead_id),
-                        Some(idx),
-                    ));
-                }
-            }
-            graveyard.push(dead_unit);
-        }
-        for (idx, dead_unit) in dead_enemy {
-            let dead_id = dead_unit.instance_id;
-            if registry.has_subscribers(AbilityTrigger::OnFaint) {
-                reaction_queue.extend(capture_triggers_for_unit(
-                    &dead_unit,
-                    idx,
-                    Team::Enemy,
-                    AbilityTrigger::OnFaint,
-                    Some(dead_id),
-                    Some(idx),
-                ));
-            }
-            registry.unregister_unit(dead_id);
-            if registry.has_subscribers(AbilityTrigger::OnAllyFaint) {
-                for (s_idx, survivor) in enemy_units.iter().enumerate() {
-                    reaction_queue.extend(capture_triggers_for_unit(
-                        survivor,
-                        s_idx,
-                        Team::Enemy,
-                        AbilityTrigger::OnAllyFaint,
-                        Some(dead_id),
-                        Some(idx),
-                    ));
-                }
-            }
-            graveyard.push(dead_unit);
-        }
+        capture_death_triggers(
+            dead_player,
+            dead_enemy,
+            player_units,
+            enemy_units,
+            registry,
+            graveyard,
+            &mut reaction_queue,
+        );
 
         // E. RECURSION (Depth-First)
         if !reaction_queue.is_empty() {
@@ -811,33 +1917,455 @@ fn resolve_trigger_queue<R: BattleRng>(
     Ok(())
 }
 
+/// Step `step` of the ability `trigger` captured: 0 is its effect, later
+/// steps its `then` effects. Each step is looked up again just before it
+/// resolves; earlier steps can't change what the reference points at, since
+/// a source retires its abilities before replacing them. Only that step is
+/// copied out, because resolving it mutates the boards the ability lives on.
+/// `AbilityEffect` holds no heap data, so the copy never allocates.
+fn captured_step(
+    trigger: &PendingTrigger,
+    step: usize,
+    player_units: &UnitList,
+    enemy_units: &UnitList,
+    graveyard: &Graveyard,
+    registry: &TriggerRegistry,
+) -> Option<AbilityEffect> {
+    let source = find_unit(trigger.source_id, player_units, enemy_units)
+        .or_else(|| graveyard.find(trigger.source_id));
+    let ability = registry.captured_ability(trigger, source)?;
+    match step.checked_sub(1) {
+        None => Some(ability.effect.clone()),
+        Some(then_idx) => ability.then.get(then_idx).cloned(),
+    }
+}
+
 // ==========================================
 // EFFECT APPLICATION
 // ==========================================
 
+/// What a single effect did: units that took damage (for OnHurt) and units
+/// it hit (for a following `AbilityTarget::PreviousTargets` step).
+#[derive(Default)]
+struct EffectOutcome {
+    damaged: Vec<UnitInstanceId>,
+    hit: Vec<UnitInstanceId>,
+}
+
 #[allow(clippy::too_many_arguments)]
-fn apply_ability_effect<R: BattleRng>(
+fn apply_ability_effect<R: BattleRng, S: EventSink>(
     source_instance_id: UnitInstanceId,
     source_team: Team,
     effect: &AbilityEffect,
-    player_units: &mut Vec<CombatUnit>,
-    enemy_units: &mut Vec<CombatUnit>,
-    graveyard: &mut Vec<CombatUnit>,
-    events: &mut Vec<CombatEvent>,
+    player_units: &mut UnitList,
+    enemy_units: &mut UnitList,
+    graveyard: &mut Graveyard,
+    events: &mut S,
     rng: &mut R,
     limits: &mut BattleLimits,
     spawn_index_override: Option<usize>,
     trigger_target_id: Option<UnitInstanceId>,
+    previous_targets: &[UnitInstanceId],
+    trigger_count: CountValue,
     card_pool: &BTreeMap<CardId, UnitCard>,
     registry: &mut TriggerRegistry,
     board_size: usize,
-) -> Result<Vec<UnitInstanceId>, ()> {
+) -> Result<EffectOutcome, ()> {
     limits.enter_recursion(source_team)?;
-    let result = (|| -> Result<Vec<UnitInstanceId>, ()> {
-        let mut damaged_units = Vec::new();
+    let result = (|| -> Result<EffectOutcome, ()> {
+        let mut outcome = EffectOutcome::default();
+        let value = |expr: &ValueExpr, player_units: &UnitList, enemy_units: &UnitList| {
+            evaluate_value(
+                expr,
+                source_instance_id,
+                source_team,
+                player_units,
+                enemy_units,
+                graveyard,
+                trigger_target_id,
+                trigger_count,
+            )
+        };
+
+        match effect {
+            AbilityEffect::Damage { amount, target } => {
+                let amount = value(amount, player_units, enemy_units);
+                let targets = resolve_targets(
+                    source_instance_id,
+                    source_team,
+                    target,
+                    player_units,
+                    enemy_units,
+                    rng,
+                    trigger_target_id,
+                    previous_targets,
+                    spawn_index_override,
+                );
+                for target_id in targets {
+                    if let Some(unit) = find_unit_mut(target_id, player_units, enemy_units) {
+                        outcome.hit.push(target_id);
+                        let actual_damage = amount.max(0);
+                        if actual_damage > 0 && !unit.try_block_with_shield(events) {
+                            unit.note_hit(source_instance_id);
+                            unit.health = unit.health.saturating_sub(actual_damage);
+                            outcome.damaged.push(target_id);
+                            events.record(CombatEvent::AbilityDamage {
+                                source_instance_id,
+                                target_instance_id: target_id,
+                                damage: actual_damage,
+                                remaining_hp: unit.health,
+                            });
+                        }
+                    }
+                }
+                Ok(outcome)
+            }
+            AbilityEffect::Heal { amount, target } => {
+                let amount = value(amount, player_units, enemy_units);
+                let targets = resolve_targets(
+                    source_instance_id,
+                    source_team,
+                    target,
+                    player_units,
+                    enemy_units,
+                    rng,
+                    trigger_target_id,
+                    previous_targets,
+                    spawn_index_override,
+                );
+                for target_id in targets {
+                    if let Some(unit) = find_unit_mut(target_id, player_units, enemy_units) {
+                        // Units already at 0 HP are awaiting the death check and stay dead.
+                        if !unit.is_alive() {
+                            continue;
+                        }
+                        outcome.hit.push(target_id);
+                        let healed = amount.max(0).min(unit.missing_health());
+                        if healed > 0 {
+                            unit.health = unit.health.saturating_add(healed);
+                            events.record(CombatEvent::AbilityHeal {
+                                source_instance_id,
+                                target_instance_id: target_id,
+                                amount: healed,
+                                new_health: unit.effective_health(),
+                            });
+                        }
+                    }
+                }
+                Ok(outcome)
+            }
+            AbilityEffect::ModifyStats {
+                health,
+                attack,
+                target,
+            } => {
+                let health = value(health, player_units, enemy_units);
+                let attack = value(attack, player_units, enemy_units);
+                let targets = resolve_targets(
+                    source_instance_id,
+                    source_team,
+                    target,
+                    player_units,
+                    enemy_units,
+                    rng,
+                    trigger_target_id,
+                    previous_targets,
+                    spawn_index_override,
+                );
+                for target_id in targets {
+                    if let Some(unit) = find_unit_mut(target_id, player_units, enemy_units) {
+                        outcome.hit.push(target_id);
+                        unit.attack_buff = unit.attack_buff.saturating_add(attack);
+                        unit.health = unit.health.saturating_add(health);
+                        unit.health_buff = unit.health_buff.saturating_add(health);
+                        events.record(CombatEvent::AbilityModifyStats {
+                            source_instance_id,
+                            target_instance_id: target_id,
+                            health_change: health,
+                            attack_change: attack,
+                            new_attack: unit.effective_attack(),
+                            new_health: unit.effective_health(),
+                        });
+                    }
+                }
+                Ok(outcome)
+            }
+            AbilityEffect::ModifyStatsPermanent {
+                health,
+                attack,
+                target,
+            } => {
+                let health = value(health, player_units, enemy_units);
+                let attack = value(attack, player_units, enemy_units);
+                let targets = resolve_targets(
+                    source_instance_id,
+                    source_team,
+                    target,
+                    player_units,
+                    enemy_units,
+                    rng,
+                    trigger_target_id,
+                    previous_targets,
+                    spawn_index_override,
+                );
+                for target_id in targets {
+                    if let Some(unit) = find_unit_mut(target_id, player_units, enemy_units) {
+                        outcome.hit.push(target_id);
+                        unit.attack_buff = unit.attack_buff.saturating_add(attack);
+                        unit.health = unit.health.saturating_add(health);
+                        unit.health_buff = unit.health_buff.saturating_add(health);
+                        events.record(CombatEvent::AbilityModifyStatsPermanent {
+                            source_instance_id,
+                            target_instance_id: target_id,
+                            health_change: health,
+                            attack_change: attack,
+                            new_attack: unit.effective_attack(),
+                            new_health: unit.effective_health(),
+                        });
+                    }
+                }
+                Ok(outcome)
+            }
+            AbilityEffect::SpawnUnit {
+                card_id: spawn_card_id,
+                spawn_location,
+                count,
+                side,
+                stats,
+            } => {
+                let spawn_team = match (side, source_team) {
+                    (SpawnSide::Own, team) => team,
+                    (SpawnSide::Opposing, Team::Player) => Team::Enemy,
+                    (SpawnSide::Opposing, Team::Enemy) => Team::Player,
+                };
+                // Read the source's buffs once, before any copy's reactions resolve.
+                let inherited_buffs = find_unit(source_instance_id, player_units, enemy_units)
+                    .or_else(|| graveyard.find(source_instance_id))
+                    .map(|unit| (unit.attack_buff, unit.health_buff));
+
+                // Every copy counts as a spawn. Once the board is full the
+                // rest fizzle, charging only the first like a single spawn.
+                for _ in 0..*count {
+                    limits.record_spawn(source_team)?;
+
+                    let board_len = match spawn_team {
+                        Team::Player => player_units.len(),
+                        Team::Enemy => enemy_units.len(),
+                    };
+                    if board_len >= board_size {
+                        break;
+                    }
+
+                    let Some(spawn_card) = card_pool.get(spawn_card_id) else {
+                        // Invalid card refs should safely fizzle instead of panicking.
+                        continue;
+                    };
+
+                    let mut new_unit = CombatUnit::from_card(spawn_card.clone());
+                    match stats {
+                        SpawnStats::Base => {}
+                        SpawnStats::Fixed { attack, health } => {
+                            new_unit.attack = *attack;
+                            new_unit.base_health = *health;
+                            new_unit.health = *health;
+                        }
+                        SpawnStats::InheritBuffs => {
+                            if let Some((attack_buff, health_buff)) = inherited_buffs {
+                                new_unit.attack_buff = attack_buff;
+                                new_unit.health_buff = health_buff;
+                                new_unit.health = new_unit.max_health().max(1);
+                            }
+                        }
+                    }
+
+                    let insert_idx = spawn_insert_index(
+                        *spawn_location,
+                        source_instance_id,
+                        source_team,
+                        spawn_team,
+                        spawn_index_override,
+                        player_units,
+                        enemy_units,
+                    );
+                    let spawned_id = place_spawned_unit(
+                        new_unit,
+                        spawn_team,
+                        insert_idx,
+                        player_units,
+                        enemy_units,
+                        graveyard,
+                        events,
+                        rng,
+                        limits,
+                        card_pool,
+                        registry,
+                        board_size,
+                    )?;
+                    outcome.hit.push(spawned_id);
+                }
+                Ok(outcome)
+            }
+            AbilityEffect::Resurrect {
+                pick,
+                stats,
+                spawn_location,
+            } => {
+                limits.record_spawn(source_team)?;
+
+                let board_len = match source_team {
+                    Team::Player => player_units.len(),
+                    Team::Enemy => enemy_units.len(),
+                };
+                if board_len >= board_size {
+                    return Ok(outcome);
+                }
+
+                let Some(mut revived) = graveyard.take(source_team, *pick) else {
+                    return Ok(outcome);
+                };
+                registry.retire_abilities(&revived);
+                revived.revive(*stats);
+
+                let insert_idx = spawn_insert_index(
+                    *spawn_location,
+                    source_instance_id,
+                    source_team,
+                    source_team,
+                    spawn_index_override,
+                    player_units,
+                    enemy_units,
+                );
+                let revived_id = place_spawned_unit(
+                    revived,
+                    source_team,
+                    insert_idx,
+                    player_units,
+                    enemy_units,
+                    graveyard,
+                    events,
+                    rng,
+                    limits,
+                    card_pool,
+                    registry,
+                    board_size,
+                )?;
+                outcome.hit.push(revived_id);
+                Ok(outcome)
+            }
+            AbilityEffect::Mimic { target } => {
+                let targets = resolve_targets(
+                    source_instance_id,
+                    source_team,
+                    target,
+                    player_units,
+                    enemy_units,
+                    rng,
+                    trigger_target_id,
+                    previous_targets,
+                    spawn_index_override,
+                );
+                let model = match targets.first() {
+                    Some(id) => find_unit(*id, player_units, enemy_units).cloned(),
+                    // A fallen trigger source is copied from the graveyard.
+                    None if target_scope(target) == Some(TargetScope::TriggerSource) => {
+                        trigger_target_id.and_then(|id| graveyard.find(id).cloned())
+                    }
+                    None => None,
+                };
+                let Some(model) = model.filter(|m| m.instance_id != source_instance_id) else {
+                    return Ok(outcome);
+                };
+
+                let my_board = match source_team {
+                    Team::Player => &mut *player_units,
+                    Team::Enemy => &mut *enemy_units,
+                };
+                let Some(idx) = my_board
+                    .position(source_instance_id)
+                    .filter(|&idx| my_board[idx].is_alive())
+                else {
+                    return Ok(outcome);
+                };
+
+                registry.retire_abilities(&my_board[idx]);
+                registry.unregister_unit(&my_board[idx]);
+                my_board[idx].become_copy_of(&model);
+                registry.register_unit(&my_board[idx]);
+                let notice = SnapshotNotice::Transform {
+                    team: source_team,
+                    instance_id: source_instance_id,
+                };
+                events.record_snapshot(notice, || CombatEvent::UnitTransform {
+                    team: source_team,
+                    transformed_unit: my_board[idx].to_view(card_pool),
+                    new_board_state: my_board.iter().map(|u| u.to_view(card_pool)).collect(),
+                });
+                outcome.hit.push(source_instance_id);
+                Ok(outcome)
+            }
+            AbilityEffect::Steal { target } => {
+                let targets = resolve_targets(
+                    source_instance_id,
+                    source_team,
+                    target,
+                    player_units,
+                    enemy_units,
+                    rng,
+                    trigger_target_id,
+                    previous_targets,
+                    spawn_index_override,
+                );
+                for target_id in targets {
+                    let (their_board, my_board) = match source_team {
+                        Team::Player => (&mut *enemy_units, &mut *player_units),
+                        Team::Enemy => (&mut *player_units, &mut *enemy_units),
+                    };
+                    if my_board.len() >= board_size {
+                        break;
+                    }
+                    // Only living enemies change sides.
+                    let Some(idx) = their_board
+                        .position(target_id)
+                        .filter(|&idx| their_board[idx].is_alive())
+                    else {
+                        continue;
+                    };
 
-        match effect {
-            AbilityEffect::Damage { amount, target } => {
+                    let mut stolen = their_board.remove(idx);
+                    registry.retire_abilities(&stolen);
+                    registry.unregister_unit(&stolen);
+                    let new_id = limits.generate_instance_id(source_team);
+                    stolen.instance_id = new_id;
+                    stolen.team = source_team;
+                    stolen.last_damaged_by = None;
+                    stolen.hurt_from_health = None;
+                    registry.register_unit(&stolen);
+                    my_board.push(stolen);
+                    outcome.hit.push(new_id);
+
+                    let notice = SnapshotNotice::Stolen {
+                        team: source_team,
+                        old_instance_id: target_id,
+                        new_instance_id: new_id,
+                    };
+                    events.record_snapshot(notice, || CombatEvent::UnitStolen {
+                        source_instance_id,
+                        old_instance_id: target_id,
+                        new_instance_id: new_id,
+                        team: source_team,
+                        player_board_state: player_units
+                            .iter()
+                            .map(|u| u.to_view(card_pool))
+                            .collect(),
+                        enemy_board_state: enemy_units
+                            .iter()
+                            .map(|u| u.to_view(card_pool))
+                            .collect(),
+                    });
+                }
+                Ok(outcome)
+            }
+            AbilityEffect::Silence { target } => {
                 let targets = resolve_targets(
                     source_instance_id,
                     source_team,
@@ -846,30 +2374,25 @@ fn apply_ability_effect<R: BattleRng>(
                     enemy_units,
                     rng,
                     trigger_target_id,
+                    previous_targets,
                     spawn_index_override,
                 );
                 for target_id in targets {
-                    if let Some(unit) = find_unit_mut(target_id, player_units, enemy_units) {
-                        let actual_damage = (*amount).max(0);
-                        if actual_damage > 0 {
-                            unit.health = unit.health.saturating_sub(actual_damage);
-                            damaged_units.push(target_id);
-                            events.push(CombatEvent::AbilityDamage {
-                                source_instance_id,
-                                target_instance_id: target_id,
-                                damage: actual_damage,
-                                remaining_hp: unit.health,
-                            });
-                        }
-                    }
+                    let Some(unit) = find_unit_mut(target_id, player_units, enemy_units) else {
+                        continue;
+                    };
+                    registry.retire_abilities(unit);
+                    registry.unregister_unit(unit);
+                    unit.silence();
+                    outcome.hit.push(target_id);
+                    events.record(CombatEvent::UnitSilenced {
+                        source_instance_id,
+                        target_instance_id: target_id,
+                    });
                 }
-                Ok(damaged_units)
+                Ok(outcome)
             }
-            AbilityEffect::ModifyStats {
-                health,
-                attack,
-                target,
-            } => {
+            AbilityEffect::Destroy { target } => {
                 let targets = resolve_targets(
                     source_instance_id,
                     source_team,
@@ -878,30 +2401,36 @@ fn apply_ability_effect<R: BattleRng>(
                     enemy_units,
                     rng,
                     trigger_target_id,
+                    previous_targets,
                     spawn_index_override,
                 );
                 for target_id in targets {
                     if let Some(unit) = find_unit_mut(target_id, player_units, enemy_units) {
-                        unit.attack_buff = unit.attack_buff.saturating_add(*attack);
-                        unit.health = unit.health.saturating_add(*health);
-                        unit.health_buff = unit.health_buff.saturating_add(*health);
-                        events.push(CombatEvent::AbilityModifyStats {
+                        outcome.hit.push(target_id);
+                        unit.note_hit(source_instance_id);
+                        unit.health = 0;
+                        outcome.damaged.push(target_id);
+                        events.record(CombatEvent::AbilityDestroy {
                             source_instance_id,
                             target_instance_id: target_id,
-                            health_change: *health,
-                            attack_change: *attack,
-                            new_attack: unit.effective_attack(),
-                            new_health: unit.effective_health(),
                         });
                     }
                 }
-                Ok(damaged_units)
+                Ok(outcome)
             }
-            AbilityEffect::ModifyStatsPermanent {
-                health,
-                attack,
-                target,
-            } => {
+            AbilityEffect::GainMana { amount } => {
+                let amount = value(amount, player_units, enemy_units)
+                    .clamp(ManaDelta::MIN as StatValue, ManaDelta::MAX as StatValue);
+                events.record(CombatEvent::AbilityGainMana {
+                    source_instance_id,
+                    team: source_team,
+                    amount: amount as ManaDelta,
+                });
+                Ok(outcome)
+            }
+            AbilityEffect::GrantStatusThisBattle { status, target }
+            | AbilityEffect::GrantStatusPermanent { status, target } => {
+                let permanent = matches!(effect, AbilityEffect::GrantStatusPermanent { .. });
                 let targets = resolve_targets(
                     source_instance_id,
                     source_team,
@@ -910,156 +2439,29 @@ fn apply_ability_effect<R: BattleRng>(
                     enemy_units,
                     rng,
                     trigger_target_id,
+                    previous_targets,
                     spawn_index_override,
                 );
                 for target_id in targets {
                     if let Some(unit) = find_unit_mut(target_id, player_units, enemy_units) {
-                        unit.attack_buff = unit.attack_buff.saturating_add(*attack);
-                        unit.health = unit.health.saturating_add(*health);
-                        unit.health_buff = unit.health_buff.saturating_add(*health);
-                        events.push(CombatEvent::AbilityModifyStatsPermanent {
-                            source_instance_id,
+                        outcome.hit.push(target_id);
+                        if permanent {
+                            unit.permanent_statuses.insert(*status);
+                        } else {
+                            unit.battle_statuses.insert(*status);
+                        }
+                        // A fresh grant restores a status consumed earlier this battle.
+                        unit.consumed_statuses.remove(*status);
+                        events.record(CombatEvent::StatusApplied {
                             target_instance_id: target_id,
-                            health_change: *health,
-                            attack_change: *attack,
-                            new_attack: unit.effective_attack(),
-                            new_health: unit.effective_health(),
+                            status: *status,
+                            permanent,
                         });
                     }
                 }
-                Ok(damaged_units)
-            }
-            AbilityEffect::SpawnUnit {
-                card_id: spawn_card_id,
-                spawn_location,
-            } => {
-                limits.record_spawn(source_team)?;
-
-                // 1. Create the unit and insert into the board
-                let (spawned_id, safe_idx) = {
-                    let my_board = match source_team {
-                        Team::Player => &mut *player_units,
-                        Team::Enemy => &mut *enemy_units,
-                    };
-
-                    if my_board.len() >= board_size {
-                        return Ok(damaged_units);
-                    }
-
-                    let Some(spawn_card) = card_pool.get(spawn_card_id) else {
-                        // Invalid card refs should safely fizzle instead of panicking.
-                        return Ok(damaged_units);
-                    };
-
-                    let instance_id = limits.generate_instance_id(source_team);
-
-                    let mut new_unit = CombatUnit::from_card(spawn_card.clone());
-                    new_unit.instance_id = instance_id;
-                    new_unit.team = source_team;
-
-                    // INSERTION LOGIC: Use the effect's spawn_location preference.
-                    // DeathPosition uses the override from the death context, falling back to Front.
-                    let insert_idx = match spawn_location {
-                        SpawnLocation::Front => 0,
-                        SpawnLocation::Back => my_board.len(),
-                        SpawnLocation::DeathPosition => spawn_index_override.unwrap_or(0),
-                    };
-                    let safe_idx = core::cmp::min(insert_idx, my_board.len());
-
-                    my_board.insert(safe_idx, new_unit);
-
-                    // Log Spawn
-                    events.push(CombatEvent::UnitSpawn {
-                        team: source_team,
-                        spawned_unit: my_board[safe_idx].to_view(card_pool),
-                        new_board_state: my_board.iter().map(|u| u.to_view(card_pool)).collect(),
-                    });
-
-                    registry.register_unit(&my_board[safe_idx]);
-
-                    (instance_id, safe_idx)
-                };
-
-                // 2. TRIGGER REACTIONS: OnSpawn for the spawned unit, OnAllySpawn for others
-                let mut reactions = Vec::new();
-
-                {
-                    let my_board = match source_team {
-                        Team::Player => &mut *player_units,
-                        Team::Enemy => &mut *enemy_units,
-                    };
-
-                    if registry.has_subscribers(AbilityTrigger::OnSpawn) {
-                        reactions.extend(capture_triggers_for_unit(
-                            &my_board[safe_idx],
-                            safe_idx,
-                            source_team,
-                            AbilityTrigger::OnSpawn,
-                            Some(spawned_id),
-                            None,
-                        ));
-                    }
-
-                    if registry.has_subscribers(AbilityTrigger::OnAllySpawn) {
-                        for (i, unit) in my_board.iter().enumerate() {
-                            if unit.instance_id == spawned_id {
-                                continue;
-                            }
-                            reactions.extend(capture_triggers_for_unit(
-                                unit,
-                                i,
-                                source_team,
-                                AbilityTrigger::OnAllySpawn,
-                                Some(spawned_id),
-                                None,
-                            ));
-                        }
-                    }
-
-                    // OnEnemySpawn for units on the opposite team
-                    let opposing_board = match source_team {
-                        Team::Player => &mut *enemy_units,
-                        Team::Enemy => &mut *player_units,
-                    };
-                    let opposing_team = match source_team {
-                        Team::Player => Team::Enemy,
-                        Team::Enemy => Team::Player,
-                    };
-
-                    if registry.has_subscribers(AbilityTrigger::OnEnemySpawn) {
-                        for (i, unit) in opposing_board.iter().enumerate() {
-                            reactions.extend(capture_triggers_for_unit(
-                                unit,
-                                i,
-                                opposing_team,
-                                AbilityTrigger::OnEnemySpawn,
-                                Some(spawned_id),
-                                None,
-                            ));
-                        }
-                    }
-                }
-
-                if !reactions.is_empty() {
-                    limits.enter_trigger_depth(source_team)?;
-                    resolve_trigger_queue(
-                        &mut reactions,
-                        player_units,
-                        enemy_units,
-                        graveyard,
-                        events,
-                        rng,
-                        limits,
-                        card_pool,
-                        registry,
-                        board_size,
-                    )?;
-                    limits.exit_trigger_depth();
-                }
-
-                Ok(damaged_units)
+                Ok(outcome)
             }
-            AbilityEffect::Destroy { target } => {
+            AbilityEffect::RemoveStatusPermanent { status, target } => {
                 let targets = resolve_targets(
                     source_instance_id,
                     source_team,
@@ -1068,27 +2470,24 @@ fn apply_ability_effect<R: BattleRng>(
                     enemy_units,
                     rng,
                     trigger_target_id,
+                    previous_targets,
                     spawn_index_override,
                 );
                 for target_id in targets {
                     if let Some(unit) = find_unit_mut(target_id, player_units, enemy_units) {
-                        unit.health = 0;
-                        damaged_units.push(target_id);
-                        events.push(CombatEvent::AbilityDestroy {
-                            source_instance_id,
-                            target_instance_id: target_id,
-                        });
+                        outcome.hit.push(target_id);
+                        // Only the permanent layer is affected; base card statuses stay.
+                        if unit.permanent_statuses.contains(*status) {
+                            unit.permanent_statuses.remove(*status);
+                            events.record(CombatEvent::StatusRemoved {
+                                target_instance_id: target_id,
+                                status: *status,
+                                permanent: true,
+                            });
+                        }
                     }
                 }
-                Ok(damaged_units)
-            }
-            AbilityEffect::GainMana { amount } => {
-                events.push(CombatEvent::AbilityGainMana {
-                    source_instance_id,
-                    team: source_team,
-                    amount: *amount,
-                });
-                Ok(damaged_units)
+                Ok(outcome)
             }
         }
     })();
@@ -1097,25 +2496,182 @@ fn apply_ability_effect<R: BattleRng>(
     result
 }
 
+/// Board index a spawn at `spawn_location` is inserted at on `spawn_team`'s
+/// board. DeathPosition uses the override from the death context, falling back
+/// to Front; AdjacentToSource goes directly behind the source on its own board.
+/// `place_spawned_unit` clamps the index to the board it lands on.
+fn spawn_insert_index(
+    spawn_location: SpawnLocation,
+    source_instance_id: UnitInstanceId,
+    source_team: Team,
+    spawn_team: Team,
+    spawn_index_override: Option<usize>,
+    player_units: &UnitList,
+    enemy_units: &UnitList,
+) -> usize {
+    let board_of = |team| match team {
+        Team::Player => player_units,
+        Team::Enemy => enemy_units,
+    };
+    match spawn_location {
+        SpawnLocation::Front => 0,
+        SpawnLocation::Back => board_of(spawn_team).len(),
+        SpawnLocation::DeathPosition => spawn_index_override.unwrap_or(0),
+        SpawnLocation::AdjacentToSource => board_of(source_team)
+            .position(source_instance_id)
+            .map(|idx| idx + 1)
+            .unwrap_or_else(|| spawn_index_override.unwrap_or(0)),
+    }
+}
+
+/// Inserts a spawned or revived unit into its team's board, logs the spawn and
+/// resolves OnSpawn, OnAllySpawn and OnEnemySpawn reactions. Callers check
+/// board space and record the spawn against the limits first.
+#[allow(clippy::too_many_arguments)]
+fn place_spawned_unit<R: BattleRng, S: EventSink>(
+    mut new_unit: CombatUnit,
+    team: Team,
+    insert_idx: usize,
+    player_units: &mut UnitList,
+    enemy_units: &mut UnitList,
+    graveyard: &mut Graveyard,
+    events: &mut S,
+    rng: &mut R,
+    limits: &mut BattleLimits,
+    card_pool: &BTreeMap<CardId, UnitCard>,
+    registry: &mut TriggerRegistry,
+    board_size: usize,
+) -> Result<UnitInstanceId, ()> {
+    let spawned_id = limits.generate_instance_id(team);
+    new_unit.instance_id = spawned_id;
+    new_unit.team = team;
+
+    let safe_idx = {
+        let my_board = match team {
+            Team::Player => &mut *player_units,
+            Team::Enemy => &mut *enemy_units,
+        };
+
+        let safe_idx = core::cmp::min(insert_idx, my_board.len());
+
+        my_board.insert(safe_idx, new_unit);
+
+        // Log Spawn
+        let notice = SnapshotNotice::Spawn {
+            team,
+            instance_id: spawned_id,
+        };
+        events.record_snapshot(notice, || CombatEvent::UnitSpawn {
+            team,
+            spawned_unit: my_board[safe_idx].to_view(card_pool),
+            new_board_state: my_board.iter().map(|u| u.to_view(card_pool)).collect(),
+        });
+
+        registry.register_unit(&my_board[safe_idx]);
+        safe_idx
+    };
+
+    // OnSpawn for the spawned unit, OnAllySpawn for others
+    let mut reactions = Vec::new();
+
+    {
+        let my_board = match team {
+            Team::Player => &mut *player_units,
+            Team::Enemy => &mut *enemy_units,
+        };
+
+        if registry.has_subscribers(AbilityTrigger::OnSpawn) {
+            reactions.extend(capture_triggers_for_unit(
+                &my_board[safe_idx],
+                safe_idx,
+                team,
+                AbilityTrigger::OnSpawn,
+                Some(spawned_id),
+                None,
+            ));
+        }
+
+        if registry.has_subscribers(AbilityTrigger::OnAllySpawn) {
+            for (i, unit) in my_board.iter().enumerate() {
+                if unit.instance_id == spawned_id {
+                    continue;
+                }
+                reactions.extend(capture_triggers_for_unit(
+                    unit,
+                    i,
+                    team,
+                    AbilityTrigger::OnAllySpawn,
+                    Some(spawned_id),
+                    None,
+                ));
+            }
+        }
+
+        // OnEnemySpawn for units on the opposite team
+        let opposing_board = match team {
+            Team::Player => &mut *enemy_units,
+            Team::Enemy => &mut *player_units,
+        };
+        let opposing_team = match team {
+            Team::Player => Team::Enemy,
+            Team::Enemy => Team::Player,
+        };
+
+        if registry.has_subscribers(AbilityTrigger::OnEnemySpawn) {
+            for (i, unit) in opposing_board.iter().enumerate() {
+                reactions.extend(capture_triggers_for_unit(
+                    unit,
+                    i,
+                    opposing_team,
+                    AbilityTrigger::OnEnemySpawn,
+                    Some(spawned_id),
+                    None,
+                ));
+            }
+        }
+    }
+
+    if !reactions.is_empty() {
+        limits.enter_trigger_depth(team)?;
+        resolve_trigger_queue(
+            &mut reactions,
+            player_units,
+            enemy_units,
+            graveyard,
+            events,
+            rng,
+            limits,
+            card_pool,
+            registry,
+            board_size,
+        )?;
+        limits.exit_trigger_depth();
+    }
+
+    Ok(spawned_id)
+}
+
 // ==========================================
 // HELPERS
 // ==========================================
 
 #[allow(clippy::too_many_arguments)]
-fn execute_phase<R: BattleRng>(
+fn execute_phase<R: BattleRng, S: EventSink>(
     phase: BattlePhase,
-    player_units: &mut Vec<CombatUnit>,
-    enemy_units: &mut Vec<CombatUnit>,
-    events: &mut Vec<CombatEvent>,
+    player_units: &mut UnitList,
+    enemy_units: &mut UnitList,
+    graveyard: &mut Graveyard,
+    events: &mut S,
     rng: &mut R,
     limits: &mut BattleLimits,
     card_pool: &BTreeMap<CardId, UnitCard>,
     clash_context: Option<ClashContext>,
+    clash_multiplier: StatValue,
     registry: &mut TriggerRegistry,
     board_size: usize,
 ) -> Result<(), ()> {
     if phase != BattlePhase::End {
-        events.push(CombatEvent::PhaseStart { phase });
+        events.record(CombatEvent::PhaseStart { phase });
     }
 
     match phase {
@@ -1124,6 +2680,7 @@ fn execute_phase<R: BattleRng>(
                 &[AbilityTrigger::OnStart],
                 player_units,
                 enemy_units,
+                graveyard,
                 events,
                 rng,
                 limits,
@@ -1141,6 +2698,7 @@ fn execute_phase<R: BattleRng>(
                 ],
                 player_units,
                 enemy_units,
+                graveyard,
                 events,
                 rng,
                 limits,
@@ -1151,12 +2709,14 @@ fn execute_phase<R: BattleRng>(
             )?;
         }
         BattlePhase::Attack => {
-            let clash_outcome = execute_attack_clash(player_units, enemy_units, events);
+            let clash_outcome =
+                execute_attack_clash(player_units, enemy_units, clash_multiplier, events);
             // After clash, all triggers (OnHurt, AfterUnitAttack, AfterAnyAttack, OnFaint, etc.)
             // are captured eagerly before the death check.
             resolve_hurt_and_faint_loop(
                 player_units,
                 enemy_units,
+                graveyard,
                 clash_outcome,
                 events,
                 rng,
@@ -1167,27 +2727,45 @@ fn execute_phase<R: BattleRng>(
             )?;
         }
         BattlePhase::End => {
+            // Survivors react before the result is decided. Skipped entirely when
+            // nobody listens so the RNG stream is left untouched.
+            if registry.has_subscribers(AbilityTrigger::OnBattleEnd) {
+                collect_and_resolve_triggers(
+                    &[AbilityTrigger::OnBattleEnd],
+                    player_units,
+                    enemy_units,
+                    graveyard,
+                    events,
+                    rng,
+                    limits,
+                    card_pool,
+                    None,
+                    registry,
+                    board_size,
+                )?;
+            }
             let result = match (player_units.is_empty(), enemy_units.is_empty()) {
                 (false, true) => BattleResult::Victory,
                 (true, false) => BattleResult::Defeat,
                 _ => BattleResult::Draw,
             };
-            events.push(CombatEvent::BattleEnd { result });
+            events.record(CombatEvent::BattleEnd { result });
         }
     }
 
     if phase != BattlePhase::End {
-        events.push(CombatEvent::PhaseEnd { phase });
+        events.record(CombatEvent::PhaseEnd { phase });
     }
     Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
-fn collect_and_resolve_triggers<R: BattleRng>(
+fn collect_and_resolve_triggers<R: BattleRng, S: EventSink>(
     trigger_types: &[AbilityTrigger],
-    player_units: &mut Vec<CombatUnit>,
-    enemy_units: &mut Vec<CombatUnit>,
-    events: &mut Vec<CombatEvent>,
+    player_units: &mut UnitList,
+    enemy_units: &mut UnitList,
+    graveyard: &mut Graveyard,
+    events: &mut S,
     rng: &mut R,
     limits: &mut BattleLimits,
     card_pool: &BTreeMap<CardId, UnitCard>,
@@ -1229,12 +2807,11 @@ fn collect_and_resolve_triggers<R: BattleRng>(
         scan_board(enemy_units, Team::Enemy);
     }
 
-    let mut graveyard = Vec::new();
     resolve_trigger_queue(
         &mut queue,
         player_units,
         enemy_units,
-        &mut graveyard,
+        graveyard,
         events,
         rng,
         limits,
@@ -1253,7 +2830,7 @@ struct ClashContext {
 }
 
 impl ClashContext {
-    fn capture(player_units: &[CombatUnit], enemy_units: &[CombatUnit]) -> Self {
+    fn capture(player_units: &UnitList, enemy_units: &UnitList) -> Self {
         Self {
             player_id: player_units.first().map(|u| u.instance_id),
             enemy_id: enemy_units.first().map(|u| u.instance_id),
@@ -1267,69 +2844,292 @@ struct ClashOutcome {
     enemy_id: Option<UnitInstanceId>,
     player_hurt: bool,
     enemy_hurt: bool,
+    /// Unit behind the player front that took Trample damage this clash.
+    player_trampled: Option<UnitInstanceId>,
+    /// Unit behind the enemy front that took Trample damage this clash.
+    enemy_trampled: Option<UnitInstanceId>,
+}
+
+/// Attack snapshot of a front unit, taken before any damage lands in a swing.
+#[derive(Debug, Clone, Copy)]
+struct Striker {
+    id: UnitInstanceId,
+    damage: StatValue,
+    poison: bool,
+    trample: bool,
+}
+
+impl Striker {
+    fn of(unit: &CombatUnit, multiplier: StatValue) -> Self {
+        Self {
+            id: unit.instance_id,
+            damage: unit.effective_attack().saturating_mul(multiplier),
+            poison: unit.has_status(Status::Poison),
+            trample: unit.has_keyword(Keyword::Trample),
+        }
+    }
+}
+
+/// Land one hit from `source` on `target`. Shield blocks the whole hit; Poison makes it lethal.
+/// Returns true if the target was hurt.
+fn land_hit<S: EventSink>(
+    source: UnitInstanceId,
+    target: &mut CombatUnit,
+    damage: StatValue,
+    poison: bool,
+    events: &mut S,
+) -> bool {
+    if damage <= 0 || target.try_block_with_shield(events) {
+        return false;
+    }
+    target.note_hit(source);
+    target.health = target.health.saturating_sub(damage);
+    if poison {
+        target.health = target.health.min(0);
+    }
+    true
+}
+
+/// Hit the front of `defenders`, carrying Trample overflow into the next living unit.
+/// Returns true if the front was hurt.
+fn strike_front<S: EventSink>(
+    striker: Striker,
+    defenders: &mut [CombatUnit],
+    events: &mut S,
+    trampled: &mut Option<UnitInstanceId>,
+) -> bool {
+    let health_before = defenders[0].health.max(0);
+    if !land_hit(
+        striker.id,
+        &mut defenders[0],
+        striker.damage,
+        striker.poison,
+        events,
+    ) {
+        return false;
+    }
+    if !striker.trample {
+        return true;
+    }
+
+    let excess = striker.damage.saturating_sub(health_before);
+    if excess <= 0 {
+        return true;
+    }
+    if let Some(next) = defenders.iter_mut().skip(1).find(|u| u.is_alive()) {
+        let next_health_before = next.health;
+        if land_hit(striker.id, next, excess, striker.poison, events) {
+            *trampled = Some(next.instance_id);
+            events.record(CombatEvent::Trample {
+                source_instance_id: striker.id,
+                target_instance_id: next.instance_id,
+                damage: next_health_before.saturating_sub(next.health),
+                remaining_hp: next.health,
+            });
+        }
+    }
+    true
+}
+
+/// One exchange between the two fronts. Only sides flagged as striking deal damage.
+/// A First Strike front lands first and is not hit back if that kills the opposing front;
+/// otherwise both hits land simultaneously.
+fn clash_swing<S: EventSink>(
+    player_units: &mut UnitList,
+    enemy_units: &mut UnitList,
+    player_strikes: bool,
+    enemy_strikes: bool,
+    multiplier: StatValue,
+    events: &mut S,
+    outcome: &mut ClashOutcome,
+) {
+    let p = Striker::of(&player_units[0], multiplier);
+    let e = Striker::of(&enemy_units[0], multiplier);
+    let p_first = player_units[0].has_keyword(Keyword::FirstStrike);
+    let e_first = enemy_units[0].has_keyword(Keyword::FirstStrike);
+
+    if player_strikes && enemy_strikes && p_first != e_first {
+        let (first, second) = if p_first { (p, e) } else { (e, p) };
+        events.record(CombatEvent::FirstStrike {
+            source_instance_id: first.id,
+            target_instance_id: second.id,
+        });
+        if p_first {
+            outcome.enemy_hurt |= strike_front(p, enemy_units, events, &mut outcome.enemy_trampled);
+            if enemy_units[0].is_alive() {
+                outcome.player_hurt |=
+                    strike_front(e, player_units, events, &mut outcome.player_trampled);
+            }
+        } else {
+            outcome.player_hurt |=
+                strike_front(e, player_units, events, &mut outcome.player_trampled);
+            if player_units[0].is_alive() {
+                outcome.enemy_hurt |=
+                    strike_front(p, enemy_units, events, &mut outcome.enemy_trampled);
+            }
+        }
+        return;
+    }
+
+    if enemy_strikes {
+        outcome.player_hurt |= strike_front(e, player_units, events, &mut outcome.player_trampled);
+    }
+    if player_strikes {
+        outcome.enemy_hurt |= strike_front(p, enemy_units, events, &mut outcome.enemy_trampled);
+    }
+}
+
+/// A Reach unit standing in slot 1 hits the opposing front if both are still alive.
+fn reach_strike<S: EventSink>(
+    attackers: &[CombatUnit],
+    defenders: &mut [CombatUnit],
+    multiplier: StatValue,
+    events: &mut S,
+) -> bool {
+    let Some(reacher) = attackers.get(1) else {
+        return false;
+    };
+    if !reacher.has_keyword(Keyword::Reach) || !reacher.is_alive() || !defenders[0].is_alive() {
+        return false;
+    }
+    let damage = reacher.effective_attack().saturating_mul(multiplier);
+    if damage <= 0 {
+        return false;
+    }
+
+    let health_before = defenders[0].health;
+    if !land_hit(
+        reacher.instance_id,
+        &mut defenders[0],
+        damage,
+        reacher.has_status(Status::Poison),
+        events,
+    ) {
+        return false;
+    }
+    events.record(CombatEvent::Reach {
+        source_instance_id: reacher.instance_id,
+        target_instance_id: defenders[0].instance_id,
+        damage: health_before.saturating_sub(defenders[0].health),
+        remaining_hp: defenders[0].health,
+    });
+    true
+}
+
+/// Report both fronts' health after a swing that started at `health_before`.
+fn push_front_damage<S: EventSink>(
+    player_units: &UnitList,
+    enemy_units: &UnitList,
+    health_before: (StatValue, StatValue),
+    events: &mut S,
+) {
+    events.record(CombatEvent::DamageTaken {
+        target_instance_id: player_units[0].instance_id,
+        team: Team::Player,
+        remaining_hp: player_units[0].health,
+        source_instance_id: enemy_units[0].instance_id,
+        damage: health_before.0.saturating_sub(player_units[0].health),
+    });
+    events.record(CombatEvent::DamageTaken {
+        target_instance_id: enemy_units[0].instance_id,
+        team: Team::Enemy,
+        remaining_hp: enemy_units[0].health,
+        source_instance_id: player_units[0].instance_id,
+        damage: health_before.1.saturating_sub(enemy_units[0].health),
+    });
+}
+
+fn front_health(player_units: &UnitList, enemy_units: &UnitList) -> (StatValue, StatValue) {
+    (player_units[0].health, enemy_units[0].health)
 }
 
-fn execute_attack_clash(
-    player_units: &mut [CombatUnit],
-    enemy_units: &mut [CombatUnit],
-    events: &mut Vec<CombatEvent>,
+/// Resolve the round's clash: the front exchange, then Reach hits from slot 1,
+/// then a second swing for Windfury fronts if both fronts survived.
+/// All clash damage is scaled by `multiplier` (Sudden Death).
+fn execute_attack_clash<S: EventSink>(
+    player_units: &mut UnitList,
+    enemy_units: &mut UnitList,
+    multiplier: StatValue,
+    events: &mut S,
 ) -> ClashOutcome {
     let mut outcome = ClashOutcome {
         player_id: None,
         enemy_id: None,
         player_hurt: false,
         enemy_hurt: false,
+        player_trampled: None,
+        enemy_trampled: None,
     };
 
     if player_units.is_empty() || enemy_units.is_empty() {
         return outcome;
     }
 
-    let p = &player_units[0];
-    let e = &enemy_units[0];
-    let p_dmg = p.effective_attack();
-    let e_dmg = e.effective_attack();
-
-    events.push(CombatEvent::Clash { p_dmg, e_dmg });
-
-    let p_id = p.instance_id;
-    let e_id = e.instance_id;
-    outcome.player_id = Some(p_id);
-    outcome.enemy_id = Some(e_id);
+    let p_dmg = player_units[0]
+        .effective_attack()
+        .saturating_mul(multiplier);
+    let e_dmg = enemy_units[0].effective_attack().saturating_mul(multiplier);
+    events.record(CombatEvent::Clash {
+        p_dmg,
+        e_dmg,
+        player_instance_id: player_units[0].instance_id,
+        enemy_instance_id: enemy_units[0].instance_id,
+    });
 
-    // Enemy hits player front.
-    if e_dmg > 0 {
-        player_units[0].health = player_units[0].health.saturating_sub(e_dmg);
-        outcome.player_hurt = true;
-    }
+    outcome.player_id = Some(player_units[0].instance_id);
+    outcome.enemy_id = Some(enemy_units[0].instance_id);
 
-    // Player hits enemy front.
-    if p_dmg > 0 {
-        enemy_units[0].health = enemy_units[0].health.saturating_sub(p_dmg);
-        outcome.enemy_hurt = true;
+    let health_before = front_health(player_units, enemy_units);
+    clash_swing(
+        player_units,
+        enemy_units,
+        true,
+        true,
+        multiplier,
+        events,
+        &mut outcome,
+    );
+    push_front_damage(player_units, enemy_units, health_before, events);
+
+    outcome.enemy_hurt |= reach_strike(player_units, enemy_units, multiplier, events);
+    outcome.player_hurt |= reach_strike(enemy_units, player_units, multiplier, events);
+
+    let p_windfury = player_units[0].has_keyword(Keyword::Windfury);
+    let e_windfury = enemy_units[0].has_keyword(Keyword::Windfury);
+    if (p_windfury || e_windfury) && player_units[0].is_alive() && enemy_units[0].is_alive() {
+        for (unit, windfury) in [
+            (&player_units[0], p_windfury),
+            (&enemy_units[0], e_windfury),
+        ] {
+            if windfury {
+                events.record(CombatEvent::Windfury {
+                    source_instance_id: unit.instance_id,
+                });
+            }
+        }
+        let health_before = front_health(player_units, enemy_units);
+        clash_swing(
+            player_units,
+            enemy_units,
+            p_windfury,
+            e_windfury,
+            multiplier,
+            events,
+            &mut outcome,
+        );
+        push_front_damage(player_units, enemy_units, health_before, events);
     }
 
-    events.push(CombatEvent::DamageTaken {
-        target_instance_id: p_id,
-        team: Team::Player,
-        remaining_hp: player_units[0].health,
-    });
-    events.push(CombatEvent::DamageTaken {
-        target_instance_id: e_id,
-        team: Team::Enemy,
-        remaining_hp: enemy_units[0].health,
-    });
-
     outcome
 }
 
 type FallenUnits = Vec<(usize, CombatUnit)>;
 
 /// Returns a tuple of (Index, Unit) for dead units
-fn execute_death_check_phase(
-    player_units: &mut Vec<CombatUnit>,
-    enemy_units: &mut Vec<CombatUnit>,
-    events: &mut Vec<CombatEvent>,
+fn execute_death_check_phase<S: EventSink>(
+    player_units: &mut UnitList,
+    enemy_units: &mut UnitList,
+    events: &mut S,
     card_pool: &BTreeMap<CardId, UnitCard>,
 ) -> (FallenUnits, FallenUnits) {
     let mut player_dead = Vec::new();
@@ -1357,13 +3157,15 @@ fn execute_death_check_phase(
 
     // Emit Events if needed (Frontend State Sync)
     if !player_dead.is_empty() {
-        events.push(CombatEvent::UnitDeath {
+        let notice = SnapshotNotice::Death { team: Team::Player };
+        events.record_snapshot(notice, || CombatEvent::UnitDeath {
             team: Team::Player,
             new_board_state: player_units.iter().map(|u| u.to_view(card_pool)).collect(),
         });
     }
     if !enemy_dead.is_empty() {
-        events.push(CombatEvent::UnitDeath {
+        let notice = SnapshotNotice::Death { team: Team::Enemy };
+        events.record_snapshot(notice, || CombatEvent::UnitDeath {
             team: Team::Enemy,
             new_board_state: enemy_units.iter().map(|u| u.to_view(card_pool)).collect(),
         });
@@ -1373,11 +3175,12 @@ fn execute_death_check_phase(
 }
 
 #[allow(clippy::too_many_arguments)]
-fn resolve_hurt_and_faint_loop<R: BattleRng>(
-    player_units: &mut Vec<CombatUnit>,
-    enemy_units: &mut Vec<CombatUnit>,
+fn resolve_hurt_and_faint_loop<R: BattleRng, S: EventSink>(
+    player_units: &mut UnitList,
+    enemy_units: &mut UnitList,
+    graveyard: &mut Graveyard,
     clash_outcome: ClashOutcome,
-    events: &mut Vec<CombatEvent>,
+    events: &mut S,
     rng: &mut R,
     limits: &mut BattleLimits,
     card_pool: &BTreeMap<CardId, UnitCard>,
@@ -1393,10 +3196,9 @@ fn resolve_hurt_and_faint_loop<R: BattleRng>(
 
     {
         let reg = &*registry;
-        let mut capture_clash_triggers = |units: &[CombatUnit],
+        let mut capture_clash_triggers = |units: &UnitList,
                                           clash_id: Option<UnitInstanceId>,
                                           opponent_id: Option<UnitInstanceId>,
-                                          was_hurt: bool,
                                           team: Team| {
             let Some(cid) = clash_id else { return };
             let Some((idx, unit)) = find_unit_with_position(cid, units) else {
@@ -1405,17 +3207,6 @@ fn resolve_hurt_and_faint_loop<R: BattleRng>(
 
             let spawn_override = if unit.health <= 0 { Some(idx) } else { None };
 
-            if was_hurt && reg.has_subscribers(AbilityTrigger::OnHurt) {
-                queue.extend(capture_triggers_for_unit(
-                    unit,
-                    idx,
-                    team,
-                    AbilityTrigger::OnHurt,
-                    opponent_id,
-                    spawn_override,
-                ));
-            }
-
             if reg.has_subscribers(AbilityTrigger::AfterUnitAttack) {
                 queue.extend(capture_triggers_for_unit(
                     unit,
@@ -1428,20 +3219,35 @@ fn resolve_hurt_and_faint_loop<R: BattleRng>(
             }
         };
 
-        capture_clash_triggers(
-            player_units,
-            clashing_p_id,
-            clashing_e_id,
-            clash_outcome.player_hurt,
-            Team::Player,
-        );
-        capture_clash_triggers(
-            enemy_units,
+        capture_clash_triggers(player_units, clashing_p_id, clashing_e_id, Team::Player);
+        capture_clash_triggers(enemy_units, clashing_e_id, clashing_p_id, Team::Enemy);
+    }
+
+    // OnHurt / OnAllyHurt for the clashing fronts and any unit behind them hit by Trample.
+
+    let hurt_units = [
+        (
+            clashing_p_id.filter(|_| clash_outcome.player_hurt),
             clashing_e_id,
+        ),
+        (
+            clashing_e_id.filter(|_| clash_outcome.enemy_hurt),
             clashing_p_id,
-            clash_outcome.enemy_hurt,
-            Team::Enemy,
-        );
+        ),
+        (clash_outcome.player_trampled, clashing_e_id),
+        (clash_outcome.enemy_trampled, clashing_p_id),
+    ];
+    for (hurt_id, attacker_id) in hurt_units {
+        if let Some(hurt_id) = hurt_id {
+            capture_hurt_triggers(
+                hurt_id,
+                attacker_id,
+                player_units,
+                enemy_units,
+                registry,
+                &mut queue,
+            );
+        }
     }
 
     // AfterAnyAttack for ALL units (not just front) — captured before death check.
@@ -1532,68 +3338,23 @@ fn resolve_hurt_and_faint_loop<R: BattleRng>(
         return Ok(());
     }
 
-    // ── OnFaint and OnAllyFaint from dead lists, then move to graveyard ──
-
-    let mut graveyard = Vec::new();
+    // ── Death reactions from dead lists, then move to graveyard ──
 
-    for (idx, dead_unit) in dead_player {
-        if registry.has_subscribers(AbilityTrigger::OnFaint) {
-            queue.extend(capture_triggers_for_unit(
-                &dead_unit,
-                idx,
-                Team::Player,
-                AbilityTrigger::OnFaint,
-                Some(dead_unit.instance_id),
-                Some(idx),
-            ));
-        }
-        registry.unregister_unit(dead_unit.instance_id);
-        if registry.has_subscribers(AbilityTrigger::OnAllyFaint) {
-            for (s_idx, survivor) in player_units.iter().enumerate() {
-                queue.extend(capture_triggers_for_unit(
-                    survivor,
-                    s_idx,
-                    Team::Player,
-                    AbilityTrigger::OnAllyFaint,
-                    Some(dead_unit.instance_id),
-                    Some(idx),
-                ));
-            }
-        }
-        graveyard.push(dead_unit);
-    }
-    for (idx, dead_unit) in dead_enemy {
-        if registry.has_subscribers(AbilityTrigger::OnFaint) {
-            queue.extend(capture_triggers_for_unit(
-                &dead_unit,
-                idx,
-                Team::Enemy,
-                AbilityTrigger::OnFaint,
-                Some(dead_unit.instance_id),
-                Some(idx),
-            ));
-        }
-        registry.unregister_unit(dead_unit.instance_id);
-        if registry.has_subscribers(AbilityTrigger::OnAllyFaint) {
-            for (s_idx, survivor) in enemy_units.iter().enumerate() {
-                queue.extend(capture_triggers_for_unit(
-                    survivor,
-                    s_idx,
-                    Team::Enemy,
-                    AbilityTrigger::OnAllyFaint,
-                    Some(dead_unit.instance_id),
-                    Some(idx),
-                ));
-            }
-        }
-        graveyard.push(dead_unit);
-    }
+    capture_death_triggers(
+        dead_player,
+        dead_enemy,
+        player_units,
+        enemy_units,
+        registry,
+        graveyard,
+        &mut queue,
+    );
 
     resolve_trigger_queue(
         &mut queue,
         player_units,
         enemy_units,
-        &mut graveyard,
+        graveyard,
         events,
         rng,
         limits,
@@ -1609,10 +3370,11 @@ fn resolve_targets<R: BattleRng>(
     source_instance_id: UnitInstanceId,
     source_team: Team,
     target: &AbilityTarget,
-    player_units: &[CombatUnit],
-    enemy_units: &[CombatUnit],
+    player_units: &UnitList,
+    enemy_units: &UnitList,
     rng: &mut R,
     trigger_target_id: Option<UnitInstanceId>,
+    previous_targets: &[UnitInstanceId],
     source_position_override: Option<usize>,
 ) -> Vec<UnitInstanceId> {
     match target {
@@ -1639,7 +3401,7 @@ fn resolve_targets<R: BattleRng>(
             source_position_override,
         ),
         AbilityTarget::Random { scope, count } => {
-            let candidates = resolve_scope_ids(
+            let mut units = resolve_scope_units(
                 *scope,
                 source_instance_id,
                 source_team,
@@ -1647,14 +3409,21 @@ fn resolve_targets<R: BattleRng>(
                 enemy_units,
                 trigger_target_id,
             );
-            if candidates.is_empty() {
-                return vec![];
-            }
-            let actual_count = (*count as usize).min(candidates.len());
-            let mut results = candidates;
-            rng.shuffle(&mut results);
-            results.truncate(actual_count);
-            results
+            apply_guard(*scope, &mut units);
+            pick_random_ids(units, *count, rng)
+        }
+        AbilityTarget::RandomWithTag { scope, tag, count } => {
+            let mut units = resolve_scope_units(
+                *scope,
+                source_instance_id,
+                source_team,
+                player_units,
+                enemy_units,
+                trigger_target_id,
+            );
+            units.retain(|u| u.tags.contains(*tag));
+            apply_guard(*scope, &mut units);
+            pick_random_ids(units, *count, rng)
         }
         AbilityTarget::Standard {
             scope,
@@ -1680,6 +3449,25 @@ fn resolve_targets<R: BattleRng>(
             enemy_units,
             trigger_target_id,
         ),
+        AbilityTarget::PreviousTargets => previous_targets
+            .iter()
+            .copied()
+            .filter(|id| {
+                find_unit(*id, player_units, enemy_units).is_some_and(CombatUnit::is_alive)
+            })
+            .collect(),
+        AbilityTarget::AllWithTag { scope, tag } => resolve_scope_units(
+            *scope,
+            source_instance_id,
+            source_team,
+            player_units,
+            enemy_units,
+            trigger_target_id,
+        )
+        .iter()
+        .filter(|u| u.tags.contains(*tag))
+        .map(|u| u.instance_id)
+        .collect(),
     }
 }
 
@@ -1687,12 +3475,50 @@ fn resolve_targets<R: BattleRng>(
 // TARGETING HELPERS
 // ==========================================
 
+/// The scope an ability target selects from (`None` for `PreviousTargets`).
+fn target_scope(target: &AbilityTarget) -> Option<TargetScope> {
+    match target {
+        AbilityTarget::Position { scope, .. }
+        | AbilityTarget::Adjacent { scope }
+        | AbilityTarget::Random { scope, .. }
+        | AbilityTarget::Standard { scope, .. }
+        | AbilityTarget::All { scope }
+        | AbilityTarget::AllWithTag { scope, .. }
+        | AbilityTarget::RandomWithTag { scope, .. } => Some(*scope),
+        AbilityTarget::PreviousTargets => None,
+    }
+}
+
+/// Picks up to `count` of `units` in random order.
+fn pick_random_ids<R: BattleRng>(
+    units: Vec<&CombatUnit>,
+    count: CountValue,
+    rng: &mut R,
+) -> Vec<UnitInstanceId> {
+    if units.is_empty() {
+        return vec![];
+    }
+    let actual_count = (count as usize).min(units.len());
+    let mut results: Vec<UnitInstanceId> = units.iter().map(|u| u.instance_id).collect();
+    rng.shuffle(&mut results);
+    results.truncate(actual_count);
+    results
+}
+
+/// Guard: if any candidate of an enemy-scoped selector has Guard, only guarded
+/// candidates remain valid. `All { scope: Enemies }` never goes through this filter.
+fn apply_guard(scope: TargetScope, units: &mut Vec<&CombatUnit>) {
+    if scope == TargetScope::Enemies && units.iter().any(|u| u.has_status(Status::Guard)) {
+        units.retain(|u| u.has_status(Status::Guard));
+    }
+}
+
 fn resolve_scope_ids(
     scope: TargetScope,
     source_id: UnitInstanceId,
     source_team: Team,
-    player_units: &[CombatUnit],
-    enemy_units: &[CombatUnit],
+    player_units: &UnitList,
+    enemy_units: &UnitList,
     trigger_target_id: Option<UnitInstanceId>,
 ) -> Vec<UnitInstanceId> {
     resolve_scope_units(
@@ -1712,8 +3538,8 @@ fn resolve_scope_units<'a>(
     scope: TargetScope,
     source_id: UnitInstanceId,
     source_team: Team,
-    player_units: &'a [CombatUnit],
-    enemy_units: &'a [CombatUnit],
+    player_units: &'a UnitList,
+    enemy_units: &'a UnitList,
     trigger_target_id: Option<UnitInstanceId>,
 ) -> Vec<&'a CombatUnit> {
     let (allies, enemies) = match source_team {
@@ -1723,7 +3549,7 @@ fn resolve_scope_units<'a>(
 
     match scope {
         TargetScope::SelfUnit => {
-            if let Some(u) = find_unit_in_slices(source_id, player_units, enemy_units) {
+            if let Some(u) = find_unit(source_id, player_units, enemy_units) {
                 vec![u]
             } else {
                 vec![]
@@ -1738,7 +3564,7 @@ fn resolve_scope_units<'a>(
             .collect(),
         TargetScope::TriggerSource | TargetScope::Aggressor => {
             if let Some(tid) = trigger_target_id {
-                if let Some(u) = find_unit_in_slices(tid, player_units, enemy_units) {
+                if let Some(u) = find_unit(tid, player_units, enemy_units) {
                     vec![u]
                 } else {
                     vec![]
@@ -1750,17 +3576,6 @@ fn resolve_scope_units<'a>(
     }
 }
 
-fn find_unit_in_slices<'a>(
-    instance_id: UnitInstanceId,
-    player_units: &'a [CombatUnit],
-    enemy_units: &'a [CombatUnit],
-) -> Option<&'a CombatUnit> {
-    player_units
-        .iter()
-        .chain(enemy_units.iter())
-        .find(|u| u.instance_id == instance_id)
-}
-
 /// Resolve adjacent units for a source unit.
 /// The battle line is: [P5][P4][P3][P2][P1] <clash> [E1][E2][E3][E4][E5]
 /// Adjacent allies: same-team units at position ± 1.
@@ -1770,8 +3585,8 @@ fn resolve_adjacent(
     source_id: UnitInstanceId,
     source_team: Team,
     scope: TargetScope,
-    player_units: &[CombatUnit],
-    enemy_units: &[CombatUnit],
+    player_units: &UnitList,
+    enemy_units: &UnitList,
     source_position_override: Option<usize>,
 ) -> Vec<UnitInstanceId> {
     let (allies, enemies) = match source_team {
@@ -1780,7 +3595,7 @@ fn resolve_adjacent(
     };
 
     // Find source position (alive on board, or override for dead units)
-    let live_pos = allies.iter().position(|u| u.instance_id == source_id);
+    let live_pos = allies.position(source_id);
     let pos = live_pos.or(source_position_override);
 
     let Some(pos) = pos else {
@@ -1796,10 +3611,11 @@ fn resolve_adjacent(
 
     // Same-team neighbors at position ± 1.
     // For dead units using a position override, the board has already compacted,
-    // so the dead unit's former right neighbor now sits at `pos`.
+    // so the dead unit's former right neighbor now sits at `pos`. Other deaths
+    // may have compacted it further, leaving `pos` past the end.
     if include_allies {
         if live_pos.is_none() && source_position_override.is_some() {
-            if pos > 0 {
+            if pos > 0 && pos <= allies.len() {
                 result.push(allies[pos - 1].instance_id);
             }
             if pos < allies.len() {
@@ -1829,8 +3645,8 @@ fn resolve_relative_position(
     source_id: UnitInstanceId,
     source_team: Team,
     index: SignedIndex,
-    player_units: &[CombatUnit],
-    enemy_units: &[CombatUnit],
+    player_units: &UnitList,
+    enemy_units: &UnitList,
     source_position_override: Option<usize>,
 ) -> Vec<UnitInstanceId> {
     let allies = match source_team {
@@ -1839,7 +3655,7 @@ fn resolve_relative_position(
     };
 
     // If unit is on the board, position() finds it (alive). Otherwise use override (dead).
-    let found_pos = allies.iter().position(|u| u.instance_id == source_id);
+    let found_pos = allies.position(source_id);
     let is_alive = found_pos.is_some();
     let pos = found_pos.or(source_position_override);
 
@@ -1888,14 +3704,16 @@ fn resolve_absolute_position(
     scope: TargetScope,
     source_team: Team,
     index: SignedIndex,
-    player_units: &[CombatUnit],
-    enemy_units: &[CombatUnit],
+    player_units: &UnitList,
+    enemy_units: &UnitList,
 ) -> Vec<UnitInstanceId> {
-    let targets = match (scope, source_team) {
+    let board = match (scope, source_team) {
         (TargetScope::Allies, Team::Player) | (TargetScope::Enemies, Team::Enemy) => player_units,
         (TargetScope::Allies, Team::Enemy) | (TargetScope::Enemies, Team::Player) => enemy_units,
         _ => return vec![],
     };
+    let mut targets: Vec<&CombatUnit> = board.iter().collect();
+    apply_guard(scope, &mut targets);
 
     if targets.is_empty() {
         return vec![];
@@ -1922,8 +3740,8 @@ fn resolve_stat_based(
     stat: StatType,
     order: SortOrder,
     count: CountValue,
-    player_units: &[CombatUnit],
-    enemy_units: &[CombatUnit],
+    player_units: &UnitList,
+    enemy_units: &UnitList,
     trigger_target_id: Option<UnitInstanceId>,
 ) -> Vec<UnitInstanceId> {
     let mut units = resolve_scope_units(
@@ -1934,6 +3752,7 @@ fn resolve_stat_based(
         enemy_units,
         trigger_target_id,
     );
+    apply_guard(scope, &mut units);
     if units.is_empty() {
         return vec![];
     }
@@ -1962,66 +3781,203 @@ fn get_stat_value(unit: &CombatUnit, stat: StatType) -> StatValue {
     }
 }
 
+/// Evaluates an effect amount against the current boards.
+#[allow(clippy::too_many_arguments)]
+fn evaluate_value(
+    expr: &ValueExpr,
+    source_id: UnitInstanceId,
+    source_team: Team,
+    player_units: &UnitList,
+    enemy_units: &UnitList,
+    graveyard: &Graveyard,
+    trigger_target_id: Option<UnitInstanceId>,
+    trigger_count: CountValue,
+) -> StatValue {
+    let (term, factor) = match expr {
+        ValueExpr::Const(value) => return *value,
+        ValueExpr::Scaled { term, factor } => (term, *factor),
+    };
+    let base: i32 = match term {
+        ValueTerm::Stat {
+            scope: TargetScope::SelfUnit,
+            stat,
+        } => find_unit(source_id, player_units, enemy_units)
+            .or_else(|| graveyard.find(source_id))
+            .map_or(0, |unit| get_stat_value(unit, *stat) as i32),
+        ValueTerm::Stat { scope, stat } => resolve_scope_units(
+            *scope,
+            source_id,
+            source_team,
+            player_units,
+            enemy_units,
+            trigger_target_id,
+        )
+        .iter()
+        .map(|unit| get_stat_value(unit, *stat) as i32)
+        .sum(),
+        ValueTerm::UnitCount { scope } => resolve_scope_units(
+            *scope,
+            source_id,
+            source_team,
+            player_units,
+            enemy_units,
+            trigger_target_id,
+        )
+        .len() as i32,
+        ValueTerm::TagCount { scope, tag } => resolve_scope_units(
+            *scope,
+            source_id,
+            source_team,
+            player_units,
+            enemy_units,
+            trigger_target_id,
+        )
+        .iter()
+        .filter(|unit| unit.tags.contains(*tag))
+        .count() as i32,
+        ValueTerm::TriggerCount => trigger_count as i32,
+    };
+    base.saturating_mul(factor as i32)
+        .clamp(StatValue::MIN as i32, StatValue::MAX as i32) as StatValue
+}
+
 // ==========================================
 // CONDITION EVALUATION
 // ==========================================
 
 /// Evaluates a list of conditions (implicit AND).
+/// A condition nested deeper than `MAX_CONDITION_DEPTH` never passes, which
+/// also bounds the recursion in `evaluate_nested_condition`.
 fn evaluate_condition<R: BattleRng>(
     conditions: &[Condition],
     source: &CombatUnit,
-    player_units: &[CombatUnit],
-    enemy_units: &[CombatUnit],
+    player_units: &UnitList,
+    enemy_units: &UnitList,
+    rng: &mut R,
+    trigger_target_id: Option<UnitInstanceId>,
+) -> bool {
+    conditions.iter().all(|condition| {
+        condition.within_depth(MAX_CONDITION_DEPTH)
+            && evaluate_nested_condition(
+                condition,
+                source,
+                player_units,
+                enemy_units,
+                rng,
+                trigger_target_id,
+            )
+    })
+}
+
+/// Evaluates a single (possibly nested) condition. Callers must check depth first.
+fn evaluate_nested_condition<R: BattleRng>(
+    condition: &Condition,
+    source: &CombatUnit,
+    player_units: &UnitList,
+    enemy_units: &UnitList,
     rng: &mut R,
     trigger_target_id: Option<UnitInstanceId>,
 ) -> bool {
-    for condition in conditions {
-        match condition {
-            Condition::Is(matcher) => {
-                if !evaluate_matcher(
-                    matcher,
+    let (combinator, passed) = match condition {
+        Condition::Is(matcher) => {
+            return evaluate_matcher(
+                matcher,
+                source,
+                player_units,
+                enemy_units,
+                rng,
+                trigger_target_id,
+            )
+        }
+        Condition::AnyOf(children) => (
+            Combinator::AnyOf,
+            children.iter().any(|child| {
+                evaluate_nested_condition(
+                    child,
                     source,
                     player_units,
                     enemy_units,
                     rng,
                     trigger_target_id,
-                ) {
-                    return false;
-                }
-            }
-            Condition::AnyOf(matchers) => {
-                let mut any_passed = false;
-                for matcher in matchers {
-                    if evaluate_matcher(
-                        matcher,
-                        source,
-                        player_units,
-                        enemy_units,
-                        rng,
-                        trigger_target_id,
-                    ) {
-                        any_passed = true;
-                        break;
-                    }
-                }
-                if !any_passed {
-                    return false;
-                }
-            }
-        }
-    }
-    true
+                )
+            }),
+        ),
+        Condition::AllOf(children) => (
+            Combinator::AllOf,
+            children.iter().all(|child| {
+                evaluate_nested_condition(
+                    child,
+                    source,
+                    player_units,
+                    enemy_units,
+                    rng,
+                    trigger_target_id,
+                )
+            }),
+        ),
+        Condition::Not(child) => (
+            Combinator::Not,
+            !evaluate_nested_condition(
+                child,
+                source,
+                player_units,
+                enemy_units,
+                rng,
+                trigger_target_id,
+            ),
+        ),
+    };
+    trace::record(|| TraceEntry::Combined {
+        source: source.instance_id,
+        combinator,
+        passed,
+    });
+    passed
 }
 
 /// Evaluates a single matcher.
 fn evaluate_matcher<R: BattleRng>(
     matcher: &Matcher,
     source: &CombatUnit,
-    player_units: &[CombatUnit],
-    enemy_units: &[CombatUnit],
+    player_units: &UnitList,
+    enemy_units: &UnitList,
+    rng: &mut R,
+    trigger_target_id: Option<UnitInstanceId>,
+) -> bool {
+    let mut operands = Operands::new();
+    let passed = matcher_passes(
+        matcher,
+        source,
+        player_units,
+        enemy_units,
+        rng,
+        trigger_target_id,
+        &mut operands,
+    );
+    trace::record(|| TraceEntry::Condition {
+        source: source.instance_id,
+        matcher: matcher.clone(),
+        operands: operands.into_vec(),
+        passed,
+    });
+    passed
+}
+
+/// Evaluates a single matcher, collecting the values it compares into `operands`.
+fn matcher_passes<R: BattleRng>(
+    matcher: &Matcher,
+    source: &CombatUnit,
+    player_units: &UnitList,
+    enemy_units: &UnitList,
     rng: &mut R,
     trigger_target_id: Option<UnitInstanceId>,
+    operands: &mut Operands,
 ) -> bool {
+    let stat_operand = |unit: &CombatUnit, stat: StatType, value: StatValue| Operand::Stat {
+        unit: unit.instance_id.into(),
+        stat,
+        value,
+    };
     match matcher {
         Matcher::StatValueCompare {
             scope,
@@ -2046,9 +4002,11 @@ fn evaluate_matcher<R: BattleRng>(
                 return false;
             }
 
-            scoped_targets
-                .iter()
-                .any(|unit| compare_stat(get_stat_value(unit, *stat), *op, *value))
+            scoped_targets.iter().any(|unit| {
+                let actual = get_stat_value(unit, *stat);
+                operands.push(|| stat_operand(unit, *stat, actual));
+                compare_stat(actual, *op, *value)
+            })
         }
         Matcher::TargetStatValueCompare {
             target,
@@ -2064,6 +4022,7 @@ fn evaluate_matcher<R: BattleRng>(
                 enemy_units,
                 rng,
                 trigger_target_id,
+                &[],
                 None,
             );
             if target_ids.is_empty() {
@@ -2071,8 +4030,12 @@ fn evaluate_matcher<R: BattleRng>(
             }
 
             target_ids.iter().any(|target_id| {
-                find_unit_in_slices(*target_id, player_units, enemy_units)
-                    .map(|unit| compare_stat(get_stat_value(unit, *stat), *op, *value))
+                find_unit(*target_id, player_units, enemy_units)
+                    .map(|unit| {
+                        let actual = get_stat_value(unit, *stat);
+                        operands.push(|| stat_operand(unit, *stat, actual));
+                        compare_stat(actual, *op, *value)
+                    })
                     .unwrap_or(false)
             })
         }
@@ -2100,10 +4063,55 @@ fn evaluate_matcher<R: BattleRng>(
             }
 
             let source_val = get_stat_value(source, *source_stat);
+            operands.push(|| stat_operand(source, *source_stat, source_val));
             scoped_targets.iter().any(|target_unit| {
-                compare_stat(source_val, *op, get_stat_value(target_unit, *target_stat))
+                let target_val = get_stat_value(target_unit, *target_stat);
+                operands.push(|| stat_operand(target_unit, *target_stat, target_val));
+                compare_stat(source_val, *op, target_val)
+            })
+        }
+        Matcher::HasTag { scope, tag } => {
+            let scoped_targets: Vec<&CombatUnit> = if *scope == TargetScope::SelfUnit {
+                vec![source]
+            } else {
+                resolve_scope_units(
+                    *scope,
+                    source.instance_id,
+                    source.team,
+                    player_units,
+                    enemy_units,
+                    trigger_target_id,
+                )
+            };
+            scoped_targets.iter().any(|unit| {
+                let has_tag = unit.tags.contains(*tag);
+                operands.push(|| Operand::HasTag {
+                    unit: unit.instance_id.into(),
+                    has_tag,
+                });
+                has_tag
             })
         }
+        Matcher::TagCount {
+            scope,
+            tag,
+            op,
+            value,
+        } => {
+            let count = resolve_scope_units(
+                *scope,
+                source.instance_id,
+                source.team,
+                player_units,
+                enemy_units,
+                trigger_target_id,
+            )
+            .iter()
+            .filter(|unit| unit.tags.contains(*tag))
+            .count() as CountValue;
+            operands.push(|| Operand::Count(count));
+            compare_count(count, *op, *value)
+        }
         Matcher::UnitCount { scope, op, value } => {
             let count = resolve_scope_ids(
                 *scope,
@@ -2114,6 +4122,7 @@ fn evaluate_matcher<R: BattleRng>(
                 trigger_target_id,
             )
             .len() as CountValue;
+            operands.push(|| Operand::Count(count));
             compare_count(count, *op, *value)
         }
         Matcher::IsPosition { scope, index } => {
@@ -2142,11 +4151,38 @@ fn evaluate_matcher<R: BattleRng>(
                 return false;
             };
 
-            scoped_targets
-                .get(actual_idx)
+            let at_position = scoped_targets.get(actual_idx);
+            operands.push(|| Operand::Position {
+                index: actual_idx,
+                unit: at_position.map(|unit| unit.instance_id.into()),
+            });
+            at_position
                 .map(|unit| unit.instance_id == source.instance_id)
                 .unwrap_or(false)
         }
+        Matcher::MissingHealthCompare { scope, op, value } => {
+            let scoped_targets: Vec<&CombatUnit> = if *scope == TargetScope::SelfUnit {
+                vec![source]
+            } else {
+                resolve_scope_units(
+                    *scope,
+                    source.instance_id,
+                    source.team,
+                    player_units,
+                    enemy_units,
+                    trigger_target_id,
+                )
+            };
+
+            scoped_targets.iter().any(|unit| {
+                let missing = unit.missing_health();
+                operands.push(|| Operand::MissingHealth {
+                    unit: unit.instance_id.into(),
+                    value: missing,
+                });
+                compare_stat(missing, *op, *value)
+            })
+        }
     }
 }
 
@@ -2170,24 +4206,30 @@ fn compare_count(a: CountValue, op: CompareOp, b: CountValue) -> bool {
     }
 }
 
+/// Units always sit on the board of the team their instance id encodes, so a
+/// lookup only consults that board's index.
 fn find_unit<'a>(
     instance_id: UnitInstanceId,
-    player_units: &'a [CombatUnit],
-    enemy_units: &'a [CombatUnit],
+    player_units: &'a UnitList,
+    enemy_units: &'a UnitList,
 ) -> Option<&'a CombatUnit> {
-    player_units
-        .iter()
-        .chain(enemy_units.iter())
-        .find(|u| u.instance_id == instance_id)
+    let board = if instance_id.is_player() {
+        player_units
+    } else {
+        enemy_units
+    };
+    board.find(instance_id)
 }
 
 fn find_unit_mut<'a>(
     instance_id: UnitInstanceId,
-    player_units: &'a mut [CombatUnit],
-    enemy_units: &'a mut [CombatUnit],
+    player_units: &'a mut UnitList,
+    enemy_units: &'a mut UnitList,
 ) -> Option<&'a mut CombatUnit> {
-    player_units
-        .iter_mut()
-        .chain(enemy_units.iter_mut())
-        .find(|u| u.instance_id == instance_id)
+    let board = if instance_id.is_player() {
+        player_units
+    } else {
+        enemy_units
+    };
+    board.find_mut(instance_id)
 }
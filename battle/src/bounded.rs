@@ -5,20 +5,24 @@
 //!
 //! Requires the `bounded` feature.
 
+use alloc::boxed::Box;
 use alloc::string::String;
-use bounded_collections::{BoundedVec, Get};
+use alloc::vec::Vec;
+use bounded_collections::{BoundedVec, ConstU32, Get};
 use core::fmt::Debug;
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
 use crate::battle::{BattlePhase, BattleResult, UnitId};
 use crate::limits::{LimitReason, Team};
+use crate::modifiers::BattleModifier;
 use crate::state::CardSetEntry;
 use crate::types::{
     Ability, AbilityEffect, AbilityTarget, AbilityTrigger, CardId, CommitTurnAction, Condition,
-    CountValue, EconomyStats, ManaDelta, Matcher, ShopAbility, ShopCondition, ShopEffect,
-    ShopMatcher, ShopTarget, ShopTrigger, SpawnLocation, StatValue, TurnAction, UnitCard,
-    UnitStats,
+    CountValue, EconomyStats, KeywordMask, ManaDelta, Matcher, ResurrectPick, ResurrectStats,
+    ShopAbility, ShopCondition, ShopEffect, ShopMatcher, ShopTarget, ShopTrigger, ShopValueExpr,
+    SpawnLocation, SpawnSide, SpawnStats, StatValue, Status, StatusMask, TagMask, TurnAction,
+    UnitCard, UnitStats, ValueExpr, MAX_ABILITY_EFFECTS,
 };
 
 // --- Ghost Opponent Types (re-exported from types.rs) ---
@@ -82,124 +86,307 @@ fn bounded_to_string<S: Get<u32>>(b: BoundedVec<u8, S>) -> String {
 
 // --- Bounded Condition ---
 
-/// Bounded version of Condition for on-chain storage.
-/// The AnyOf variant uses a BoundedVec instead of Vec.
+/// One level of a depth-bounded condition tree.
+///
+/// The children of each level are the next level down, so the maximum
+/// nesting depth is fixed by the type and enforced by its SCALE encoding.
+pub trait BoundedConditionLevel<C>: Sized {
+    /// Converts a condition, or returns `None` if it nests too deeply for this level.
+    fn try_from_condition(condition: C) -> Option<Self>;
+    fn into_condition(self) -> C;
+}
+
+fn bounded_children<C, Child, MaxConditions>(
+    children: Vec<C>,
+) -> Option<BoundedVec<Child, MaxConditions>>
+where
+    Child: BoundedConditionLevel<C>,
+    MaxConditions: Get<u32>,
+{
+    let children = children
+        .into_iter()
+        .map(Child::try_from_condition)
+        .collect::<Option<Vec<_>>>()?;
+    Some(BoundedVec::truncate_from(children))
+}
+
+/// Deepest level of a bounded battle condition: a single matcher.
+/// Encodes identically to `BoundedBattleConditionNode::Is`.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen,
+)]
+pub enum BoundedBattleConditionLeaf {
+    Is(Matcher),
+}
+
+impl BoundedConditionLevel<Condition> for BoundedBattleConditionLeaf {
+    fn try_from_condition(condition: Condition) -> Option<Self> {
+        match condition {
+            Condition::Is(m) => Some(Self::Is(m)),
+            _ => None,
+        }
+    }
+
+    fn into_condition(self) -> Condition {
+        match self {
+            Self::Is(m) => Condition::Is(m),
+        }
+    }
+}
+
+/// A nesting level of a bounded battle condition.
+/// The composite variants use a BoundedVec instead of Vec.
 #[derive(Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(MaxConditions))]
-pub enum BoundedBattleCondition<MaxConditions>
+pub enum BoundedBattleConditionNode<Child, MaxConditions>
 where
     MaxConditions: Get<u32>,
 {
     /// A single mandatory requirement.
     Is(Matcher),
-    /// A "Shallow OR" list (bounded).
-    AnyOf(BoundedVec<Matcher, MaxConditions>),
-}
-
-impl<MaxConditions: Get<u32>> Clone for BoundedBattleCondition<MaxConditions> {
+    /// True if ANY of the nested conditions are true (bounded).
+    AnyOf(BoundedVec<Child, MaxConditions>),
+    /// True if ALL of the nested conditions are true (bounded).
+    AllOf(BoundedVec<Child, MaxConditions>),
+    /// Inverts the nested condition.
+    Not(Box<Child>),
+}
+
+/// Bounded version of Condition for on-chain storage, nested at most
+/// `MAX_CONDITION_DEPTH` levels deep.
+pub type BoundedBattleCondition<MaxConditions> = BoundedBattleConditionNode<
+    BoundedBattleConditionNode<
+        BoundedBattleConditionNode<BoundedBattleConditionLeaf, MaxConditions>,
+        MaxConditions,
+    >,
+    MaxConditions,
+>;
+
+impl<Child: Clone, MaxConditions: Get<u32>> Clone
+    for BoundedBattleConditionNode<Child, MaxConditions>
+{
     fn clone(&self) -> Self {
         match self {
             Self::Is(m) => Self::Is(m.clone()),
             Self::AnyOf(v) => Self::AnyOf(v.clone()),
+            Self::AllOf(v) => Self::AllOf(v.clone()),
+            Self::Not(c) => Self::Not(c.clone()),
         }
     }
 }
 
-impl<MaxConditions: Get<u32>> PartialEq for BoundedBattleCondition<MaxConditions> {
+impl<Child: PartialEq, MaxConditions: Get<u32>> PartialEq
+    for BoundedBattleConditionNode<Child, MaxConditions>
+{
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Is(m1), Self::Is(m2)) => m1 == m2,
             (Self::AnyOf(v1), Self::AnyOf(v2)) => v1 == v2,
+            (Self::AllOf(v1), Self::AllOf(v2)) => v1 == v2,
+            (Self::Not(c1), Self::Not(c2)) => c1 == c2,
             _ => false,
         }
     }
 }
 
-impl<MaxConditions: Get<u32>> Eq for BoundedBattleCondition<MaxConditions> {}
+impl<Child: Eq, MaxConditions: Get<u32>> Eq for BoundedBattleConditionNode<Child, MaxConditions> {}
 
-impl<MaxConditions: Get<u32>> Debug for BoundedBattleCondition<MaxConditions> {
+impl<Child: Debug, MaxConditions: Get<u32>> Debug
+    for BoundedBattleConditionNode<Child, MaxConditions>
+{
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Is(m) => f.debug_tuple("Is").field(m).finish(),
             Self::AnyOf(v) => f.debug_tuple("AnyOf").field(v).finish(),
+            Self::AllOf(v) => f.debug_tuple("AllOf").field(v).finish(),
+            Self::Not(c) => f.debug_tuple("Not").field(c).finish(),
         }
     }
 }
 
-impl<MaxConditions: Get<u32>> From<Condition> for BoundedBattleCondition<MaxConditions> {
-    fn from(c: Condition) -> Self {
-        match c {
+impl<Child, MaxConditions> BoundedConditionLevel<Condition>
+    for BoundedBattleConditionNode<Child, MaxConditions>
+where
+    Child: BoundedConditionLevel<Condition>,
+    MaxConditions: Get<u32>,
+{
+    fn try_from_condition(condition: Condition) -> Option<Self> {
+        Some(match condition {
             Condition::Is(m) => Self::Is(m),
-            Condition::AnyOf(v) => Self::AnyOf(BoundedVec::truncate_from(v)),
+            Condition::AnyOf(v) => Self::AnyOf(bounded_children(v)?),
+            Condition::AllOf(v) => Self::AllOf(bounded_children(v)?),
+            Condition::Not(c) => Self::Not(Box::new(Child::try_from_condition(*c)?)),
+        })
+    }
+
+    fn into_condition(self) -> Condition {
+        match self {
+            Self::Is(m) => Condition::Is(m),
+            Self::AnyOf(v) => Condition::AnyOf(v.into_iter().map(Child::into_condition).collect()),
+            Self::AllOf(v) => Condition::AllOf(v.into_iter().map(Child::into_condition).collect()),
+            Self::Not(c) => Condition::Not(Box::new(c.into_condition())),
         }
     }
 }
 
-impl<MaxConditions: Get<u32>> From<BoundedBattleCondition<MaxConditions>> for Condition {
-    fn from(bounded: BoundedBattleCondition<MaxConditions>) -> Self {
-        match bounded {
-            BoundedBattleCondition::Is(m) => Condition::Is(m),
-            BoundedBattleCondition::AnyOf(v) => Condition::AnyOf(v.into_inner()),
+impl<Child, MaxConditions> From<Condition> for BoundedBattleConditionNode<Child, MaxConditions>
+where
+    Child: BoundedConditionLevel<Condition>,
+    MaxConditions: Get<u32>,
+{
+    /// Conditions nested too deeply become an empty `AnyOf`, which never passes
+    /// (matching how `evaluate_condition` treats them).
+    fn from(c: Condition) -> Self {
+        Self::try_from_condition(c).unwrap_or_else(|| Self::AnyOf(BoundedVec::new()))
+    }
+}
+
+impl<Child, MaxConditions> From<BoundedBattleConditionNode<Child, MaxConditions>> for Condition
+where
+    Child: BoundedConditionLevel<Condition>,
+    MaxConditions: Get<u32>,
+{
+    fn from(bounded: BoundedBattleConditionNode<Child, MaxConditions>) -> Self {
+        bounded.into_condition()
+    }
+}
+
+/// Deepest level of a bounded shop condition: a single matcher.
+/// Encodes identically to `BoundedShopConditionNode::Is`.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen,
+)]
+pub enum BoundedShopConditionLeaf {
+    Is(ShopMatcher),
+}
+
+impl BoundedConditionLevel<ShopCondition> for BoundedShopConditionLeaf {
+    fn try_from_condition(condition: ShopCondition) -> Option<Self> {
+        match condition {
+            ShopCondition::Is(m) => Some(Self::Is(m)),
+            _ => None,
+        }
+    }
+
+    fn into_condition(self) -> ShopCondition {
+        match self {
+            Self::Is(m) => ShopCondition::Is(m),
         }
     }
 }
 
-/// Bounded version of ShopCondition for on-chain storage.
+/// A nesting level of a bounded shop condition.
 #[derive(Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(MaxConditions))]
-pub enum BoundedShopCondition<MaxConditions>
+pub enum BoundedShopConditionNode<Child, MaxConditions>
 where
     MaxConditions: Get<u32>,
 {
     Is(ShopMatcher),
-    AnyOf(BoundedVec<ShopMatcher, MaxConditions>),
-}
-
-impl<MaxConditions: Get<u32>> Clone for BoundedShopCondition<MaxConditions> {
+    AnyOf(BoundedVec<Child, MaxConditions>),
+    AllOf(BoundedVec<Child, MaxConditions>),
+    Not(Box<Child>),
+}
+
+/// Bounded version of ShopCondition for on-chain storage, nested at most
+/// `MAX_CONDITION_DEPTH` levels deep.
+pub type BoundedShopCondition<MaxConditions> = BoundedShopConditionNode<
+    BoundedShopConditionNode<
+        BoundedShopConditionNode<BoundedShopConditionLeaf, MaxConditions>,
+        MaxConditions,
+    >,
+    MaxConditions,
+>;
+
+impl<Child: Clone, MaxConditions: Get<u32>> Clone
+    for BoundedShopConditionNode<Child, MaxConditions>
+{
     fn clone(&self) -> Self {
         match self {
             Self::Is(m) => Self::Is(m.clone()),
             Self::AnyOf(v) => Self::AnyOf(v.clone()),
+            Self::AllOf(v) => Self::AllOf(v.clone()),
+            Self::Not(c) => Self::Not(c.clone()),
         }
     }
 }
 
-impl<MaxConditions: Get<u32>> PartialEq for BoundedShopCondition<MaxConditions> {
+impl<Child: PartialEq, MaxConditions: Get<u32>> PartialEq
+    for BoundedShopConditionNode<Child, MaxConditions>
+{
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Is(m1), Self::Is(m2)) => m1 == m2,
             (Self::AnyOf(v1), Self::AnyOf(v2)) => v1 == v2,
+            (Self::AllOf(v1), Self::AllOf(v2)) => v1 == v2,
+            (Self::Not(c1), Self::Not(c2)) => c1 == c2,
             _ => false,
         }
     }
 }
 
-impl<MaxConditions: Get<u32>> Eq for BoundedShopCondition<MaxConditions> {}
+impl<Child: Eq, MaxConditions: Get<u32>> Eq for BoundedShopConditionNode<Child, MaxConditions> {}
 
-impl<MaxConditions: Get<u32>> Debug for BoundedShopCondition<MaxConditions> {
+impl<Child: Debug, MaxConditions: Get<u32>> Debug
+    for BoundedShopConditionNode<Child, MaxConditions>
+{
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Is(m) => f.debug_tuple("Is").field(m).finish(),
             Self::AnyOf(v) => f.debug_tuple("AnyOf").field(v).finish(),
+            Self::AllOf(v) => f.debug_tuple("AllOf").field(v).finish(),
+            Self::Not(c) => f.debug_tuple("Not").field(c).finish(),
         }
     }
 }
 
-impl<MaxConditions: Get<u32>> From<ShopCondition> for BoundedShopCondition<MaxConditions> {
-    fn from(c: ShopCondition) -> Self {
-        match c {
+impl<Child, MaxConditions> BoundedConditionLevel<ShopCondition>
+    for BoundedShopConditionNode<Child, MaxConditions>
+where
+    Child: BoundedConditionLevel<ShopCondition>,
+    MaxConditions: Get<u32>,
+{
+    fn try_from_condition(condition: ShopCondition) -> Option<Self> {
+        Some(match condition {
             ShopCondition::Is(m) => Self::Is(m),
-            ShopCondition::AnyOf(v) => Self::AnyOf(BoundedVec::truncate_from(v)),
+            ShopCondition::AnyOf(v) => Self::AnyOf(bounded_children(v)?),
+            ShopCondition::AllOf(v) => Self::AllOf(bounded_children(v)?),
+            ShopCondition::Not(c) => Self::Not(Box::new(Child::try_from_condition(*c)?)),
+        })
+    }
+
+    fn into_condition(self) -> ShopCondition {
+        match self {
+            Self::Is(m) => ShopCondition::Is(m),
+            Self::AnyOf(v) => {
+                ShopCondition::AnyOf(v.into_iter().map(Child::into_condition).collect())
+            }
+            Self::AllOf(v) => {
+                ShopCondition::AllOf(v.into_iter().map(Child::into_condition).collect())
+            }
+            Self::Not(c) => ShopCondition::Not(Box::new(c.into_condition())),
         }
     }
 }
 
-impl<MaxConditions: Get<u32>> From<BoundedShopCondition<MaxConditions>> for ShopCondition {
-    fn from(bounded: BoundedShopCondition<MaxConditions>) -> Self {
-        match bounded {
-            BoundedShopCondition::Is(m) => ShopCondition::Is(m),
-            BoundedShopCondition::AnyOf(v) => ShopCondition::AnyOf(v.into_inner()),
-        }
+impl<Child, MaxConditions> From<ShopCondition> for BoundedShopConditionNode<Child, MaxConditions>
+where
+    Child: BoundedConditionLevel<ShopCondition>,
+    MaxConditions: Get<u32>,
+{
+    /// Conditions nested too deeply become an empty `AnyOf`, which never passes.
+    fn from(c: ShopCondition) -> Self {
+        Self::try_from_condition(c).unwrap_or_else(|| Self::AnyOf(BoundedVec::new()))
+    }
+}
+
+impl<Child, MaxConditions> From<BoundedShopConditionNode<Child, MaxConditions>> for ShopCondition
+where
+    Child: BoundedConditionLevel<ShopCondition>,
+    MaxConditions: Get<u32>,
+{
+    fn from(bounded: BoundedShopConditionNode<Child, MaxConditions>) -> Self {
+        bounded.into_condition()
     }
 }
 
@@ -210,28 +397,61 @@ impl<MaxConditions: Get<u32>> From<BoundedShopCondition<MaxConditions>> for Shop
 )]
 pub enum BoundedBattleEffect {
     Damage {
-        amount: StatValue,
+        amount: ValueExpr,
         target: AbilityTarget,
     },
     ModifyStats {
-        health: StatValue,
-        attack: StatValue,
+        health: ValueExpr,
+        attack: ValueExpr,
         target: AbilityTarget,
     },
     ModifyStatsPermanent {
-        health: StatValue,
-        attack: StatValue,
+        health: ValueExpr,
+        attack: ValueExpr,
         target: AbilityTarget,
     },
     SpawnUnit {
         card_id: CardId,
         spawn_location: SpawnLocation,
+        count: CountValue,
+        side: SpawnSide,
+        stats: SpawnStats,
     },
     Destroy {
         target: AbilityTarget,
     },
     GainMana {
-        amount: ManaDelta,
+        amount: ValueExpr,
+    },
+    GrantStatusThisBattle {
+        status: Status,
+        target: AbilityTarget,
+    },
+    GrantStatusPermanent {
+        status: Status,
+        target: AbilityTarget,
+    },
+    RemoveStatusPermanent {
+        status: Status,
+        target: AbilityTarget,
+    },
+    Heal {
+        amount: ValueExpr,
+        target: AbilityTarget,
+    },
+    Resurrect {
+        pick: ResurrectPick,
+        stats: ResurrectStats,
+        spawn_location: SpawnLocation,
+    },
+    Mimic {
+        target: AbilityTarget,
+    },
+    Steal {
+        target: AbilityTarget,
+    },
+    Silence {
+        target: AbilityTarget,
     },
 }
 
@@ -260,12 +480,40 @@ impl From<AbilityEffect> for BoundedBattleEffect {
             AbilityEffect::SpawnUnit {
                 card_id,
                 spawn_location,
+                count,
+                side,
+                stats,
             } => Self::SpawnUnit {
                 card_id,
                 spawn_location,
+                count,
+                side,
+                stats,
             },
             AbilityEffect::Destroy { target } => Self::Destroy { target },
             AbilityEffect::GainMana { amount } => Self::GainMana { amount },
+            AbilityEffect::GrantStatusThisBattle { status, target } => {
+                Self::GrantStatusThisBattle { status, target }
+            }
+            AbilityEffect::GrantStatusPermanent { status, target } => {
+                Self::GrantStatusPermanent { status, target }
+            }
+            AbilityEffect::RemoveStatusPermanent { status, target } => {
+                Self::RemoveStatusPermanent { status, target }
+            }
+            AbilityEffect::Heal { amount, target } => Self::Heal { amount, target },
+            AbilityEffect::Resurrect {
+                pick,
+                stats,
+                spawn_location,
+            } => Self::Resurrect {
+                pick,
+                stats,
+                spawn_location,
+            },
+            AbilityEffect::Mimic { target } => Self::Mimic { target },
+            AbilityEffect::Steal { target } => Self::Steal { target },
+            AbilityEffect::Silence { target } => Self::Silence { target },
         }
     }
 }
@@ -297,12 +545,40 @@ impl From<BoundedBattleEffect> for AbilityEffect {
             BoundedBattleEffect::SpawnUnit {
                 card_id,
                 spawn_location,
+                count,
+                side,
+                stats,
             } => AbilityEffect::SpawnUnit {
                 card_id,
                 spawn_location,
+                count,
+                side,
+                stats,
             },
             BoundedBattleEffect::Destroy { target } => AbilityEffect::Destroy { target },
             BoundedBattleEffect::GainMana { amount } => AbilityEffect::GainMana { amount },
+            BoundedBattleEffect::GrantStatusThisBattle { status, target } => {
+                AbilityEffect::GrantStatusThisBattle { status, target }
+            }
+            BoundedBattleEffect::GrantStatusPermanent { status, target } => {
+                AbilityEffect::GrantStatusPermanent { status, target }
+            }
+            BoundedBattleEffect::RemoveStatusPermanent { status, target } => {
+                AbilityEffect::RemoveStatusPermanent { status, target }
+            }
+            BoundedBattleEffect::Heal { amount, target } => AbilityEffect::Heal { amount, target },
+            BoundedBattleEffect::Resurrect {
+                pick,
+                stats,
+                spawn_location,
+            } => AbilityEffect::Resurrect {
+                pick,
+                stats,
+                spawn_location,
+            },
+            BoundedBattleEffect::Mimic { target } => AbilityEffect::Mimic { target },
+            BoundedBattleEffect::Steal { target } => AbilityEffect::Steal { target },
+            BoundedBattleEffect::Silence { target } => AbilityEffect::Silence { target },
         }
     }
 }
@@ -312,19 +588,36 @@ impl From<BoundedBattleEffect> for AbilityEffect {
 )]
 pub enum BoundedShopEffect {
     ModifyStatsPermanent {
-        health: StatValue,
-        attack: StatValue,
+        health: ShopValueExpr,
+        attack: ShopValueExpr,
         target: ShopTarget,
     },
     SpawnUnit {
         card_id: CardId,
         spawn_location: SpawnLocation,
+        count: CountValue,
+        stats: SpawnStats,
     },
     Destroy {
         target: ShopTarget,
     },
     GainMana {
-        amount: ManaDelta,
+        amount: ShopValueExpr,
+    },
+    GrantStatusPermanent {
+        status: Status,
+        target: ShopTarget,
+    },
+    RemoveStatusPermanent {
+        status: Status,
+        target: ShopTarget,
+    },
+    Heal {
+        amount: ShopValueExpr,
+        target: ShopTarget,
+    },
+    Silence {
+        target: ShopTarget,
     },
 }
 
@@ -343,12 +636,24 @@ impl From<ShopEffect> for BoundedShopEffect {
             ShopEffect::SpawnUnit {
                 card_id,
                 spawn_location,
+                count,
+                stats,
             } => Self::SpawnUnit {
                 card_id,
                 spawn_location,
+                count,
+                stats,
             },
             ShopEffect::Destroy { target } => Self::Destroy { target },
             ShopEffect::GainMana { amount } => Self::GainMana { amount },
+            ShopEffect::GrantStatusPermanent { status, target } => {
+                Self::GrantStatusPermanent { status, target }
+            }
+            ShopEffect::RemoveStatusPermanent { status, target } => {
+                Self::RemoveStatusPermanent { status, target }
+            }
+            ShopEffect::Heal { amount, target } => Self::Heal { amount, target },
+            ShopEffect::Silence { target } => Self::Silence { target },
         }
     }
 }
@@ -368,18 +673,33 @@ impl From<BoundedShopEffect> for ShopEffect {
             BoundedShopEffect::SpawnUnit {
                 card_id,
                 spawn_location,
+                count,
+                stats,
             } => ShopEffect::SpawnUnit {
                 card_id,
                 spawn_location,
+                count,
+                stats,
             },
             BoundedShopEffect::Destroy { target } => ShopEffect::Destroy { target },
             BoundedShopEffect::GainMana { amount } => ShopEffect::GainMana { amount },
+            BoundedShopEffect::GrantStatusPermanent { status, target } => {
+                ShopEffect::GrantStatusPermanent { status, target }
+            }
+            BoundedShopEffect::RemoveStatusPermanent { status, target } => {
+                ShopEffect::RemoveStatusPermanent { status, target }
+            }
+            BoundedShopEffect::Heal { amount, target } => ShopEffect::Heal { amount, target },
+            BoundedShopEffect::Silence { target } => ShopEffect::Silence { target },
         }
     }
 }
 
 // --- Bounded Ability ---
 
+/// Bound on an ability's `then` list: every step after the first effect.
+pub type MaxFollowUpEffects = ConstU32<{ MAX_ABILITY_EFFECTS as u32 - 1 }>;
+
 #[derive(Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(MaxConditions))]
 pub struct BoundedBattleAbility<MaxConditions>
@@ -390,6 +710,9 @@ where
     pub effect: BoundedBattleEffect,
     pub conditions: BoundedVec<BoundedBattleCondition<MaxConditions>, MaxConditions>,
     pub max_triggers: Option<CountValue>,
+    pub then: BoundedVec<BoundedBattleEffect, MaxFollowUpEffects>,
+    pub max_triggers_per_round: Option<CountValue>,
+    pub cooldown: Option<CountValue>,
 }
 
 impl<MaxConditions: Get<u32>> Clone for BoundedBattleAbility<MaxConditions> {
@@ -399,6 +722,9 @@ impl<MaxConditions: Get<u32>> Clone for BoundedBattleAbility<MaxConditions> {
             effect: self.effect.clone(),
             conditions: self.conditions.clone(),
             max_triggers: self.max_triggers,
+            then: self.then.clone(),
+            max_triggers_per_round: self.max_triggers_per_round,
+            cooldown: self.cooldown,
         }
     }
 }
@@ -409,6 +735,9 @@ impl<MaxConditions: Get<u32>> PartialEq for BoundedBattleAbility<MaxConditions>
             && self.effect == other.effect
             && self.conditions == other.conditions
             && self.max_triggers == other.max_triggers
+            && self.then == other.then
+            && self.max_triggers_per_round == other.max_triggers_per_round
+            && self.cooldown == other.cooldown
     }
 }
 
@@ -421,6 +750,9 @@ impl<MaxConditions: Get<u32>> Debug for BoundedBattleAbility<MaxConditions> {
             .field("effect", &self.effect)
             .field("conditions", &self.conditions)
             .field("max_triggers", &self.max_triggers)
+            .field("then", &self.then)
+            .field("max_triggers_per_round", &self.max_triggers_per_round)
+            .field("cooldown", &self.cooldown)
             .finish()
     }
 }
@@ -434,6 +766,9 @@ impl<MaxConditions: Get<u32>> From<Ability> for BoundedBattleAbility<MaxConditio
                 a.conditions.into_iter().map(Into::into).collect(),
             ),
             max_triggers: a.max_triggers,
+            then: BoundedVec::truncate_from(a.then.into_iter().map(Into::into).collect()),
+            max_triggers_per_round: a.max_triggers_per_round,
+            cooldown: a.cooldown,
         }
     }
 }
@@ -450,6 +785,14 @@ impl<MaxConditions: Get<u32>> From<BoundedBattleAbility<MaxConditions>> for Abil
                 .map(Into::into)
                 .collect(),
             max_triggers: bounded.max_triggers,
+            then: bounded
+                .then
+                .into_inner()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            max_triggers_per_round: bounded.max_triggers_per_round,
+            cooldown: bounded.cooldown,
         }
     }
 }
@@ -464,6 +807,7 @@ where
     pub effect: BoundedShopEffect,
     pub conditions: BoundedVec<BoundedShopCondition<MaxConditions>, MaxConditions>,
     pub max_triggers: Option<CountValue>,
+    pub then: BoundedVec<BoundedShopEffect, MaxFollowUpEffects>,
 }
 
 impl<MaxConditions: Get<u32>> Clone for BoundedShopAbility<MaxConditions> {
@@ -473,6 +817,7 @@ impl<MaxConditions: Get<u32>> Clone for BoundedShopAbility<MaxConditions> {
             effect: self.effect.clone(),
             conditions: self.conditions.clone(),
             max_triggers: self.max_triggers,
+            then: self.then.clone(),
         }
     }
 }
@@ -483,6 +828,7 @@ impl<MaxConditions: Get<u32>> PartialEq for BoundedShopAbility<MaxConditions> {
             && self.effect == other.effect
             && self.conditions == other.conditions
             && self.max_triggers == other.max_triggers
+            && self.then == other.then
     }
 }
 
@@ -495,6 +841,7 @@ impl<MaxConditions: Get<u32>> Debug for BoundedShopAbility<MaxConditions> {
             .field("effect", &self.effect)
             .field("conditions", &self.conditions)
             .field("max_triggers", &self.max_triggers)
+            .field("then", &self.then)
             .finish()
     }
 }
@@ -508,6 +855,7 @@ impl<MaxConditions: Get<u32>> From<ShopAbility> for BoundedShopAbility<MaxCondit
                 a.conditions.into_iter().map(Into::into).collect(),
             ),
             max_triggers: a.max_triggers,
+            then: BoundedVec::truncate_from(a.then.into_iter().map(Into::into).collect()),
         }
     }
 }
@@ -524,6 +872,12 @@ impl<MaxConditions: Get<u32>> From<BoundedShopAbility<MaxConditions>> for ShopAb
                 .map(Into::into)
                 .collect(),
             max_triggers: bounded.max_triggers,
+            then: bounded
+                .then
+                .into_inner()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
         }
     }
 }
@@ -544,6 +898,9 @@ where
     pub economy: EconomyStats,
     pub shop_abilities: BoundedVec<BoundedShopAbility<MaxConditions>, MaxAbilities>,
     pub battle_abilities: BoundedVec<BoundedBattleAbility<MaxConditions>, MaxAbilities>,
+    pub base_statuses: StatusMask,
+    pub tags: TagMask,
+    pub keywords: KeywordMask,
 }
 
 impl<MaxAbilities: Get<u32>, MaxStringLen: Get<u32>, MaxConditions: Get<u32>> Clone
@@ -557,6 +914,9 @@ impl<MaxAbilities: Get<u32>, MaxStringLen: Get<u32>, MaxConditions: Get<u32>> Cl
             economy: self.economy.clone(),
             shop_abilities: self.shop_abilities.clone(),
             battle_abilities: self.battle_abilities.clone(),
+            base_statuses: self.base_statuses,
+            tags: self.tags,
+            keywords: self.keywords,
         }
     }
 }
@@ -571,6 +931,9 @@ impl<MaxAbilities: Get<u32>, MaxStringLen: Get<u32>, MaxConditions: Get<u32>> Pa
             && self.economy == other.economy
             && self.shop_abilities == other.shop_abilities
             && self.battle_abilities == other.battle_abilities
+            && self.base_statuses == other.base_statuses
+            && self.tags == other.tags
+            && self.keywords == other.keywords
     }
 }
 
@@ -590,6 +953,9 @@ impl<MaxAbilities: Get<u32>, MaxStringLen: Get<u32>, MaxConditions: Get<u32>> De
             .field("economy", &self.economy)
             .field("shop_abilities", &self.shop_abilities)
             .field("battle_abilities", &self.battle_abilities)
+            .field("base_statuses", &self.base_statuses)
+            .field("tags", &self.tags)
+            .field("keywords", &self.keywords)
             .finish()
     }
 }
@@ -613,6 +979,9 @@ where
             battle_abilities: BoundedVec::truncate_from(
                 card.battle_abilities.into_iter().map(Into::into).collect(),
             ),
+            base_statuses: card.base_statuses,
+            tags: card.tags,
+            keywords: card.keywords,
         }
     }
 }
@@ -642,6 +1011,9 @@ where
                 .into_iter()
                 .map(Into::into)
                 .collect(),
+            base_statuses: bounded.base_statuses,
+            tags: bounded.tags,
+            keywords: bounded.keywords,
         }
     }
 }
@@ -788,6 +1160,7 @@ where
     pub attack: StatValue,
     pub health: StatValue,
     pub battle_abilities: BoundedVec<BoundedBattleAbility<MaxConditions>, MaxAbilities>,
+    pub statuses: StatusMask,
 }
 
 impl<MaxAbilities: Get<u32>, MaxStringLen: Get<u32>, MaxConditions: Get<u32>> Clone
@@ -801,6 +1174,7 @@ impl<MaxAbilities: Get<u32>, MaxStringLen: Get<u32>, MaxConditions: Get<u32>> Cl
             attack: self.attack,
             health: self.health,
             battle_abilities: self.battle_abilities.clone(),
+            statuses: self.statuses,
         }
     }
 }
@@ -815,6 +1189,7 @@ impl<MaxAbilities: Get<u32>, MaxStringLen: Get<u32>, MaxConditions: Get<u32>> Pa
             && self.attack == other.attack
             && self.health == other.health
             && self.battle_abilities == other.battle_abilities
+            && self.statuses == other.statuses
     }
 }
 
@@ -834,6 +1209,7 @@ impl<MaxAbilities: Get<u32>, MaxStringLen: Get<u32>, MaxConditions: Get<u32>> De
             .field("attack", &self.attack)
             .field("health", &self.health)
             .field("battle_abilities", &self.battle_abilities)
+            .field("statuses", &self.statuses)
             .finish()
     }
 }
@@ -855,6 +1231,7 @@ where
             battle_abilities: BoundedVec::truncate_from(
                 uv.battle_abilities.into_iter().map(Into::into).collect(),
             ),
+            statuses: uv.statuses,
         }
     }
 }
@@ -879,6 +1256,7 @@ where
                 .into_iter()
                 .map(Into::into)
                 .collect(),
+            statuses: bounded.statuses,
         }
     }
 }
@@ -907,11 +1285,15 @@ where
     Clash {
         p_dmg: StatValue,
         e_dmg: StatValue,
+        player_instance_id: UnitId,
+        enemy_instance_id: UnitId,
     },
     DamageTaken {
         target_instance_id: UnitId,
         team: Team,
         remaining_hp: StatValue,
+        source_instance_id: UnitId,
+        damage: StatValue,
     },
     UnitDeath {
         team: Team,
@@ -927,6 +1309,12 @@ where
         damage: StatValue,
         remaining_hp: StatValue,
     },
+    AbilityHeal {
+        source_instance_id: UnitId,
+        target_instance_id: UnitId,
+        amount: StatValue,
+        new_health: StatValue,
+    },
     AbilityModifyStats {
         source_instance_id: UnitId,
         target_instance_id: UnitId,
@@ -958,6 +1346,67 @@ where
         new_board_state:
             BoundedVec<BoundedUnitView<MaxAbilities, MaxStringLen, MaxConditions>, MaxBoardSize>,
     },
+    StatusApplied {
+        target_instance_id: UnitId,
+        status: Status,
+        permanent: bool,
+    },
+    StatusRemoved {
+        target_instance_id: UnitId,
+        status: Status,
+        permanent: bool,
+    },
+    StatusConsumed {
+        target_instance_id: UnitId,
+        status: Status,
+    },
+    FirstStrike {
+        source_instance_id: UnitId,
+        target_instance_id: UnitId,
+    },
+    Trample {
+        source_instance_id: UnitId,
+        target_instance_id: UnitId,
+        damage: StatValue,
+        remaining_hp: StatValue,
+    },
+    Windfury {
+        source_instance_id: UnitId,
+    },
+    Reach {
+        source_instance_id: UnitId,
+        target_instance_id: UnitId,
+        damage: StatValue,
+        remaining_hp: StatValue,
+    },
+    UnitTransform {
+        team: Team,
+        transformed_unit: BoundedUnitView<MaxAbilities, MaxStringLen, MaxConditions>,
+        new_board_state:
+            BoundedVec<BoundedUnitView<MaxAbilities, MaxStringLen, MaxConditions>, MaxBoardSize>,
+    },
+    UnitStolen {
+        source_instance_id: UnitId,
+        old_instance_id: UnitId,
+        new_instance_id: UnitId,
+        team: Team,
+        player_board_state:
+            BoundedVec<BoundedUnitView<MaxAbilities, MaxStringLen, MaxConditions>, MaxBoardSize>,
+        enemy_board_state:
+            BoundedVec<BoundedUnitView<MaxAbilities, MaxStringLen, MaxConditions>, MaxBoardSize>,
+    },
+    UnitSilenced {
+        source_instance_id: UnitId,
+        target_instance_id: UnitId,
+    },
+    ModifierActive {
+        modifier: BattleModifier,
+    },
+    FatigueDamage {
+        target_instance_id: UnitId,
+        damage: StatValue,
+        remaining_hp: StatValue,
+    },
     LimitExceeded {
         losing_team: Option<Team>,
         reason: LimitReason,
@@ -982,18 +1431,29 @@ impl<
                 source_instance_id: *source_instance_id,
                 ability_index: *ability_index,
             },
-            Self::Clash { p_dmg, e_dmg } => Self::Clash {
+            Self::Clash {
+                p_dmg,
+                e_dmg,
+                player_instance_id,
+                enemy_instance_id,
+            } => Self::Clash {
                 p_dmg: *p_dmg,
                 e_dmg: *e_dmg,
+                player_instance_id: *player_instance_id,
+                enemy_instance_id: *enemy_instance_id,
             },
             Self::DamageTaken {
                 target_instance_id,
                 team,
                 remaining_hp,
+                source_instance_id,
+                damage,
             } => Self::DamageTaken {
                 target_instance_id: *target_instance_id,
                 team: *team,
                 remaining_hp: *remaining_hp,
+                source_instance_id: *source_instance_id,
+                damage: *damage,
             },
             Self::UnitDeath {
                 team,
@@ -1016,6 +1476,17 @@ impl<
                 damage: *damage,
                 remaining_hp: *remaining_hp,
             },
+            Self::AbilityHeal {
+                source_instance_id,
+                target_instance_id,
+                amount,
+                new_health,
+            } => Self::AbilityHeal {
+                source_instance_id: *source_instance_id,
+                target_instance_id: *target_instance_id,
+                amount: *amount,
+                new_health: *new_health,
+            },
             Self::AbilityModifyStats {
                 source_instance_id,
                 target_instance_id,
@@ -1071,6 +1542,106 @@ impl<
                 spawned_unit: spawned_unit.clone(),
                 new_board_state: new_board_state.clone(),
             },
+            Self::StatusApplied {
+                target_instance_id,
+                status,
+                permanent,
+            } => Self::StatusApplied {
+                target_instance_id: *target_instance_id,
+                status: *status,
+                permanent: *permanent,
+            },
+            Self::StatusRemoved {
+                target_instance_id,
+                status,
+                permanent,
+            } => Self::StatusRemoved {
+                target_instance_id: *target_instance_id,
+                status: *status,
+                permanent: *permanent,
+            },
+            Self::StatusConsumed {
+                target_instance_id,
+                status,
+            } => Self::StatusConsumed {
+                target_instance_id: *target_instance_id,
+                status: *status,
+            },
+            Self::FirstStrike {
+                source_instance_id,
+                target_instance_id,
+            } => Self::FirstStrike {
+                source_instance_id: *source_instance_id,
+                target_instance_id: *target_instance_id,
+            },
+            Self::Trample {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => Self::Trample {
+                source_instance_id: *source_instance_id,
+                target_instance_id: *target_instance_id,
+                damage: *damage,
+                remaining_hp: *remaining_hp,
+            },
+            Self::Windfury { source_instance_id } => Self::Windfury {
+                source_instance_id: *source_instance_id,
+            },
+            Self::Reach {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => Self::Reach {
+                source_instance_id: *source_instance_id,
+                target_instance_id: *target_instance_id,
+                damage: *damage,
+                remaining_hp: *remaining_hp,
+            },
+            Self::UnitTransform {
+                team,
+                transformed_unit,
+                new_board_state,
+            } => Self::UnitTransform {
+                team: *team,
+                transformed_unit: transformed_unit.clone(),
+                new_board_state: new_board_state.clone(),
+            },
+            Self::UnitStolen {
+                source_instance_id,
+                old_instance_id,
+                new_instance_id,
+                team,
+                player_board_state,
+                enemy_board_state,
+            } => Self::UnitStolen {
+                source_instance_id: *source_instance_id,
+                old_instance_id: *old_instance_id,
+                new_instance_id: *new_instance_id,
+                team: *team,
+                player_board_state: player_board_state.clone(),
+                enemy_board_state: enemy_board_state.clone(),
+            },
+            Self::UnitSilenced {
+                source_instance_id,
+                target_instance_id,
+            } => Self::UnitSilenced {
+                source_instance_id: *source_instance_id,
+                target_instance_id: *target_instance_id,
+            },
+            Self::ModifierActive { modifier } => Self::ModifierActive {
+                modifier: *modifier,
+            },
+            Self::FatigueDamage {
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => Self::FatigueDamage {
+                target_instance_id: *target_instance_id,
+                damage: *damage,
+                remaining_hp: *remaining_hp,
+            },
             Self::LimitExceeded {
                 losing_team,
                 reason,
@@ -1107,24 +1678,32 @@ impl<
                 Self::Clash {
                     p_dmg: p1,
                     e_dmg: e1,
+                    player_instance_id: pi1,
+                    enemy_instance_id: ei1,
                 },
                 Self::Clash {
                     p_dmg: p2,
                     e_dmg: e2,
+                    player_instance_id: pi2,
+                    enemy_instance_id: ei2,
                 },
-            ) => p1 == p2 && e1 == e2,
+            ) => p1 == p2 && e1 == e2 && pi1 == pi2 && ei1 == ei2,
             (
                 Self::DamageTaken {
                     target_instance_id: t1,
                     team: tm1,
                     remaining_hp: r1,
+                    source_instance_id: s1,
+                    damage: d1,
                 },
                 Self::DamageTaken {
                     target_instance_id: t2,
                     team: tm2,
                     remaining_hp: r2,
+                    source_instance_id: s2,
+                    damage: d2,
                 },
-            ) => t1 == t2 && tm1 == tm2 && r1 == r2,
+            ) => t1 == t2 && tm1 == tm2 && r1 == r2 && s1 == s2 && d1 == d2,
             (
                 Self::UnitDeath {
                     team: tm1,
@@ -1150,6 +1729,20 @@ impl<
                     remaining_hp: r2,
                 },
             ) => s1 == s2 && t1 == t2 && d1 == d2 && r1 == r2,
+            (
+                Self::AbilityHeal {
+                    source_instance_id: s1,
+                    target_instance_id: t1,
+                    amount: a1,
+                    new_health: nh1,
+                },
+                Self::AbilityHeal {
+                    source_instance_id: s2,
+                    target_instance_id: t2,
+                    amount: a2,
+                    new_health: nh2,
+                },
+            ) => s1 == s2 && t1 == t2 && a1 == a2 && nh1 == nh2,
             (
                 Self::AbilityModifyStats {
                     source_instance_id: s1,
@@ -1220,6 +1813,154 @@ impl<
                     new_board_state: b2,
                 },
             ) => tm1 == tm2 && s1 == s2 && b1 == b2,
+            (
+                Self::StatusApplied {
+                    target_instance_id: t1,
+                    status: s1,
+                    permanent: p1,
+                },
+                Self::StatusApplied {
+                    target_instance_id: t2,
+                    status: s2,
+                    permanent: p2,
+                },
+            ) => t1 == t2 && s1 == s2 && p1 == p2,
+            (
+                Self::StatusRemoved {
+                    target_instance_id: t1,
+                    status: s1,
+                    permanent: p1,
+                },
+                Self::StatusRemoved {
+                    target_instance_id: t2,
+                    status: s2,
+                    permanent: p2,
+                },
+            ) => t1 == t2 && s1 == s2 && p1 == p2,
+            (
+                Self::StatusConsumed {
+                    target_instance_id: t1,
+                    status: s1,
+                },
+                Self::StatusConsumed {
+                    target_instance_id: t2,
+                    status: s2,
+                },
+            ) => t1 == t2 && s1 == s2,
+            (
+                Self::FirstStrike {
+                    source_instance_id: source_instance_id1,
+                    target_instance_id: target_instance_id1,
+                },
+                Self::FirstStrike {
+                    source_instance_id: source_instance_id2,
+                    target_instance_id: target_instance_id2,
+                },
+            ) => {
+                source_instance_id1 == source_instance_id2
+                    && target_instance_id1 == target_instance_id2
+            }
+            (
+                Self::Trample {
+                    source_instance_id: source_instance_id1,
+                    target_instance_id: target_instance_id1,
+                    damage: damage1,
+                    remaining_hp: remaining_hp1,
+                },
+                Self::Trample {
+                    source_instance_id: source_instance_id2,
+                    target_instance_id: target_instance_id2,
+                    damage: damage2,
+                    remaining_hp: remaining_hp2,
+                },
+            ) => {
+                source_instance_id1 == source_instance_id2
+                    && target_instance_id1 == target_instance_id2
+                    && damage1 == damage2
+                    && remaining_hp1 == remaining_hp2
+            }
+            (
+                Self::Windfury {
+                    source_instance_id: source_instance_id1,
+                },
+                Self::Windfury {
+                    source_instance_id: source_instance_id2,
+                },
+            ) => source_instance_id1 == source_instance_id2,
+            (
+                Self::Reach {
+                    source_instance_id: source_instance_id1,
+                    target_instance_id: target_instance_id1,
+                    damage: damage1,
+                    remaining_hp: remaining_hp1,
+                },
+                Self::Reach {
+                    source_instance_id: source_instance_id2,
+                    target_instance_id: target_instance_id2,
+                    damage: damage2,
+                    remaining_hp: remaining_hp2,
+                },
+            ) => {
+                source_instance_id1 == source_instance_id2
+                    && target_instance_id1 == target_instance_id2
+                    && damage1 == damage2
+                    && remaining_hp1 == remaining_hp2
+            }
+            (
+                Self::UnitTransform {
+                    team: tm1,
+                    transformed_unit: u1,
+                    new_board_state: b1,
+                },
+                Self::UnitTransform {
+                    team: tm2,
+                    transformed_unit: u2,
+                    new_board_state: b2,
+                },
+            ) => tm1 == tm2 && u1 == u2 && b1 == b2,
+            (
+                Self::UnitStolen {
+                    source_instance_id: s1,
+                    old_instance_id: o1,
+                    new_instance_id: n1,
+                    team: tm1,
+                    player_board_state: p1,
+                    enemy_board_state: e1,
+                },
+                Self::UnitStolen {
+                    source_instance_id: s2,
+                    old_instance_id: o2,
+                    new_instance_id: n2,
+                    team: tm2,
+                    player_board_state: p2,
+                    enemy_board_state: e2,
+                },
+            ) => s1 == s2 && o1 == o2 && n1 == n2 && tm1 == tm2 && p1 == p2 && e1 == e2,
+            (
+                Self::UnitSilenced {
+                    source_instance_id: s1,
+                    target_instance_id: t1,
+                },
+                Self::UnitSilenced {
+                    source_instance_id: s2,
+                    target_instance_id: t2,
+                },
+            ) => s1 == s2 && t1 == t2,
+            (Self::ModifierActive { modifier: m1 }, Self::ModifierActive { modifier: m2 }) => {
+                m1 == m2
+            }
+            (
+                Self::FatigueDamage {
+                    target_instance_id: t1,
+                    damage: d1,
+                    remaining_hp: r1,
+                },
+                Self::FatigueDamage {
+                    target_instance_id: t2,
+                    damage: d2,
+                    remaining_hp: r2,
+                },
+            ) => t1 == t2 && d1 == d2 && r1 == r2,
             (
                 Self::LimitExceeded {
                     losing_team: l1,
@@ -1265,20 +2006,31 @@ impl<
                 .field("source_instance_id", source_instance_id)
                 .field("ability_index", ability_index)
                 .finish(),
-            Self::Clash { p_dmg, e_dmg } => f
+            Self::Clash {
+                p_dmg,
+                e_dmg,
+                player_instance_id,
+                enemy_instance_id,
+            } => f
                 .debug_struct("Clash")
                 .field("p_dmg", p_dmg)
                 .field("e_dmg", e_dmg)
+                .field("player_instance_id", player_instance_id)
+                .field("enemy_instance_id", enemy_instance_id)
                 .finish(),
             Self::DamageTaken {
                 target_instance_id,
                 team,
                 remaining_hp,
+                source_instance_id,
+                damage,
             } => f
                 .debug_struct("DamageTaken")
                 .field("target_instance_id", target_instance_id)
                 .field("team", team)
                 .field("remaining_hp", remaining_hp)
+                .field("source_instance_id", source_instance_id)
+                .field("damage", damage)
                 .finish(),
             Self::UnitDeath {
                 team,
@@ -1303,6 +2055,18 @@ impl<
                 .field("damage", damage)
                 .field("remaining_hp", remaining_hp)
                 .finish(),
+            Self::AbilityHeal {
+                source_instance_id,
+                target_instance_id,
+                amount,
+                new_health,
+            } => f
+                .debug_struct("AbilityHeal")
+                .field("source_instance_id", source_instance_id)
+                .field("target_instance_id", target_instance_id)
+                .field("amount", amount)
+                .field("new_health", new_health)
+                .finish(),
             Self::AbilityModifyStats {
                 source_instance_id,
                 target_instance_id,
@@ -1363,6 +2127,118 @@ impl<
                 .field("spawned_unit", spawned_unit)
                 .field("new_board_state", new_board_state)
                 .finish(),
+            Self::StatusApplied {
+                target_instance_id,
+                status,
+                permanent,
+            } => f
+                .debug_struct("StatusApplied")
+                .field("target_instance_id", target_instance_id)
+                .field("status", status)
+                .field("permanent", permanent)
+                .finish(),
+            Self::StatusRemoved {
+                target_instance_id,
+                status,
+                permanent,
+            } => f
+                .debug_struct("StatusRemoved")
+                .field("target_instance_id", target_instance_id)
+                .field("status", status)
+                .field("permanent", permanent)
+                .finish(),
+            Self::StatusConsumed {
+                target_instance_id,
+                status,
+            } => f
+                .debug_struct("StatusConsumed")
+                .field("target_instance_id", target_instance_id)
+                .field("status", status)
+                .finish(),
+            Self::FirstStrike {
+                source_instance_id,
+                target_instance_id,
+            } => f
+                .debug_struct("FirstStrike")
+                .field("source_instance_id", source_instance_id)
+                .field("target_instance_id", target_instance_id)
+                .finish(),
+            Self::Trample {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => f
+                .debug_struct("Trample")
+                .field("source_instance_id", source_instance_id)
+                .field("target_instance_id", target_instance_id)
+                .field("damage", damage)
+                .field("remaining_hp", remaining_hp)
+                .finish(),
+            Self::Windfury { source_instance_id } => f
+                .debug_struct("Windfury")
+                .field("source_instance_id", source_instance_id)
+                .finish(),
+            Self::Reach {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => f
+                .debug_struct("Reach")
+                .field("source_instance_id", source_instance_id)
+                .field("target_instance_id", target_instance_id)
+                .field("damage", damage)
+                .field("remaining_hp", remaining_hp)
+                .finish(),
+            Self::UnitTransform {
+                team,
+                transformed_unit,
+                new_board_state,
+            } => f
+                .debug_struct("UnitTransform")
+                .field("team", team)
+                .field("transformed_unit", transformed_unit)
+                .field("new_board_state", new_board_state)
+                .finish(),
+            Self::UnitStolen {
+                source_instance_id,
+                old_instance_id,
+                new_instance_id,
+                team,
+                player_board_state,
+                enemy_board_state,
+            } => f
+                .debug_struct("UnitStolen")
+                .field("source_instance_id", source_instance_id)
+                .field("old_instance_id", old_instance_id)
+                .field("new_instance_id", new_instance_id)
+                .field("team", team)
+                .field("player_board_state", player_board_state)
+                .field("enemy_board_state", enemy_board_state)
+                .finish(),
+            Self::UnitSilenced {
+                source_instance_id,
+                target_instance_id,
+            } => f
+                .debug_struct("UnitSilenced")
+                .field("source_instance_id", source_instance_id)
+                .field("target_instance_id", target_instance_id)
+                .finish(),
+            Self::ModifierActive { modifier } => f
+                .debug_struct("ModifierActive")
+                .field("modifier", modifier)
+                .finish(),
+            Self::FatigueDamage {
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => f
+                .debug_struct("FatigueDamage")
+                .field("target_instance_id", target_instance_id)
+                .field("damage", damage)
+                .field("remaining_hp", remaining_hp)
+                .finish(),
             Self::LimitExceeded {
                 losing_team,
                 reason,
@@ -1394,15 +2270,29 @@ where
                 source_instance_id,
                 ability_index,
             },
-            crate::battle::CombatEvent::Clash { p_dmg, e_dmg } => Self::Clash { p_dmg, e_dmg },
+            crate::battle::CombatEvent::Clash {
+                p_dmg,
+                e_dmg,
+                player_instance_id,
+                enemy_instance_id,
+            } => Self::Clash {
+                p_dmg,
+                e_dmg,
+                player_instance_id,
+                enemy_instance_id,
+            },
             crate::battle::CombatEvent::DamageTaken {
                 target_instance_id,
                 team,
                 remaining_hp,
+                source_instance_id,
+                damage,
             } => Self::DamageTaken {
                 target_instance_id,
                 team,
                 remaining_hp,
+                source_instance_id,
+                damage,
             },
             crate::battle::CombatEvent::UnitDeath {
                 team,
@@ -1425,6 +2315,17 @@ where
                 damage,
                 remaining_hp,
             },
+            crate::battle::CombatEvent::AbilityHeal {
+                source_instance_id,
+                target_instance_id,
+                amount,
+                new_health,
+            } => Self::AbilityHeal {
+                source_instance_id,
+                target_instance_id,
+                amount,
+                new_health,
+            },
             crate::battle::CombatEvent::AbilityModifyStats {
                 source_instance_id,
                 target_instance_id,
@@ -1482,6 +2383,112 @@ where
                     new_board_state.into_iter().map(Into::into).collect(),
                 ),
             },
+            crate::battle::CombatEvent::StatusApplied {
+                target_instance_id,
+                status,
+                permanent,
+            } => Self::StatusApplied {
+                target_instance_id,
+                status,
+                permanent,
+            },
+            crate::battle::CombatEvent::StatusRemoved {
+                target_instance_id,
+                status,
+                permanent,
+            } => Self::StatusRemoved {
+                target_instance_id,
+                status,
+                permanent,
+            },
+            crate::battle::CombatEvent::StatusConsumed {
+                target_instance_id,
+                status,
+            } => Self::StatusConsumed {
+                target_instance_id,
+                status,
+            },
+            crate::battle::CombatEvent::FirstStrike {
+                source_instance_id,
+                target_instance_id,
+            } => Self::FirstStrike {
+                source_instance_id,
+                target_instance_id,
+            },
+            crate::battle::CombatEvent::Trample {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => Self::Trample {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            },
+            crate::battle::CombatEvent::Windfury { source_instance_id } => {
+                Self::Windfury { source_instance_id }
+            }
+            crate::battle::CombatEvent::Reach {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => Self::Reach {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            },
+            crate::battle::CombatEvent::UnitTransform {
+                team,
+                transformed_unit,
+                new_board_state,
+            } => Self::UnitTransform {
+                team,
+                transformed_unit: transformed_unit.into(),
+                new_board_state: BoundedVec::truncate_from(
+                    new_board_state.into_iter().map(Into::into).collect(),
+                ),
+            },
+            crate::battle::CombatEvent::UnitStolen {
+                source_instance_id,
+                old_instance_id,
+                new_instance_id,
+                team,
+                player_board_state,
+                enemy_board_state,
+            } => Self::UnitStolen {
+                source_instance_id,
+                old_instance_id,
+                new_instance_id,
+                team,
+                player_board_state: BoundedVec::truncate_from(
+                    player_board_state.into_iter().map(Into::into).collect(),
+                ),
+                enemy_board_state: BoundedVec::truncate_from(
+                    enemy_board_state.into_iter().map(Into::into).collect(),
+                ),
+            },
+            crate::battle::CombatEvent::UnitSilenced {
+                source_instance_id,
+                target_instance_id,
+            } => Self::UnitSilenced {
+                source_instance_id,
+                target_instance_id,
+            },
+            crate::battle::CombatEvent::ModifierActive { modifier } => {
+                Self::ModifierActive { modifier }
+            }
+            crate::battle::CombatEvent::FatigueDamage {
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => Self::FatigueDamage {
+                target_instance_id,
+                damage,
+                remaining_hp,
+            },
             crate::battle::CombatEvent::LimitExceeded {
                 losing_team,
                 reason,
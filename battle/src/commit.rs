@@ -8,26 +8,26 @@ use alloc::vec;
 use alloc::vec::Vec;
 
 use crate::error::{GameError, GameResult};
-use crate::rng::{BattleRng, XorShiftRng};
+use crate::rng::{BattleRng, RngStream, VersionedRng};
 use crate::state::ShopState;
+use crate::trace::{self, Combinator, Operand, Operands, SkipReason, TraceEntry, TraceUnit};
 use crate::types::{
-    CardId, CommitTurnAction, CompareOp, CountValue, IndexValue, ManaValue, ShopAbility,
-    ShopCondition, ShopEffect, ShopMatcher, ShopScope, ShopTarget, ShopTrigger, SignedIndex,
-    StatType, StatValue, TurnAction,
+    BoardUnit, CardId, CommitTurnAction, CompareOp, CountValue, IndexValue, ManaDelta, ManaValue,
+    ShopAbility, ShopCondition, ShopEffect, ShopMatcher, ShopScope, ShopTarget, ShopTrigger,
+    ShopValueExpr, ShopValueTerm, SignedIndex, SpawnLocation, SpawnStats, StatType, StatValue, Tag,
+    TurnAction, MAX_ABILITY_EFFECTS, MAX_CONDITION_DEPTH,
 };
 use crate::BattleResult;
 
-const SHOP_START_SALT: u64 = 0x5348_4f50_0000_0001;
-const SHOP_BUY_SALT: u64 = 0x5348_4f50_0000_0002;
-const SHOP_SELL_SALT: u64 = 0x5348_4f50_0000_0003;
-const SHOP_AFTER_LOSS_SALT: u64 = 0x5348_4f50_0000_0004;
-const SHOP_AFTER_WIN_SALT: u64 = 0x5348_4f50_0000_0005;
-const SHOP_AFTER_DRAW_SALT: u64 = 0x5348_4f50_0000_0006;
-
 #[derive(Clone)]
 struct ShopPendingAbility {
     source_slot: Option<usize>,
     source_on_board: bool,
+    /// The sold unit, for abilities that fire from it after it left the board.
+    sold_unit: Option<BoardUnit>,
+    card_id: CardId,
+    /// Index of the ability in its card's `shop_abilities`.
+    ability_index: usize,
     ability: ShopAbility,
 }
 
@@ -47,16 +47,17 @@ pub fn apply_shop_start_triggers_with_result(
     previous_battle_result: Option<BattleResult>,
 ) {
     state.shop_mana = state.shop_mana.min(state.mana_limit);
-    let mut start_rng = shop_rng(state, SHOP_START_SALT);
+    let round = state.round;
+    let mut start_rng = shop_rng(state, RngStream::ShopStart { round });
     execute_shop_trigger(state, ShopTrigger::OnShopStart, None, None, &mut start_rng);
 
     if let Some(result) = previous_battle_result {
-        let (trigger, salt) = match result {
-            BattleResult::Defeat => (ShopTrigger::AfterLoss, SHOP_AFTER_LOSS_SALT),
-            BattleResult::Victory => (ShopTrigger::AfterWin, SHOP_AFTER_WIN_SALT),
-            BattleResult::Draw => (ShopTrigger::AfterDraw, SHOP_AFTER_DRAW_SALT),
+        let (trigger, stream) = match result {
+            BattleResult::Defeat => (ShopTrigger::AfterLoss, RngStream::ShopAfterLoss { round }),
+            BattleResult::Victory => (ShopTrigger::AfterWin, RngStream::ShopAfterWin { round }),
+            BattleResult::Draw => (ShopTrigger::AfterDraw, RngStream::ShopAfterDraw { round }),
         };
-        let mut outcome_rng = shop_rng(state, salt);
+        let mut outcome_rng = shop_rng(state, stream);
         execute_shop_trigger(state, trigger, None, None, &mut outcome_rng);
     }
 
@@ -69,29 +70,34 @@ pub fn apply_on_buy_triggers(state: &mut ShopState, action_index: usize, bought_
         return;
     }
 
-    let mut rng = shop_rng(state, SHOP_BUY_SALT.wrapping_add(action_index as u64));
+    let stream = RngStream::ShopBuy {
+        round: state.round,
+        action_index: action_index as u32,
+    };
+    let mut rng = shop_rng(state, stream);
     execute_shop_trigger(state, ShopTrigger::OnBuy, Some(bought_slot), None, &mut rng);
 }
 
 /// Apply `OnSell` triggers for a successful shop sell action.
+///
+/// A silenced `sold_unit` does not fire its own abilities; allies still react.
 pub fn apply_on_sell_triggers(
     state: &mut ShopState,
     action_index: usize,
-    sold_card_id: CardId,
+    sold_unit: &BoardUnit,
     sold_slot: usize,
 ) {
     if sold_slot >= state.board.len() {
         return;
     }
 
-    let mut rng = shop_rng(state, SHOP_SELL_SALT.wrapping_add(action_index as u64));
-    execute_shop_trigger(
-        state,
-        ShopTrigger::OnSell,
-        None,
-        Some((sold_card_id, sold_slot)),
-        &mut rng,
-    );
+    let sold_source = (!sold_unit.silenced).then_some((sold_unit, sold_slot));
+    let stream = RngStream::ShopSell {
+        round: state.round,
+        action_index: action_index as u32,
+    };
+    let mut rng = shop_rng(state, stream);
+    execute_shop_trigger(state, ShopTrigger::OnSell, None, sold_source, &mut rng);
 }
 
 /// Find the nearest empty board slot to `target`, preferring higher indices first.
@@ -340,7 +346,7 @@ pub fn apply_single_action(
                 .min(state.mana_limit);
 
             state.shop_mana = ctx.current_mana;
-            apply_on_sell_triggers(state, ctx.action_index, sold_unit.card_id, bs);
+            apply_on_sell_triggers(state, ctx.action_index, &sold_unit, bs);
             ctx.current_mana = state.shop_mana;
         }
 
@@ -415,16 +421,15 @@ pub fn verify_and_apply_turn(state: &mut ShopState, action: &CommitTurnAction) -
     Ok(())
 }
 
-fn shop_rng(state: &ShopState, salt: u64) -> XorShiftRng {
-    let round_mix = (state.round as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
-    XorShiftRng::seed_from_u64(state.game_seed ^ round_mix ^ salt)
+fn shop_rng(state: &ShopState, stream: RngStream) -> VersionedRng {
+    VersionedRng::new(state.rng_version, state.game_seed, stream)
 }
 
 fn execute_shop_trigger<R: BattleRng>(
     state: &mut ShopState,
     trigger: ShopTrigger,
     trigger_source_slot: Option<usize>,
-    sold_source: Option<(CardId, usize)>,
+    sold_source: Option<(&BoardUnit, usize)>,
     rng: &mut R,
 ) {
     let mut pending = Vec::new();
@@ -433,29 +438,38 @@ fn execute_shop_trigger<R: BattleRng>(
         let Some(board_unit) = board_unit else {
             continue;
         };
+        if board_unit.silenced {
+            continue;
+        }
 
         let Some(card) = state.card_pool.get(&board_unit.card_id) else {
             continue;
         };
 
-        for ability in &card.shop_abilities {
+        for (ability_index, ability) in card.shop_abilities.iter().enumerate() {
             if ability.trigger == trigger {
                 pending.push(ShopPendingAbility {
                     source_slot: Some(slot),
                     source_on_board: true,
+                    sold_unit: None,
+                    card_id: card.id,
+                    ability_index,
                     ability: ability.clone(),
                 });
             }
         }
     }
 
-    if let Some((sold_card_id, sold_slot)) = sold_source {
-        if let Some(card) = state.card_pool.get(&sold_card_id) {
-            for ability in &card.shop_abilities {
+    if let Some((sold_unit, sold_slot)) = sold_source {
+        if let Some(card) = state.card_pool.get(&sold_unit.card_id) {
+            for (ability_index, ability) in card.shop_abilities.iter().enumerate() {
                 if ability.trigger == trigger {
                     pending.push(ShopPendingAbility {
                         source_slot: Some(sold_slot),
                         source_on_board: false,
+                        sold_unit: Some(sold_unit.clone()),
+                        card_id: card.id,
+                        ability_index,
                         ability: ability.clone(),
                     });
                 }
@@ -463,7 +477,38 @@ fn execute_shop_trigger<R: BattleRng>(
         }
     }
 
+    for pending_ability in &pending {
+        trace::record(|| TraceEntry::ShopTriggerQueued {
+            trigger: trigger.clone(),
+            slot: pending_ability.source_slot,
+            card_id: pending_ability.card_id,
+            ability_index: pending_ability.ability_index,
+            on_board: pending_ability.source_on_board,
+        });
+    }
+
     for pending_ability in pending {
+        let trace_skip = |reason| {
+            trace::record(|| TraceEntry::ShopTriggerSkipped {
+                slot: pending_ability.source_slot,
+                card_id: pending_ability.card_id,
+                ability_index: pending_ability.ability_index,
+                reason,
+            })
+        };
+
+        // A board source silenced by an earlier ability in this trigger loses the rest.
+        if pending_ability.source_on_board
+            && pending_ability
+                .source_slot
+                .and_then(|slot| state.board.get(slot))
+                .and_then(|slot| slot.as_ref())
+                .is_some_and(|unit| unit.silenced)
+        {
+            trace_skip(SkipReason::Silenced);
+            continue;
+        }
+
         if !shop_conditions_pass(
             state,
             &pending_ability.ability.conditions,
@@ -471,68 +516,134 @@ fn execute_shop_trigger<R: BattleRng>(
             pending_ability.source_on_board,
             trigger_source_slot,
         ) {
+            trace_skip(SkipReason::ConditionsFailed);
             continue;
         }
 
-        apply_shop_effect(
-            state,
-            &pending_ability.ability.effect,
-            pending_ability.source_slot,
-            pending_ability.source_on_board,
-            trigger_source_slot,
-            rng,
-        );
+        trace::record(|| TraceEntry::ShopTriggerFired {
+            slot: pending_ability.source_slot,
+            card_id: pending_ability.card_id,
+            ability_index: pending_ability.ability_index,
+        });
+        let ability = &pending_ability.ability;
+        let mut previous_slots = Vec::new();
+        for effect in core::iter::once(&ability.effect)
+            .chain(ability.then.iter())
+            .take(MAX_ABILITY_EFFECTS)
+        {
+            previous_slots = apply_shop_effect(
+                state,
+                effect,
+                pending_ability.source_slot,
+                pending_ability.source_on_board,
+                pending_ability.sold_unit.as_ref(),
+                trigger_source_slot,
+                &previous_slots,
+                rng,
+            );
+        }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn apply_shop_effect<R: BattleRng>(
     state: &mut ShopState,
     effect: &ShopEffect,
     source_slot: Option<usize>,
     source_on_board: bool,
+    sold_unit: Option<&BoardUnit>,
     trigger_source_slot: Option<usize>,
+    previous_slots: &[usize],
     rng: &mut R,
-) {
+) -> Vec<usize> {
     match effect {
         ShopEffect::ModifyStatsPermanent {
             health,
             attack,
             target,
         } => {
+            let health = evaluate_shop_value(
+                state,
+                health,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+            );
+            let attack = evaluate_shop_value(
+                state,
+                attack,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+            );
             let targets = resolve_shop_targets(
                 state,
                 target,
                 source_slot,
                 source_on_board,
                 trigger_source_slot,
+                previous_slots,
                 rng,
             );
-            for slot in targets {
-                if let Some(unit) = state.board.get_mut(slot).and_then(|s| s.as_mut()) {
-                    unit.perm_health = unit.perm_health.saturating_add(*health);
-                    unit.perm_attack = unit.perm_attack.saturating_add(*attack);
+            for slot in &targets {
+                if let Some(unit) = state.board.get_mut(*slot).and_then(|s| s.as_mut()) {
+                    unit.perm_health = unit.perm_health.saturating_add(health);
+                    unit.perm_attack = unit.perm_attack.saturating_add(attack);
                 }
             }
             cleanup_dead_units(state);
+            targets
         }
         ShopEffect::SpawnUnit {
             card_id,
             spawn_location,
+            count,
+            stats,
         } => {
-            let Some(_spawn_card) = state.card_pool.get(card_id) else {
-                return;
+            let Some(spawn_card) = state.card_pool.get(card_id) else {
+                return Vec::new();
             };
-            let empty_slot = match spawn_location {
-                crate::types::SpawnLocation::Front | crate::types::SpawnLocation::DeathPosition => {
-                    state.board.iter().position(|s| s.is_none())
+            let (perm_attack, perm_health) = match stats {
+                SpawnStats::Base => (0, 0),
+                SpawnStats::Fixed { attack, health } => (
+                    attack.saturating_sub(spawn_card.stats.attack),
+                    health.saturating_sub(spawn_card.stats.health),
+                ),
+                SpawnStats::InheritBuffs => {
+                    let source = if source_on_board {
+                        source_slot
+                            .and_then(|slot| state.board.get(slot))
+                            .and_then(|slot| slot.as_ref())
+                    } else {
+                        sold_unit
+                    };
+                    source.map_or((0, 0), |unit| (unit.perm_attack, unit.perm_health))
                 }
-                crate::types::SpawnLocation::Back => state.board.iter().rposition(|s| s.is_none()),
-            };
-            let Some(empty_slot) = empty_slot else {
-                return;
             };
 
-            state.board[empty_slot] = Some(crate::types::BoardUnit::new(*card_id));
+            let mut spawned = Vec::new();
+            for _ in 0..*count {
+                let empty_slot = match spawn_location {
+                    SpawnLocation::Front | SpawnLocation::DeathPosition => {
+                        state.board.iter().position(|s| s.is_none())
+                    }
+                    SpawnLocation::Back => state.board.iter().rposition(|s| s.is_none()),
+                    SpawnLocation::AdjacentToSource => match source_slot {
+                        Some(slot) => find_nearest_empty_board_slot(&state.board, slot),
+                        None => state.board.iter().position(|s| s.is_none()),
+                    },
+                };
+                let Some(empty_slot) = empty_slot else {
+                    break;
+                };
+
+                let mut unit = BoardUnit::new(*card_id);
+                unit.perm_attack = perm_attack;
+                unit.perm_health = perm_health;
+                state.board[empty_slot] = Some(unit);
+                spawned.push(empty_slot);
+            }
+            spawned
         }
         ShopEffect::Destroy { target } => {
             let targets = resolve_shop_targets(
@@ -541,21 +652,110 @@ fn apply_shop_effect<R: BattleRng>(
                 source_slot,
                 source_on_board,
                 trigger_source_slot,
+                previous_slots,
                 rng,
             );
-            for slot in targets {
-                state.board[slot] = None;
+            for slot in &targets {
+                state.board[*slot] = None;
             }
+            targets
         }
         ShopEffect::GainMana { amount } => {
-            if *amount >= 0 {
+            let amount = evaluate_shop_value(
+                state,
+                amount,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+            )
+            .clamp(ManaDelta::MIN as StatValue, ManaDelta::MAX as StatValue)
+                as ManaDelta;
+            if amount >= 0 {
                 state.shop_mana = state
                     .shop_mana
-                    .saturating_add(*amount as u8)
+                    .saturating_add(amount as u8)
                     .min(state.mana_limit);
             } else {
                 state.shop_mana = state.shop_mana.saturating_sub(amount.unsigned_abs());
             }
+            Vec::new()
+        }
+        ShopEffect::GrantStatusPermanent { status, target } => {
+            let targets = resolve_shop_targets(
+                state,
+                target,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+                previous_slots,
+                rng,
+            );
+            for slot in &targets {
+                if let Some(unit) = state.board.get_mut(*slot).and_then(|s| s.as_mut()) {
+                    unit.perm_statuses.insert(*status);
+                }
+            }
+            targets
+        }
+        ShopEffect::RemoveStatusPermanent { status, target } => {
+            let targets = resolve_shop_targets(
+                state,
+                target,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+                previous_slots,
+                rng,
+            );
+            for slot in &targets {
+                if let Some(unit) = state.board.get_mut(*slot).and_then(|s| s.as_mut()) {
+                    unit.perm_statuses.remove(*status);
+                }
+            }
+            targets
+        }
+        ShopEffect::Heal { amount, target } => {
+            let amount = evaluate_shop_value(
+                state,
+                amount,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+            );
+            let targets = resolve_shop_targets(
+                state,
+                target,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+                previous_slots,
+                rng,
+            );
+            for slot in &targets {
+                if let Some(unit) = state.board.get_mut(*slot).and_then(|s| s.as_mut()) {
+                    if unit.perm_health < 0 {
+                        unit.perm_health = unit.perm_health.saturating_add(amount.max(0)).min(0);
+                    }
+                }
+            }
+            targets
+        }
+        ShopEffect::Silence { target } => {
+            let targets = resolve_shop_targets(
+                state,
+                target,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+                previous_slots,
+                rng,
+            );
+            for slot in &targets {
+                if let Some(unit) = state.board.get_mut(*slot).and_then(|s| s.as_mut()) {
+                    unit.silenced = true;
+                }
+            }
+            targets
         }
     }
 }
@@ -566,6 +766,7 @@ fn resolve_shop_targets<R: BattleRng>(
     source_slot: Option<usize>,
     source_on_board: bool,
     trigger_source_slot: Option<usize>,
+    previous_slots: &[usize],
     rng: &mut R,
 ) -> Vec<usize> {
     match target {
@@ -599,6 +800,23 @@ fn resolve_shop_targets<R: BattleRng>(
             candidates.truncate((*count as usize).min(candidates.len()));
             candidates
         }
+        ShopTarget::RandomWithTag { scope, tag, count } => {
+            let mut candidates = resolve_scope_slots(
+                state,
+                *scope,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+            );
+            candidates.retain(|slot| shop_unit_has_tag(state, *slot, *tag));
+            if candidates.is_empty() {
+                return Vec::new();
+            }
+
+            rng.shuffle(&mut candidates);
+            candidates.truncate((*count as usize).min(candidates.len()));
+            candidates
+        }
         ShopTarget::Standard {
             scope,
             stat,
@@ -635,6 +853,21 @@ fn resolve_shop_targets<R: BattleRng>(
             source_on_board,
             trigger_source_slot,
         ),
+        ShopTarget::PreviousTargets => previous_slots
+            .iter()
+            .copied()
+            .filter(|slot| state.board.get(*slot).is_some_and(Option::is_some))
+            .collect(),
+        ShopTarget::AllWithTag { scope, tag } => resolve_scope_slots(
+            state,
+            *scope,
+            source_slot,
+            source_on_board,
+            trigger_source_slot,
+        )
+        .into_iter()
+        .filter(|slot| shop_unit_has_tag(state, *slot, *tag))
+        .collect(),
     }
 }
 
@@ -761,6 +994,8 @@ fn resolve_scope_slots(
     }
 }
 
+/// Conditions are an implicit AND. A condition nested deeper than
+/// `MAX_CONDITION_DEPTH` never passes, which bounds the recursion below.
 fn shop_conditions_pass(
     state: &ShopState,
     conditions: &[ShopCondition],
@@ -768,35 +1003,76 @@ fn shop_conditions_pass(
     source_on_board: bool,
     trigger_source_slot: Option<usize>,
 ) -> bool {
-    for condition in conditions {
-        match condition {
-            ShopCondition::Is(matcher) => {
-                if !shop_matcher_pass(
+    conditions.iter().all(|condition| {
+        condition.within_depth(MAX_CONDITION_DEPTH)
+            && shop_condition_pass(
+                state,
+                condition,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+            )
+    })
+}
+
+fn shop_condition_pass(
+    state: &ShopState,
+    condition: &ShopCondition,
+    source_slot: Option<usize>,
+    source_on_board: bool,
+    trigger_source_slot: Option<usize>,
+) -> bool {
+    let (combinator, passed) = match condition {
+        ShopCondition::Is(matcher) => {
+            return shop_matcher_pass(
+                state,
+                matcher,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+            )
+        }
+        ShopCondition::AnyOf(children) => (
+            Combinator::AnyOf,
+            children.iter().any(|child| {
+                shop_condition_pass(
                     state,
-                    matcher,
+                    child,
                     source_slot,
                     source_on_board,
                     trigger_source_slot,
-                ) {
-                    return false;
-                }
-            }
-            ShopCondition::AnyOf(matchers) => {
-                if !matchers.iter().any(|matcher| {
-                    shop_matcher_pass(
-                        state,
-                        matcher,
-                        source_slot,
-                        source_on_board,
-                        trigger_source_slot,
-                    )
-                }) {
-                    return false;
-                }
-            }
-        }
-    }
-    true
+                )
+            }),
+        ),
+        ShopCondition::AllOf(children) => (
+            Combinator::AllOf,
+            children.iter().all(|child| {
+                shop_condition_pass(
+                    state,
+                    child,
+                    source_slot,
+                    source_on_board,
+                    trigger_source_slot,
+                )
+            }),
+        ),
+        ShopCondition::Not(child) => (
+            Combinator::Not,
+            !shop_condition_pass(
+                state,
+                child,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+            ),
+        ),
+    };
+    trace::record(|| TraceEntry::ShopCombined {
+        slot: source_slot,
+        combinator,
+        passed,
+    });
+    passed
 }
 
 fn shop_matcher_pass(
@@ -805,6 +1081,33 @@ fn shop_matcher_pass(
     source_slot: Option<usize>,
     source_on_board: bool,
     trigger_source_slot: Option<usize>,
+) -> bool {
+    let mut operands = Operands::new();
+    let passed = shop_matcher_passes(
+        state,
+        matcher,
+        source_slot,
+        source_on_board,
+        trigger_source_slot,
+        &mut operands,
+    );
+    trace::record(|| TraceEntry::ShopCondition {
+        slot: source_slot,
+        matcher: matcher.clone(),
+        operands: operands.into_vec(),
+        passed,
+    });
+    passed
+}
+
+/// Evaluates a shop matcher, collecting the values it compares into `operands`.
+fn shop_matcher_passes(
+    state: &ShopState,
+    matcher: &ShopMatcher,
+    source_slot: Option<usize>,
+    source_on_board: bool,
+    trigger_source_slot: Option<usize>,
+    operands: &mut Operands,
 ) -> bool {
     match matcher {
         ShopMatcher::UnitCount { scope, op, value } => {
@@ -816,6 +1119,7 @@ fn shop_matcher_pass(
                 trigger_source_slot,
             )
             .len() as CountValue;
+            operands.push(|| Operand::Count(count));
             compare_count(count, *op, *value)
         }
         ShopMatcher::StatValueCompare {
@@ -837,7 +1141,14 @@ fn shop_matcher_pass(
 
             targets.iter().any(|slot| {
                 shop_stat_value(state, *slot, *stat)
-                    .map(|actual| compare_stat(actual, *op, *value))
+                    .map(|actual| {
+                        operands.push(|| Operand::Stat {
+                            unit: TraceUnit::Slot(*slot),
+                            stat: *stat,
+                            value: actual,
+                        });
+                        compare_stat(actual, *op, *value)
+                    })
                     .unwrap_or(false)
             })
         }
@@ -861,11 +1172,64 @@ fn shop_matcher_pass(
                 None
             };
 
-            desired.map(|idx| targets.contains(&idx)).unwrap_or(false)
+            desired
+                .map(|idx| {
+                    let in_scope = targets.contains(&idx);
+                    operands.push(|| Operand::Position {
+                        index: idx,
+                        unit: in_scope.then_some(TraceUnit::Slot(idx)),
+                    });
+                    in_scope
+                })
+                .unwrap_or(false)
+        }
+        ShopMatcher::HasTag { scope, tag } => resolve_scope_slots(
+            state,
+            *scope,
+            source_slot,
+            source_on_board,
+            trigger_source_slot,
+        )
+        .into_iter()
+        .any(|slot| {
+            let has_tag = shop_unit_has_tag(state, slot, *tag);
+            operands.push(|| Operand::HasTag {
+                unit: TraceUnit::Slot(slot),
+                has_tag,
+            });
+            has_tag
+        }),
+        ShopMatcher::TagCount {
+            scope,
+            tag,
+            op,
+            value,
+        } => {
+            let count = resolve_scope_slots(
+                state,
+                *scope,
+                source_slot,
+                source_on_board,
+                trigger_source_slot,
+            )
+            .into_iter()
+            .filter(|slot| shop_unit_has_tag(state, *slot, *tag))
+            .count() as CountValue;
+            operands.push(|| Operand::Count(count));
+            compare_count(count, *op, *value)
         }
     }
 }
 
+fn shop_unit_has_tag(state: &ShopState, slot: usize, tag: Tag) -> bool {
+    state
+        .board
+        .get(slot)
+        .and_then(|unit| unit.as_ref())
+        .and_then(|unit| state.card_pool.get(&unit.card_id))
+        .is_some_and(|card| card.tags.contains(tag))
+}
+
 fn shop_stat_value(state: &ShopState, slot: usize, stat: StatType) -> Option<StatValue> {
     let unit = state.board.get(slot)?.as_ref()?;
     let card = state.card_pool.get(&unit.card_id)?;
@@ -877,6 +1241,53 @@ fn shop_stat_value(state: &ShopState, slot: usize, stat: StatType) -> Option<Sta
     }
 }
 
+/// Evaluates a shop effect amount against the current board.
+fn evaluate_shop_value(
+    state: &ShopState,
+    expr: &ShopValueExpr,
+    source_slot: Option<usize>,
+    source_on_board: bool,
+    trigger_source_slot: Option<usize>,
+) -> StatValue {
+    let (term, factor) = match expr {
+        ShopValueExpr::Const(value) => return *value,
+        ShopValueExpr::Scaled { term, factor } => (term, *factor),
+    };
+    let base: i32 = match term {
+        ShopValueTerm::Stat { scope, stat } => resolve_scope_slots(
+            state,
+            *scope,
+            source_slot,
+            source_on_board,
+            trigger_source_slot,
+        )
+        .into_iter()
+        .filter_map(|slot| shop_stat_value(state, slot, *stat))
+        .map(i32::from)
+        .sum(),
+        ShopValueTerm::UnitCount { scope } => resolve_scope_slots(
+            state,
+            *scope,
+            source_slot,
+            source_on_board,
+            trigger_source_slot,
+        )
+        .len() as i32,
+        ShopValueTerm::TagCount { scope, tag } => resolve_scope_slots(
+            state,
+            *scope,
+            source_slot,
+            source_on_board,
+            trigger_source_slot,
+        )
+        .into_iter()
+        .filter(|slot| shop_unit_has_tag(state, *slot, *tag))
+        .count() as i32,
+    };
+    base.saturating_mul(factor as i32)
+        .clamp(StatValue::MIN as i32, StatValue::MAX as i32) as StatValue
+}
+
 fn cleanup_dead_units(state: &mut ShopState) {
     for idx in 0..state.board.len() {
         let should_remove = state.board[idx]
@@ -0,0 +1,251 @@
+//! Exact outcome distributions
+//!
+//! Instead of sampling seeds, this mode replaces the battle RNG with a
+//! branching oracle and follows every random choice: shuffles behind random
+//! targeting and the seeded trigger tiebreak. Each phase is replayed once per
+//! combination of choices, and branches that reach the same battle state
+//! between phases are merged, so small boards resolve exactly. When the
+//! branch budget runs out it falls back to `evaluate_matchup`.
+//!
+//! Choices are taken as uniform. The odds are those of an ideal RNG; they
+//! ignore the tiny modulo bias of `RngVersion::V1`.
+
+use std::collections::BTreeMap;
+use std::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+use crate::battle::{modified_card_pool, BattleResult, BattleState, CombatEvent, CombatUnit};
+use crate::matchup::{evaluate_matchup, MatchupConfig, MatchupUnit};
+use crate::rng::BattleRng;
+use crate::sink::{EventSink, SnapshotNotice};
+use crate::types::{CardId, UnitCard};
+
+/// How an exact distribution is computed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExactConfig {
+    /// Phase replays the enumeration may run before falling back to sampling.
+    pub branch_budget: u64,
+    /// Rules and modifiers for the battle, and the sampling to fall back on.
+    pub sampling: MatchupConfig,
+}
+
+impl Default for ExactConfig {
+    fn default() -> Self {
+        Self {
+            branch_budget: 100_000,
+            sampling: MatchupConfig::default(),
+        }
+    }
+}
+
+/// The probability of each battle result, from the player's side.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeDistribution {
+    pub victory: f64,
+    pub draw: f64,
+    pub defeat: f64,
+    /// False when the branch budget ran out and the odds were sampled.
+    pub exact: bool,
+    /// Phase replays the enumeration ran.
+    pub branches: u64,
+    /// Battles sampled after falling back; 0 when exact.
+    pub samples: u32,
+}
+
+impl OutcomeDistribution {
+    pub fn probability(&self, result: BattleResult) -> f64 {
+        match result {
+            BattleResult::Victory => self.victory,
+            BattleResult::Draw => self.draw,
+            BattleResult::Defeat => self.defeat,
+        }
+    }
+}
+
+/// A `BattleRng` that follows a script of choices instead of drawing values.
+/// Draws past the end of the script take their first choice; every draw's
+/// number of choices is recorded so the caller can visit the others.
+struct BranchOracle {
+    script: Vec<u32>,
+    arities: Vec<u32>,
+    /// A raw draw was made, which cannot be enumerated.
+    unbranchable: bool,
+}
+
+impl BranchOracle {
+    fn new(script: Vec<u32>) -> Self {
+        Self {
+            script,
+            arities: Vec::new(),
+            unbranchable: false,
+        }
+    }
+
+    fn choose(&mut self, arity: u32) -> u32 {
+        let position = self.arities.len();
+        self.arities.push(arity);
+        self.script.get(position).copied().unwrap_or(0)
+    }
+}
+
+impl BattleRng for BranchOracle {
+    fn next_u32(&mut self) -> u32 {
+        self.unbranchable = true;
+        0
+    }
+
+    fn gen_range(&mut self, max: usize) -> usize {
+        if max <= 1 {
+            return 0;
+        }
+        self.choose(max as u32) as usize
+    }
+
+    fn tiebreak_base(&mut self, decisive_bits: impl FnOnce() -> u16) -> u16 {
+        let bits = decisive_bits();
+        if bits == 0 {
+            return 0;
+        }
+        // Every setting of the decisive bits is equally likely; the others
+        // do not change the order.
+        let choice = self.choose(1 << bits.count_ones());
+        let mut base = 0u16;
+        let mut next = 0;
+        for bit in 0..16 {
+            if bits & (1 << bit) != 0 {
+                if choice & (1 << next) != 0 {
+                    base |= 1 << bit;
+                }
+                next += 1;
+            }
+        }
+        base
+    }
+}
+
+/// Keeps only the result of a battle.
+#[derive(Default)]
+struct ResultSink(Option<BattleResult>);
+
+impl EventSink for ResultSink {
+    fn record(&mut self, event: CombatEvent) {
+        if let CombatEvent::BattleEnd { result } = event {
+            self.0 = Some(result);
+        }
+    }
+
+    fn record_snapshot(&mut self, _notice: SnapshotNotice, _event: impl FnOnce() -> CombatEvent) {}
+}
+
+/// The probability of each result of a battle between two boards. Units
+/// whose card is missing from `card_pool` are left out, as in
+/// `evaluate_matchup`.
+pub fn exact_outcome_distribution<P: MatchupUnit, E: MatchupUnit>(
+    player: &[P],
+    enemy: &[E],
+    card_pool: &BTreeMap<CardId, UnitCard>,
+    config: &ExactConfig,
+) -> OutcomeDistribution {
+    let player_units: Vec<CombatUnit> = player
+        .iter()
+        .filter_map(|unit| unit.to_combat_unit(card_pool))
+        .collect();
+    let enemy_units: Vec<CombatUnit> = enemy
+        .iter()
+        .filter_map(|unit| unit.to_combat_unit(card_pool))
+        .collect();
+
+    match enumerate(player_units, enemy_units, card_pool, config) {
+        Ok(distribution) => distribution,
+        Err(branches) => {
+            let report = evaluate_matchup(player, enemy, card_pool, &config.sampling);
+            OutcomeDistribution {
+                victory: report.win_rate,
+                draw: report.draw_rate,
+                defeat: report.loss_rate,
+                exact: false,
+                branches,
+                samples: report.battles,
+            }
+        }
+    }
+}
+
+/// Walks the battle one step at a time, carrying the probability of every
+/// distinct state. Returns the branches used if the budget runs out.
+fn enumerate(
+    player_units: Vec<CombatUnit>,
+    enemy_units: Vec<CombatUnit>,
+    card_pool: &BTreeMap<CardId, UnitCard>,
+    config: &ExactConfig,
+) -> Result<OutcomeDistribution, u64> {
+    let rules = &config.sampling.rules;
+    let modifiers = &config.sampling.modifiers;
+    let card_pool = modified_card_pool(card_pool, modifiers);
+
+    let start = BattleState::new(
+        player_units,
+        enemy_units,
+        rules,
+        modifiers,
+        &mut ResultSink::default(),
+    );
+    let mut frontier = BTreeMap::new();
+    frontier.insert(start.memo_key(), (start, 1.0f64));
+
+    let mut distribution = OutcomeDistribution {
+        victory: 0.0,
+        draw: 0.0,
+        defeat: 0.0,
+        exact: true,
+        branches: 0,
+        samples: 0,
+    };
+    while !frontier.is_empty() {
+        let mut next: BTreeMap<Vec<u8>, (BattleState, f64)> = BTreeMap::new();
+        for (state, probability) in frontier.into_values() {
+            let mut scripts = vec![Vec::new()];
+            while let Some(script) = scripts.pop() {
+                distribution.branches += 1;
+                if distribution.branches > config.branch_budget {
+                    return Err(distribution.branches - 1);
+                }
+
+                let mut branch = state.clone();
+                let mut oracle = BranchOracle::new(script);
+                let mut end = ResultSink::default();
+                branch.advance(&mut oracle, &card_pool, modifiers, &mut end);
+                if oracle.unbranchable {
+                    return Err(distribution.branches);
+                }
+
+                // Queue every other choice at the draws this run made first.
+                let taken = oracle.script.len();
+                for position in taken..oracle.arities.len() {
+                    for choice in 1..oracle.arities[position] {
+                        let mut sibling = oracle.script.clone();
+                        sibling.resize(position, 0);
+                        sibling.push(choice);
+                        scripts.push(sibling);
+                    }
+                }
+
+                let weight = oracle
+                    .arities
+                    .iter()
+                    .fold(probability, |weight, &arity| weight / f64::from(arity));
+                match end.0 {
+                    Some(BattleResult::Victory) => distribution.victory += weight,
+                    Some(BattleResult::Draw) => distribution.draw += weight,
+                    Some(BattleResult::Defeat) => distribution.defeat += weight,
+                    None => {
+                        next.entry(branch.memo_key()).or_insert((branch, 0.0)).1 += weight;
+                    }
+                }
+            }
+        }
+        frontier = next;
+    }
+    Ok(distribution)
+}
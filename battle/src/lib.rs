@@ -10,10 +10,19 @@ extern crate alloc;
 pub mod battle;
 pub mod commit;
 pub mod error;
+#[cfg(feature = "std")]
+pub mod exact;
 pub mod limits;
 pub mod log;
+#[cfg(feature = "std")]
+pub mod matchup;
+pub mod modifiers;
 pub mod rng;
+pub mod rules;
+pub mod sink;
 pub mod state;
+pub mod summary;
+pub mod trace;
 pub mod types;
 
 #[cfg(test)]
@@ -24,14 +33,24 @@ pub mod bounded;
 
 // Core exports
 pub use battle::{
-    resolve_battle, BattlePhase, BattleResult, CombatEvent, CombatUnit, UnitId, UnitView,
+    resolve_battle, resolve_battle_into, resolve_battle_outcome, resolve_battle_with_modifiers,
+    BattlePhase, BattleResult, BattleSimulator, BattleSnapshot, BattleStep, CombatEvent,
+    CombatUnit, UnitId, UnitView,
 };
 pub use commit::{
     apply_shop_start_triggers, apply_shop_start_triggers_with_result, apply_single_action,
     finalize_turn, verify_and_apply_turn, ShopTurnContext,
 };
 pub use error::{GameError, GameResult};
+#[cfg(feature = "std")]
+pub use exact::{exact_outcome_distribution, ExactConfig, OutcomeDistribution};
 pub use limits::{BattleLimits, LimitReason, Team};
-pub use rng::{BattleRng, XorShiftRng};
+#[cfg(feature = "std")]
+pub use matchup::{evaluate_matchup, MatchupConfig, MatchupReport, MatchupUnit};
+pub use modifiers::{select_modifiers, BattleModifier, ModifierSelection};
+pub use rng::{BattleRng, RngStream, RngVersion, SplitMix64Rng, VersionedRng, XorShiftRng};
+pub use rules::{BattleRules, RulesVersion, TiebreakPolicy};
+pub use sink::{BattleOutcome, EventSink};
 pub use state::*;
+pub use summary::{BattleSummary, SummarySink, UnitSummary};
 pub use types::*;
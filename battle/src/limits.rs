@@ -3,19 +3,14 @@
 //! This module provides safeguards against runaway battle computations.
 
 use crate::battle::UnitId;
-use crate::types::LimitValue;
+use crate::rules::BattleRules;
+use crate::types::{LimitValue, StatValue};
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
-pub const MAX_RECURSION_DEPTH: LimitValue = 50;
-pub const MAX_SPAWNS_PER_BATTLE: LimitValue = 100;
-pub const MAX_TRIGGERS_PER_PHASE: LimitValue = 200;
-pub const MAX_TRIGGER_DEPTH: LimitValue = 10;
-pub const MAX_BATTLE_ROUNDS: LimitValue = 100;
-
 #[derive(
     Debug,
     Clone,
@@ -63,6 +58,39 @@ pub enum LimitReason {
     },
 }
 
+/// Fatigue ends stalled battles: from `start_round` on, both front units take
+/// unavoidable damage at the end of every round. The damage starts at
+/// `base_damage` and doubles each round, so any front falls within 16 rounds.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FatigueRule {
+    pub start_round: LimitValue,
+    pub base_damage: StatValue,
+}
+
+impl FatigueRule {
+    /// Fatigue damage dealt at the end of `round`, if fatigue has set in.
+    pub fn damage_for_round(&self, round: LimitValue) -> Option<StatValue> {
+        let step = round.checked_sub(self.start_round)?;
+        if self.base_damage <= 0 {
+            return None;
+        }
+        let damage = (self.base_damage as i32) << step.min(16);
+        Some(damage.min(StatValue::MAX as i32) as StatValue)
+    }
+}
+
 /// Tracks execution limits to prevent infinite loops and stack overflows
 #[derive(Debug, Clone, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen)]
 pub struct BattleLimits {
@@ -76,11 +104,13 @@ pub struct BattleLimits {
     pub limit_exceeded_reason: Option<LimitReason>,
     pub next_player_index: u16,
     pub next_enemy_index: u16,
+    /// Rules the caps above are checked against.
+    pub rules: BattleRules,
 }
 
 #[allow(clippy::result_unit_err)]
 impl BattleLimits {
-    pub fn new() -> Self {
+    pub fn new(rules: &BattleRules) -> Self {
         Self {
             recursion_depth: 0,
             trigger_depth: 0,
@@ -92,17 +122,18 @@ impl BattleLimits {
             limit_exceeded_reason: None,
             next_player_index: 1,
             next_enemy_index: 1,
+            rules: *rules,
         }
     }
 
     pub fn record_round(&mut self) -> Result<(), ()> {
         self.total_rounds += 1;
-        if self.total_rounds > MAX_BATTLE_ROUNDS {
+        if self.total_rounds > self.rules.max_battle_rounds {
             // Draws don't attribute to a specific team
             self.limit_exceeded_by = None;
             self.limit_exceeded_reason = Some(LimitReason::RoundLimit {
                 current: self.total_rounds,
-                max: MAX_BATTLE_ROUNDS,
+                max: self.rules.max_battle_rounds,
             });
             return Err(());
         }
@@ -135,11 +166,11 @@ impl BattleLimits {
     pub fn enter_recursion(&mut self, team: Team) -> Result<(), ()> {
         self.current_executing_team = Some(team);
         self.recursion_depth += 1;
-        if self.recursion_depth > MAX_RECURSION_DEPTH {
+        if self.recursion_depth > self.rules.max_recursion_depth {
             self.limit_exceeded_by = Some(team);
             self.limit_exceeded_reason = Some(LimitReason::RecursionLimit {
                 current: self.recursion_depth,
-                max: MAX_RECURSION_DEPTH,
+                max: self.rules.max_recursion_depth,
             });
             return Err(());
         }
@@ -155,11 +186,11 @@ impl BattleLimits {
     pub fn record_spawn(&mut self, team: Team) -> Result<(), ()> {
         self.current_executing_team = Some(team);
         self.total_spawns += 1;
-        if self.total_spawns > MAX_SPAWNS_PER_BATTLE {
+        if self.total_spawns > self.rules.max_spawns_per_battle {
             self.limit_exceeded_by = Some(team);
             self.limit_exceeded_reason = Some(LimitReason::SpawnLimit {
                 current: self.total_spawns,
-                max: MAX_SPAWNS_PER_BATTLE,
+                max: self.rules.max_spawns_per_battle,
             });
             return Err(());
         }
@@ -169,11 +200,11 @@ impl BattleLimits {
     pub fn record_trigger(&mut self, team: Team) -> Result<(), ()> {
         self.current_executing_team = Some(team);
         self.phase_triggers += 1;
-        if self.phase_triggers > MAX_TRIGGERS_PER_PHASE {
+        if self.phase_triggers > self.rules.max_triggers_per_phase {
             self.limit_exceeded_by = Some(team);
             self.limit_exceeded_reason = Some(LimitReason::TriggerLimit {
                 current: self.phase_triggers,
-                max: MAX_TRIGGERS_PER_PHASE,
+                max: self.rules.max_triggers_per_phase,
             });
             return Err(());
         }
@@ -183,11 +214,11 @@ impl BattleLimits {
     pub fn enter_trigger_depth(&mut self, team: Team) -> Result<(), ()> {
         self.current_executing_team = Some(team);
         self.trigger_depth += 1;
-        if self.trigger_depth > MAX_TRIGGER_DEPTH {
+        if self.trigger_depth > self.rules.max_trigger_depth {
             self.limit_exceeded_by = Some(team);
             self.limit_exceeded_reason = Some(LimitReason::TriggerDepthLimit {
                 current: self.trigger_depth,
-                max: MAX_TRIGGER_DEPTH,
+                max: self.rules.max_trigger_depth,
             });
             return Err(());
         }
@@ -203,6 +234,6 @@ impl BattleLimits {
 
 impl Default for BattleLimits {
     fn default() -> Self {
-        Self::new()
+        Self::new(&BattleRules::default())
     }
 }
@@ -0,0 +1,319 @@
+//! Monte Carlo matchup evaluation
+//!
+//! Runs the same two boards over a range of seeds and reports how the
+//! matchup tends to go: win/draw/loss rates, a confidence interval for the
+//! win rate, the average number of survivors and how often each battle limit
+//! ended a battle. Battles run in parallel where threads are available; the
+//! report only depends on the boards and the config.
+
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+use crate::battle::{resolve_battle_into, BattleResult, CombatEvent, CombatUnit};
+use crate::limits::LimitReason;
+use crate::modifiers::BattleModifier;
+use crate::rng::{RngStream, RngVersion, VersionedRng};
+use crate::rules::BattleRules;
+use crate::sink::{EventSink, SnapshotNotice};
+use crate::types::{BoardUnit, CardId, GhostBoardUnit, StatValue, StatusMask, UnitCard};
+
+/// z-score of the two-sided 95% confidence level.
+const Z_95: f64 = 1.959_963_984_540_054;
+
+/// A stored board unit that can be turned back into a combat unit.
+pub trait MatchupUnit {
+    /// The combat unit this unit fights as, or None if its card is not in
+    /// the pool.
+    fn to_combat_unit(&self, card_pool: &BTreeMap<CardId, UnitCard>) -> Option<CombatUnit>;
+}
+
+impl MatchupUnit for BoardUnit {
+    fn to_combat_unit(&self, card_pool: &BTreeMap<CardId, UnitCard>) -> Option<CombatUnit> {
+        let card = card_pool.get(&self.card_id)?;
+        Some(stored_unit(
+            card,
+            self.perm_attack,
+            self.perm_health,
+            self.perm_statuses,
+            self.silenced,
+        ))
+    }
+}
+
+impl MatchupUnit for GhostBoardUnit {
+    fn to_combat_unit(&self, card_pool: &BTreeMap<CardId, UnitCard>) -> Option<CombatUnit> {
+        let card = card_pool.get(&self.card_id)?;
+        Some(stored_unit(
+            card,
+            self.perm_attack,
+            self.perm_health,
+            self.perm_statuses,
+            self.silenced,
+        ))
+    }
+}
+
+/// `card` as a combat unit with a stored unit's permanent changes applied.
+/// Health never starts below zero.
+fn stored_unit(
+    card: &UnitCard,
+    perm_attack: StatValue,
+    perm_health: StatValue,
+    perm_statuses: StatusMask,
+    silenced: bool,
+) -> CombatUnit {
+    let mut unit = CombatUnit::from_card(card.clone());
+    unit.attack_buff = perm_attack;
+    unit.health_buff = perm_health;
+    unit.health = unit.health.saturating_add(perm_health).max(0);
+    unit.permanent_statuses = perm_statuses;
+    if silenced {
+        unit.silence();
+    }
+    unit
+}
+
+/// How a matchup is evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchupConfig {
+    /// Number of battles to run.
+    pub battles: u32,
+    /// Battle `i` uses seed `first_seed + i`.
+    pub first_seed: u64,
+    pub rng_version: RngVersion,
+    pub rules: BattleRules,
+    pub modifiers: Vec<BattleModifier>,
+    /// Worker threads to use. None uses every available core.
+    pub threads: Option<NonZeroUsize>,
+}
+
+impl Default for MatchupConfig {
+    fn default() -> Self {
+        Self {
+            battles: 1000,
+            first_seed: 0,
+            rng_version: RngVersion::CURRENT,
+            rules: BattleRules::default(),
+            modifiers: Vec::new(),
+            threads: None,
+        }
+    }
+}
+
+/// How many battles ended on each battle limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LimitReasonCounts {
+    pub round: u32,
+    pub recursion: u32,
+    pub spawn: u32,
+    pub trigger: u32,
+    pub trigger_depth: u32,
+}
+
+impl LimitReasonCounts {
+    fn add(&mut self, reason: &LimitReason) {
+        let count = match reason {
+            LimitReason::RoundLimit { .. } => &mut self.round,
+            LimitReason::RecursionLimit { .. } => &mut self.recursion,
+            LimitReason::SpawnLimit { .. } => &mut self.spawn,
+            LimitReason::TriggerLimit { .. } => &mut self.trigger,
+            LimitReason::TriggerDepthLimit { .. } => &mut self.trigger_depth,
+        };
+        *count += 1;
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.round += other.round;
+        self.recursion += other.recursion;
+        self.spawn += other.spawn;
+        self.trigger += other.trigger;
+        self.trigger_depth += other.trigger_depth;
+    }
+
+    /// Battles that ended on any limit.
+    pub fn total(&self) -> u32 {
+        self.round + self.recursion + self.spawn + self.trigger + self.trigger_depth
+    }
+}
+
+/// The aggregated results of a matchup, from the player's side.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchupReport {
+    pub battles: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    pub win_rate: f64,
+    pub draw_rate: f64,
+    pub loss_rate: f64,
+    /// 95% Wilson score interval for the win rate.
+    pub win_rate_interval: (f64, f64),
+    /// Player units alive when the battle ended, averaged over all battles.
+    pub avg_player_survivors: f64,
+    /// Enemy units alive when the battle ended, averaged over all battles.
+    pub avg_enemy_survivors: f64,
+    pub limit_reasons: LimitReasonCounts,
+}
+
+/// Running totals over a batch of battles. Records one battle per
+/// `BattleEnd` and never builds board snapshots.
+#[derive(Default)]
+struct Tally {
+    wins: u32,
+    draws: u32,
+    losses: u32,
+    player_survivors: u64,
+    enemy_survivors: u64,
+    limit_reasons: LimitReasonCounts,
+}
+
+impl Tally {
+    fn merge(&mut self, other: &Self) {
+        self.wins += other.wins;
+        self.draws += other.draws;
+        self.losses += other.losses;
+        self.player_survivors += other.player_survivors;
+        self.enemy_survivors += other.enemy_survivors;
+        self.limit_reasons.merge(&other.limit_reasons);
+    }
+
+    fn into_report(self) -> MatchupReport {
+        let battles = self.wins + self.draws + self.losses;
+        let rate = |count: u32| {
+            if battles == 0 {
+                0.0
+            } else {
+                f64::from(count) / f64::from(battles)
+            }
+        };
+        let average = |total: u64| {
+            if battles == 0 {
+                0.0
+            } else {
+                total as f64 / f64::from(battles)
+            }
+        };
+        MatchupReport {
+            battles,
+            wins: self.wins,
+            draws: self.draws,
+            losses: self.losses,
+            win_rate: rate(self.wins),
+            draw_rate: rate(self.draws),
+            loss_rate: rate(self.losses),
+            win_rate_interval: wilson_interval(self.wins, battles),
+            avg_player_survivors: average(self.player_survivors),
+            avg_enemy_survivors: average(self.enemy_survivors),
+            limit_reasons: self.limit_reasons,
+        }
+    }
+}
+
+impl EventSink for Tally {
+    fn record(&mut self, event: CombatEvent) {
+        match event {
+            CombatEvent::BattleEnd { result } => match result {
+                BattleResult::Victory => self.wins += 1,
+                BattleResult::Draw => self.draws += 1,
+                BattleResult::Defeat => self.losses += 1,
+            },
+            CombatEvent::LimitExceeded { reason, .. } => self.limit_reasons.add(&reason),
+            _ => {}
+        }
+    }
+
+    fn record_snapshot(&mut self, _notice: SnapshotNotice, _event: impl FnOnce() -> CombatEvent) {}
+
+    fn finish(&mut self, player_units: &[CombatUnit], enemy_units: &[CombatUnit]) {
+        self.player_survivors += player_units.len() as u64;
+        self.enemy_survivors += enemy_units.len() as u64;
+    }
+}
+
+/// 95% Wilson score interval for `successes` out of `trials`.
+fn wilson_interval(successes: u32, trials: u32) -> (f64, f64) {
+    if trials == 0 {
+        return (0.0, 1.0);
+    }
+    let n = f64::from(trials);
+    let p = f64::from(successes) / n;
+    let z2 = Z_95 * Z_95;
+    let denominator = 1.0 + z2 / n;
+    let center = (p + z2 / (2.0 * n)) / denominator;
+    let margin = Z_95 * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / denominator;
+    ((center - margin).max(0.0), (center + margin).min(1.0))
+}
+
+fn worker_count(config: &MatchupConfig) -> usize {
+    // wasm32 builds have no threads to spawn.
+    if cfg!(target_arch = "wasm32") {
+        return 1;
+    }
+    let threads = config
+        .threads
+        .or_else(|| std::thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get);
+    threads.min(config.battles as usize).max(1)
+}
+
+/// Run `config.battles` seeded battles between two boards. Units whose card
+/// is missing from `card_pool` are left out, as in the sandbox.
+pub fn evaluate_matchup<P: MatchupUnit, E: MatchupUnit>(
+    player: &[P],
+    enemy: &[E],
+    card_pool: &BTreeMap<CardId, UnitCard>,
+    config: &MatchupConfig,
+) -> MatchupReport {
+    let player_units: Vec<CombatUnit> = player
+        .iter()
+        .filter_map(|unit| unit.to_combat_unit(card_pool))
+        .collect();
+    let enemy_units: Vec<CombatUnit> = enemy
+        .iter()
+        .filter_map(|unit| unit.to_combat_unit(card_pool))
+        .collect();
+
+    let run_range = |first: u32, end: u32| {
+        let mut tally = Tally::default();
+        for index in first..end {
+            let seed = config.first_seed.wrapping_add(u64::from(index));
+            let mut rng = VersionedRng::new(config.rng_version, seed, RngStream::Battle);
+            resolve_battle_into(
+                player_units.clone(),
+                enemy_units.clone(),
+                &mut rng,
+                card_pool,
+                &config.rules,
+                &config.modifiers,
+                &mut tally,
+            );
+        }
+        tally
+    };
+
+    let workers = worker_count(config);
+    if workers <= 1 {
+        return run_range(0, config.battles).into_report();
+    }
+
+    let chunk = config.battles.div_ceil(workers as u32);
+    let mut total = Tally::default();
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..config.battles)
+            .step_by(chunk as usize)
+            .map(|first| {
+                let end = first.saturating_add(chunk).min(config.battles);
+                let run_range = &run_range;
+                scope.spawn(move || run_range(first, end))
+            })
+            .collect();
+        for handle in handles {
+            let tally = handle.join().expect("matchup worker panicked");
+            total.merge(&tally);
+        }
+    });
+    total.into_report()
+}
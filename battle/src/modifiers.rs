@@ -0,0 +1,220 @@
+//! Battlefield modifiers
+//!
+//! Round-level rules that apply to both teams for a whole battle, such as
+//! extra attack for every unit or doubled clash damage late in the fight.
+//! The battle entry point receives the active set; game modes pick it with
+//! `select_modifiers` so every runtime derives the same set from the same seeds.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+use crate::battle::CombatUnit;
+use crate::limits::FatigueRule;
+use crate::rng::{BattleRng, RngStream, RngVersion, VersionedRng};
+use crate::types::{
+    Ability, AbilityEffect, AbilityTarget, LimitValue, MatchmakingBracket, RoundValue, StatValue,
+    TargetScope, UnitCard,
+};
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// A rule applied to both teams for a whole battle.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "type", content = "data"))]
+pub enum BattleModifier {
+    /// Blood Moon: every unit, including spawned ones, gains attack.
+    BloodMoon { attack: StatValue },
+    /// Fog: front/back and stat-based ability targets become random picks
+    /// from the same scope. Self-relative, neighbour, all and tag targets are unchanged.
+    Fog,
+    /// Sudden Death: clash damage is multiplied from `from_round` onward.
+    SuddenDeath {
+        from_round: RoundValue,
+        multiplier: StatValue,
+    },
+    /// Fatigue sets in from `from_round` with `base_damage`, replacing the
+    /// default rule. A base damage of 0 switches fatigue off.
+    Fatigue {
+        from_round: RoundValue,
+        base_damage: StatValue,
+    },
+}
+
+/// Modifiers a seeded or bracket-based selection picks from.
+pub const MODIFIER_ROTATION: [BattleModifier; 3] = [
+    BattleModifier::BloodMoon { attack: 1 },
+    BattleModifier::Fog,
+    BattleModifier::SuddenDeath {
+        from_round: 10,
+        multiplier: 2,
+    },
+];
+
+/// How a game mode chooses the modifiers for each battle.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "type", content = "data"))]
+pub enum ModifierSelection {
+    /// Battles are fought without modifiers.
+    #[default]
+    None,
+    /// Every battle uses this modifier.
+    Fixed(BattleModifier),
+    /// One modifier from the rotation, picked from the battle seed.
+    FromSeed,
+    /// One modifier from the rotation, picked from the matchmaking bracket so
+    /// every battle in a bracket shares it.
+    FromBracket,
+}
+
+/// Pick the modifiers for one battle. Deterministic in its inputs and
+/// independent of the battle RNG stream.
+pub fn select_modifiers(
+    selection: &ModifierSelection,
+    battle_seed: u64,
+    bracket: &MatchmakingBracket,
+    rng_version: RngVersion,
+) -> Vec<BattleModifier> {
+    let key = match selection {
+        ModifierSelection::None => return Vec::new(),
+        ModifierSelection::Fixed(modifier) => return vec![*modifier],
+        ModifierSelection::FromSeed => battle_seed,
+        ModifierSelection::FromBracket => {
+            ((bracket.set_id as u64) << 24)
+                | ((bracket.round as u64) << 16)
+                | ((bracket.wins as u64) << 8)
+                | bracket.lives as u64
+        }
+    };
+    let mut rng = VersionedRng::new(rng_version, key, RngStream::Modifiers);
+    vec![MODIFIER_ROTATION[rng.gen_range(MODIFIER_ROTATION.len())]]
+}
+
+impl BattleModifier {
+    /// True if cards in the pool must be rewritten so spawned units follow the rule.
+    pub(crate) fn alters_cards(&self) -> bool {
+        matches!(self, BattleModifier::BloodMoon { .. } | BattleModifier::Fog)
+    }
+
+    /// The fatigue rule this modifier sets, if any.
+    pub(crate) fn fatigue_rule(&self) -> Option<FatigueRule> {
+        match self {
+            BattleModifier::Fatigue {
+                from_round,
+                base_damage,
+            } => Some(FatigueRule {
+                start_round: *from_round,
+                base_damage: *base_damage,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Apply the rule to a card before any unit is created from it.
+    pub(crate) fn apply_to_card(&self, card: &mut UnitCard) {
+        match self {
+            BattleModifier::BloodMoon { attack } => {
+                card.stats.attack = card.stats.attack.saturating_add(*attack);
+            }
+            BattleModifier::Fog => fog_abilities(&mut card.battle_abilities),
+            BattleModifier::SuddenDeath { .. } | BattleModifier::Fatigue { .. } => {}
+        }
+    }
+
+    /// Apply the rule to a unit that starts the battle on the board.
+    pub(crate) fn apply_to_unit(&self, unit: &mut CombatUnit) {
+        match self {
+            BattleModifier::BloodMoon { attack } => {
+                unit.attack = unit.attack.saturating_add(*attack);
+            }
+            BattleModifier::Fog => fog_abilities(&mut unit.abilities),
+            BattleModifier::SuddenDeath { .. } | BattleModifier::Fatigue { .. } => {}
+        }
+    }
+}
+
+/// Combined clash damage multiplier for the given (1-based) round.
+pub(crate) fn clash_multiplier(modifiers: &[BattleModifier], round: LimitValue) -> StatValue {
+    modifiers
+        .iter()
+        .fold(1, |acc: StatValue, modifier| match modifier {
+            BattleModifier::SuddenDeath {
+                from_round,
+                multiplier,
+            } if round >= *from_round => acc.saturating_mul(*multiplier),
+            _ => acc,
+        })
+}
+
+fn fog_abilities(abilities: &mut [Ability]) {
+    for ability in abilities {
+        fog_effect(&mut ability.effect);
+        for effect in &mut ability.then {
+            fog_effect(effect);
+        }
+    }
+}
+
+fn fog_effect(effect: &mut AbilityEffect) {
+    let target = match effect {
+        AbilityEffect::Damage { target, .. }
+        | AbilityEffect::ModifyStats { target, .. }
+        | AbilityEffect::ModifyStatsPermanent { target, .. }
+        | AbilityEffect::Destroy { target }
+        | AbilityEffect::GrantStatusThisBattle { target, .. }
+        | AbilityEffect::GrantStatusPermanent { target, .. }
+        | AbilityEffect::RemoveStatusPermanent { target, .. }
+        | AbilityEffect::Heal { target, .. }
+        | AbilityEffect::Mimic { target }
+        | AbilityEffect::Steal { target }
+        | AbilityEffect::Silence { target } => target,
+        AbilityEffect::SpawnUnit { .. }
+        | AbilityEffect::GainMana { .. }
+        | AbilityEffect::Resurrect { .. } => return,
+    };
+    let fogged = match *target {
+        AbilityTarget::Position { scope, .. } if is_group_scope(scope) => {
+            AbilityTarget::Random { scope, count: 1 }
+        }
+        AbilityTarget::Standard { scope, count, .. } if is_group_scope(scope) => {
+            AbilityTarget::Random { scope, count }
+        }
+        _ => return,
+    };
+    *target = fogged;
+}
+
+/// Scopes that can hold more than one unit, so a random pick differs from a fixed one.
+fn is_group_scope(scope: TargetScope) -> bool {
+    matches!(
+        scope,
+        TargetScope::Allies | TargetScope::Enemies | TargetScope::All | TargetScope::AlliesOther
+    )
+}
@@ -1,16 +1,29 @@
 //! Deterministic RNG for battle resolution
 //!
-//! This module provides a no_std compatible RNG trait and implementation.
+//! This module provides a no_std compatible RNG trait and implementations.
 //! Substrate provides block hash as seed, browser WASM uses JavaScript seed.
+//!
+//! Every random draw in a game comes from a named `RngStream` of a seed, built
+//! through `VersionedRng` so a session keeps the generator it started with.
 
-use parity_scale_codec::{Decode, Encode};
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
+use crate::types::RoundValue;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
 /// Trait for random number generation in battles
 pub trait BattleRng {
     /// Generate a random u32
     fn next_u32(&mut self) -> u32;
 
+    /// Generate a random u64
+    fn next_u64(&mut self) -> u64 {
+        ((self.next_u32() as u64) << 32) | self.next_u32() as u64
+    }
+
     /// Generate a random number in range [0, max)
     fn gen_range(&mut self, max: usize) -> usize {
         if max == 0 {
@@ -19,6 +32,13 @@ pub trait BattleRng {
         (self.next_u32() as usize) % max
     }
 
+    /// Draw the base of the seeded trigger tiebreak. `decisive_bits` gives
+    /// the bits of the base that change the resulting order; generators that
+    /// only draw a value never call it.
+    fn tiebreak_base(&mut self, _decisive_bits: impl FnOnce() -> u16) -> u16 {
+        self.next_u32() as u16
+    }
+
     /// Shuffle a slice using Fisher-Yates algorithm
     fn shuffle<T>(&mut self, slice: &mut [T]) {
         for i in (1..slice.len()).rev() {
@@ -28,10 +48,34 @@ pub trait BattleRng {
     }
 }
 
+/// A borrowed generator draws from, and advances, the one it borrows.
+impl<R: BattleRng> BattleRng for &mut R {
+    fn next_u32(&mut self) -> u32 {
+        (**self).next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+
+    fn gen_range(&mut self, max: usize) -> usize {
+        (**self).gen_range(max)
+    }
+
+    fn tiebreak_base(&mut self, decisive_bits: impl FnOnce() -> u16) -> u16 {
+        (**self).tiebreak_base(decisive_bits)
+    }
+
+    fn shuffle<T>(&mut self, slice: &mut [T]) {
+        (**self).shuffle(slice)
+    }
+}
+
 /// XorShift32 RNG - simple, fast, deterministic
 ///
 /// This is suitable for game logic where cryptographic security is not needed.
-/// The same seed will always produce the same sequence.
+/// The same seed will always produce the same sequence. Kept for sessions
+/// recorded under `RngVersion::V1`.
 #[derive(Debug, Clone, Encode, Decode, TypeInfo)]
 pub struct XorShiftRng {
     state: u32,
@@ -64,6 +108,230 @@ impl BattleRng for XorShiftRng {
     }
 }
 
+/// SplitMix64 RNG - full 64-bit state and unbiased range sampling
+///
+/// Every seed, including 0, gives a distinct sequence with a period of 2^64.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+pub struct SplitMix64Rng {
+    state: u64,
+}
+
+impl SplitMix64Rng {
+    pub fn seed_from_u64(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl BattleRng for SplitMix64Rng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+        mix64(self.state)
+    }
+
+    /// Lemire's multiply-and-reject: every value in [0, max) is equally likely.
+    fn gen_range(&mut self, max: usize) -> usize {
+        if max == 0 {
+            return 0;
+        }
+        let range = max as u64;
+        let threshold = range.wrapping_neg() % range;
+        loop {
+            let product = (self.next_u64() as u128) * (range as u128);
+            if product as u64 >= threshold {
+                return (product >> 64) as usize;
+            }
+        }
+    }
+}
+
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// SplitMix64 output function; a bijection with full avalanche.
+fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Which generator and stream derivation a session uses.
+///
+/// Recorded when a game starts so later generator changes never alter how an
+/// existing game draws hands, resolves shop triggers or fights battles.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum RngVersion {
+    /// XorShift32 with modulo range sampling and XOR-salted stream seeds.
+    /// Sessions recorded without a version use it.
+    #[default]
+    V1,
+    /// SplitMix64 with unbiased range sampling and hashed stream seeds.
+    V2,
+}
+
+impl RngVersion {
+    /// Version new games start with.
+    pub const CURRENT: Self = Self::V2;
+}
+
+/// A named source of randomness within a game.
+///
+/// Each stream gets its own seed, so draws in one (say, the shop after a win)
+/// never line up with draws in another (the next hand).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RngStream {
+    Battle,
+    StartingBag,
+    Hand {
+        round: RoundValue,
+    },
+    ShopStart {
+        round: RoundValue,
+    },
+    ShopBuy {
+        round: RoundValue,
+        action_index: u32,
+    },
+    ShopSell {
+        round: RoundValue,
+        action_index: u32,
+    },
+    ShopAfterLoss {
+        round: RoundValue,
+    },
+    ShopAfterWin {
+        round: RoundValue,
+    },
+    ShopAfterDraw {
+        round: RoundValue,
+    },
+    GhostPick,
+    Modifiers,
+}
+
+impl RngStream {
+    /// Domain tag and fields hashed into the stream seed.
+    fn parts(self) -> (&'static [u8], u64, u64) {
+        match self {
+            RngStream::Battle => (b"oab/battle", 0, 0),
+            RngStream::StartingBag => (b"oab/starting-bag", 0, 0),
+            RngStream::Hand { round } => (b"oab/hand", round as u64, 0),
+            RngStream::ShopStart { round } => (b"oab/shop-start", round as u64, 0),
+            RngStream::ShopBuy {
+                round,
+                action_index,
+            } => (b"oab/shop-buy", round as u64, action_index as u64),
+            RngStream::ShopSell {
+                round,
+                action_index,
+            } => (b"oab/shop-sell", round as u64, action_index as u64),
+            RngStream::ShopAfterLoss { round } => (b"oab/shop-after-loss", round as u64, 0),
+            RngStream::ShopAfterWin { round } => (b"oab/shop-after-win", round as u64, 0),
+            RngStream::ShopAfterDraw { round } => (b"oab/shop-after-draw", round as u64, 0),
+            RngStream::GhostPick => (b"oab/ghost-pick", 0, 0),
+            RngStream::Modifiers => (b"oab/modifiers", 0, 0),
+        }
+    }
+
+    /// The XOR-salted seed `RngVersion::V1` games used for this stream.
+    fn legacy_seed(self, seed: u64) -> u64 {
+        const SHOP_START_SALT: u64 = 0x5348_4f50_0000_0001;
+        const SHOP_BUY_SALT: u64 = 0x5348_4f50_0000_0002;
+        const SHOP_SELL_SALT: u64 = 0x5348_4f50_0000_0003;
+        const SHOP_AFTER_LOSS_SALT: u64 = 0x5348_4f50_0000_0004;
+        const SHOP_AFTER_WIN_SALT: u64 = 0x5348_4f50_0000_0005;
+        const SHOP_AFTER_DRAW_SALT: u64 = 0x5348_4f50_0000_0006;
+        const MODIFIER_SEED_SALT: u64 = 0x6d6f_6469_6669_6572;
+
+        let shop =
+            |round: RoundValue, salt: u64| seed ^ (round as u64).wrapping_mul(GOLDEN_GAMMA) ^ salt;
+        match self {
+            RngStream::Battle | RngStream::StartingBag | RngStream::GhostPick => seed,
+            RngStream::Hand { round } => seed ^ round as u64,
+            RngStream::ShopStart { round } => shop(round, SHOP_START_SALT),
+            RngStream::ShopBuy {
+                round,
+                action_index,
+            } => shop(round, SHOP_BUY_SALT.wrapping_add(action_index as u64)),
+            RngStream::ShopSell {
+                round,
+                action_index,
+            } => shop(round, SHOP_SELL_SALT.wrapping_add(action_index as u64)),
+            RngStream::ShopAfterLoss { round } => shop(round, SHOP_AFTER_LOSS_SALT),
+            RngStream::ShopAfterWin { round } => shop(round, SHOP_AFTER_WIN_SALT),
+            RngStream::ShopAfterDraw { round } => shop(round, SHOP_AFTER_DRAW_SALT),
+            RngStream::Modifiers => seed ^ MODIFIER_SEED_SALT,
+        }
+    }
+}
+
+/// Seed for `stream` of `seed`: the stream's domain tag and fields hashed
+/// together with the seed.
+pub fn stream_seed(seed: u64, stream: RngStream) -> u64 {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let (tag, a, b) = stream.parts();
+    let tag_hash = tag.iter().fold(FNV_OFFSET, |h, byte| {
+        (h ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    });
+    mix64(mix64(mix64(tag_hash ^ seed) ^ a) ^ b)
+}
+
+/// The generator a session's `RngVersion` selects, seeded for one stream.
+#[derive(Debug, Clone)]
+pub enum VersionedRng {
+    V1(XorShiftRng),
+    V2(SplitMix64Rng),
+}
+
+impl VersionedRng {
+    pub fn new(version: RngVersion, seed: u64, stream: RngStream) -> Self {
+        match version {
+            RngVersion::V1 => Self::V1(XorShiftRng::seed_from_u64(stream.legacy_seed(seed))),
+            RngVersion::V2 => Self::V2(SplitMix64Rng::seed_from_u64(stream_seed(seed, stream))),
+        }
+    }
+}
+
+impl BattleRng for VersionedRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            Self::V1(rng) => rng.next_u32(),
+            Self::V2(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            Self::V1(rng) => rng.next_u64(),
+            Self::V2(rng) => rng.next_u64(),
+        }
+    }
+
+    fn gen_range(&mut self, max: usize) -> usize {
+        match self {
+            Self::V1(rng) => rng.gen_range(max),
+            Self::V2(rng) => rng.gen_range(max),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,4 +381,82 @@ mod tests {
         // Very unlikely to be in same order after shuffle
         assert_ne!(arr, original);
     }
+
+    #[test]
+    fn test_splitmix_reference_output() {
+        // First outputs of the reference SplitMix64 for seed 0.
+        let mut rng = SplitMix64Rng::seed_from_u64(0);
+        assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+    }
+
+    #[test]
+    fn test_splitmix_gen_range_covers_range_evenly() {
+        let mut rng = SplitMix64Rng::seed_from_u64(7);
+        let mut counts = [0u32; 6];
+        for _ in 0..60_000 {
+            counts[rng.gen_range(6)] += 1;
+        }
+        for count in counts {
+            assert!((9_000..11_000).contains(&count), "{counts:?}");
+        }
+        assert_eq!(rng.gen_range(0), 0);
+        assert_eq!(rng.gen_range(1), 0);
+    }
+
+    #[test]
+    fn test_v1_streams_reproduce_legacy_seeds() {
+        let seed = 0x1234_5678_9abc_def0;
+        let mut hand = VersionedRng::new(RngVersion::V1, seed, RngStream::Hand { round: 3 });
+        let mut legacy = XorShiftRng::seed_from_u64(seed ^ 3);
+        for _ in 0..10 {
+            assert_eq!(hand.gen_range(45), legacy.gen_range(45));
+        }
+
+        let round_mix = 3u64.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        let mut buy = VersionedRng::new(
+            RngVersion::V1,
+            seed,
+            RngStream::ShopBuy {
+                round: 3,
+                action_index: 2,
+            },
+        );
+        let mut legacy = XorShiftRng::seed_from_u64(seed ^ round_mix ^ 0x5348_4f50_0000_0004);
+        assert_eq!(buy.next_u32(), legacy.next_u32());
+    }
+
+    #[test]
+    fn test_v2_streams_are_separated() {
+        let seed = 42;
+        let first = |stream| VersionedRng::new(RngVersion::V2, seed, stream).next_u64();
+
+        let streams = [
+            RngStream::Battle,
+            RngStream::StartingBag,
+            RngStream::Hand { round: 1 },
+            RngStream::Hand { round: 2 },
+            RngStream::ShopStart { round: 1 },
+            RngStream::ShopBuy {
+                round: 1,
+                action_index: 0,
+            },
+            RngStream::ShopBuy {
+                round: 1,
+                action_index: 1,
+            },
+            RngStream::ShopSell {
+                round: 1,
+                action_index: 0,
+            },
+            RngStream::GhostPick,
+            RngStream::Modifiers,
+        ];
+        for (i, a) in streams.iter().enumerate() {
+            for b in &streams[i + 1..] {
+                assert_ne!(first(*a), first(*b), "{a:?} and {b:?} share a seed");
+            }
+        }
+        assert_eq!(first(RngStream::Battle), first(RngStream::Battle));
+    }
 }
@@ -0,0 +1,125 @@
+//! Versioned battle rules
+//!
+//! Board size, execution limits, fatigue and tiebreak policy are grouped into
+//! a `BattleRules` value identified by a rules version. A run records the
+//! version it started with, so rebalancing the current rules never changes
+//! how an old replay or stored ghost re-simulates.
+
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+use crate::limits::FatigueRule;
+use crate::types::{IndexValue, LimitValue};
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Identifies a published set of battle rules.
+pub type RulesVersion = u16;
+
+/// Rules before fatigue existed. Runs recorded without a version use these.
+pub const LEGACY_RULES_VERSION: RulesVersion = 1;
+/// Rules new runs start with.
+pub const CURRENT_RULES_VERSION: RulesVersion = 2;
+
+/// The largest board a battle supports. `BattleOutcome` keeps the starting
+/// player units' permanent changes in arrays of this size.
+pub const MAX_BOARD_SIZE: IndexValue = 10;
+
+/// How triggers with equal attack, health and position are ordered.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TiebreakPolicy {
+    /// One RNG draw per trigger batch, mixed with each source's instance id.
+    #[default]
+    Seeded,
+    /// Player triggers resolve first. Draws nothing from the RNG; meant for
+    /// puzzles and tests that need fixed ordering.
+    PlayerFirst,
+}
+
+/// Everything the battle engine treats as a tunable rule.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BattleRules {
+    pub version: RulesVersion,
+    pub board_size: IndexValue,
+    /// Nested ability triggers.
+    pub max_recursion_depth: LimitValue,
+    /// Total units spawned in one battle.
+    pub max_spawns_per_battle: LimitValue,
+    /// Ability activations per phase.
+    pub max_triggers_per_phase: LimitValue,
+    /// Chained trigger depth.
+    pub max_trigger_depth: LimitValue,
+    /// Combat rounds before the battle is called a draw.
+    pub max_battle_rounds: LimitValue,
+    pub fatigue: FatigueRule,
+    pub tiebreak: TiebreakPolicy,
+}
+
+impl BattleRules {
+    /// The rules published under `version`, or None if it is unknown.
+    pub fn for_version(version: RulesVersion) -> Option<Self> {
+        let fatigue = match version {
+            LEGACY_RULES_VERSION => FatigueRule {
+                start_round: 0,
+                base_damage: 0,
+            },
+            CURRENT_RULES_VERSION => FatigueRule {
+                start_round: 40,
+                base_damage: 1,
+            },
+            _ => return None,
+        };
+        Some(Self {
+            version,
+            board_size: 5,
+            max_recursion_depth: 50,
+            max_spawns_per_battle: 100,
+            max_triggers_per_phase: 200,
+            max_trigger_depth: 10,
+            max_battle_rounds: 100,
+            fatigue,
+            tiebreak: TiebreakPolicy::Seeded,
+        })
+    }
+
+    /// The same rules on a board with `board_size` slots, clamped to
+    /// `MAX_BOARD_SIZE`.
+    pub fn with_board_size(self, board_size: IndexValue) -> Self {
+        Self {
+            board_size: board_size.min(MAX_BOARD_SIZE),
+            ..self
+        }
+    }
+}
+
+impl Default for BattleRules {
+    fn default() -> Self {
+        Self::for_version(CURRENT_RULES_VERSION).expect("current rules version is published")
+    }
+}
@@ -0,0 +1,243 @@
+//! Battle event sinks
+//!
+//! The battle resolver reports everything that happens through an `EventSink`.
+//! `Vec<CombatEvent>` keeps the full log for replays and the UI. `BattleOutcome`
+//! keeps only what the game applies after a battle in fixed-size fields, so it
+//! never allocates and never builds the board snapshots that make up most of a
+//! full log.
+
+use crate::battle::{BattleResult, CombatEvent, CombatUnit, UnitId};
+use crate::limits::Team;
+use crate::rules::MAX_BOARD_SIZE;
+use crate::types::{StatValue, StatusMask};
+
+const SLOTS: usize = MAX_BOARD_SIZE as usize;
+
+/// Receives combat events as a battle resolves.
+pub trait EventSink {
+    fn record(&mut self, event: CombatEvent);
+
+    /// Record an event that carries board snapshots. `notice` says what
+    /// happened without the boards; sinks that only need that never call
+    /// `event`, so the views are never built.
+    fn record_snapshot(&mut self, _notice: SnapshotNotice, event: impl FnOnce() -> CombatEvent) {
+        self.record(event());
+    }
+
+    /// Called once after the battle ends with the boards it ended on.
+    fn finish(&mut self, _player_units: &[CombatUnit], _enemy_units: &[CombatUnit]) {}
+}
+
+/// What a snapshot event reports, minus its board views.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotNotice {
+    Spawn {
+        team: Team,
+        instance_id: UnitId,
+    },
+    Death {
+        team: Team,
+    },
+    Transform {
+        team: Team,
+        instance_id: UnitId,
+    },
+    Stolen {
+        team: Team,
+        old_instance_id: UnitId,
+        new_instance_id: UnitId,
+    },
+}
+
+impl SnapshotNotice {
+    /// The notice for a snapshot event, or None for any other event.
+    pub fn of(event: &CombatEvent) -> Option<Self> {
+        Some(match event {
+            CombatEvent::UnitSpawn {
+                team, spawned_unit, ..
+            } => Self::Spawn {
+                team: *team,
+                instance_id: spawned_unit.instance_id,
+            },
+            CombatEvent::UnitDeath { team, .. } => Self::Death { team: *team },
+            CombatEvent::UnitTransform {
+                team,
+                transformed_unit,
+                ..
+            } => Self::Transform {
+                team: *team,
+                instance_id: transformed_unit.instance_id,
+            },
+            CombatEvent::UnitStolen {
+                team,
+                old_instance_id,
+                new_instance_id,
+                ..
+            } => Self::Stolen {
+                team: *team,
+                old_instance_id: *old_instance_id,
+                new_instance_id: *new_instance_id,
+            },
+            _ => return None,
+        })
+    }
+}
+
+impl EventSink for alloc::vec::Vec<CombatEvent> {
+    fn record(&mut self, event: CombatEvent) {
+        self.push(event);
+    }
+}
+
+/// Reports every event to both sinks. A snapshot is built once, and only if
+/// either sink wants it.
+impl<A: EventSink, B: EventSink> EventSink for (A, B) {
+    fn record(&mut self, event: CombatEvent) {
+        self.0.record(event.clone());
+        self.1.record(event);
+    }
+
+    fn record_snapshot(&mut self, notice: SnapshotNotice, event: impl FnOnce() -> CombatEvent) {
+        let mut event = Some(event);
+        let mut built = None;
+        let mut build = || {
+            built
+                .get_or_insert_with(|| (event.take().expect("snapshot built once"))())
+                .clone()
+        };
+        self.0.record_snapshot(notice, &mut build);
+        self.1.record_snapshot(notice, build);
+    }
+
+    fn finish(&mut self, player_units: &[CombatUnit], enemy_units: &[CombatUnit]) {
+        self.0.finish(player_units, enemy_units);
+        self.1.finish(player_units, enemy_units);
+    }
+}
+
+/// What a battle changes outside of itself: the result and the player's
+/// carry-over into the next shop.
+///
+/// Permanent changes are kept for the starting player units only, in the
+/// slot of their instance id (`UnitId::player(1)` first). Spawned and stolen
+/// units never return to the shop board, so their changes are dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BattleOutcome {
+    pub result: BattleResult,
+    /// Next-shop mana the player's units granted.
+    pub player_shop_mana_delta: i16,
+    /// Permanent (attack, health) changes per starting player unit.
+    stat_deltas: [(StatValue, StatValue); SLOTS],
+    /// Permanent (granted, removed) statuses per starting player unit.
+    status_deltas: [(StatusMask, StatusMask); SLOTS],
+}
+
+impl Default for BattleOutcome {
+    fn default() -> Self {
+        Self {
+            result: BattleResult::Draw,
+            player_shop_mana_delta: 0,
+            stat_deltas: [(0, 0); SLOTS],
+            status_deltas: [(StatusMask::empty(), StatusMask::empty()); SLOTS],
+        }
+    }
+}
+
+impl BattleOutcome {
+    /// The outcome a full event log describes.
+    pub fn from_events(events: &[CombatEvent]) -> Self {
+        let mut outcome = Self::default();
+        for event in events {
+            outcome.record(event.clone());
+        }
+        outcome
+    }
+
+    /// Non-zero permanent (attack, health) changes per starting player unit.
+    pub fn player_permanent_stat_deltas(
+        &self,
+    ) -> impl Iterator<Item = (UnitId, (StatValue, StatValue))> + '_ {
+        self.stat_deltas
+            .iter()
+            .zip(1..)
+            .filter(|(delta, _)| **delta != (0, 0))
+            .map(|(delta, index)| (UnitId::player(index), *delta))
+    }
+
+    /// Non-empty permanent (granted, removed) statuses per starting player unit.
+    pub fn player_permanent_status_deltas(
+        &self,
+    ) -> impl Iterator<Item = (UnitId, (StatusMask, StatusMask))> + '_ {
+        self.status_deltas
+            .iter()
+            .zip(1..)
+            .filter(|((granted, removed), _)| !granted.is_empty() || !removed.is_empty())
+            .map(|(delta, index)| (UnitId::player(index), *delta))
+    }
+
+    /// The slot of a starting player unit.
+    fn slot(unit: UnitId) -> Option<usize> {
+        if !unit.is_player() {
+            return None;
+        }
+        (unit.raw() as usize)
+            .checked_sub(1)
+            .filter(|&slot| slot < SLOTS)
+    }
+}
+
+impl EventSink for BattleOutcome {
+    fn record(&mut self, event: CombatEvent) {
+        match event {
+            CombatEvent::BattleEnd { result } => self.result = result,
+            CombatEvent::AbilityGainMana {
+                team: Team::Player,
+                amount,
+                ..
+            } => {
+                self.player_shop_mana_delta =
+                    self.player_shop_mana_delta.saturating_add(amount as i16);
+            }
+            CombatEvent::AbilityModifyStatsPermanent {
+                target_instance_id,
+                health_change,
+                attack_change,
+                ..
+            } => {
+                let Some(slot) = Self::slot(target_instance_id) else {
+                    return;
+                };
+                let entry = &mut self.stat_deltas[slot];
+                entry.0 = entry.0.saturating_add(attack_change);
+                entry.1 = entry.1.saturating_add(health_change);
+            }
+            CombatEvent::StatusApplied {
+                target_instance_id,
+                status,
+                permanent: true,
+            } => {
+                let Some(slot) = Self::slot(target_instance_id) else {
+                    return;
+                };
+                let entry = &mut self.status_deltas[slot];
+                entry.0.insert(status);
+                entry.1.remove(status);
+            }
+            CombatEvent::StatusRemoved {
+                target_instance_id,
+                status,
+                permanent: true,
+            } => {
+                let Some(slot) = Self::slot(target_instance_id) else {
+                    return;
+                };
+                let entry = &mut self.status_deltas[slot];
+                entry.0.remove(status);
+                entry.1.insert(status);
+            }
+            _ => {}
+        }
+    }
+
+    fn record_snapshot(&mut self, _notice: SnapshotNotice, _event: impl FnOnce() -> CombatEvent) {}
+}
@@ -8,6 +8,7 @@ use alloc::vec::Vec;
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
+use crate::rng::RngVersion;
 use crate::types::{BoardUnit, CardId, ManaValue, RarityValue, RoundValue, SetIdValue, UnitCard};
 
 #[cfg(feature = "std")]
@@ -56,6 +57,9 @@ pub struct ShopState {
     pub round: RoundValue,
     /// Seed for deterministic shop trigger RNG
     pub game_seed: u64,
+    /// Generator the session started with
+    #[cfg_attr(feature = "std", serde(default))]
+    pub rng_version: RngVersion,
 }
 
 /// Find an empty board slot
@@ -0,0 +1,251 @@
+//! Battle summaries
+//!
+//! Per-unit statistics for a finished battle, derived from its combat events:
+//! damage dealt and taken, kills, abilities triggered, units spawned and mana
+//! generated, plus the battle's MVP. `SummarySink` builds the summary while
+//! the battle resolves. It reads snapshot events from their notices only, so
+//! beside an outcome-only sink no board views are ever built.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode};
+use scale_info::TypeInfo;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::battle::{BattleResult, CombatEvent, UnitId};
+use crate::sink::{EventSink, SnapshotNotice};
+use crate::types::StatValue;
+
+/// What one unit did in a battle. A stolen unit's stats after the steal are
+/// kept under its new instance id.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct UnitSummary {
+    pub instance_id: UnitId,
+    /// Clash, Trample, Reach and ability damage this unit landed.
+    pub damage_dealt: u32,
+    /// Damage landed on this unit, fatigue included.
+    pub damage_taken: u32,
+    /// Enemy units whose fatal hit came from this unit.
+    pub kills: u16,
+    pub abilities_triggered: u16,
+    pub units_spawned: u16,
+    /// Next-shop mana this unit's abilities granted.
+    pub mana_generated: i16,
+}
+
+/// Per-unit statistics for a battle, ordered by instance id (player units
+/// first). Units that neither acted nor were hit are left out.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BattleSummary {
+    pub units: Vec<UnitSummary>,
+    /// The unit on the winning side (either side on a draw) that dealt the
+    /// most damage, ties going to more kills and then the lower id.
+    pub mvp: Option<UnitId>,
+}
+
+impl BattleSummary {
+    /// The summary a full event log describes.
+    pub fn from_events(events: &[CombatEvent]) -> Self {
+        let mut sink = SummarySink::default();
+        for event in events {
+            sink.record(event.clone());
+        }
+        sink.into_summary()
+    }
+
+    pub fn unit(&self, instance_id: UnitId) -> Option<&UnitSummary> {
+        self.units
+            .iter()
+            .find(|unit| unit.instance_id == instance_id)
+    }
+}
+
+/// Builds a `BattleSummary` from events as they are recorded.
+#[derive(Debug, Clone, Default)]
+pub struct SummarySink {
+    units: BTreeMap<UnitId, UnitSummary>,
+    /// Last reported health of each unit, to spot the hit that fells it.
+    health: BTreeMap<UnitId, StatValue>,
+    /// The source of the ability whose effects are resolving.
+    active_source: Option<UnitId>,
+    result: Option<BattleResult>,
+}
+
+impl SummarySink {
+    fn unit(&mut self, instance_id: UnitId) -> &mut UnitSummary {
+        self.units
+            .entry(instance_id)
+            .or_insert_with(|| UnitSummary {
+                instance_id,
+                damage_dealt: 0,
+                damage_taken: 0,
+                kills: 0,
+                abilities_triggered: 0,
+                units_spawned: 0,
+                mana_generated: 0,
+            })
+    }
+
+    /// Note a unit's new health and report whether this change felled it.
+    fn set_health(&mut self, instance_id: UnitId, health: StatValue) -> bool {
+        let was_alive = self
+            .health
+            .insert(instance_id, health)
+            .is_none_or(|old| old > 0);
+        was_alive && health <= 0
+    }
+
+    /// `source` landed `damage` on `target`, leaving it at `remaining_hp`.
+    fn hit(&mut self, source: UnitId, target: UnitId, damage: StatValue, remaining_hp: StatValue) {
+        let damage = damage.max(0) as u32;
+        self.unit(source).damage_dealt += damage;
+        self.unit(target).damage_taken += damage;
+        // As in the engine's OnKill, only a hit on an enemy counts as a kill.
+        if self.set_health(target, remaining_hp) && source.is_player() != target.is_player() {
+            let killer = self.unit(source);
+            killer.kills = killer.kills.saturating_add(1);
+        }
+    }
+
+    fn notice(&mut self, notice: SnapshotNotice) {
+        match notice {
+            // Spawns only happen inside an ability's effects, right after its
+            // trigger event.
+            SnapshotNotice::Spawn { .. } => {
+                if let Some(source) = self.active_source {
+                    let unit = self.unit(source);
+                    unit.units_spawned = unit.units_spawned.saturating_add(1);
+                }
+            }
+            SnapshotNotice::Stolen {
+                old_instance_id,
+                new_instance_id,
+                ..
+            } => {
+                if let Some(health) = self.health.remove(&old_instance_id) {
+                    self.health.insert(new_instance_id, health);
+                }
+            }
+            SnapshotNotice::Death { .. } | SnapshotNotice::Transform { .. } => {}
+        }
+    }
+
+    pub fn into_summary(self) -> BattleSummary {
+        let contender = |unit: &&UnitSummary| match self.result {
+            Some(BattleResult::Victory) => unit.instance_id.is_player(),
+            Some(BattleResult::Defeat) => unit.instance_id.is_enemy(),
+            Some(BattleResult::Draw) | None => true,
+        };
+        let mvp = self
+            .units
+            .values()
+            .filter(contender)
+            .max_by(|a, b| {
+                a.damage_dealt
+                    .cmp(&b.damage_dealt)
+                    .then(a.kills.cmp(&b.kills))
+                    .then(b.instance_id.cmp(&a.instance_id))
+            })
+            .map(|unit| unit.instance_id);
+        BattleSummary {
+            units: self.units.into_values().collect(),
+            mvp,
+        }
+    }
+}
+
+impl EventSink for SummarySink {
+    fn record(&mut self, event: CombatEvent) {
+        match event {
+            CombatEvent::AbilityTrigger {
+                source_instance_id, ..
+            } => {
+                self.active_source = Some(source_instance_id);
+                let unit = self.unit(source_instance_id);
+                unit.abilities_triggered = unit.abilities_triggered.saturating_add(1);
+            }
+            CombatEvent::DamageTaken {
+                target_instance_id,
+                remaining_hp,
+                source_instance_id,
+                damage,
+                ..
+            } => {
+                if damage > 0 {
+                    self.hit(source_instance_id, target_instance_id, damage, remaining_hp);
+                } else {
+                    self.set_health(target_instance_id, remaining_hp);
+                }
+            }
+            CombatEvent::AbilityDamage {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            }
+            | CombatEvent::Trample {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            }
+            | CombatEvent::Reach {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => self.hit(source_instance_id, target_instance_id, damage, remaining_hp),
+            CombatEvent::AbilityDestroy {
+                source_instance_id,
+                target_instance_id,
+            } => self.hit(source_instance_id, target_instance_id, 0, 0),
+            CombatEvent::FatigueDamage {
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => {
+                self.unit(target_instance_id).damage_taken += damage.max(0) as u32;
+                self.set_health(target_instance_id, remaining_hp);
+            }
+            CombatEvent::AbilityHeal {
+                target_instance_id,
+                new_health,
+                ..
+            }
+            | CombatEvent::AbilityModifyStats {
+                target_instance_id,
+                new_health,
+                ..
+            }
+            | CombatEvent::AbilityModifyStatsPermanent {
+                target_instance_id,
+                new_health,
+                ..
+            } => {
+                self.set_health(target_instance_id, new_health);
+            }
+            CombatEvent::AbilityGainMana {
+                source_instance_id,
+                amount,
+                ..
+            } => {
+                let unit = self.unit(source_instance_id);
+                unit.mana_generated = unit.mana_generated.saturating_add(amount.into());
+            }
+            CombatEvent::BattleEnd { result } => self.result = Some(result),
+            event => {
+                if let Some(notice) = SnapshotNotice::of(&event) {
+                    self.notice(notice);
+                }
+            }
+        }
+    }
+
+    fn record_snapshot(&mut self, notice: SnapshotNotice, _event: impl FnOnce() -> CombatEvent) {
+        self.notice(notice);
+    }
+}
@@ -1,9 +1,10 @@
 use crate::battle::{
     permanent_stat_deltas_from_events, player_permanent_stat_deltas_from_events,
-    player_shop_mana_delta_from_events, shop_mana_delta_from_events, BattleResult, CombatEvent,
-    UnitId,
+    player_shop_mana_delta_from_events, resolve_battle_outcome, shop_mana_delta_from_events,
+    BattleResult, CombatEvent, UnitId,
 };
 use crate::limits::Team;
+use crate::sink::BattleOutcome;
 use crate::tests::*;
 use crate::types::*;
 
@@ -83,8 +84,8 @@ fn test_modify_stats_permanent_effect_emits_expected_delta() {
     let durable = create_dummy_card(1, "Durable", 2, 5).with_battle_ability(create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStatsPermanent {
-            health: 3,
-            attack: 2,
+            health: ValueExpr::Const(3),
+            attack: ValueExpr::Const(2),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -126,3 +127,84 @@ fn test_modify_stats_permanent_effect_emits_expected_delta() {
     let deltas = player_permanent_stat_deltas_from_events(&events);
     assert_eq!(deltas.get(&UnitId::player(1)), Some(&(2, 3)));
 }
+
+#[test]
+fn test_battle_outcome_skips_snapshots_and_non_board_carry_over() {
+    let events = vec![
+        CombatEvent::UnitDeath {
+            team: Team::Player,
+            new_board_state: vec![],
+        },
+        CombatEvent::AbilityGainMana {
+            source_instance_id: UnitId::player(1),
+            team: Team::Player,
+            amount: 2,
+        },
+        CombatEvent::AbilityGainMana {
+            source_instance_id: UnitId::enemy(1),
+            team: Team::Enemy,
+            amount: 3,
+        },
+        CombatEvent::AbilityModifyStatsPermanent {
+            source_instance_id: UnitId::enemy(1),
+            target_instance_id: UnitId::enemy(1),
+            health_change: 1,
+            attack_change: 1,
+            new_attack: 2,
+            new_health: 2,
+        },
+        // A unit spawned past the starting board never reaches the shop.
+        CombatEvent::AbilityModifyStatsPermanent {
+            source_instance_id: UnitId::player(1),
+            target_instance_id: UnitId::player(crate::rules::MAX_BOARD_SIZE as u16 + 1),
+            health_change: 1,
+            attack_change: 1,
+            new_attack: 2,
+            new_health: 2,
+        },
+        CombatEvent::BattleEnd {
+            result: BattleResult::Victory,
+        },
+    ];
+
+    let outcome = BattleOutcome::from_events(&events);
+    assert_eq!(outcome.result, BattleResult::Victory);
+    assert_eq!(
+        outcome.player_shop_mana_delta,
+        player_shop_mana_delta_from_events(&events)
+    );
+    assert_eq!(outcome.player_permanent_stat_deltas().count(), 0);
+    assert_eq!(outcome.player_permanent_status_deltas().count(), 0);
+}
+
+#[test]
+fn test_battle_outcome_matches_event_log() {
+    let durable = create_dummy_card(1, "Durable", 2, 5).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::ModifyStatsPermanent {
+            health: ValueExpr::Const(3),
+            attack: ValueExpr::Const(2),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    ));
+    let p_board = vec![CombatUnit::from_card(durable)];
+    let e_board = vec![create_dummy_enemy()];
+
+    let events = run_battle(&p_board, &e_board, 123);
+    let outcome = resolve_battle_outcome(
+        p_board,
+        e_board,
+        &mut XorShiftRng::seed_from_u64(123),
+        &empty_card_pool(),
+        &BattleRules::default(),
+        &[],
+    );
+
+    assert_eq!(outcome, BattleOutcome::from_events(&events));
+    assert_eq!(
+        outcome.player_permanent_stat_deltas().collect::<Vec<_>>(),
+        vec![(UnitId::player(1), (2, 3))]
+    );
+}
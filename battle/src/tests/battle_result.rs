@@ -21,7 +21,7 @@ fn test_mutual_destruction_chain() {
     let start_nuke = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 10,
+            amount: ValueExpr::Const(10),
             target: AbilityTarget::Position {
                 scope: TargetScope::Enemies,
                 index: 0,
@@ -31,7 +31,7 @@ fn test_mutual_destruction_chain() {
     let faint_nuke = create_ability(
         AbilityTrigger::OnFaint,
         AbilityEffect::Damage {
-            amount: 10,
+            amount: ValueExpr::Const(10),
             target: AbilityTarget::Position {
                 scope: TargetScope::Enemies,
                 index: 0,
@@ -82,8 +82,8 @@ fn test_infinite_battle_draw() {
     let squire = create_dummy_card(2, "Squire", 2, 3).with_battle_ability(create_ability(
         AbilityTrigger::BeforeAnyAttack,
         AbilityEffect::ModifyStats {
-            health: 2,
-            attack: 0,
+            health: ValueExpr::Const(2),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::Position {
                 scope: TargetScope::SelfUnit,
                 index: -1,
@@ -110,11 +110,16 @@ fn test_infinite_battle_draw() {
         panic!("Battle did not end correctly: {:?}", last_event);
     }
 
-    let has_limit_exceeded = events
-        .iter()
-        .any(|e| matches!(e, CombatEvent::LimitExceeded { .. }));
     assert!(
-        has_limit_exceeded,
-        "Stalemate should trigger a LimitExceeded event"
+        events
+            .iter()
+            .any(|e| matches!(e, CombatEvent::FatigueDamage { .. })),
+        "Fatigue should break the stalemate"
+    );
+    assert!(
+        !events
+            .iter()
+            .any(|e| matches!(e, CombatEvent::LimitExceeded { .. })),
+        "Fatigue should end the stalemate before the round limit"
     );
 }
@@ -0,0 +1,67 @@
+use crate::bounded::{BoundedBattleAbility, BoundedBattleCondition};
+use crate::types::*;
+use alloc::boxed::Box;
+use bounded_collections::ConstU32;
+use parity_scale_codec::{Decode, Encode};
+
+type Bounded = BoundedBattleCondition<ConstU32<4>>;
+
+fn nested(depth: usize) -> Condition {
+    let mut condition = Condition::Is(Matcher::UnitCount {
+        scope: TargetScope::Allies,
+        op: CompareOp::GreaterThanOrEqual,
+        value: 1,
+    });
+    for i in 1..depth {
+        condition = if i % 2 == 0 {
+            Condition::Not(Box::new(condition))
+        } else {
+            Condition::AnyOf(vec![condition])
+        };
+    }
+    condition
+}
+
+#[test]
+fn test_bounded_condition_round_trips_at_max_depth() {
+    let condition = nested(MAX_CONDITION_DEPTH);
+    let bounded: Bounded = condition.clone().into();
+
+    // Within the limit the bounded encoding matches the unbounded one.
+    assert_eq!(bounded.encode(), condition.encode());
+    let decoded = Bounded::decode(&mut &condition.encode()[..]).unwrap();
+    assert_eq!(Condition::from(decoded), condition);
+}
+
+#[test]
+fn test_bounded_condition_rejects_deeper_nesting() {
+    let too_deep = nested(MAX_CONDITION_DEPTH + 1);
+
+    assert!(Bounded::decode(&mut &too_deep.encode()[..]).is_err());
+
+    // Lossy conversion fails closed: an empty AnyOf never passes.
+    let bounded: Bounded = too_deep.into();
+    assert_eq!(Condition::from(bounded), Condition::AnyOf(vec![]));
+}
+
+#[test]
+fn test_bounded_ability_keeps_follow_up_effects_within_limit() {
+    let step = AbilityEffect::GainMana {
+        amount: ValueExpr::Const(1),
+    };
+    let mut ability = Ability {
+        trigger: AbilityTrigger::OnStart,
+        effect: step.clone(),
+        conditions: vec![],
+        max_triggers: None,
+        then: vec![step.clone(); MAX_ABILITY_EFFECTS - 1],
+        max_triggers_per_round: None,
+        cooldown: None,
+    };
+    let bounded: BoundedBattleAbility<ConstU32<4>> = ability.clone().into();
+    assert_eq!(bounded.encode(), ability.encode());
+    assert_eq!(Ability::from(bounded), ability);
+
+    ability.then.push(step);
+    assert!(BoundedBattleAbility::<ConstU32<4>>::decode(&mut &ability.encode()[..]).is_err());
+}
@@ -0,0 +1,191 @@
+use super::*;
+use crate::battle::BattleResult;
+use crate::exact::{exact_outcome_distribution, ExactConfig};
+use crate::matchup::{evaluate_matchup, MatchupConfig};
+
+fn pool_of(cards: Vec<UnitCard>) -> BTreeMap<CardId, UnitCard> {
+    cards.into_iter().map(|card| (card.id, card)).collect()
+}
+
+fn board(card_ids: &[u16]) -> Vec<BoardUnit> {
+    card_ids
+        .iter()
+        .map(|&id| BoardUnit::new(CardId(id)))
+        .collect()
+}
+
+fn random_hit(amount: StatValue) -> Ability {
+    create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(amount),
+            target: AbilityTarget::Random {
+                scope: TargetScope::Enemies,
+                count: 1,
+            },
+        },
+    )
+}
+
+#[test]
+fn test_exact_deterministic_battle_is_certain() {
+    let pool = pool_of(vec![
+        create_dummy_card(1, "Giant", 10, 10),
+        create_dummy_card(2, "Pup", 1, 1),
+    ]);
+
+    let odds = exact_outcome_distribution(
+        &board(&[1]),
+        &board(&[2, 2]),
+        &pool,
+        &ExactConfig::default(),
+    );
+
+    assert!(odds.exact);
+    assert_eq!(odds.victory, 1.0);
+    assert_eq!(odds.draw, 0.0);
+    assert_eq!(odds.defeat, 0.0);
+}
+
+#[test]
+fn test_exact_random_target_is_a_coin_flip() {
+    // Hitting the 3/3 wins, hitting the 1/1 loses.
+    let pool = pool_of(vec![
+        create_dummy_card(1, "Gambler", 2, 3).with_battle_ability(random_hit(3)),
+        create_dummy_card(2, "Brute", 3, 3),
+        create_dummy_card(3, "Pup", 1, 1),
+    ]);
+
+    let odds = exact_outcome_distribution(
+        &board(&[1]),
+        &board(&[2, 3]),
+        &pool,
+        &ExactConfig::default(),
+    );
+
+    assert!(odds.exact);
+    assert_eq!(odds.victory, 0.5);
+    assert_eq!(odds.defeat, 0.5);
+    assert_eq!(odds.probability(BattleResult::Draw), 0.0);
+}
+
+#[test]
+fn test_exact_follows_the_trigger_tiebreak() {
+    // Both start triggers tie on attack, health and position. If the sniper
+    // goes first the sentinel fails its health check and loses the clash;
+    // otherwise it grows to 6 attack and trades.
+    let snipe = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(1),
+            target: AbilityTarget::Position {
+                scope: TargetScope::Enemies,
+                index: 0,
+            },
+        },
+    );
+    let mut grow = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(5),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    );
+    grow.conditions = vec![Condition::Is(Matcher::StatValueCompare {
+        scope: TargetScope::SelfUnit,
+        stat: StatType::Health,
+        op: CompareOp::GreaterThanOrEqual,
+        value: 2,
+    })];
+    let pool = pool_of(vec![
+        create_dummy_card(1, "Sniper", 1, 2).with_battle_ability(snipe),
+        create_dummy_card(2, "Sentinel", 1, 2).with_battle_ability(grow),
+    ]);
+
+    let odds =
+        exact_outcome_distribution(&board(&[1]), &board(&[2]), &pool, &ExactConfig::default());
+
+    assert!(odds.exact);
+    assert_eq!(odds.victory, 0.5);
+    assert_eq!(odds.draw, 0.5);
+    assert_eq!(odds.defeat, 0.0);
+}
+
+#[test]
+fn test_exact_merges_equal_states() {
+    // The start shuffle has 6 orders but only the first pick matters, so
+    // they reach 3 states. Each then plays 7 more steps: two rounds of
+    // before-attack, attack and fatigue, then the end phase.
+    let pool = pool_of(vec![
+        create_dummy_card(1, "Gambler", 5, 5).with_battle_ability(random_hit(1)),
+        create_dummy_card(2, "Pup", 1, 1),
+    ]);
+
+    let odds = exact_outcome_distribution(
+        &board(&[1]),
+        &board(&[2, 2, 2]),
+        &pool,
+        &ExactConfig::default(),
+    );
+
+    assert!(odds.exact);
+    assert_eq!(odds.victory, 1.0);
+    assert_eq!(odds.branches, 6 + 3 * 7);
+}
+
+#[test]
+fn test_exact_agrees_with_sampling() {
+    let pool = pool_of(vec![
+        create_dummy_card(1, "Gambler", 2, 3).with_battle_ability(random_hit(2)),
+        create_dummy_card(2, "Slinger", 1, 4).with_battle_ability(random_hit(1)),
+        create_dummy_card(3, "Brute", 3, 3),
+        create_dummy_card(4, "Pup", 1, 2),
+    ]);
+    let player = board(&[1, 2, 4]);
+    let enemy = board(&[3, 4, 2]);
+    let config = ExactConfig::default();
+
+    let odds = exact_outcome_distribution(&player, &enemy, &pool, &config);
+    let sampled = evaluate_matchup(
+        &player,
+        &enemy,
+        &pool,
+        &MatchupConfig {
+            battles: 4000,
+            ..MatchupConfig::default()
+        },
+    );
+
+    assert!(odds.exact);
+    assert!((odds.victory + odds.draw + odds.defeat - 1.0).abs() < 1e-9);
+    assert!((odds.victory - sampled.win_rate).abs() < 0.03);
+    assert!((odds.draw - sampled.draw_rate).abs() < 0.03);
+    assert!((odds.defeat - sampled.loss_rate).abs() < 0.03);
+}
+
+#[test]
+fn test_exact_falls_back_to_sampling_over_budget() {
+    let pool = pool_of(vec![
+        create_dummy_card(1, "Gambler", 2, 3).with_battle_ability(random_hit(3)),
+        create_dummy_card(2, "Brute", 3, 3),
+        create_dummy_card(3, "Pup", 1, 1),
+    ]);
+    let config = ExactConfig {
+        branch_budget: 1,
+        sampling: MatchupConfig {
+            battles: 200,
+            ..MatchupConfig::default()
+        },
+    };
+
+    let odds = exact_outcome_distribution(&board(&[1]), &board(&[2, 3]), &pool, &config);
+
+    assert!(!odds.exact);
+    assert_eq!(odds.branches, 1);
+    assert_eq!(odds.samples, 200);
+    assert!(odds.victory > 0.0 && odds.defeat > 0.0);
+    assert!((odds.victory + odds.draw + odds.defeat - 1.0).abs() < 1e-9);
+}
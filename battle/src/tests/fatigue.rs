@@ -0,0 +1,132 @@
+use super::*;
+use crate::battle::{resolve_battle_with_modifiers, BattleResult, CombatEvent, UnitId};
+use crate::limits::LimitReason;
+use crate::modifiers::BattleModifier;
+use crate::types::*;
+
+fn run_with_fatigue(
+    player_board: &[CombatUnit],
+    enemy_board: &[CombatUnit],
+    from_round: RoundValue,
+    base_damage: StatValue,
+) -> Vec<CombatEvent> {
+    let mut rng = XorShiftRng::seed_from_u64(42);
+    resolve_battle_with_modifiers(
+        player_board.to_vec(),
+        enemy_board.to_vec(),
+        &mut rng,
+        &empty_card_pool(),
+        &BattleRules::default(),
+        &[BattleModifier::Fatigue {
+            from_round,
+            base_damage,
+        }],
+    )
+}
+
+fn fatigue_hits(events: &[CombatEvent]) -> Vec<(UnitId, StatValue, StatValue)> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::FatigueDamage {
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => Some((*target_instance_id, *damage, *remaining_hp)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_fatigue_doubles_from_start_round() {
+    let p_board = vec![create_board_unit(1, "Wall", 0, 50)];
+    let e_board = vec![create_board_unit(2, "Wall", 0, 50)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    let clashes_before_fatigue = events
+        .iter()
+        .take_while(|e| !matches!(e, CombatEvent::FatigueDamage { .. }))
+        .filter(|e| matches!(e, CombatEvent::Clash { .. }))
+        .count();
+    assert_eq!(
+        clashes_before_fatigue,
+        BattleRules::default().fatigue.start_round as usize
+    );
+
+    let damages: Vec<StatValue> = fatigue_hits(&events)
+        .iter()
+        .filter(|(id, ..)| *id == UnitId::player(1))
+        .map(|(_, damage, _)| *damage)
+        .collect();
+    assert_eq!(damages, vec![1, 2, 4, 8, 16, 32]);
+    assert!(matches!(
+        events.last(),
+        Some(CombatEvent::BattleEnd {
+            result: BattleResult::Draw
+        })
+    ));
+}
+
+#[test]
+fn test_fatigue_ignores_shield_and_fires_hurt_and_faint() {
+    let bomb = create_dummy_card(1, "Bomb", 0, 3).with_battle_ability(create_ability(
+        AbilityTrigger::OnFaint,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(1),
+            target: AbilityTarget::All {
+                scope: TargetScope::Enemies,
+            },
+        },
+    ));
+    let brute = create_dummy_card(2, "Brute", 0, 10)
+        .with_base_statuses(StatusMask::from_statuses(&[Status::Shield]))
+        .with_battle_ability(create_ability(
+            AbilityTrigger::OnHurt,
+            AbilityEffect::ModifyStats {
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(1),
+                target: AbilityTarget::All {
+                    scope: TargetScope::SelfUnit,
+                },
+            },
+        ));
+
+    let events = run_with_fatigue(
+        &[CombatUnit::from_card(bomb)],
+        &[CombatUnit::from_card(brute)],
+        1,
+        3,
+    );
+
+    assert_eq!(
+        fatigue_hits(&events)[..2],
+        [(UnitId::player(1), 3, 0), (UnitId::enemy(1), 3, 7)]
+    );
+    assert!(has_ability_trigger(&events, UnitId::player(1), 0));
+    assert!(has_ability_trigger(&events, UnitId::enemy(1), 0));
+    assert!(matches!(
+        events.last(),
+        Some(CombatEvent::BattleEnd {
+            result: BattleResult::Defeat
+        })
+    ));
+}
+
+#[test]
+fn test_zero_fatigue_falls_back_to_round_limit() {
+    let p_board = vec![create_board_unit(1, "Wall", 0, 50)];
+    let e_board = vec![create_board_unit(2, "Wall", 0, 50)];
+
+    let events = run_with_fatigue(&p_board, &e_board, 1, 0);
+
+    assert!(fatigue_hits(&events).is_empty());
+    assert!(events.iter().any(|e| matches!(
+        e,
+        CombatEvent::LimitExceeded {
+            reason: LimitReason::RoundLimit { .. },
+            ..
+        }
+    )));
+}
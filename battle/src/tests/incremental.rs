@@ -8,6 +8,7 @@ use alloc::vec::Vec;
 
 use crate::commit::{apply_single_action, finalize_turn, verify_and_apply_turn, ShopTurnContext};
 use crate::error::GameError;
+use crate::rng::RngVersion;
 use crate::state::ShopState;
 use crate::types::*;
 
@@ -25,6 +26,7 @@ fn base_state() -> ShopState {
         shop_mana: 0,
         round: 1,
         game_seed: 42,
+        rng_version: RngVersion::CURRENT,
     }
 }
 
@@ -157,14 +159,15 @@ fn burn_from_board_with_on_sell() {
     let seller = make_card(1, "Seller", 1, 2, 1, 1).with_shop_ability(ShopAbility {
         trigger: ShopTrigger::OnSell,
         effect: ShopEffect::ModifyStatsPermanent {
-            health: 0,
-            attack: 3,
+            health: ShopValueExpr::Const(0),
+            attack: ShopValueExpr::Const(3),
             target: ShopTarget::All {
                 scope: ShopScope::AlliesOther,
             },
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
     });
     state.card_pool.insert(seller.id, seller);
     state.card_pool.insert(ally.id, ally);
@@ -181,14 +184,15 @@ fn play_from_hand_with_on_buy() {
     let support = make_card(1, "Support", 1, 2, 1, 1).with_shop_ability(ShopAbility {
         trigger: ShopTrigger::OnBuy,
         effect: ShopEffect::ModifyStatsPermanent {
-            health: 2,
-            attack: 0,
+            health: ShopValueExpr::Const(2),
+            attack: ShopValueExpr::Const(0),
             target: ShopTarget::All {
                 scope: ShopScope::SelfUnit,
             },
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
     });
     let buyer = make_card(2, "Buyer", 1, 1, 0, 1);
     state.card_pool.insert(support.id, support);
@@ -312,8 +316,8 @@ fn on_buy_rng_determinism() {
     let support = make_card(1, "Randomizer", 1, 2, 1, 1).with_shop_ability(ShopAbility {
         trigger: ShopTrigger::OnBuy,
         effect: ShopEffect::ModifyStatsPermanent {
-            health: 1,
-            attack: 0,
+            health: ShopValueExpr::Const(1),
+            attack: ShopValueExpr::Const(0),
             target: ShopTarget::Random {
                 scope: ShopScope::AlliesOther,
                 count: 1,
@@ -321,6 +325,7 @@ fn on_buy_rng_determinism() {
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
     });
     let ally_a = make_card(2, "AllyA", 2, 3, 0, 0);
     let ally_b = make_card(3, "AllyB", 2, 3, 0, 0);
@@ -352,8 +357,8 @@ fn on_sell_rng_determinism() {
     let seller = make_card(1, "Seller", 1, 2, 1, 1).with_shop_ability(ShopAbility {
         trigger: ShopTrigger::OnSell,
         effect: ShopEffect::ModifyStatsPermanent {
-            health: 1,
-            attack: 0,
+            health: ShopValueExpr::Const(1),
+            attack: ShopValueExpr::Const(0),
             target: ShopTarget::Random {
                 scope: ShopScope::Allies,
                 count: 1,
@@ -361,6 +366,7 @@ fn on_sell_rng_determinism() {
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
     });
     let ally_a = make_card(2, "AllyA", 2, 3, 0, 0);
     let ally_b = make_card(3, "AllyB", 2, 3, 0, 0);
@@ -387,9 +393,12 @@ fn complex_mixed_sequence_with_triggers() {
     let play_card = make_card(2, "PlayMe", 2, 3, 2, 1);
     let board_sell = make_card(3, "SellMe", 1, 2, 0, 1).with_shop_ability(ShopAbility {
         trigger: ShopTrigger::OnSell,
-        effect: ShopEffect::GainMana { amount: 1 },
+        effect: ShopEffect::GainMana {
+            amount: ShopValueExpr::Const(1),
+        },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
     });
     let board_stay_a = make_card(4, "StayA", 3, 3, 0, 0);
     let board_stay_b = make_card(5, "StayB", 4, 4, 0, 0);
@@ -9,14 +9,17 @@ fn test_max_triggers_unlimited() {
         card.battle_abilities = vec![Ability {
             trigger: AbilityTrigger::OnHurt,
             effect: AbilityEffect::ModifyStats {
-                health: 0,
-                attack: 1,
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(1),
                 target: AbilityTarget::All {
                     scope: TargetScope::SelfUnit,
                 },
             },
             conditions: vec![],
             max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         }];
         card
     };
@@ -56,14 +59,17 @@ fn test_max_triggers_not_exceeded_on_death() {
         card.battle_abilities = vec![Ability {
             trigger: AbilityTrigger::OnHurt,
             effect: AbilityEffect::ModifyStats {
-                health: 0,
-                attack: 1,
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(1),
                 target: AbilityTarget::All {
                     scope: TargetScope::SelfUnit,
                 },
             },
             conditions: vec![],
             max_triggers: Some(2),
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         }];
         card
     };
@@ -103,14 +109,17 @@ fn test_max_triggers_limit() {
         card.battle_abilities = vec![Ability {
             trigger: AbilityTrigger::OnHurt,
             effect: AbilityEffect::ModifyStats {
-                health: 0,
-                attack: 1,
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(1),
                 target: AbilityTarget::All {
                     scope: TargetScope::SelfUnit,
                 },
             },
             conditions: vec![],
             max_triggers: Some(2),
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         }];
         card
     };
@@ -141,3 +150,105 @@ fn test_max_triggers_limit() {
         "Limited Rage should only trigger 2 times despite taking damage multiple times"
     );
 }
+
+/// Builds a board where an enemy Bomber pings every player unit before each
+/// attack, so a Watcher's OnAllyHurt can fire several times in one round.
+fn ally_hurt_watcher_battle(max_triggers_per_round: Option<CountValue>) -> Vec<CombatEvent> {
+    let bomber = create_dummy_card(4, "Bomber", 1, 40).with_battle_ability(create_ability(
+        AbilityTrigger::BeforeAnyAttack,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(1),
+            target: AbilityTarget::All {
+                scope: TargetScope::Enemies,
+            },
+        },
+    ));
+    let watcher = create_dummy_card(3, "Watcher", 0, 10).with_battle_ability(Ability {
+        max_triggers_per_round,
+        ..create_ability(
+            AbilityTrigger::OnAllyHurt,
+            AbilityEffect::GainMana {
+                amount: ValueExpr::Const(1),
+            },
+        )
+    });
+
+    let p_board = vec![
+        create_board_unit(1, "Tank", 1, 10),
+        create_board_unit(2, "Backup", 0, 10),
+        CombatUnit::from_card(watcher),
+    ];
+    let e_board = vec![CombatUnit::from_card(bomber)];
+
+    run_battle(&p_board, &e_board, 42)
+}
+
+#[test]
+fn test_max_triggers_per_round_resets_each_round() {
+    let unlimited = ally_hurt_watcher_battle(None);
+    let limited = ally_hurt_watcher_battle(Some(1));
+
+    // Trigger counts per round, split at each BeforeAttack phase.
+    let per_round: Vec<usize> = limited
+        .split(|e| {
+            matches!(
+                e,
+                CombatEvent::PhaseStart {
+                    phase: crate::battle::BattlePhase::BeforeAttack
+                }
+            )
+        })
+        .skip(1)
+        .map(|round| count_ability_triggers(round, UnitId::player(3), 0))
+        .collect();
+
+    assert!(
+        per_round.iter().all(|&count| count <= 1),
+        "Watcher should trigger at most once per round, got {:?}",
+        per_round
+    );
+    assert!(
+        per_round.iter().filter(|&&count| count == 1).count() > 1,
+        "Per-round limit should reset so the Watcher fires again in later rounds"
+    );
+    assert!(
+        count_ability_triggers(&unlimited, UnitId::player(3), 0)
+            > count_ability_triggers(&limited, UnitId::player(3), 0),
+        "Without a per-round limit the Watcher should fire several times per round"
+    );
+}
+
+#[test]
+fn test_cooldown_fires_every_nth_activation() {
+    let create_rager = |cooldown: Option<CountValue>| {
+        create_dummy_card(1, "Rager", 1, 10).with_battle_ability(Ability {
+            cooldown,
+            ..create_ability(
+                AbilityTrigger::OnHurt,
+                AbilityEffect::ModifyStats {
+                    health: ValueExpr::Const(0),
+                    attack: ValueExpr::Const(1),
+                    target: AbilityTarget::All {
+                        scope: TargetScope::SelfUnit,
+                    },
+                },
+            )
+        })
+    };
+    let e_board = vec![create_board_unit(2, "Enemy", 1, 50)];
+
+    let every = run_battle(&[CombatUnit::from_card(create_rager(None))], &e_board, 42);
+    let every_third = run_battle(
+        &[CombatUnit::from_card(create_rager(Some(3)))],
+        &e_board,
+        42,
+    );
+
+    let activations = count_ability_triggers(&every, UnitId::player(1), 0);
+    assert!(activations >= 6, "Rager should be hurt repeatedly");
+    assert_eq!(
+        count_ability_triggers(&every_third, UnitId::player(1), 0),
+        activations / 3,
+        "Cooldown 3 should only fire on every third activation"
+    );
+}
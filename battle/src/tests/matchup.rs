@@ -0,0 +1,258 @@
+use super::*;
+use crate::battle::BattleResult;
+use crate::matchup::{evaluate_matchup, MatchupConfig, MatchupUnit};
+use crate::rng::{RngStream, RngVersion, VersionedRng};
+use crate::rules::LEGACY_RULES_VERSION;
+use core::num::NonZeroUsize;
+
+fn pool_of(cards: Vec<UnitCard>) -> BTreeMap<CardId, UnitCard> {
+    cards.into_iter().map(|card| (card.id, card)).collect()
+}
+
+fn ghost(card_id: u16) -> GhostBoardUnit {
+    GhostBoardUnit {
+        card_id: CardId(card_id),
+        perm_attack: 0,
+        perm_health: 0,
+        perm_statuses: StatusMask::empty(),
+        silenced: false,
+    }
+}
+
+/// A 2/3 that hits a random enemy for 3 at the start: it wins if it picks the
+/// 3/3 and loses if it picks the 1/1.
+fn coin_flip_matchup() -> (
+    Vec<BoardUnit>,
+    Vec<GhostBoardUnit>,
+    BTreeMap<CardId, UnitCard>,
+) {
+    let opener = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(3),
+            target: AbilityTarget::Random {
+                scope: TargetScope::Enemies,
+                count: 1,
+            },
+        },
+    );
+    let pool = pool_of(vec![
+        create_dummy_card(1, "Gambler", 2, 3).with_battle_ability(opener),
+        create_dummy_card(2, "Brute", 3, 3),
+        create_dummy_card(3, "Pup", 1, 1),
+    ]);
+    (
+        vec![BoardUnit::new(CardId(1))],
+        vec![ghost(2), ghost(3)],
+        pool,
+    )
+}
+
+#[test]
+fn test_matchup_stronger_board_always_wins() {
+    let pool = pool_of(vec![
+        create_dummy_card(1, "Giant", 10, 10),
+        create_dummy_card(2, "Pup", 1, 1),
+    ]);
+    let config = MatchupConfig {
+        battles: 40,
+        ..MatchupConfig::default()
+    };
+
+    let report = evaluate_matchup(
+        &[BoardUnit::new(CardId(1))],
+        &[ghost(2), ghost(2)],
+        &pool,
+        &config,
+    );
+
+    assert_eq!(report.battles, 40);
+    assert_eq!((report.wins, report.draws, report.losses), (40, 0, 0));
+    assert_eq!(report.win_rate, 1.0);
+    assert_eq!(report.win_rate_interval.1, 1.0);
+    assert!(report.win_rate_interval.0 > 0.9);
+    assert_eq!(report.avg_player_survivors, 1.0);
+    assert_eq!(report.avg_enemy_survivors, 0.0);
+    assert_eq!(report.limit_reasons.total(), 0);
+}
+
+#[test]
+fn test_matchup_applies_permanent_board_changes() {
+    let pool = pool_of(vec![create_dummy_card(1, "Pup", 1, 1)]);
+    let mut buffed = BoardUnit::new(CardId(1));
+    buffed.perm_attack = 2;
+    buffed.perm_health = 2;
+    let config = MatchupConfig {
+        battles: 5,
+        ..MatchupConfig::default()
+    };
+
+    let report = evaluate_matchup(&[buffed], &[ghost(1)], &pool, &config);
+    assert_eq!(report.wins, 5);
+
+    let mut ghost_buffed = ghost(1);
+    ghost_buffed.perm_health = 5;
+    let report = evaluate_matchup(
+        &[BoardUnit::new(CardId(1))],
+        &[ghost_buffed],
+        &pool,
+        &config,
+    );
+    assert_eq!(report.losses, 5);
+}
+
+#[test]
+fn test_matchup_ghost_health_never_starts_negative() {
+    let pool = pool_of(vec![create_dummy_card(1, "Pup", 1, 1)]);
+    let mut drained = ghost(1);
+    drained.perm_health = -50;
+    let mut drained_unit = BoardUnit::new(CardId(1));
+    drained_unit.perm_health = -50;
+
+    let ghost_unit = drained.to_combat_unit(&pool).unwrap();
+    let board_unit = drained_unit.to_combat_unit(&pool).unwrap();
+
+    assert_eq!(ghost_unit.health, 0);
+    assert_eq!(ghost_unit.health_buff, -50);
+    assert_eq!(board_unit.health, ghost_unit.health);
+}
+
+#[test]
+fn test_matchup_silenced_ghost_fights_without_abilities() {
+    // The Gambler's opener always finds the lone Brute and kills it.
+    let (_, _, pool) = coin_flip_matchup();
+    let config = MatchupConfig {
+        battles: 5,
+        ..MatchupConfig::default()
+    };
+    let brute = [BoardUnit::new(CardId(2))];
+
+    let report = evaluate_matchup(&brute, &[ghost(1)], &pool, &config);
+    assert_eq!(report.losses, 5);
+
+    let mut silenced = ghost(1);
+    silenced.silenced = true;
+    let report = evaluate_matchup(&brute, &[silenced], &pool, &config);
+    assert_eq!(report.wins, 5);
+}
+
+#[test]
+fn test_matchup_ghost_keeps_permanent_statuses() {
+    // The two trade evenly unless the ghost's shop Shield blocks the Striker.
+    let pool = pool_of(vec![
+        create_dummy_card(1, "Striker", 2, 1),
+        create_dummy_card(2, "Duelist", 1, 2),
+    ]);
+    let config = MatchupConfig {
+        battles: 5,
+        ..MatchupConfig::default()
+    };
+    let striker = [BoardUnit::new(CardId(1))];
+
+    let report = evaluate_matchup(&striker, &[ghost(2)], &pool, &config);
+    assert_eq!(report.draws, 5);
+
+    let mut shielded = ghost(2);
+    shielded.perm_statuses = StatusMask::from_statuses(&[Status::Shield]);
+    let report = evaluate_matchup(&striker, &[shielded], &pool, &config);
+    assert_eq!(report.losses, 5);
+}
+
+#[test]
+fn test_matchup_counts_limit_reasons() {
+    let pool = pool_of(vec![create_dummy_card(1, "Wall", 0, 50)]);
+    let config = MatchupConfig {
+        battles: 8,
+        rules: BattleRules::for_version(LEGACY_RULES_VERSION).unwrap(),
+        ..MatchupConfig::default()
+    };
+
+    let report = evaluate_matchup(&[BoardUnit::new(CardId(1))], &[ghost(1)], &pool, &config);
+
+    assert_eq!(report.draws, 8);
+    assert_eq!(report.limit_reasons.round, 8);
+    assert_eq!(report.limit_reasons.total(), 8);
+    assert_eq!(report.avg_player_survivors, 1.0);
+    assert_eq!(report.avg_enemy_survivors, 1.0);
+}
+
+#[test]
+fn test_matchup_matches_individual_battles() {
+    let (player, enemy, pool) = coin_flip_matchup();
+    let config = MatchupConfig {
+        battles: 60,
+        first_seed: 1_000,
+        ..MatchupConfig::default()
+    };
+
+    let report = evaluate_matchup(&player, &enemy, &pool, &config);
+
+    let mut wins = 0;
+    let mut losses = 0;
+    for seed in 1_000..1_060 {
+        let mut rng = VersionedRng::new(RngVersion::CURRENT, seed, RngStream::Battle);
+        let events = resolve_battle(
+            vec![CombatUnit::from_card(pool[&CardId(1)].clone())],
+            vec![
+                CombatUnit::from_card(pool[&CardId(2)].clone()),
+                CombatUnit::from_card(pool[&CardId(3)].clone()),
+            ],
+            &mut rng,
+            &pool,
+            &BattleRules::default(),
+        );
+        match events.last() {
+            Some(CombatEvent::BattleEnd {
+                result: BattleResult::Victory,
+            }) => wins += 1,
+            Some(CombatEvent::BattleEnd {
+                result: BattleResult::Defeat,
+            }) => losses += 1,
+            other => panic!("unexpected battle end {other:?}"),
+        }
+    }
+
+    assert!(wins > 0 && losses > 0, "matchup should depend on the seed");
+    assert_eq!(
+        (report.wins, report.losses, report.draws),
+        (wins, losses, 0)
+    );
+    let (low, high) = report.win_rate_interval;
+    assert!(low < report.win_rate && report.win_rate < high);
+}
+
+#[test]
+fn test_matchup_report_does_not_depend_on_threads() {
+    let (player, enemy, pool) = coin_flip_matchup();
+    let config = |threads| MatchupConfig {
+        battles: 101,
+        threads: NonZeroUsize::new(threads),
+        ..MatchupConfig::default()
+    };
+
+    let sequential = evaluate_matchup(&player, &enemy, &pool, &config(1));
+    assert_eq!(
+        sequential,
+        evaluate_matchup(&player, &enemy, &pool, &config(4))
+    );
+    assert_eq!(
+        sequential,
+        evaluate_matchup(&player, &enemy, &pool, &config(0))
+    );
+}
+
+#[test]
+fn test_matchup_with_no_battles_is_empty() {
+    let (player, enemy, pool) = coin_flip_matchup();
+    let config = MatchupConfig {
+        battles: 0,
+        ..MatchupConfig::default()
+    };
+
+    let report = evaluate_matchup(&player, &enemy, &pool, &config);
+
+    assert_eq!(report.battles, 0);
+    assert_eq!(report.win_rate, 0.0);
+    assert_eq!(report.win_rate_interval, (0.0, 1.0));
+    assert_eq!(report.limit_reasons, Default::default());
+}
@@ -24,8 +24,8 @@ fn test_saturating_stat_buffs() {
     let buff_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: i16::MAX,
-            attack: i16::MAX,
+            health: ValueExpr::Const(i16::MAX),
+            attack: ValueExpr::Const(i16::MAX),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -72,8 +72,8 @@ fn test_negative_attack_prevention() {
     let debuff_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: -50,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(-50),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
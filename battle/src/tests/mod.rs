@@ -1,14 +1,28 @@
 mod battle_helpers;
 mod battle_result;
+#[cfg(feature = "bounded")]
+mod bounded;
+#[cfg(feature = "std")]
+mod exact;
+mod fatigue;
 mod incremental;
 mod limits;
 mod log;
+#[cfg(feature = "std")]
+mod matchup;
 mod math;
+mod modifiers;
 mod priority;
+mod rules;
+mod simulator;
+mod summary;
+#[cfg(feature = "trace")]
+mod trace;
 mod triggers;
 
 use crate::battle::{resolve_battle, CombatEvent, CombatUnit, UnitId};
 use crate::rng::XorShiftRng;
+use crate::rules::BattleRules;
 use crate::types::*;
 use alloc::collections::BTreeMap;
 
@@ -30,6 +44,9 @@ fn create_ability(trigger: AbilityTrigger, effect: AbilityEffect) -> Ability {
         effect,
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     }
 }
 
@@ -37,14 +54,17 @@ fn create_tester_unit(id: u16, name: &str, attack: i16, health: i16) -> CombatUn
     let ability = Ability {
         trigger: AbilityTrigger::OnStart,
         effect: AbilityEffect::ModifyStats {
-            health: 1,
-            attack: 0,
+            health: ValueExpr::Const(1),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     };
 
     let card = UnitCard {
@@ -57,6 +77,9 @@ fn create_tester_unit(id: u16, name: &str, attack: i16, health: i16) -> CombatUn
         },
         shop_abilities: vec![],
         battle_abilities: vec![ability],
+        base_statuses: StatusMask::empty(),
+        tags: TagMask::empty(),
+        keywords: KeywordMask::empty(),
     };
 
     CombatUnit::from_card(card)
@@ -76,6 +99,9 @@ fn create_dummy_enemy() -> CombatUnit {
         },
         shop_abilities: vec![],
         battle_abilities: vec![],
+        base_statuses: StatusMask::empty(),
+        tags: TagMask::empty(),
+        keywords: KeywordMask::empty(),
     };
     CombatUnit::from_card(card)
 }
@@ -120,7 +146,7 @@ fn run_battle(
         enemy_board.to_vec(),
         &mut rng,
         &card_pool,
-        5,
+        &BattleRules::default(),
     )
 }
 
@@ -136,7 +162,7 @@ fn run_battle_with_pool(
         enemy_board.to_vec(),
         &mut rng,
         card_pool,
-        5,
+        &BattleRules::default(),
     )
 }
 
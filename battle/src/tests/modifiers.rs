@@ -0,0 +1,191 @@
+use super::*;
+use crate::battle::{resolve_battle_with_modifiers, CombatEvent, UnitId};
+use crate::modifiers::{select_modifiers, BattleModifier, ModifierSelection, MODIFIER_ROTATION};
+use crate::rng::RngVersion;
+use crate::types::*;
+
+fn run_with_modifiers(
+    player_board: &[CombatUnit],
+    enemy_board: &[CombatUnit],
+    seed: u64,
+    card_pool: &BTreeMap<CardId, UnitCard>,
+    modifiers: &[BattleModifier],
+) -> Vec<CombatEvent> {
+    let mut rng = XorShiftRng::seed_from_u64(seed);
+    resolve_battle_with_modifiers(
+        player_board.to_vec(),
+        enemy_board.to_vec(),
+        &mut rng,
+        card_pool,
+        &BattleRules::default(),
+        modifiers,
+    )
+}
+
+fn clash_damages(events: &[CombatEvent]) -> Vec<(StatValue, StatValue)> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::Clash { p_dmg, e_dmg, .. } => Some((*p_dmg, *e_dmg)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn bracket(round: RoundValue) -> MatchmakingBracket {
+    MatchmakingBracket {
+        set_id: 0,
+        round,
+        wins: 0,
+        lives: 3,
+    }
+}
+
+#[test]
+fn test_blood_moon_announced_and_raises_attack() {
+    let blood_moon = BattleModifier::BloodMoon { attack: 1 };
+    let p = CombatUnit::from_card(create_dummy_card(1, "P", 2, 3));
+    let e = CombatUnit::from_card(create_dummy_card(2, "E", 2, 3));
+
+    let events = run_with_modifiers(&[p], &[e], 42, &empty_card_pool(), &[blood_moon]);
+
+    assert!(matches!(
+        events.first(),
+        Some(CombatEvent::ModifierActive { modifier }) if *modifier == blood_moon
+    ));
+    assert_eq!(clash_damages(&events), vec![(3, 3)]);
+}
+
+#[test]
+fn test_blood_moon_applies_to_spawned_units() {
+    let spawner = create_dummy_card(1, "Spawner", 1, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::SpawnUnit {
+            card_id: CardId(40),
+            spawn_location: SpawnLocation::Back,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
+        },
+    ));
+    let enemy = CombatUnit::from_card(create_dummy_card(2, "E", 1, 10));
+
+    let events = run_with_modifiers(
+        &[CombatUnit::from_card(spawner)],
+        &[enemy],
+        42,
+        &spawn_test_card_pool(),
+        &[BattleModifier::BloodMoon { attack: 2 }],
+    );
+
+    let spawned = events.iter().find_map(|e| match e {
+        CombatEvent::UnitSpawn { spawned_unit, .. } => Some(spawned_unit),
+        _ => None,
+    });
+    assert_eq!(spawned.expect("Rat Token should spawn").attack, 3);
+}
+
+#[test]
+fn test_fog_turns_fixed_targets_random() {
+    let sniper = create_dummy_card(1, "Sniper", 1, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(1),
+            target: AbilityTarget::Position {
+                scope: TargetScope::Enemies,
+                index: 0,
+            },
+        },
+    ));
+    let enemies: Vec<CombatUnit> = (0..3)
+        .map(|i| CombatUnit::from_card(create_dummy_card(10 + i, "E", 1, 10)))
+        .collect();
+
+    let first_target = |modifiers: &[BattleModifier], seed: u64| {
+        let events = run_with_modifiers(
+            &[CombatUnit::from_card(sniper.clone())],
+            &enemies,
+            seed,
+            &empty_card_pool(),
+            modifiers,
+        );
+        events.iter().find_map(|e| match e {
+            CombatEvent::AbilityDamage {
+                target_instance_id, ..
+            } => Some(*target_instance_id),
+            _ => None,
+        })
+    };
+
+    for seed in 0..20 {
+        assert_eq!(first_target(&[], seed), Some(UnitId::enemy(1)));
+    }
+    assert!(
+        (0..20).any(|seed| first_target(&[BattleModifier::Fog], seed) != Some(UnitId::enemy(1))),
+        "Fog should let the shot land past the front"
+    );
+}
+
+#[test]
+fn test_sudden_death_multiplies_clash_damage_from_round() {
+    let sudden_death = BattleModifier::SuddenDeath {
+        from_round: 2,
+        multiplier: 3,
+    };
+    let p = CombatUnit::from_card(create_dummy_card(1, "P", 1, 5));
+    let e = CombatUnit::from_card(create_dummy_card(2, "E", 1, 5));
+
+    let events = run_with_modifiers(&[p], &[e], 42, &empty_card_pool(), &[sudden_death]);
+
+    assert_eq!(clash_damages(&events), vec![(1, 1), (3, 3), (3, 3)]);
+}
+
+#[test]
+fn test_select_modifiers_is_deterministic() {
+    let fixed = BattleModifier::Fog;
+    assert!(select_modifiers(
+        &ModifierSelection::None,
+        7,
+        &bracket(1),
+        RngVersion::CURRENT
+    )
+    .is_empty());
+    assert_eq!(
+        select_modifiers(
+            &ModifierSelection::Fixed(fixed),
+            7,
+            &bracket(1),
+            RngVersion::CURRENT
+        ),
+        vec![fixed]
+    );
+
+    let from_seed = |seed| {
+        select_modifiers(
+            &ModifierSelection::FromSeed,
+            seed,
+            &bracket(1),
+            RngVersion::CURRENT,
+        )
+    };
+    assert_eq!(from_seed(99), from_seed(99));
+    for modifier in MODIFIER_ROTATION {
+        assert!(
+            (0..64).any(|seed| from_seed(seed) == vec![modifier]),
+            "{modifier:?} should be reachable from some seed"
+        );
+    }
+
+    // Bracket picks ignore the battle seed.
+    let from_bracket = |seed, round| {
+        select_modifiers(
+            &ModifierSelection::FromBracket,
+            seed,
+            &bracket(round),
+            RngVersion::CURRENT,
+        )
+    };
+    for round in 1..10 {
+        assert_eq!(from_bracket(1, round), from_bracket(12345, round));
+    }
+}
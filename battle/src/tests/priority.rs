@@ -59,14 +59,17 @@ fn test_priority_tiebreaker_team() {
     let enemy_ability = Ability {
         trigger: AbilityTrigger::OnStart,
         effect: AbilityEffect::ModifyStats {
-            health: 1,
-            attack: 0,
+            health: ValueExpr::Const(1),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     };
     let e_card = UnitCard::new(CardId(2), "Enemy", 5, 5, 0, 0).with_battle_ability(enemy_ability);
     let e_unit = CombatUnit::from_card(e_card);
@@ -123,8 +126,8 @@ fn test_priority_tiebreaker_ability_order() {
     let ability_a = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 0,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -134,8 +137,8 @@ fn test_priority_tiebreaker_ability_order() {
     let ability_b = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 0,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -163,8 +166,8 @@ fn test_priority_full_hierarchy_with_ability_order() {
         UnitCard::new(CardId(1), "U1", 10, 1, 0, 0).with_battle_ability(create_ability(
             AbilityTrigger::OnStart,
             AbilityEffect::ModifyStats {
-                health: 0,
-                attack: 0,
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(0),
                 target: AbilityTarget::All {
                     scope: TargetScope::SelfUnit,
                 },
@@ -177,8 +180,8 @@ fn test_priority_full_hierarchy_with_ability_order() {
         UnitCard::new(CardId(4), "U4", 5, 5, 0, 0).with_battle_ability(create_ability(
             AbilityTrigger::OnStart,
             AbilityEffect::ModifyStats {
-                health: 0,
-                attack: 0,
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(0),
                 target: AbilityTarget::All {
                     scope: TargetScope::SelfUnit,
                 },
@@ -188,8 +191,8 @@ fn test_priority_full_hierarchy_with_ability_order() {
     let ability_u5_a = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 0,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -198,8 +201,8 @@ fn test_priority_full_hierarchy_with_ability_order() {
     let ability_u5_b = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 0,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -236,8 +239,8 @@ fn test_priority_full_hierarchy() {
     let ability_u1 = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 0,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -250,8 +253,8 @@ fn test_priority_full_hierarchy() {
     let ability_u4 = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 0,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -288,8 +291,8 @@ fn test_priority_keeps_same_unit_abilities_consecutive() {
     let ability_a = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 1,
-            attack: 0,
+            health: ValueExpr::Const(1),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -298,8 +301,8 @@ fn test_priority_keeps_same_unit_abilities_consecutive() {
     let ability_b = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 1,
-            attack: 0,
+            health: ValueExpr::Const(1),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -308,8 +311,8 @@ fn test_priority_keeps_same_unit_abilities_consecutive() {
     let ability_c = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 1,
-            attack: 0,
+            health: ValueExpr::Const(1),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -0,0 +1,90 @@
+use super::*;
+use crate::battle::{CombatEvent, UnitId};
+use crate::limits::LimitReason;
+use crate::rules::{TiebreakPolicy, CURRENT_RULES_VERSION, LEGACY_RULES_VERSION, MAX_BOARD_SIZE};
+
+fn run_with_rules(
+    player_board: &[CombatUnit],
+    enemy_board: &[CombatUnit],
+    seed: u64,
+    rules: &BattleRules,
+) -> Vec<CombatEvent> {
+    let mut rng = XorShiftRng::seed_from_u64(seed);
+    resolve_battle(
+        player_board.to_vec(),
+        enemy_board.to_vec(),
+        &mut rng,
+        &empty_card_pool(),
+        rules,
+    )
+}
+
+#[test]
+fn test_rules_versions_are_published() {
+    assert_eq!(BattleRules::default().version, CURRENT_RULES_VERSION);
+    assert!(BattleRules::for_version(LEGACY_RULES_VERSION).is_some());
+    assert!(BattleRules::for_version(0).is_none());
+    assert!(BattleRules::for_version(CURRENT_RULES_VERSION + 1).is_none());
+}
+
+#[test]
+fn test_board_size_is_clamped_to_max() {
+    assert_eq!(BattleRules::default().with_board_size(3).board_size, 3);
+    assert_eq!(
+        BattleRules::default()
+            .with_board_size(MAX_BOARD_SIZE + 1)
+            .board_size,
+        MAX_BOARD_SIZE
+    );
+    assert_eq!(
+        BattleRules::default()
+            .with_board_size(IndexValue::MAX)
+            .board_size,
+        MAX_BOARD_SIZE
+    );
+}
+
+#[test]
+fn test_legacy_rules_end_stalls_at_round_limit() {
+    let legacy = BattleRules::for_version(LEGACY_RULES_VERSION).unwrap();
+    let p_board = vec![create_board_unit(1, "Wall", 0, 50)];
+    let e_board = vec![create_board_unit(2, "Wall", 0, 50)];
+
+    let events = run_with_rules(&p_board, &e_board, 42, &legacy);
+
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, CombatEvent::FatigueDamage { .. })));
+    assert!(events.iter().any(|e| matches!(
+        e,
+        CombatEvent::LimitExceeded {
+            reason: LimitReason::RoundLimit { .. },
+            ..
+        }
+    )));
+}
+
+#[test]
+fn test_player_first_tiebreak_ignores_seed() {
+    let rules = BattleRules {
+        tiebreak: TiebreakPolicy::PlayerFirst,
+        ..BattleRules::default()
+    };
+
+    for seed in [42u64, 1, 123, 456, 789, 1000, 2000, 3000, 4000, 5000] {
+        let p_board = vec![create_tester_unit(1, "Player", 5, 5)];
+        let e_board = vec![create_tester_unit(2, "Enemy", 5, 5)];
+
+        let events = run_with_rules(&p_board, &e_board, seed, &rules);
+        let sources: Vec<UnitId> = collect_ability_triggers(&events)
+            .iter()
+            .map(|t| t.source_id)
+            .collect();
+
+        assert_eq!(
+            sources[..2],
+            [UnitId::player(1), UnitId::enemy(1)],
+            "seed {seed}"
+        );
+    }
+}
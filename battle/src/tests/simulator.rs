@@ -0,0 +1,194 @@
+use super::*;
+use crate::battle::{resolve_battle_with_modifiers, BattleSimulator, BattleStep};
+use crate::modifiers::BattleModifier;
+use crate::rng::BattleRng;
+
+fn random_hit(amount: StatValue) -> Ability {
+    create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(amount),
+            target: AbilityTarget::Random {
+                scope: TargetScope::Enemies,
+                count: 1,
+            },
+        },
+    )
+}
+
+fn mixed_boards() -> (Vec<CombatUnit>, Vec<CombatUnit>) {
+    let player = vec![
+        create_tester_unit(1, "Tester", 2, 3),
+        CombatUnit::from_card(
+            create_dummy_card(2, "Gambler", 2, 4).with_battle_ability(random_hit(2)),
+        ),
+        create_board_unit(3, "Pup", 1, 2),
+    ];
+    let enemy = vec![
+        CombatUnit::from_card(
+            create_dummy_card(4, "Slinger", 1, 4).with_battle_ability(random_hit(1)),
+        ),
+        create_board_unit(5, "Brute", 3, 3),
+        create_tester_unit(6, "Tester", 1, 2),
+    ];
+    (player, enemy)
+}
+
+#[test]
+fn test_simulator_steps_match_resolve_battle() {
+    let (player, enemy) = mixed_boards();
+    let modifiers = [BattleModifier::BloodMoon { attack: 1 }];
+    for seed in 0..20 {
+        let mut rng = XorShiftRng::seed_from_u64(seed);
+        let expected = resolve_battle_with_modifiers(
+            player.clone(),
+            enemy.clone(),
+            &mut rng,
+            &empty_card_pool(),
+            &BattleRules::default(),
+            &modifiers,
+        );
+
+        let pool = empty_card_pool();
+        let mut simulator = BattleSimulator::new(
+            player.clone(),
+            enemy.clone(),
+            XorShiftRng::seed_from_u64(seed),
+            &pool,
+            &BattleRules::default(),
+            &modifiers,
+        );
+        let mut stepped = Vec::new();
+        while let Some(events) = simulator.step() {
+            stepped.extend(events);
+        }
+
+        assert_eq!(
+            format!("{stepped:?}"),
+            format!("{expected:?}"),
+            "seed {seed}"
+        );
+        assert_eq!(
+            simulator.into_rng().next_u32(),
+            rng.next_u32(),
+            "seed {seed}"
+        );
+    }
+}
+
+#[test]
+fn test_simulator_reports_modifiers_with_first_step() {
+    let pool = empty_card_pool();
+    let mut simulator = BattleSimulator::new(
+        vec![create_board_unit(1, "Pup", 1, 1)],
+        vec![create_board_unit(2, "Pup", 1, 1)],
+        XorShiftRng::seed_from_u64(1),
+        &pool,
+        &BattleRules::default(),
+        &[BattleModifier::BloodMoon { attack: 1 }],
+    );
+
+    // Modifiers apply to the boards before the first step.
+    assert_eq!(simulator.snapshot().player_units[0].attack, 2);
+
+    let first = simulator.step().unwrap();
+    assert!(matches!(first[0], CombatEvent::ModifierActive { .. }));
+    let second = simulator.step().unwrap();
+    assert!(!second
+        .iter()
+        .any(|event| matches!(event, CombatEvent::ModifierActive { .. })));
+}
+
+#[test]
+fn test_simulator_snapshot_shows_state_between_steps() {
+    let pool = empty_card_pool();
+    let mut simulator = BattleSimulator::new(
+        vec![create_tester_unit(1, "Tester", 2, 3)],
+        vec![create_board_unit(2, "Wall", 1, 5)],
+        XorShiftRng::seed_from_u64(7),
+        &pool,
+        &BattleRules::default(),
+        &[],
+    );
+
+    let snapshot = simulator.snapshot();
+    assert_eq!(snapshot.next_step, BattleStep::Start);
+    assert_eq!(snapshot.round, 0);
+    assert_eq!(snapshot.player_units[0].health, 3);
+    assert_eq!(snapshot.player_units[0].instance_id, UnitId::player(1));
+
+    // Start: the tester's OnStart buff lands.
+    simulator.step().unwrap();
+    let snapshot = simulator.snapshot();
+    assert_eq!(snapshot.next_step, BattleStep::BeforeAttack);
+    assert_eq!(snapshot.player_units[0].health, 4);
+    assert_eq!(snapshot.player_units[0].ability_trigger_counts, vec![1]);
+
+    simulator.step().unwrap();
+    let snapshot = simulator.snapshot();
+    assert_eq!(snapshot.next_step, BattleStep::Attack);
+    assert_eq!(snapshot.round, 1);
+
+    // Attack: both fronts trade hits.
+    simulator.step().unwrap();
+    let snapshot = simulator.snapshot();
+    assert_eq!(snapshot.next_step, BattleStep::Fatigue);
+    assert_eq!(snapshot.player_units[0].health, 3);
+    assert_eq!(snapshot.enemy_units[0].health, 3);
+}
+
+#[test]
+fn test_simulator_snapshot_keeps_the_fallen() {
+    let pool = empty_card_pool();
+    let mut simulator = BattleSimulator::new(
+        vec![create_board_unit(1, "Giant", 5, 5)],
+        vec![
+            create_board_unit(2, "Pup", 1, 1),
+            create_board_unit(3, "Pup", 1, 1),
+        ],
+        XorShiftRng::seed_from_u64(3),
+        &pool,
+        &BattleRules::default(),
+        &[],
+    );
+
+    while simulator.next_step() != BattleStep::Fatigue {
+        simulator.step().unwrap();
+    }
+
+    let snapshot = simulator.snapshot();
+    assert_eq!(snapshot.enemy_units.len(), 1);
+    assert_eq!(snapshot.enemy_graveyard.len(), 1);
+    assert_eq!(snapshot.enemy_graveyard[0].instance_id, UnitId::enemy(1));
+    assert!(snapshot.player_graveyard.is_empty());
+}
+
+#[test]
+fn test_simulator_run_to_end_finishes_the_battle() {
+    let (player, enemy) = mixed_boards();
+    let pool = empty_card_pool();
+    let simulator = |seed| {
+        BattleSimulator::new(
+            player.clone(),
+            enemy.clone(),
+            XorShiftRng::seed_from_u64(seed),
+            &pool,
+            &BattleRules::default(),
+            &[],
+        )
+    };
+
+    let full = simulator(11).run_to_end();
+
+    let mut partial = simulator(11);
+    let mut events = partial.step().unwrap();
+    events.extend(partial.step().unwrap());
+    events.extend(partial.run_to_end());
+
+    assert_eq!(format!("{events:?}"), format!("{full:?}"));
+    assert!(matches!(events.last(), Some(CombatEvent::BattleEnd { .. })));
+    assert!(partial.is_finished());
+    assert_eq!(partial.next_step(), BattleStep::Finished);
+    assert!(partial.step().is_none());
+    assert!(partial.run_to_end().is_empty());
+}
@@ -0,0 +1,303 @@
+use super::*;
+use crate::battle::resolve_battle_into;
+use crate::sink::{BattleOutcome, EventSink, SnapshotNotice};
+use crate::summary::{BattleSummary, SummarySink};
+
+fn summarize(events: &[CombatEvent]) -> BattleSummary {
+    BattleSummary::from_events(events)
+}
+
+fn snipe(amount: StatValue) -> Ability {
+    create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(amount),
+            target: AbilityTarget::Position {
+                scope: TargetScope::Enemies,
+                index: 0,
+            },
+        },
+    )
+}
+
+#[test]
+fn test_summary_attributes_clash_damage_and_kills() {
+    let events = run_battle(
+        &[create_board_unit(1, "Giant", 5, 5)],
+        &[
+            create_board_unit(2, "Pup", 1, 1),
+            create_board_unit(3, "Pup", 2, 2),
+        ],
+        42,
+    );
+    let summary = summarize(&events);
+
+    // The giant fells each pup in one swing; overkill counts as damage.
+    let giant = summary.unit(UnitId::player(1)).unwrap();
+    assert_eq!(giant.damage_dealt, 10);
+    assert_eq!(giant.damage_taken, 3);
+    assert_eq!(giant.kills, 2);
+
+    let first_pup = summary.unit(UnitId::enemy(1)).unwrap();
+    assert_eq!(first_pup.damage_dealt, 1);
+    assert_eq!(first_pup.damage_taken, 5);
+    assert_eq!(first_pup.kills, 0);
+    assert_eq!(summary.mvp, Some(UnitId::player(1)));
+}
+
+#[test]
+fn test_summary_credits_ability_damage_and_triggers() {
+    let sniper =
+        CombatUnit::from_card(create_dummy_card(1, "Sniper", 1, 5).with_battle_ability(snipe(2)));
+    let events = run_battle(&[sniper], &[create_board_unit(2, "Pup", 1, 2)], 42);
+    let summary = summarize(&events);
+
+    let sniper = summary.unit(UnitId::player(1)).unwrap();
+    assert_eq!(sniper.abilities_triggered, 1);
+    assert_eq!(sniper.damage_dealt, 2);
+    assert_eq!(sniper.kills, 1);
+    assert_eq!(summary.unit(UnitId::enemy(1)).unwrap().damage_taken, 2);
+}
+
+#[test]
+fn test_summary_counts_spawns_and_mana() {
+    let spawner = create_dummy_card(1, "Spawner", 1, 10).with_battle_abilities(vec![
+        create_ability(
+            AbilityTrigger::OnStart,
+            AbilityEffect::SpawnUnit {
+                card_id: CardId(40),
+                spawn_location: SpawnLocation::Back,
+                count: 2,
+                side: SpawnSide::Own,
+                stats: SpawnStats::Base,
+            },
+        ),
+        create_ability(
+            AbilityTrigger::OnStart,
+            AbilityEffect::GainMana {
+                amount: ValueExpr::Const(3),
+            },
+        ),
+    ]);
+    let events = run_battle_with_pool(
+        &[CombatUnit::from_card(spawner)],
+        &[create_board_unit(2, "Wall", 0, 10)],
+        42,
+        &spawn_test_card_pool(),
+    );
+    let summary = summarize(&events);
+
+    let spawner = summary.unit(UnitId::player(1)).unwrap();
+    assert_eq!(spawner.abilities_triggered, 2);
+    assert_eq!(spawner.units_spawned, 2);
+    assert_eq!(spawner.mana_generated, 3);
+}
+
+fn keyworded(card: UnitCard, keyword: Keyword, statuses: &[Status]) -> CombatUnit {
+    CombatUnit::from_card(
+        card.with_keywords(KeywordMask::from_keywords(&[keyword]))
+            .with_base_statuses(StatusMask::from_statuses(statuses)),
+    )
+}
+
+fn shielded(id: u16, name: &str, attack: StatValue, health: StatValue) -> CombatUnit {
+    CombatUnit::from_card(
+        create_dummy_card(id, name, attack, health)
+            .with_base_statuses(StatusMask::from_statuses(&[Status::Shield])),
+    )
+}
+
+#[test]
+fn test_summary_credits_nothing_for_trample_and_reach_blocked_by_shield() {
+    // The Rhino's overflow into the shielded Knight is blocked; it kills the
+    // Knight with its next swing.
+    let rhino = keyworded(create_dummy_card(1, "Rhino", 6, 10), Keyword::Trample, &[]);
+    let events = run_battle(
+        &[rhino],
+        &[
+            create_board_unit(2, "Squire", 0, 2),
+            shielded(3, "Knight", 0, 6),
+        ],
+        42,
+    );
+    let summary = summarize(&events);
+
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, CombatEvent::Trample { .. })));
+    assert_eq!(summary.unit(UnitId::player(1)).unwrap().damage_dealt, 12);
+    assert_eq!(summary.unit(UnitId::enemy(2)).unwrap().damage_taken, 6);
+
+    // The Spearman's first Reach hit only pops the Guard's Shield.
+    let spearman = keyworded(create_dummy_card(2, "Spearman", 3, 5), Keyword::Reach, &[]);
+    let events = run_battle(
+        &[create_board_unit(1, "Dummy", 0, 10), spearman],
+        &[shielded(3, "Guard", 0, 3)],
+        42,
+    );
+    let summary = summarize(&events);
+
+    let reaches = events
+        .iter()
+        .filter(|e| matches!(e, CombatEvent::Reach { .. }))
+        .count();
+    assert_eq!(reaches, 1);
+    let spearman = summary.unit(UnitId::player(2)).unwrap();
+    assert_eq!(spearman.damage_dealt, 3);
+    assert_eq!(spearman.kills, 1);
+    assert_eq!(summary.unit(UnitId::enemy(1)).unwrap().damage_taken, 3);
+}
+
+#[test]
+fn test_summary_credits_health_lost_to_poisoned_reach() {
+    let spearman = keyworded(
+        create_dummy_card(2, "Spearman", 1, 5),
+        Keyword::Reach,
+        &[Status::Poison],
+    );
+    let events = run_battle(
+        &[create_board_unit(1, "Dummy", 0, 10), spearman],
+        &[create_board_unit(3, "Wall", 0, 8)],
+        42,
+    );
+    let summary = summarize(&events);
+
+    // Poison drops the Wall to 0 however small the hit.
+    let spearman = summary.unit(UnitId::player(2)).unwrap();
+    assert_eq!(spearman.damage_dealt, 8);
+    assert_eq!(spearman.kills, 1);
+    assert_eq!(summary.unit(UnitId::enemy(1)).unwrap().damage_taken, 8);
+}
+
+#[test]
+fn test_summary_mvp_comes_from_the_winning_side() {
+    // The enemy giant deals the most damage but loses to the sniper's ping.
+    let sniper =
+        CombatUnit::from_card(create_dummy_card(1, "Sniper", 1, 1).with_battle_ability(snipe(1)));
+    let events = run_battle(
+        &[sniper, create_board_unit(2, "Brute", 4, 6)],
+        &[create_board_unit(3, "Giant", 5, 5)],
+        42,
+    );
+    let summary = summarize(&events);
+
+    assert!(matches!(
+        events.last(),
+        Some(CombatEvent::BattleEnd {
+            result: crate::battle::BattleResult::Victory
+        })
+    ));
+    let giant = summary.unit(UnitId::enemy(1)).unwrap();
+    assert!(giant.damage_dealt > summary.unit(UnitId::player(2)).unwrap().damage_dealt);
+    assert_eq!(summary.mvp, Some(UnitId::player(2)));
+}
+
+#[test]
+fn test_summary_sink_matches_event_log() {
+    let player = vec![
+        create_tester_unit(1, "Tester", 2, 3),
+        CombatUnit::from_card(create_dummy_card(2, "Sniper", 2, 4).with_battle_ability(snipe(1))),
+        create_board_unit(3, "Pup", 1, 2),
+    ];
+    let enemy = vec![
+        create_board_unit(4, "Brute", 3, 3),
+        create_tester_unit(5, "Tester", 1, 2),
+    ];
+    for seed in 0..10 {
+        let events = run_battle(&player, &enemy, seed);
+
+        let mut report = (BattleOutcome::default(), SummarySink::default());
+        resolve_battle_into(
+            player.clone(),
+            enemy.clone(),
+            &mut XorShiftRng::seed_from_u64(seed),
+            &empty_card_pool(),
+            &BattleRules::default(),
+            &[],
+            &mut report,
+        );
+        let (outcome, summary) = report;
+
+        assert_eq!(outcome, BattleOutcome::from_events(&events), "seed {seed}");
+        assert_eq!(summary.into_summary(), summarize(&events), "seed {seed}");
+    }
+}
+
+/// Counts the snapshot events the wrapped sink builds.
+struct SnapshotProbe<S> {
+    inner: S,
+    built: usize,
+}
+
+impl<S: EventSink> EventSink for SnapshotProbe<S> {
+    fn record(&mut self, event: CombatEvent) {
+        self.inner.record(event);
+    }
+
+    fn record_snapshot(&mut self, notice: SnapshotNotice, event: impl FnOnce() -> CombatEvent) {
+        let built = &mut self.built;
+        self.inner.record_snapshot(notice, || {
+            *built += 1;
+            event()
+        });
+    }
+}
+
+#[test]
+fn test_contract_report_sink_builds_no_snapshots() {
+    let spawner = create_dummy_card(1, "Spawner", 1, 3).with_battle_abilities(vec![
+        create_ability(
+            AbilityTrigger::OnStart,
+            AbilityEffect::SpawnUnit {
+                card_id: CardId(40),
+                spawn_location: SpawnLocation::Back,
+                count: 2,
+                side: SpawnSide::Own,
+                stats: SpawnStats::Base,
+            },
+        ),
+        create_ability(
+            AbilityTrigger::OnStart,
+            AbilityEffect::Steal {
+                target: AbilityTarget::Position {
+                    scope: TargetScope::Enemies,
+                    index: 0,
+                },
+            },
+        ),
+    ]);
+    let player = vec![CombatUnit::from_card(spawner)];
+    let enemy = vec![
+        create_board_unit(2, "Pup", 1, 1),
+        create_board_unit(3, "Brute", 3, 4),
+    ];
+    let pool = spawn_test_card_pool();
+    let events = run_battle_with_pool(&player, &enemy, 42, &pool);
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, CombatEvent::UnitStolen { .. })));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, CombatEvent::UnitDeath { .. })));
+
+    let mut report = SnapshotProbe {
+        inner: (BattleOutcome::default(), SummarySink::default()),
+        built: 0,
+    };
+    resolve_battle_into(
+        player,
+        enemy,
+        &mut XorShiftRng::seed_from_u64(42),
+        &pool,
+        &BattleRules::default(),
+        &[],
+        &mut report,
+    );
+    let (outcome, summary) = report.inner;
+
+    assert_eq!(report.built, 0);
+    assert_eq!(outcome, BattleOutcome::from_events(&events));
+    let summary = summary.into_summary();
+    assert_eq!(summary, summarize(&events));
+    assert_eq!(summary.unit(UnitId::player(1)).unwrap().units_spawned, 2);
+}
@@ -0,0 +1,379 @@
+use super::*;
+use crate::commit::apply_shop_start_triggers;
+use crate::rng::RngVersion;
+use crate::state::ShopState;
+use crate::trace::{capture, Combinator, Operand, SkipReason, Trace, TraceEntry, TraceUnit};
+
+fn traced_battle(player: Vec<CombatUnit>, enemy: Vec<CombatUnit>) -> Trace {
+    let mut rng = XorShiftRng::seed_from_u64(7);
+    let (_, trace) = capture(|| {
+        resolve_battle(
+            player,
+            enemy,
+            &mut rng,
+            &BTreeMap::new(),
+            &BattleRules::default(),
+        )
+    });
+    trace
+}
+
+fn buff_self(trigger: AbilityTrigger) -> Ability {
+    create_ability(
+        trigger,
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(1),
+            attack: ValueExpr::Const(0),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    )
+}
+
+fn skips(trace: &Trace) -> Vec<(UnitId, SkipReason)> {
+    trace
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            TraceEntry::TriggerSkipped { source, reason, .. } => Some((*source, *reason)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_trace_lists_queue_in_resolution_order() {
+    let trace = traced_battle(
+        vec![create_tester_unit(1, "Small", 1, 2)],
+        vec![create_tester_unit(2, "Big", 4, 4)],
+    );
+
+    let queued: Vec<_> = trace
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            TraceEntry::TriggerQueued { source, attack, .. } => Some((*source, *attack)),
+            _ => None,
+        })
+        .collect();
+    let fired: Vec<_> = trace
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            TraceEntry::TriggerFired { source, .. } => Some(*source),
+            _ => None,
+        })
+        .collect();
+
+    // Higher attack resolves first.
+    assert_eq!(queued, vec![(UnitId::enemy(1), 4), (UnitId::player(1), 1)]);
+    assert_eq!(fired, vec![UnitId::enemy(1), UnitId::player(1)]);
+    assert!(skips(&trace).is_empty());
+}
+
+#[test]
+fn test_trace_shows_failed_condition_operands() {
+    let mut ability = buff_self(AbilityTrigger::OnStart);
+    ability.conditions = vec![Condition::Is(Matcher::StatValueCompare {
+        scope: TargetScope::SelfUnit,
+        stat: StatType::Health,
+        op: CompareOp::GreaterThanOrEqual,
+        value: 10,
+    })];
+    let trace = traced_battle(
+        vec![CombatUnit::from_card(
+            create_dummy_card(1, "Picky", 2, 3).with_battle_ability(ability),
+        )],
+        vec![create_board_unit(2, "Pup", 1, 1)],
+    );
+
+    let condition = trace
+        .entries
+        .iter()
+        .find_map(|entry| match entry {
+            TraceEntry::Condition {
+                source,
+                operands,
+                passed,
+                ..
+            } => Some((*source, operands.clone(), *passed)),
+            _ => None,
+        })
+        .expect("condition traced");
+    assert_eq!(
+        condition,
+        (
+            UnitId::player(1),
+            vec![Operand::Stat {
+                unit: TraceUnit::Unit(UnitId::player(1)),
+                stat: StatType::Health,
+                value: 3,
+            }],
+            false
+        )
+    );
+    assert_eq!(
+        skips(&trace),
+        vec![(UnitId::player(1), SkipReason::ConditionsFailed)]
+    );
+}
+
+#[test]
+fn test_trace_records_composite_condition_results() {
+    let mut ability = buff_self(AbilityTrigger::OnStart);
+    ability.conditions = vec![Condition::Not(Box::new(Condition::Is(
+        Matcher::StatValueCompare {
+            scope: TargetScope::SelfUnit,
+            stat: StatType::Health,
+            op: CompareOp::GreaterThanOrEqual,
+            value: 10,
+        },
+    )))];
+    let trace = traced_battle(
+        vec![CombatUnit::from_card(
+            create_dummy_card(1, "Humble", 2, 3).with_battle_ability(ability),
+        )],
+        vec![create_board_unit(2, "Pup", 1, 1)],
+    );
+
+    let results: Vec<_> = trace
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            TraceEntry::Condition { passed, .. } => Some((None, *passed)),
+            TraceEntry::Combined {
+                combinator, passed, ..
+            } => Some((Some(*combinator), *passed)),
+            _ => None,
+        })
+        .collect();
+    // The matcher fails and the Not around it passes.
+    assert_eq!(results, vec![(None, false), (Some(Combinator::Not), true)]);
+    assert!(trace.to_string().contains("combined player#1 Not -> true"));
+    assert!(skips(&trace).is_empty());
+}
+
+#[test]
+fn test_trace_reports_max_triggers() {
+    // Two hits in one ability queue two OnHurt triggers; the second is over
+    // the limit by the time it resolves.
+    let double_hit = Ability {
+        then: vec![AbilityEffect::Damage {
+            amount: ValueExpr::Const(1),
+            target: AbilityTarget::Position {
+                scope: TargetScope::Enemies,
+                index: 0,
+            },
+        }],
+        ..create_ability(
+            AbilityTrigger::OnStart,
+            AbilityEffect::Damage {
+                amount: ValueExpr::Const(1),
+                target: AbilityTarget::Position {
+                    scope: TargetScope::Enemies,
+                    index: 0,
+                },
+            },
+        )
+    };
+    let once = Ability {
+        max_triggers: Some(1),
+        ..buff_self(AbilityTrigger::OnHurt)
+    };
+    let trace = traced_battle(
+        vec![CombatUnit::from_card(
+            create_dummy_card(1, "Tough", 1, 10).with_battle_ability(once),
+        )],
+        vec![CombatUnit::from_card(
+            create_dummy_card(2, "Jabber", 1, 10).with_battle_ability(double_hit),
+        )],
+    );
+
+    assert_eq!(
+        skips(&trace).first(),
+        Some(&(UnitId::player(1), SkipReason::MaxTriggers))
+    );
+}
+
+#[test]
+fn test_trace_renders_text_and_json() {
+    let mut ability = buff_self(AbilityTrigger::OnStart);
+    ability.conditions = vec![Condition::Is(Matcher::UnitCount {
+        scope: TargetScope::Allies,
+        op: CompareOp::GreaterThanOrEqual,
+        value: 3,
+    })];
+    let trace = traced_battle(
+        vec![CombatUnit::from_card(
+            create_dummy_card(1, "Leader", 2, 3).with_battle_ability(ability),
+        )],
+        vec![create_board_unit(2, "Pup", 1, 1)],
+    );
+
+    let text = trace.to_string();
+    assert!(text.contains("queued   player#1 ability 0 (attack 2, health 3, position 0"));
+    assert!(text.contains("[count=1] -> false"));
+    assert!(text.contains("skipped  player#1 ability 0: conditions failed"));
+
+    let json: serde_json::Value = serde_json::from_str(&trace.to_json()).unwrap();
+    let entries = json["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), trace.entries.len());
+    assert!(entries
+        .iter()
+        .any(|entry| entry["type"] == "TriggerSkipped"
+            && entry["data"]["reason"] == "ConditionsFailed"));
+}
+
+#[test]
+fn test_trace_covers_shop_triggers() {
+    let rally = |value| ShopAbility {
+        trigger: ShopTrigger::OnShopStart,
+        effect: ShopEffect::GainMana {
+            amount: ShopValueExpr::Const(1),
+        },
+        conditions: vec![ShopCondition::Is(ShopMatcher::UnitCount {
+            scope: ShopScope::Allies,
+            op: CompareOp::GreaterThanOrEqual,
+            value,
+        })],
+        max_triggers: None,
+        then: vec![],
+    };
+    let card = create_dummy_card(1, "Rallier", 1, 1).with_shop_abilities(vec![rally(1), rally(3)]);
+    let mut state = ShopState {
+        card_pool: BTreeMap::from([(card.id, card)]),
+        set_id: 0,
+        hand: Vec::new(),
+        board: vec![Some(BoardUnit::new(CardId(1))), None],
+        mana_limit: 10,
+        shop_mana: 0,
+        round: 1,
+        game_seed: 42,
+        rng_version: RngVersion::CURRENT,
+    };
+
+    let (_, trace) = capture(|| apply_shop_start_triggers(&mut state));
+
+    assert_eq!(
+        trace.entries,
+        vec![
+            TraceEntry::ShopTriggerQueued {
+                trigger: ShopTrigger::OnShopStart,
+                slot: Some(0),
+                card_id: CardId(1),
+                ability_index: 0,
+                on_board: true,
+            },
+            TraceEntry::ShopTriggerQueued {
+                trigger: ShopTrigger::OnShopStart,
+                slot: Some(0),
+                card_id: CardId(1),
+                ability_index: 1,
+                on_board: true,
+            },
+            TraceEntry::ShopCondition {
+                slot: Some(0),
+                matcher: ShopMatcher::UnitCount {
+                    scope: ShopScope::Allies,
+                    op: CompareOp::GreaterThanOrEqual,
+                    value: 1,
+                },
+                operands: vec![Operand::Count(1)],
+                passed: true,
+            },
+            TraceEntry::ShopTriggerFired {
+                slot: Some(0),
+                card_id: CardId(1),
+                ability_index: 0,
+            },
+            TraceEntry::ShopCondition {
+                slot: Some(0),
+                matcher: ShopMatcher::UnitCount {
+                    scope: ShopScope::Allies,
+                    op: CompareOp::GreaterThanOrEqual,
+                    value: 3,
+                },
+                operands: vec![Operand::Count(1)],
+                passed: false,
+            },
+            TraceEntry::ShopTriggerSkipped {
+                slot: Some(0),
+                card_id: CardId(1),
+                ability_index: 1,
+                reason: SkipReason::ConditionsFailed,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_trace_records_shop_composite_condition_results() {
+    let count_at_least = |value| {
+        ShopCondition::Is(ShopMatcher::UnitCount {
+            scope: ShopScope::Allies,
+            op: CompareOp::GreaterThanOrEqual,
+            value,
+        })
+    };
+    let ability = ShopAbility {
+        trigger: ShopTrigger::OnShopStart,
+        effect: ShopEffect::GainMana {
+            amount: ShopValueExpr::Const(1),
+        },
+        conditions: vec![ShopCondition::AnyOf(vec![
+            count_at_least(3),
+            count_at_least(1),
+        ])],
+        max_triggers: None,
+        then: vec![],
+    };
+    let card = create_dummy_card(1, "Rallier", 1, 1).with_shop_abilities(vec![ability]);
+    let mut state = ShopState {
+        card_pool: BTreeMap::from([(card.id, card)]),
+        set_id: 0,
+        hand: Vec::new(),
+        board: vec![Some(BoardUnit::new(CardId(1))), None],
+        mana_limit: 10,
+        shop_mana: 0,
+        round: 1,
+        game_seed: 42,
+        rng_version: RngVersion::CURRENT,
+    };
+
+    let (_, trace) = capture(|| apply_shop_start_triggers(&mut state));
+
+    let results: Vec<_> = trace
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            TraceEntry::ShopCondition { passed, .. } => Some((None, *passed)),
+            TraceEntry::ShopCombined {
+                slot,
+                combinator,
+                passed,
+            } => {
+                assert_eq!(*slot, Some(0));
+                Some((Some(*combinator), *passed))
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        results,
+        vec![(None, false), (None, true), (Some(Combinator::AnyOf), true)]
+    );
+}
+
+#[test]
+fn test_trace_nested_capture_keeps_its_own_entries() {
+    let (inner, outer) = capture(|| {
+        traced_battle(
+            vec![create_tester_unit(1, "Tester", 1, 1)],
+            vec![create_board_unit(2, "Pup", 1, 1)],
+        )
+    });
+
+    assert!(!inner.entries.is_empty());
+    assert!(outer.entries.is_empty());
+}
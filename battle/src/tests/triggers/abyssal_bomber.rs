@@ -7,7 +7,7 @@ fn test_abyssal_bomber_death_nova() {
     let bomber_ability = create_ability(
         AbilityTrigger::OnFaint,
         AbilityEffect::Damage {
-            amount: 3,
+            amount: ValueExpr::Const(3),
             target: AbilityTarget::All {
                 scope: TargetScope::All,
             },
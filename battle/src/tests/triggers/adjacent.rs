@@ -8,8 +8,8 @@ fn test_adjacent_allies_middle_unit() {
     let buff_adjacent = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 5,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(5),
             target: AbilityTarget::Adjacent {
                 scope: TargetScope::Allies,
             },
@@ -75,8 +75,8 @@ fn test_adjacent_allies_front_unit_no_enemy() {
     let buff_adjacent = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 5,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(5),
             target: AbilityTarget::Adjacent {
                 scope: TargetScope::Allies,
             },
@@ -127,7 +127,7 @@ fn test_adjacent_all_front_unit_includes_enemy() {
     let damage_adjacent = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 3,
+            amount: ValueExpr::Const(3),
             target: AbilityTarget::Adjacent {
                 scope: TargetScope::All,
             },
@@ -185,8 +185,8 @@ fn test_adjacent_all_back_unit_no_enemy() {
     let buff_adjacent = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 5,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(5),
             target: AbilityTarget::Adjacent {
                 scope: TargetScope::All,
             },
@@ -234,8 +234,8 @@ fn test_adjacent_solo_unit_fizzles() {
     let buff_adjacent = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 5,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(5),
             target: AbilityTarget::Adjacent {
                 scope: TargetScope::Allies,
             },
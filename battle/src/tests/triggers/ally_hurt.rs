@@ -0,0 +1,109 @@
+use crate::battle::{CombatEvent, CombatUnit, UnitId};
+use crate::tests::*;
+use crate::types::*;
+
+fn trigger_count(events: &[CombatEvent], source: UnitId) -> usize {
+    events
+        .iter()
+        .filter(|e| {
+            matches!(
+                e,
+                CombatEvent::AbilityTrigger { source_instance_id, .. }
+                    if *source_instance_id == source
+            )
+        })
+        .count()
+}
+
+/// A medic behind the front heals the hurt ally, found via TriggerSource.
+#[test]
+fn test_on_ally_hurt_targets_hurt_ally() {
+    let medic = create_dummy_card(2, "Medic", 0, 5).with_battle_ability(create_ability(
+        AbilityTrigger::OnAllyHurt,
+        AbilityEffect::Heal {
+            amount: ValueExpr::Const(1),
+            target: AbilityTarget::All {
+                scope: TargetScope::TriggerSource,
+            },
+        },
+    ));
+    let p_board = vec![
+        create_board_unit(1, "Tank", 1, 10),
+        CombatUnit::from_card(medic),
+    ];
+
+    let events = run_battle(&p_board, &[create_board_unit(3, "Brute", 3, 20)], 42);
+
+    let healed = events.iter().find_map(|e| match e {
+        CombatEvent::AbilityHeal {
+            source_instance_id,
+            target_instance_id,
+            new_health,
+            ..
+        } => Some((*source_instance_id, *target_instance_id, *new_health)),
+        _ => None,
+    });
+    assert_eq!(healed, Some((UnitId::player(2), UnitId::player(1), 8)));
+}
+
+/// OnAllyHurt never fires for the hurt unit itself.
+#[test]
+fn test_on_ally_hurt_ignores_self() {
+    let loner = create_dummy_card(1, "Loner", 1, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnAllyHurt,
+        AbilityEffect::GainMana {
+            amount: ValueExpr::Const(1),
+        },
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(loner)],
+        &[create_board_unit(2, "Brute", 3, 5)],
+        42,
+    );
+
+    assert_eq!(trigger_count(&events, UnitId::player(1)), 0);
+}
+
+/// Ability damage on an ally fires OnAllyHurt before any clash.
+#[test]
+fn test_on_ally_hurt_from_ability_damage() {
+    let watcher = create_dummy_card(2, "Watcher", 0, 5).with_battle_ability(create_ability(
+        AbilityTrigger::OnAllyHurt,
+        AbilityEffect::GainMana {
+            amount: ValueExpr::Const(1),
+        },
+    ));
+    let p_board = vec![
+        create_board_unit(1, "Tank", 0, 10),
+        CombatUnit::from_card(watcher),
+    ];
+    let archer = create_dummy_card(3, "Archer", 0, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(1),
+            target: AbilityTarget::Position {
+                scope: TargetScope::Enemies,
+                index: 0,
+            },
+        },
+    ));
+
+    let events = run_battle(&p_board, &[CombatUnit::from_card(archer)], 42);
+
+    let reaction_pos = events
+        .iter()
+        .position(|e| {
+            matches!(
+                e,
+                CombatEvent::AbilityTrigger { source_instance_id, .. }
+                    if *source_instance_id == UnitId::player(2)
+            )
+        })
+        .expect("watcher should react");
+    let clash_pos = events
+        .iter()
+        .position(|e| matches!(e, CombatEvent::Clash { .. }))
+        .expect("fronts should clash");
+    assert!(reaction_pos < clash_pos);
+}
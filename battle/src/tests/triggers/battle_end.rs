@@ -0,0 +1,74 @@
+use crate::battle::{
+    player_permanent_stat_deltas_from_events, player_shop_mana_delta_from_events, CombatEvent,
+    CombatUnit, UnitId,
+};
+use crate::tests::*;
+use crate::types::*;
+
+fn banker(id: u16, atk: i16, hp: i16) -> CombatUnit {
+    CombatUnit::from_card(
+        create_dummy_card(id, "Banker", atk, hp).with_battle_ability(create_ability(
+            AbilityTrigger::OnBattleEnd,
+            AbilityEffect::GainMana {
+                amount: ValueExpr::Const(2),
+            },
+        )),
+    )
+}
+
+/// Survivors fire OnBattleEnd before the result is announced.
+#[test]
+fn test_on_battle_end_fires_for_survivors() {
+    let events = run_battle(
+        &[banker(1, 5, 10)],
+        &[create_board_unit(2, "Squire", 1, 2)],
+        42,
+    );
+
+    assert_eq!(player_shop_mana_delta_from_events(&events), 2);
+    let trigger_pos = events
+        .iter()
+        .position(|e| matches!(e, CombatEvent::AbilityTrigger { .. }))
+        .expect("banker should trigger");
+    let end_pos = events
+        .iter()
+        .position(|e| matches!(e, CombatEvent::BattleEnd { .. }))
+        .expect("battle should end");
+    assert!(trigger_pos < end_pos);
+}
+
+/// Units that fell during the battle do not fire OnBattleEnd.
+#[test]
+fn test_on_battle_end_skips_fallen_units() {
+    let events = run_battle(
+        &[banker(1, 1, 2)],
+        &[create_board_unit(2, "Brute", 5, 10)],
+        42,
+    );
+
+    assert_eq!(player_shop_mana_delta_from_events(&events), 0);
+}
+
+/// End-of-battle permanent buffs are reported like any other permanent change.
+#[test]
+fn test_on_battle_end_permanent_buff() {
+    let veteran = create_dummy_card(1, "Veteran", 5, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnBattleEnd,
+        AbilityEffect::ModifyStatsPermanent {
+            health: ValueExpr::Const(1),
+            attack: ValueExpr::Const(1),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(veteran)],
+        &[create_board_unit(2, "Squire", 1, 2)],
+        42,
+    );
+
+    let deltas = player_permanent_stat_deltas_from_events(&events);
+    assert_eq!(deltas.get(&UnitId::player(1)), Some(&(1, 1)));
+}
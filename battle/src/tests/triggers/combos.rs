@@ -7,8 +7,8 @@ fn test_berserker_combo() {
     let smith_buff = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 2,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(2),
             target: AbilityTarget::All {
                 scope: TargetScope::Allies,
             },
@@ -17,7 +17,7 @@ fn test_berserker_combo() {
     let smith_dmg = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 1,
+            amount: ValueExpr::Const(1),
             target: AbilityTarget::All {
                 scope: TargetScope::Allies,
             },
@@ -29,8 +29,8 @@ fn test_berserker_combo() {
     let orc_rage = create_ability(
         AbilityTrigger::OnHurt,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 2,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(2),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
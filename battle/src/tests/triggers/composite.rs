@@ -0,0 +1,174 @@
+use crate::battle::{CombatEvent, CombatUnit, UnitId};
+use crate::tests::*;
+use crate::types::*;
+
+fn modify_targets(events: &[CombatEvent]) -> Vec<UnitId> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::AbilityModifyStats {
+                target_instance_id, ..
+            } => Some(*target_instance_id),
+            _ => None,
+        })
+        .collect()
+}
+
+fn damage_targets(events: &[CombatEvent]) -> Vec<UnitId> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::AbilityDamage {
+                target_instance_id, ..
+            } => Some(*target_instance_id),
+            _ => None,
+        })
+        .collect()
+}
+
+fn debuff_previous() -> AbilityEffect {
+    AbilityEffect::ModifyStats {
+        health: ValueExpr::Const(0),
+        attack: ValueExpr::Const(-1),
+        target: AbilityTarget::PreviousTargets,
+    }
+}
+
+#[test]
+fn test_composite_follow_up_hits_previous_targets() {
+    let mut ability = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(1),
+            target: AbilityTarget::Random {
+                scope: TargetScope::Enemies,
+                count: 1,
+            },
+        },
+    );
+    ability.then = vec![debuff_previous()];
+    let caster = create_dummy_card(1, "Hexer", 1, 5).with_battle_ability(ability);
+    let enemies = vec![
+        create_board_unit(2, "A", 1, 5),
+        create_board_unit(3, "B", 1, 5),
+        create_board_unit(4, "C", 1, 5),
+    ];
+
+    for seed in [1, 7, 42] {
+        let events = run_battle(&[CombatUnit::from_card(caster.clone())], &enemies, seed);
+        let damaged = damage_targets(&events);
+        assert_eq!(damaged.len(), 1);
+        assert_eq!(modify_targets(&events), damaged, "seed {seed}");
+    }
+}
+
+#[test]
+fn test_composite_previous_targets_skips_units_killed_by_earlier_step() {
+    let mut ability = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(5),
+            target: AbilityTarget::Position {
+                scope: TargetScope::Enemies,
+                index: 0,
+            },
+        },
+    );
+    ability.then = vec![debuff_previous()];
+    let caster = create_dummy_card(1, "Hexer", 1, 5).with_battle_ability(ability);
+
+    let events = run_battle(
+        &[CombatUnit::from_card(caster)],
+        &[create_board_unit(2, "Frail", 1, 3)],
+        42,
+    );
+    assert_eq!(damage_targets(&events), vec![UnitId::enemy(1)]);
+    assert!(modify_targets(&events).is_empty());
+}
+
+#[test]
+fn test_composite_steps_resolve_before_reactions() {
+    let mut ability = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(1),
+            target: AbilityTarget::Position {
+                scope: TargetScope::Enemies,
+                index: 0,
+            },
+        },
+    );
+    ability.then = vec![AbilityEffect::ModifyStats {
+        health: ValueExpr::Const(1),
+        attack: ValueExpr::Const(1),
+        target: AbilityTarget::All {
+            scope: TargetScope::SelfUnit,
+        },
+    }];
+    let caster = create_dummy_card(1, "Hexer", 1, 5).with_battle_ability(ability);
+    let reactor = create_dummy_card(2, "Reactor", 1, 5).with_battle_ability(create_ability(
+        AbilityTrigger::OnHurt,
+        AbilityEffect::GainMana {
+            amount: ValueExpr::Const(1),
+        },
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(caster)],
+        &[CombatUnit::from_card(reactor)],
+        42,
+    );
+
+    let caster_triggers = events
+        .iter()
+        .filter(|e| {
+            matches!(
+                e,
+                CombatEvent::AbilityTrigger {
+                    source_instance_id,
+                    ..
+                } if *source_instance_id == UnitId::player(1)
+            )
+        })
+        .count();
+    assert_eq!(caster_triggers, 1, "both steps share one trigger");
+
+    let buff_pos = events
+        .iter()
+        .position(|e| matches!(e, CombatEvent::AbilityModifyStats { .. }))
+        .expect("second step should resolve");
+    let reaction_pos = events
+        .iter()
+        .position(|e| {
+            matches!(
+                e,
+                CombatEvent::AbilityTrigger {
+                    source_instance_id,
+                    ..
+                } if *source_instance_id == UnitId::enemy(1)
+            )
+        })
+        .expect("OnHurt should fire");
+    assert!(buff_pos < reaction_pos);
+}
+
+#[test]
+fn test_composite_steps_are_capped() {
+    let buff = AbilityEffect::ModifyStats {
+        health: ValueExpr::Const(0),
+        attack: ValueExpr::Const(1),
+        target: AbilityTarget::All {
+            scope: TargetScope::SelfUnit,
+        },
+    };
+    let mut ability = create_ability(AbilityTrigger::OnStart, buff.clone());
+    ability.then = vec![buff; MAX_ABILITY_EFFECTS + 2];
+    let caster = create_dummy_card(1, "Stacker", 1, 5).with_battle_ability(ability);
+
+    let events = run_battle(
+        &[CombatUnit::from_card(caster)],
+        &[create_board_unit(2, "Dummy", 0, 5)],
+        42,
+    );
+    assert_eq!(modify_targets(&events).len(), MAX_ABILITY_EFFECTS);
+}
@@ -8,8 +8,8 @@ fn test_condition_target_health_threshold() {
         create_dummy_card(2, "Nurse", 1, 3).with_battle_ability(Ability {
             trigger: AbilityTrigger::BeforeAnyAttack,
             effect: AbilityEffect::ModifyStats {
-                health: 2,
-                attack: 0,
+                health: ValueExpr::Const(2),
+                attack: ValueExpr::Const(0),
                 target: AbilityTarget::Position {
                     scope: TargetScope::SelfUnit,
                     index: -1,
@@ -22,6 +22,9 @@ fn test_condition_target_health_threshold() {
                 value: 6,
             })],
             max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         })
     };
 
@@ -62,8 +65,8 @@ fn test_condition_ally_count() {
         create_dummy_card(1, "PackLeader", 2, 3).with_battle_ability(Ability {
             trigger: AbilityTrigger::OnStart,
             effect: AbilityEffect::ModifyStats {
-                health: 1,
-                attack: 1,
+                health: ValueExpr::Const(1),
+                attack: ValueExpr::Const(1),
                 target: AbilityTarget::All {
                     scope: TargetScope::Allies,
                 },
@@ -74,6 +77,9 @@ fn test_condition_ally_count() {
                 value: 3,
             })],
             max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         })
     };
 
@@ -122,8 +128,8 @@ fn test_condition_last_stand() {
         create_dummy_card(1, "LoneWolf", 2, 4).with_battle_ability(Ability {
             trigger: AbilityTrigger::OnStart,
             effect: AbilityEffect::ModifyStats {
-                health: 0,
-                attack: 5,
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(5),
                 target: AbilityTarget::All {
                     scope: TargetScope::SelfUnit,
                 },
@@ -134,6 +140,9 @@ fn test_condition_last_stand() {
                 value: 1,
             })],
             max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         })
     };
 
@@ -179,8 +188,8 @@ fn test_condition_logic_gates() {
         create_dummy_card(1, "Conditional", 2, 3).with_battle_ability(Ability {
             trigger: AbilityTrigger::OnStart,
             effect: AbilityEffect::ModifyStats {
-                health: 0,
-                attack: 3,
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(3),
                 target: AbilityTarget::All {
                     scope: TargetScope::SelfUnit,
                 },
@@ -199,6 +208,9 @@ fn test_condition_logic_gates() {
                 }),
             ],
             max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         })
     };
 
@@ -241,8 +253,8 @@ fn test_condition_stat_value_compare_uses_matcher_scope() {
         create_dummy_card(1, "Checker", 2, 1).with_battle_ability(Ability {
             trigger: AbilityTrigger::OnStart,
             effect: AbilityEffect::ModifyStats {
-                health: 0,
-                attack: 1,
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(1),
                 target: AbilityTarget::All {
                     scope: TargetScope::SelfUnit,
                 },
@@ -254,6 +266,9 @@ fn test_condition_stat_value_compare_uses_matcher_scope() {
                 value: 5,
             })],
             max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         })
     };
 
@@ -296,8 +311,8 @@ fn test_condition_stat_stat_compare_uses_target_scope() {
         create_dummy_card(1, "Comparator", 2, 1).with_battle_ability(Ability {
             trigger: AbilityTrigger::OnStart,
             effect: AbilityEffect::ModifyStats {
-                health: 0,
-                attack: 1,
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(1),
                 target: AbilityTarget::All {
                     scope: TargetScope::SelfUnit,
                 },
@@ -309,6 +324,9 @@ fn test_condition_stat_stat_compare_uses_target_scope() {
                 target_stat: StatType::Health,
             })],
             max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         })
     };
 
@@ -350,8 +368,8 @@ fn test_condition_is_position_uses_matcher_scope() {
     let enemy_scoped = create_dummy_card(1, "EnemyScoped", 2, 5).with_battle_ability(Ability {
         trigger: AbilityTrigger::OnStart,
         effect: AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 1,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(1),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -361,13 +379,16 @@ fn test_condition_is_position_uses_matcher_scope() {
             index: 0,
         })],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     });
 
     let ally_scoped = create_dummy_card(2, "AllyScoped", 2, 5).with_battle_ability(Ability {
         trigger: AbilityTrigger::OnStart,
         effect: AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 1,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(1),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -377,6 +398,9 @@ fn test_condition_is_position_uses_matcher_scope() {
             index: 0,
         })],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     });
 
     let enemy = create_dummy_card(3, "Enemy", 1, 5);
@@ -403,3 +427,155 @@ fn test_condition_is_position_uses_matcher_scope() {
         "Ally-scoped position check should trigger for a front source unit"
     );
 }
+
+fn create_conditional_buffer(conditions: Vec<Condition>) -> CombatUnit {
+    CombatUnit::from_card(
+        create_dummy_card(1, "Buffer", 1, 10).with_battle_ability(Ability {
+            trigger: AbilityTrigger::OnStart,
+            effect: AbilityEffect::ModifyStats {
+                health: ValueExpr::Const(1),
+                attack: ValueExpr::Const(0),
+                target: AbilityTarget::All {
+                    scope: TargetScope::SelfUnit,
+                },
+            },
+            conditions,
+            max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
+        }),
+    )
+}
+
+fn unit_count(scope: TargetScope, value: u8) -> Condition {
+    Condition::Is(Matcher::UnitCount {
+        scope,
+        op: CompareOp::GreaterThanOrEqual,
+        value,
+    })
+}
+
+#[test]
+fn test_condition_nested_and_or_not() {
+    // "if two allies and (five enemies or not two enemies)"
+    let conditions = vec![
+        unit_count(TargetScope::Allies, 2),
+        Condition::AnyOf(vec![
+            unit_count(TargetScope::Enemies, 5),
+            Condition::Not(Box::new(unit_count(TargetScope::Enemies, 2))),
+        ]),
+    ];
+
+    let p_board = vec![
+        create_conditional_buffer(conditions.clone()),
+        create_board_unit(2, "Ally", 1, 10),
+    ];
+
+    let one_enemy = vec![create_board_unit(3, "Enemy", 1, 10)];
+    let events = run_battle(&p_board, &one_enemy, 42);
+    assert!(
+        has_ability_trigger(&events, UnitId::player(1), 0),
+        "Not(two enemies) should pass against a single enemy"
+    );
+
+    let two_enemies = vec![
+        create_board_unit(3, "Enemy", 1, 10),
+        create_board_unit(4, "Enemy", 1, 10),
+    ];
+    let events = run_battle(&p_board, &two_enemies, 42);
+    assert!(
+        !has_ability_trigger(&events, UnitId::player(1), 0),
+        "neither branch of the AnyOf holds against two enemies"
+    );
+}
+
+#[test]
+fn test_condition_all_of_and_empty_groups() {
+    let enemy = vec![create_board_unit(3, "Enemy", 1, 10)];
+
+    let all_of = vec![Condition::AllOf(vec![
+        unit_count(TargetScope::Allies, 1),
+        unit_count(TargetScope::Enemies, 1),
+    ])];
+    let events = run_battle(&[create_conditional_buffer(all_of)], &enemy, 42);
+    assert!(has_ability_trigger(&events, UnitId::player(1), 0));
+
+    // An empty AllOf is vacuously true; an empty AnyOf never passes.
+    let events = run_battle(
+        &[create_conditional_buffer(vec![Condition::AllOf(vec![])])],
+        &enemy,
+        42,
+    );
+    assert!(has_ability_trigger(&events, UnitId::player(1), 0));
+    let events = run_battle(
+        &[create_conditional_buffer(vec![Condition::AnyOf(vec![])])],
+        &enemy,
+        42,
+    );
+    assert!(!has_ability_trigger(&events, UnitId::player(1), 0));
+}
+
+#[test]
+fn test_condition_deeper_than_max_depth_never_passes() {
+    let nest = |depth: usize| {
+        let mut condition = unit_count(TargetScope::Allies, 1);
+        for _ in 1..depth {
+            condition = Condition::AllOf(vec![condition]);
+        }
+        condition
+    };
+    let enemy = vec![create_board_unit(3, "Enemy", 1, 10)];
+
+    let at_max = nest(MAX_CONDITION_DEPTH);
+    assert!(at_max.within_depth(MAX_CONDITION_DEPTH));
+    let events = run_battle(&[create_conditional_buffer(vec![at_max])], &enemy, 42);
+    assert!(has_ability_trigger(&events, UnitId::player(1), 0));
+
+    // Wrapping the too-deep condition in Not must not flip it to passing.
+    let too_deep = nest(MAX_CONDITION_DEPTH + 1);
+    assert!(!too_deep.within_depth(MAX_CONDITION_DEPTH));
+    for condition in [too_deep.clone(), Condition::Not(Box::new(too_deep))] {
+        let events = run_battle(&[create_conditional_buffer(vec![condition])], &enemy, 42);
+        assert!(!has_ability_trigger(&events, UnitId::player(1), 0));
+    }
+}
+
+#[test]
+fn test_card_limits_bound_condition_depth_and_decoding() {
+    use parity_scale_codec::{DecodeLimit, Encode};
+
+    let nest = |depth: usize| {
+        let mut condition = unit_count(TargetScope::Allies, 1);
+        for _ in 1..depth {
+            condition = Condition::Not(Box::new(condition));
+        }
+        condition
+    };
+    let card = |condition: Condition| {
+        create_dummy_card(1, "Buffer", 1, 10)
+            .with_battle_abilities(create_conditional_buffer(vec![condition]).abilities)
+    };
+
+    let at_max = card(nest(MAX_CONDITION_DEPTH));
+    assert!(at_max.within_limits());
+    let decoded =
+        UnitCard::decode_with_depth_limit(MAX_CARD_DECODE_DEPTH, &mut &at_max.encode()[..]);
+    assert_eq!(decoded, Ok(at_max.clone()));
+
+    // Too deep to pass, and nested payloads fail to decode instead of recursing.
+    for depth in [MAX_CONDITION_DEPTH + 1, 64] {
+        let too_deep = card(nest(depth));
+        assert!(!too_deep.within_limits());
+        assert!(UnitCard::decode_with_depth_limit(
+            MAX_CARD_DECODE_DEPTH,
+            &mut &too_deep.encode()[..]
+        )
+        .is_err());
+    }
+
+    let mut too_long = at_max;
+    let ability = &mut too_long.battle_abilities[0];
+    ability.then = vec![ability.effect.clone(); MAX_ABILITY_EFFECTS];
+    assert!(!too_long.within_limits());
+}
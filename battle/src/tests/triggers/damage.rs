@@ -7,7 +7,7 @@ fn test_fatal_damage_trigger() {
     let revenge_shot = create_ability(
         AbilityTrigger::OnHurt,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::Position {
                 scope: TargetScope::Enemies,
                 index: 0,
@@ -167,8 +167,8 @@ fn test_dead_unit_after_unit_attack_and_on_faint_both_fire() {
     let on_faint_buff = create_ability(
         AbilityTrigger::OnFaint,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 5,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(5),
             target: AbilityTarget::All {
                 scope: TargetScope::Allies,
             },
@@ -241,8 +241,8 @@ fn test_after_any_attack_fires_for_support_with_dead_front() {
     let after_any = create_ability(
         AbilityTrigger::AfterAnyAttack,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 3,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(3),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -272,7 +272,7 @@ fn test_before_unit_attack_has_clash_target() {
     let pre_strike = create_ability(
         AbilityTrigger::BeforeUnitAttack,
         AbilityEffect::Damage {
-            amount: 3,
+            amount: ValueExpr::Const(3),
             target: AbilityTarget::All {
                 scope: TargetScope::TriggerSource,
             },
@@ -315,7 +315,7 @@ fn test_graveyard_condition_evaluation_on_dead_unit() {
     let conditional_faint = Ability {
         trigger: AbilityTrigger::OnFaint,
         effect: AbilityEffect::Damage {
-            amount: 10,
+            amount: ValueExpr::Const(10),
             target: AbilityTarget::All {
                 scope: TargetScope::Enemies,
             },
@@ -327,6 +327,9 @@ fn test_graveyard_condition_evaluation_on_dead_unit() {
             value: 5,
         })],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     };
 
     // Unit has 8 attack (> 5), so condition should pass even after death
@@ -366,7 +369,7 @@ fn test_graveyard_condition_blocks_when_not_met() {
     let conditional_faint = Ability {
         trigger: AbilityTrigger::OnFaint,
         effect: AbilityEffect::Damage {
-            amount: 10,
+            amount: ValueExpr::Const(10),
             target: AbilityTarget::All {
                 scope: TargetScope::Enemies,
             },
@@ -378,6 +381,9 @@ fn test_graveyard_condition_blocks_when_not_met() {
             value: 5,
         })],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     };
 
     // Unit has 2 attack (NOT > 5), so condition should fail
@@ -413,8 +419,8 @@ fn test_graveyard_mana_condition_on_dead_unit() {
     let expensive_faint = Ability {
         trigger: AbilityTrigger::OnFaint,
         effect: AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 5,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(5),
             target: AbilityTarget::All {
                 scope: TargetScope::Allies,
             },
@@ -426,6 +432,9 @@ fn test_graveyard_mana_condition_on_dead_unit() {
             value: 3,
         })],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     };
 
     // Unit costs 5 mana (>= 3), condition should pass
@@ -484,7 +493,7 @@ fn test_buffed_unit_dies_faint_condition_uses_graveyard_stats() {
     let conditional_faint = Ability {
         trigger: AbilityTrigger::OnFaint,
         effect: AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::Position {
                 scope: TargetScope::Enemies,
                 index: 0,
@@ -497,14 +506,17 @@ fn test_buffed_unit_dies_faint_condition_uses_graveyard_stats() {
             value: 5,
         })],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     };
 
     // OnStart: buff all allies +2/+2
     let buff_all = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 2,
-            attack: 2,
+            health: ValueExpr::Const(2),
+            attack: ValueExpr::Const(2),
             target: AbilityTarget::All {
                 scope: TargetScope::Allies,
             },
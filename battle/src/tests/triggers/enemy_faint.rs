@@ -0,0 +1,84 @@
+use crate::battle::{CombatEvent, CombatUnit, UnitId};
+use crate::tests::*;
+use crate::types::*;
+
+fn vulture() -> UnitCard {
+    create_dummy_card(2, "Vulture", 1, 5).with_battle_ability(create_ability(
+        AbilityTrigger::OnEnemyFaint,
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(1),
+            attack: ValueExpr::Const(1),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    ))
+}
+
+fn buffs_on(events: &[CombatEvent], target: UnitId) -> usize {
+    events
+        .iter()
+        .filter(|e| {
+            matches!(
+                e,
+                CombatEvent::AbilityModifyStats { target_instance_id, .. }
+                    if *target_instance_id == target
+            )
+        })
+        .count()
+}
+
+/// Every enemy death fires OnEnemyFaint for units on the other board.
+#[test]
+fn test_on_enemy_faint_fires_for_each_enemy_death() {
+    let p_board = vec![
+        create_board_unit(1, "Slayer", 5, 50),
+        CombatUnit::from_card(vulture()),
+    ];
+    let e_board = vec![
+        create_board_unit(3, "Squire", 0, 2),
+        create_board_unit(4, "Squire", 0, 2),
+    ];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    assert_eq!(buffs_on(&events, UnitId::player(2)), 2);
+}
+
+/// Allied deaths do not fire OnEnemyFaint.
+#[test]
+fn test_on_enemy_faint_ignores_allied_deaths() {
+    let p_board = vec![
+        create_board_unit(1, "Squire", 0, 2),
+        CombatUnit::from_card(vulture()),
+    ];
+    let e_board = vec![create_board_unit(3, "Brute", 5, 50)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    assert_eq!(buffs_on(&events, UnitId::player(2)), 0);
+}
+
+/// The fallen enemy's own OnFaint and the watcher's OnEnemyFaint both fire.
+#[test]
+fn test_on_enemy_faint_alongside_on_faint() {
+    let martyr = create_dummy_card(3, "Martyr", 0, 2).with_battle_ability(create_ability(
+        AbilityTrigger::OnFaint,
+        AbilityEffect::GainMana {
+            amount: ValueExpr::Const(1),
+        },
+    ));
+    let p_board = vec![
+        create_board_unit(1, "Slayer", 5, 50),
+        CombatUnit::from_card(vulture()),
+    ];
+
+    let events = run_battle(&p_board, &[CombatUnit::from_card(martyr)], 42);
+
+    assert_eq!(buffs_on(&events, UnitId::player(2)), 1);
+    assert!(events.iter().any(|e| matches!(
+        e,
+        CombatEvent::AbilityGainMana { source_instance_id, .. }
+            if *source_instance_id == UnitId::enemy(1)
+    )));
+}
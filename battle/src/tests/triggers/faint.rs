@@ -10,8 +10,8 @@ fn test_ally_behind_on_faint_buffs_correctly() {
     let martyr = create_dummy_card(1, "Martyr", 2, 3).with_battle_ability(Ability {
         trigger: AbilityTrigger::OnFaint,
         effect: AbilityEffect::ModifyStats {
-            health: 2,
-            attack: 2,
+            health: ValueExpr::Const(2),
+            attack: ValueExpr::Const(2),
             target: AbilityTarget::Position {
                 scope: TargetScope::SelfUnit,
                 index: 1,
@@ -19,6 +19,9 @@ fn test_ally_behind_on_faint_buffs_correctly() {
         },
         conditions: vec![],
         max_triggers: Some(1),
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     });
 
     let ally_behind = create_dummy_card(2, "Ally", 5, 5);
@@ -69,8 +72,8 @@ fn test_ally_behind_on_faint_with_lich_sacrifice() {
     let mk_ability = Ability {
         trigger: AbilityTrigger::OnFaint,
         effect: AbilityEffect::ModifyStats {
-            health: 2,
-            attack: 2,
+            health: ValueExpr::Const(2),
+            attack: ValueExpr::Const(2),
             target: AbilityTarget::Position {
                 scope: TargetScope::SelfUnit,
                 index: 1,
@@ -78,6 +81,9 @@ fn test_ally_behind_on_faint_with_lich_sacrifice() {
         },
         conditions: vec![],
         max_triggers: Some(1),
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     };
 
     let mk1 = create_dummy_card(1, "MK1", 2, 3).with_battle_ability(mk_ability.clone());
@@ -94,15 +100,24 @@ fn test_ally_behind_on_faint_with_lich_sacrifice() {
             },
             conditions: vec![],
             max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         },
         Ability {
             trigger: AbilityTrigger::OnStart,
             effect: AbilityEffect::SpawnUnit {
                 card_id: CardId(43), // golem
                 spawn_location: SpawnLocation::Front,
+                count: 1,
+                side: SpawnSide::Own,
+                stats: SpawnStats::Base,
             },
             conditions: vec![],
             max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         },
     ]);
     let mk5 = create_dummy_card(5, "MK5", 2, 3).with_battle_ability(mk_ability.clone());
@@ -120,15 +135,24 @@ fn test_ally_behind_on_faint_with_lich_sacrifice() {
             },
             conditions: vec![],
             max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         },
         Ability {
             trigger: AbilityTrigger::OnStart,
             effect: AbilityEffect::SpawnUnit {
                 card_id: CardId(43), // golem
                 spawn_location: SpawnLocation::Front,
+                count: 1,
+                side: SpawnSide::Own,
+                stats: SpawnStats::Base,
             },
             conditions: vec![],
             max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         },
     ]);
 
@@ -189,9 +213,14 @@ fn test_ally_behind_on_faint_with_lich_sacrifice() {
 fn test_on_faint_gain_mana_carries_to_next_shop_pool() {
     let martyr = create_dummy_card(1, "Martyr", 1, 1).with_battle_ability(Ability {
         trigger: AbilityTrigger::OnFaint,
-        effect: AbilityEffect::GainMana { amount: 1 },
+        effect: AbilityEffect::GainMana {
+            amount: ValueExpr::Const(1),
+        },
         conditions: vec![],
         max_triggers: Some(1),
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     });
 
     let p_board = vec![CombatUnit::from_card(martyr)];
@@ -226,8 +255,8 @@ fn test_on_faint_permanent_buff_to_ally_behind_persists() {
     let martyr = create_dummy_card(1, "Martyr", 2, 3).with_battle_ability(Ability {
         trigger: AbilityTrigger::OnFaint,
         effect: AbilityEffect::ModifyStatsPermanent {
-            health: 2,
-            attack: 2,
+            health: ValueExpr::Const(2),
+            attack: ValueExpr::Const(2),
             target: AbilityTarget::Position {
                 scope: TargetScope::SelfUnit,
                 index: 1,
@@ -235,6 +264,9 @@ fn test_on_faint_permanent_buff_to_ally_behind_persists() {
         },
         conditions: vec![],
         max_triggers: Some(1),
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     });
 
     let ally_behind = create_dummy_card(2, "Ally", 5, 5);
@@ -285,8 +317,8 @@ fn test_on_faint_permanent_adjacent_buff_uses_dead_unit_position() {
         create_ability(
             AbilityTrigger::OnFaint,
             AbilityEffect::ModifyStatsPermanent {
-                health: 1,
-                attack: 1,
+                health: ValueExpr::Const(1),
+                attack: ValueExpr::Const(1),
                 target: AbilityTarget::Adjacent {
                     scope: TargetScope::Allies,
                 },
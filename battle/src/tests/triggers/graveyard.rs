@@ -0,0 +1,309 @@
+use crate::battle::{CombatEvent, CombatUnit, UnitId, UnitView};
+use crate::limits::LimitReason;
+use crate::tests::*;
+use crate::types::*;
+
+fn spawned_units(events: &[CombatEvent]) -> Vec<&UnitView> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::UnitSpawn { spawned_unit, .. } => Some(spawned_unit),
+            _ => None,
+        })
+        .collect()
+}
+
+fn resurrect(pick: ResurrectPick, stats: ResurrectStats) -> AbilityEffect {
+    AbilityEffect::Resurrect {
+        pick,
+        stats,
+        spawn_location: SpawnLocation::DeathPosition,
+    }
+}
+
+/// An enemy that pings every player unit for 1 at the start of battle.
+fn start_bomber() -> CombatUnit {
+    CombatUnit::from_card(create_dummy_card(9, "Bomber", 0, 50).with_battle_ability(
+        create_ability(
+            AbilityTrigger::OnStart,
+            AbilityEffect::Damage {
+                amount: ValueExpr::Const(1),
+                target: AbilityTarget::All {
+                    scope: TargetScope::Enemies,
+                },
+            },
+        ),
+    ))
+}
+
+#[test]
+fn test_resurrect_most_recent_with_base_stats() {
+    let necro = create_dummy_card(2, "Necro", 0, 10).with_battle_ability(Ability {
+        max_triggers: Some(1),
+        ..create_ability(
+            AbilityTrigger::OnAllyFaint,
+            resurrect(ResurrectPick::MostRecent, ResurrectStats::Base),
+        )
+    });
+    // The fodder buffs itself before it falls; Base stats drop the buff.
+    let fodder = create_dummy_card(1, "Fodder", 1, 1).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(3),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    ));
+
+    let p_board = vec![CombatUnit::from_card(fodder), CombatUnit::from_card(necro)];
+    let e_board = vec![create_board_unit(3, "Wall", 1, 50)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    let spawned = spawned_units(&events);
+    assert_eq!(spawned.len(), 1, "Necro should resurrect exactly once");
+    assert_eq!(spawned[0].card_id, CardId(1));
+    assert_eq!((spawned[0].attack, spawned[0].health), (1, 1));
+    assert_ne!(
+        spawned[0].instance_id,
+        UnitId::player(1),
+        "A resurrected unit gets a fresh instance ID"
+    );
+}
+
+#[test]
+fn test_resurrect_current_keeps_buffs_at_full_health() {
+    let necro = create_dummy_card(2, "Necro", 0, 10).with_battle_ability(Ability {
+        max_triggers: Some(1),
+        ..create_ability(
+            AbilityTrigger::OnAllyFaint,
+            resurrect(ResurrectPick::MostRecent, ResurrectStats::Current),
+        )
+    });
+    let fodder = create_dummy_card(1, "Fodder", 1, 1).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(2),
+            attack: ValueExpr::Const(3),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    ));
+
+    let p_board = vec![CombatUnit::from_card(fodder), CombatUnit::from_card(necro)];
+    let e_board = vec![create_board_unit(3, "Brute", 5, 50)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    let spawned = spawned_units(&events);
+    assert_eq!(spawned.len(), 1);
+    assert_eq!((spawned[0].attack, spawned[0].health), (4, 3));
+}
+
+/// The graveyard outlives the phase a unit died in: allies killed at the start
+/// of battle are still available to a resurrect in the first round.
+#[test]
+fn test_resurrect_strongest_from_earlier_phase() {
+    let necro = create_dummy_card(3, "Necro", 0, 20).with_battle_ability(Ability {
+        max_triggers: Some(1),
+        ..create_ability(
+            AbilityTrigger::BeforeAnyAttack,
+            AbilityEffect::Resurrect {
+                pick: ResurrectPick::Strongest,
+                stats: ResurrectStats::Current,
+                spawn_location: SpawnLocation::Back,
+            },
+        )
+    });
+    let p_board = vec![
+        create_board_unit(1, "Brawler", 5, 1),
+        create_board_unit(2, "Weakling", 1, 1),
+        CombatUnit::from_card(necro),
+    ];
+
+    let events = run_battle(&p_board, &[start_bomber()], 42);
+
+    let spawned = spawned_units(&events);
+    assert_eq!(spawned.len(), 1);
+    assert_eq!(
+        spawned[0].card_id,
+        CardId(1),
+        "Strongest fallen ally returns"
+    );
+}
+
+#[test]
+fn test_resurrect_fizzles_with_empty_graveyard() {
+    let necro = create_dummy_card(1, "Necro", 0, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        resurrect(ResurrectPick::MostRecent, ResurrectStats::Base),
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(necro)],
+        &[create_board_unit(2, "Wall", 1, 50)],
+        42,
+    );
+
+    assert!(has_ability_trigger(&events, UnitId::player(1), 0));
+    assert!(spawned_units(&events).is_empty());
+}
+
+/// Resurrects count toward the spawn limit even when they fizzle, so an
+/// endless resurrect loop runs out of spawns before the round limit.
+#[test]
+fn test_resurrect_respects_spawn_limit() {
+    let revive = resurrect(ResurrectPick::MostRecent, ResurrectStats::Base);
+    let phoenix = create_dummy_card(1, "Phoenix", 1, 1).with_battle_ability(Ability {
+        then: vec![revive.clone(); MAX_ABILITY_EFFECTS - 1],
+        ..create_ability(AbilityTrigger::OnFaint, revive)
+    });
+    let phoenix = CombatUnit::from_card(phoenix);
+    let p_board = vec![phoenix.clone(), phoenix];
+    let e_board = vec![create_board_unit(2, "Wall", 1, i16::MAX)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    assert!(events.iter().any(|e| matches!(
+        e,
+        CombatEvent::LimitExceeded {
+            reason: LimitReason::SpawnLimit { .. },
+            ..
+        }
+    )));
+}
+
+#[test]
+fn test_mimic_copies_target_stats_and_abilities() {
+    let dragon_ability = create_ability(
+        AbilityTrigger::OnHurt,
+        AbilityEffect::GainMana {
+            amount: ValueExpr::Const(1),
+        },
+    );
+    let dragon = create_dummy_card(5, "Dragon", 6, 8).with_battle_ability(dragon_ability.clone());
+    let shifter = create_dummy_card(1, "Shifter", 1, 3).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Mimic {
+            target: AbilityTarget::Standard {
+                scope: TargetScope::Enemies,
+                stat: StatType::Attack,
+                order: SortOrder::Descending,
+                count: 1,
+            },
+        },
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(shifter)],
+        &[CombatUnit::from_card(dragon)],
+        42,
+    );
+
+    let transformed = events.iter().find_map(|e| match e {
+        CombatEvent::UnitTransform {
+            transformed_unit, ..
+        } => Some(transformed_unit),
+        _ => None,
+    });
+    let transformed = transformed.expect("Shifter should transform");
+    assert_eq!(transformed.instance_id, UnitId::player(1));
+    assert_eq!(transformed.card_id, CardId(5));
+    assert_eq!((transformed.attack, transformed.health), (6, 8));
+    assert_eq!(transformed.battle_abilities, vec![dragon_ability]);
+}
+
+/// Triggers queued before a Mimic still resolve the abilities they captured.
+#[test]
+fn test_mimic_keeps_already_queued_abilities() {
+    let dragon = create_dummy_card(5, "Dragon", 6, 8);
+    let shifter = create_dummy_card(1, "Shifter", 1, 3).with_battle_abilities(vec![
+        create_ability(
+            AbilityTrigger::OnStart,
+            AbilityEffect::Mimic {
+                target: AbilityTarget::Position {
+                    scope: TargetScope::Enemies,
+                    index: 0,
+                },
+            },
+        ),
+        create_ability(
+            AbilityTrigger::OnStart,
+            AbilityEffect::GainMana {
+                amount: ValueExpr::Const(2),
+            },
+        ),
+    ]);
+
+    let events = run_battle(
+        &[CombatUnit::from_card(shifter)],
+        &[CombatUnit::from_card(dragon)],
+        42,
+    );
+
+    let transform = events
+        .iter()
+        .position(|e| matches!(e, CombatEvent::UnitTransform { .. }))
+        .expect("Shifter should transform");
+    let gain = events
+        .iter()
+        .position(|e| {
+            matches!(
+                e,
+                CombatEvent::AbilityGainMana {
+                    source_instance_id,
+                    amount: 2,
+                    ..
+                } if *source_instance_id == UnitId::player(1)
+            )
+        })
+        .expect("the queued GainMana should still fire");
+    assert!(transform < gain);
+}
+
+/// Mimicking a fallen ally copies it from the graveyard, and the copied
+/// abilities are registered so they trigger later in the battle.
+#[test]
+fn test_mimic_fallen_ally_registers_copied_abilities() {
+    let fodder = create_dummy_card(1, "Fodder", 1, 1).with_battle_ability(create_ability(
+        AbilityTrigger::AfterAnyAttack,
+        AbilityEffect::GainMana {
+            amount: ValueExpr::Const(1),
+        },
+    ));
+    let copycat = create_dummy_card(2, "Copycat", 0, 5).with_battle_ability(create_ability(
+        AbilityTrigger::OnAllyFaint,
+        AbilityEffect::Mimic {
+            target: AbilityTarget::All {
+                scope: TargetScope::TriggerSource,
+            },
+        },
+    ));
+
+    let p_board = vec![
+        CombatUnit::from_card(fodder),
+        CombatUnit::from_card(copycat),
+    ];
+    let e_board = vec![create_board_unit(3, "Wall", 1, 50)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    assert!(events.iter().any(|e| matches!(
+        e,
+        CombatEvent::UnitTransform { transformed_unit, .. }
+            if transformed_unit.instance_id == UnitId::player(2)
+                && transformed_unit.card_id == CardId(1)
+                && transformed_unit.health == 1
+    )));
+    assert!(
+        events.iter().any(|e| matches!(
+            e,
+            CombatEvent::AbilityGainMana { source_instance_id, .. }
+                if *source_instance_id == UnitId::player(2)
+        )),
+        "Copycat should fire the copied AfterAnyAttack ability"
+    );
+}
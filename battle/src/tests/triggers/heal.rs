@@ -0,0 +1,144 @@
+use crate::battle::{CombatEvent, CombatUnit, UnitId};
+use crate::tests::*;
+use crate::types::*;
+
+fn heal_events(events: &[CombatEvent]) -> Vec<(UnitId, i16, i16)> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::AbilityHeal {
+                target_instance_id,
+                amount,
+                new_health,
+                ..
+            } => Some((*target_instance_id, *amount, *new_health)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn healer(amount: i16) -> Ability {
+    create_ability(
+        AbilityTrigger::AfterUnitAttack,
+        AbilityEffect::Heal {
+            amount: ValueExpr::Const(amount),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    )
+}
+
+#[test]
+fn test_heal_is_clamped_to_max_health() {
+    // Takes 2 per clash, heals up to 5 afterwards: never above 10.
+    let unit = create_dummy_card(1, "Troll", 1, 10).with_battle_ability(healer(5));
+    let enemy = create_board_unit(2, "Hitter", 2, 3);
+
+    let events = run_battle(&[CombatUnit::from_card(unit)], &[enemy], 42);
+
+    let heals = heal_events(&events);
+    assert!(!heals.is_empty(), "damaged unit should be healed");
+    for (target, amount, new_health) in heals {
+        assert_eq!(target, UnitId::player(1));
+        assert_eq!(amount, 2, "heal reports only the health actually restored");
+        assert_eq!(new_health, 10);
+    }
+}
+
+#[test]
+fn test_heal_at_full_health_emits_nothing() {
+    let unit = create_dummy_card(1, "Troll", 1, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Heal {
+            amount: ValueExpr::Const(5),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    ));
+    let events = run_battle(
+        &[CombatUnit::from_card(unit)],
+        &[create_board_unit(2, "Dummy", 0, 5)],
+        42,
+    );
+    assert!(heal_events(&events).is_empty());
+}
+
+#[test]
+fn test_buffs_raise_max_health_but_damage_does_not() {
+    let mut unit = create_board_unit(1, "Troll", 1, 10);
+    assert_eq!(unit.max_health(), 10);
+
+    unit.health_buff = 3;
+    unit.health = 13;
+    assert_eq!(unit.max_health(), 13);
+    assert_eq!(unit.missing_health(), 0);
+
+    unit.take_damage(4);
+    assert_eq!(unit.max_health(), 13);
+    assert_eq!(unit.missing_health(), 4);
+}
+
+#[test]
+fn test_heal_does_not_revive_units_awaiting_death() {
+    // Enemy's OnFaint heal targets itself; a dead unit must stay dead.
+    let enemy = create_dummy_card(2, "Fragile", 0, 1).with_battle_ability(create_ability(
+        AbilityTrigger::OnFaint,
+        AbilityEffect::Heal {
+            amount: ValueExpr::Const(5),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    ));
+    let events = run_battle(
+        &[create_board_unit(1, "Hitter", 5, 5)],
+        &[CombatUnit::from_card(enemy)],
+        42,
+    );
+    assert!(heal_events(&events).is_empty());
+}
+
+#[test]
+fn test_missing_health_matcher_detects_damaged_allies() {
+    // Medic heals the front ally only once it is damaged.
+    let medic = create_dummy_card(2, "Medic", 0, 5).with_battle_ability(Ability {
+        trigger: AbilityTrigger::AfterAnyAttack,
+        effect: AbilityEffect::Heal {
+            amount: ValueExpr::Const(1),
+            target: AbilityTarget::Position {
+                scope: TargetScope::Allies,
+                index: 0,
+            },
+        },
+        conditions: vec![Condition::Is(Matcher::MissingHealthCompare {
+            scope: TargetScope::AlliesOther,
+            op: CompareOp::GreaterThan,
+            value: 0,
+        })],
+        max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
+    });
+
+    let p_board = vec![
+        create_board_unit(1, "Tank", 1, 10),
+        CombatUnit::from_card(medic),
+    ];
+
+    let harmless = vec![create_board_unit(3, "Pacifist", 0, 3)];
+    let events = run_battle(&p_board, &harmless, 42);
+    assert!(
+        !has_ability_trigger(&events, UnitId::player(2), 0),
+        "undamaged allies should not satisfy the matcher"
+    );
+
+    let hitter = vec![create_board_unit(3, "Hitter", 2, 3)];
+    let events = run_battle(&p_board, &hitter, 42);
+    assert!(has_ability_trigger(&events, UnitId::player(2), 0));
+    assert!(heal_events(&events)
+        .iter()
+        .all(|(target, amount, _)| *target == UnitId::player(1) && *amount == 1));
+}
@@ -0,0 +1,147 @@
+use crate::battle::{CombatEvent, CombatUnit, UnitId};
+use crate::tests::*;
+use crate::types::*;
+
+fn below(threshold: HealthThreshold) -> AbilityTrigger {
+    AbilityTrigger::OnHealthBelow { threshold }
+}
+
+fn self_target() -> AbilityTarget {
+    AbilityTarget::All {
+        scope: TargetScope::SelfUnit,
+    }
+}
+
+fn trigger_count(events: &[CombatEvent], source: UnitId) -> usize {
+    events
+        .iter()
+        .filter(|e| {
+            matches!(
+                e,
+                CombatEvent::AbilityTrigger { source_instance_id, .. }
+                    if *source_instance_id == source
+            )
+        })
+        .count()
+}
+
+fn self_heal_boss(max_triggers: Option<CountValue>) -> CombatUnit {
+    let mut ability = create_ability(
+        below(HealthThreshold::Absolute(5)),
+        AbilityEffect::Heal {
+            amount: ValueExpr::Const(5),
+            target: self_target(),
+        },
+    );
+    ability.max_triggers = max_triggers;
+    CombatUnit::from_card(create_dummy_card(1, "Troll", 1, 10).with_battle_ability(ability))
+}
+
+/// A percentage threshold fires once when clash damage first crosses it.
+#[test]
+fn test_percent_threshold_fires_once_on_crossing() {
+    let boss = create_dummy_card(1, "Warlord", 2, 10).with_battle_ability(create_ability(
+        below(HealthThreshold::Percent(50)),
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(3),
+            target: self_target(),
+        },
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(boss)],
+        &[create_board_unit(2, "Brute", 3, 30)],
+        42,
+    );
+
+    assert_eq!(trigger_count(&events, UnitId::player(1)), 1);
+    let enraged = events.iter().find_map(|e| match e {
+        CombatEvent::AbilityModifyStats { new_attack, .. } => Some(*new_attack),
+        _ => None,
+    });
+    assert_eq!(enraged, Some(5));
+}
+
+/// Ability damage crosses thresholds too, and the attacker is the trigger target.
+#[test]
+fn test_absolute_threshold_fires_from_ability_damage() {
+    let boss = create_dummy_card(1, "Lich", 0, 5).with_battle_ability(create_ability(
+        below(HealthThreshold::Absolute(1)),
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(2),
+            target: AbilityTarget::All {
+                scope: TargetScope::Aggressor,
+            },
+        },
+    ));
+    let archer = create_dummy_card(2, "Archer", 0, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(4),
+            target: AbilityTarget::Position {
+                scope: TargetScope::Enemies,
+                index: 0,
+            },
+        },
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(boss)],
+        &[CombatUnit::from_card(archer)],
+        42,
+    );
+
+    let retaliation = events.iter().find_map(|e| match e {
+        CombatEvent::AbilityDamage {
+            source_instance_id,
+            target_instance_id,
+            ..
+        } if *source_instance_id == UnitId::player(1) => Some(*target_instance_id),
+        _ => None,
+    });
+    assert_eq!(retaliation, Some(UnitId::enemy(1)));
+}
+
+/// A fatal hit skips the threshold; OnFaint covers deaths.
+#[test]
+fn test_threshold_skipped_on_fatal_hit() {
+    let boss = create_dummy_card(1, "Lich", 0, 3).with_battle_ability(create_ability(
+        below(HealthThreshold::Absolute(1)),
+        AbilityEffect::GainMana {
+            amount: ValueExpr::Const(1),
+        },
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(boss)],
+        &[create_board_unit(2, "Brute", 5, 10)],
+        42,
+    );
+
+    assert_eq!(trigger_count(&events, UnitId::player(1)), 0);
+}
+
+/// Healing back above the threshold re-arms it for the next crossing.
+#[test]
+fn test_threshold_rearms_after_heal() {
+    let events = run_battle(
+        &[self_heal_boss(None)],
+        &[create_board_unit(2, "Brute", 4, 100)],
+        42,
+    );
+
+    assert!(trigger_count(&events, UnitId::player(1)) >= 2);
+}
+
+/// `max_triggers` still caps repeated crossings.
+#[test]
+fn test_threshold_respects_max_triggers() {
+    let events = run_battle(
+        &[self_heal_boss(Some(1))],
+        &[create_board_unit(2, "Brute", 4, 100)],
+        42,
+    );
+
+    assert_eq!(trigger_count(&events, UnitId::player(1)), 1);
+}
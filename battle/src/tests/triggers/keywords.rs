@@ -0,0 +1,247 @@
+use crate::battle::{BattleResult, CombatEvent, CombatUnit, UnitId};
+use crate::tests::*;
+use crate::types::*;
+
+fn with_keywords(card: UnitCard, keywords: &[Keyword]) -> CombatUnit {
+    CombatUnit::from_card(card.with_keywords(KeywordMask::from_keywords(keywords)))
+}
+
+fn damage_taken(events: &[CombatEvent], target: UnitId) -> Vec<StatValue> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::DamageTaken {
+                target_instance_id,
+                remaining_hp,
+                ..
+            } if *target_instance_id == target => Some(*remaining_hp),
+            _ => None,
+        })
+        .collect()
+}
+
+fn battle_result(events: &[CombatEvent]) -> Option<BattleResult> {
+    events.iter().find_map(|e| match e {
+        CombatEvent::BattleEnd { result } => Some(result.clone()),
+        _ => None,
+    })
+}
+
+/// First Strike kills the opposing front before it can strike back.
+#[test]
+fn test_first_strike_lands_before_retaliation() {
+    let p_board = vec![with_keywords(
+        create_dummy_card(1, "Duelist", 3, 1),
+        &[Keyword::FirstStrike],
+    )];
+    let e_board = vec![create_board_unit(2, "Brute", 5, 3)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    assert!(events.iter().any(|e| matches!(
+        e,
+        CombatEvent::FirstStrike {
+            source_instance_id,
+            target_instance_id,
+        } if *source_instance_id == UnitId::player(1) && *target_instance_id == UnitId::enemy(1)
+    )));
+    assert_eq!(damage_taken(&events, UnitId::player(1)), vec![1]);
+    assert_eq!(battle_result(&events), Some(BattleResult::Victory));
+}
+
+/// When both fronts have First Strike the exchange is simultaneous again.
+#[test]
+fn test_mutual_first_strike_is_simultaneous() {
+    let p_board = vec![with_keywords(
+        create_dummy_card(1, "Duelist", 3, 1),
+        &[Keyword::FirstStrike],
+    )];
+    let e_board = vec![with_keywords(
+        create_dummy_card(2, "Duelist", 3, 1),
+        &[Keyword::FirstStrike],
+    )];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, CombatEvent::FirstStrike { .. })));
+    assert_eq!(battle_result(&events), Some(BattleResult::Draw));
+}
+
+/// A Shield soaks the first strike, so the defender still hits back.
+#[test]
+fn test_first_strike_blocked_by_shield_allows_retaliation() {
+    let p_board = vec![with_keywords(
+        create_dummy_card(1, "Duelist", 3, 4),
+        &[Keyword::FirstStrike],
+    )];
+    let e_board = vec![CombatUnit::from_card(
+        create_dummy_card(2, "Guardian", 2, 3)
+            .with_base_statuses(StatusMask::from_statuses(&[Status::Shield])),
+    )];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    assert_eq!(damage_taken(&events, UnitId::player(1))[0], 2);
+    assert_eq!(damage_taken(&events, UnitId::enemy(1))[0], 3);
+}
+
+/// Trample carries damage beyond the front's health into the next enemy.
+#[test]
+fn test_trample_carries_excess_damage() {
+    let p_board = vec![with_keywords(
+        create_dummy_card(1, "Rhino", 6, 10),
+        &[Keyword::Trample],
+    )];
+    let e_board = vec![
+        create_board_unit(2, "Squire", 0, 2),
+        create_board_unit(3, "Knight", 0, 10),
+    ];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    let trample = events
+        .iter()
+        .find_map(|e| match e {
+            CombatEvent::Trample {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => Some((
+                *source_instance_id,
+                *target_instance_id,
+                *damage,
+                *remaining_hp,
+            )),
+            _ => None,
+        })
+        .expect("excess damage should trample");
+    assert_eq!(trample, (UnitId::player(1), UnitId::enemy(2), 4, 6));
+}
+
+/// A unit hurt by Trample reacts with OnHurt even though it is not the front.
+#[test]
+fn test_trampled_unit_fires_on_hurt() {
+    let p_board = vec![with_keywords(
+        create_dummy_card(1, "Rhino", 6, 10),
+        &[Keyword::Trample],
+    )];
+    let reactor = create_dummy_card(3, "Reactor", 0, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnHurt,
+        AbilityEffect::GainMana {
+            amount: ValueExpr::Const(1),
+        },
+    ));
+    let e_board = vec![
+        create_board_unit(2, "Squire", 0, 2),
+        CombatUnit::from_card(reactor),
+    ];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    let trample_pos = events
+        .iter()
+        .position(|e| matches!(e, CombatEvent::Trample { .. }))
+        .expect("excess damage should trample");
+    let reaction_pos = events
+        .iter()
+        .position(|e| {
+            matches!(
+                e,
+                CombatEvent::AbilityTrigger {
+                    source_instance_id,
+                    ..
+                } if *source_instance_id == UnitId::enemy(2)
+            )
+        })
+        .expect("trampled unit should fire OnHurt");
+    assert!(trample_pos < reaction_pos);
+}
+
+/// Windfury swings a second time in the same round.
+#[test]
+fn test_windfury_attacks_twice_per_round() {
+    let p_board = vec![with_keywords(
+        create_dummy_card(1, "Gale", 2, 10),
+        &[Keyword::Windfury],
+    )];
+    let e_board = vec![create_board_unit(2, "Dummy", 1, 7)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    let windfury_count = events
+        .iter()
+        .filter(|e| matches!(e, CombatEvent::Windfury { .. }))
+        .count();
+    assert!(windfury_count >= 1);
+    // Two swings per round; only the first is answered by the non-Windfury enemy.
+    assert_eq!(&damage_taken(&events, UnitId::enemy(1))[..2], &[5, 3]);
+    assert_eq!(&damage_taken(&events, UnitId::player(1))[..2], &[9, 9]);
+}
+
+/// Windfury does not swing again once either front has fallen.
+#[test]
+fn test_windfury_stops_when_front_dies() {
+    let p_board = vec![with_keywords(
+        create_dummy_card(1, "Gale", 5, 10),
+        &[Keyword::Windfury],
+    )];
+    let e_board = vec![create_board_unit(2, "Squire", 1, 3)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, CombatEvent::Windfury { .. })));
+    assert_eq!(damage_taken(&events, UnitId::enemy(1)), vec![-2]);
+}
+
+/// A Reach unit in slot 1 also hits the opposing front during the clash.
+#[test]
+fn test_reach_hits_from_second_slot() {
+    let p_board = vec![
+        create_board_unit(1, "Shieldbearer", 1, 10),
+        with_keywords(create_dummy_card(2, "Spearman", 3, 5), &[Keyword::Reach]),
+    ];
+    let e_board = vec![create_board_unit(3, "Dummy", 0, 10)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    let reach = events
+        .iter()
+        .find_map(|e| match e {
+            CombatEvent::Reach {
+                source_instance_id,
+                target_instance_id,
+                damage,
+                remaining_hp,
+            } => Some((
+                *source_instance_id,
+                *target_instance_id,
+                *damage,
+                *remaining_hp,
+            )),
+            _ => None,
+        })
+        .expect("reach unit should strike");
+    assert_eq!(reach, (UnitId::player(2), UnitId::enemy(1), 3, 6));
+}
+
+/// Reach does nothing from deeper slots.
+#[test]
+fn test_reach_ignored_beyond_slot_one() {
+    let p_board = vec![
+        create_board_unit(1, "Shieldbearer", 1, 10),
+        create_board_unit(2, "Squire", 1, 10),
+        with_keywords(create_dummy_card(3, "Spearman", 3, 5), &[Keyword::Reach]),
+    ];
+    let e_board = vec![create_board_unit(4, "Dummy", 0, 10)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, CombatEvent::Reach { .. })));
+}
@@ -8,8 +8,8 @@ fn test_on_spawn_triggers_for_spawned_unit() {
         UnitCard::new(CardId(120), "Spawnling", 1, 3, 0, 0).with_battle_ability(create_ability(
             AbilityTrigger::OnSpawn,
             AbilityEffect::ModifyStats {
-                health: 0,
-                attack: 2,
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(2),
                 target: AbilityTarget::All {
                     scope: TargetScope::SelfUnit,
                 },
@@ -24,6 +24,9 @@ fn test_on_spawn_triggers_for_spawned_unit() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(120),
             spawn_location: SpawnLocation::Front,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     ));
 
@@ -81,7 +84,7 @@ fn test_on_hurt_aggressor_scope_hits_the_attacker() {
     let retaliator = create_dummy_card(1, "Retaliator", 0, 2).with_battle_ability(create_ability(
         AbilityTrigger::OnHurt,
         AbilityEffect::Damage {
-            amount: 2,
+            amount: ValueExpr::Const(2),
             target: AbilityTarget::All {
                 scope: TargetScope::Aggressor,
             },
@@ -1,16 +1,30 @@
 mod abyssal_bomber;
 mod adjacent;
+mod ally_hurt;
+mod battle_end;
 mod combos;
+mod composite;
 mod conditions;
 mod damage;
 mod deathtouch;
+mod enemy_faint;
 mod faint;
+mod graveyard;
+mod heal;
+mod health_threshold;
+mod keywords;
 mod lifecycle;
+mod on_kill;
 mod positions;
 mod random_ally_other;
 mod scopes;
+mod silence;
 mod snipe;
 mod spawn;
 mod spines;
+mod statuses;
+mod steal;
 mod support;
+mod tags;
 mod targeting;
+mod values;
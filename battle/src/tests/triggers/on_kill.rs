@@ -0,0 +1,126 @@
+use crate::battle::{CombatEvent, CombatUnit, UnitId};
+use crate::tests::*;
+use crate::types::*;
+
+fn gain_mana_on_kill() -> Ability {
+    create_ability(
+        AbilityTrigger::OnKill,
+        AbilityEffect::GainMana {
+            amount: ValueExpr::Const(1),
+        },
+    )
+}
+
+fn mana_sources(events: &[CombatEvent]) -> Vec<UnitId> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::AbilityGainMana {
+                source_instance_id, ..
+            } => Some(*source_instance_id),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Killing the enemy front in a clash fires OnKill for the attacker.
+#[test]
+fn test_on_kill_fires_after_clash_kill() {
+    let slayer = create_dummy_card(1, "Slayer", 5, 20).with_battle_ability(create_ability(
+        AbilityTrigger::OnKill,
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(1),
+            attack: ValueExpr::Const(1),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    ));
+    let e_board = vec![
+        create_board_unit(2, "Squire", 1, 2),
+        create_board_unit(3, "Knight", 1, 30),
+    ];
+
+    let events = run_battle(&[CombatUnit::from_card(slayer)], &e_board, 42);
+
+    let buff_pos = events
+        .iter()
+        .position(|e| {
+            matches!(
+                e,
+                CombatEvent::AbilityModifyStats { target_instance_id, .. }
+                    if *target_instance_id == UnitId::player(1)
+            )
+        })
+        .expect("OnKill should buff the slayer");
+    let death_pos = events
+        .iter()
+        .position(|e| matches!(e, CombatEvent::UnitDeath { .. }))
+        .expect("squire should die");
+    assert!(death_pos < buff_pos);
+}
+
+/// Ability damage that kills an enemy is credited to the ability's source.
+#[test]
+fn test_on_kill_credits_ability_damage() {
+    let sniper = create_dummy_card(1, "Sniper", 0, 10).with_battle_abilities(vec![
+        create_ability(
+            AbilityTrigger::OnStart,
+            AbilityEffect::Damage {
+                amount: ValueExpr::Const(3),
+                target: AbilityTarget::Position {
+                    scope: TargetScope::Enemies,
+                    index: 0,
+                },
+            },
+        ),
+        gain_mana_on_kill(),
+    ]);
+
+    let events = run_battle(
+        &[CombatUnit::from_card(sniper)],
+        &[create_board_unit(2, "Frail", 0, 2)],
+        42,
+    );
+
+    assert_eq!(mana_sources(&events), vec![UnitId::player(1)]);
+}
+
+/// Killing an ally does not count as a kill.
+#[test]
+fn test_on_kill_ignores_friendly_fire() {
+    let zealot = create_dummy_card(1, "Zealot", 0, 10).with_battle_abilities(vec![
+        create_ability(
+            AbilityTrigger::OnStart,
+            AbilityEffect::Damage {
+                amount: ValueExpr::Const(5),
+                target: AbilityTarget::All {
+                    scope: TargetScope::AlliesOther,
+                },
+            },
+        ),
+        gain_mana_on_kill(),
+    ]);
+    let p_board = vec![
+        CombatUnit::from_card(zealot),
+        create_board_unit(2, "Acolyte", 0, 1),
+    ];
+
+    let events = run_battle(&p_board, &[create_board_unit(3, "Dummy", 0, 5)], 42);
+
+    assert!(mana_sources(&events).is_empty());
+}
+
+/// A unit that dies trading blows still gets credit for its kill.
+#[test]
+fn test_on_kill_fires_for_unit_killed_in_same_clash() {
+    let duelist = create_dummy_card(1, "Duelist", 3, 3).with_battle_ability(gain_mana_on_kill());
+
+    let events = run_battle(
+        &[CombatUnit::from_card(duelist)],
+        &[create_board_unit(2, "Rival", 3, 3)],
+        42,
+    );
+
+    assert_eq!(mana_sources(&events), vec![UnitId::player(1)]);
+}
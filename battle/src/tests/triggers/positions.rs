@@ -7,7 +7,7 @@ fn test_enemy_position_targeting() {
     let mage_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::Position {
                 scope: TargetScope::Enemies,
                 index: 2,
@@ -43,7 +43,7 @@ fn test_enemy_position_fizzle() {
     let mage_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::Position {
                 scope: TargetScope::Enemies,
                 index: 2,
@@ -76,8 +76,8 @@ fn test_ally_position_targeting() {
     let guard_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 3,
-            attack: 3,
+            health: ValueExpr::Const(3),
+            attack: ValueExpr::Const(3),
             target: AbilityTarget::Position {
                 scope: TargetScope::Allies,
                 index: 4,
@@ -117,8 +117,8 @@ fn test_ally_position_fizzle() {
     let guard_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 3,
-            attack: 3,
+            health: ValueExpr::Const(3),
+            attack: ValueExpr::Const(3),
             target: AbilityTarget::Position {
                 scope: TargetScope::Allies,
                 index: 4,
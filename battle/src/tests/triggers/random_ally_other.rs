@@ -7,8 +7,8 @@ fn test_random_ally_other_targeting() {
     let commander_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 2,
-            attack: 2,
+            health: ValueExpr::Const(2),
+            attack: ValueExpr::Const(2),
             target: AbilityTarget::Random {
                 scope: TargetScope::AlliesOther,
                 count: 1,
@@ -53,8 +53,8 @@ fn test_random_ally_other_fizzle() {
     let commander_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 2,
-            attack: 2,
+            health: ValueExpr::Const(2),
+            attack: ValueExpr::Const(2),
             target: AbilityTarget::Random {
                 scope: TargetScope::AlliesOther,
                 count: 1,
@@ -8,8 +8,8 @@ fn test_attack_trigger_scopes() {
         UnitCard::new(CardId(1), "Front", 1, 10, 0, 0).with_battle_ability(create_ability(
             AbilityTrigger::BeforeUnitAttack,
             AbilityEffect::ModifyStats {
-                health: 0,
-                attack: 1,
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(1),
                 target: AbilityTarget::All {
                     scope: TargetScope::SelfUnit,
                 },
@@ -22,8 +22,8 @@ fn test_attack_trigger_scopes() {
             create_ability(
                 AbilityTrigger::BeforeUnitAttack,
                 AbilityEffect::ModifyStats {
-                    health: 0,
-                    attack: 1,
+                    health: ValueExpr::Const(0),
+                    attack: ValueExpr::Const(1),
                     target: AbilityTarget::All {
                         scope: TargetScope::SelfUnit,
                     },
@@ -32,8 +32,8 @@ fn test_attack_trigger_scopes() {
             create_ability(
                 AbilityTrigger::BeforeAnyAttack,
                 AbilityEffect::ModifyStats {
-                    health: 0,
-                    attack: 1,
+                    health: ValueExpr::Const(0),
+                    attack: ValueExpr::Const(1),
                     target: AbilityTarget::All {
                         scope: TargetScope::SelfUnit,
                     },
@@ -75,8 +75,8 @@ fn test_after_attack_trigger_scopes() {
         UnitCard::new(CardId(1), "Front", 1, 10, 0, 0).with_battle_ability(create_ability(
             AbilityTrigger::AfterUnitAttack,
             AbilityEffect::ModifyStats {
-                health: 0,
-                attack: 1,
+                health: ValueExpr::Const(0),
+                attack: ValueExpr::Const(1),
                 target: AbilityTarget::All {
                     scope: TargetScope::SelfUnit,
                 },
@@ -89,8 +89,8 @@ fn test_after_attack_trigger_scopes() {
             create_ability(
                 AbilityTrigger::AfterUnitAttack,
                 AbilityEffect::ModifyStats {
-                    health: 0,
-                    attack: 1,
+                    health: ValueExpr::Const(0),
+                    attack: ValueExpr::Const(1),
                     target: AbilityTarget::All {
                         scope: TargetScope::SelfUnit,
                     },
@@ -99,8 +99,8 @@ fn test_after_attack_trigger_scopes() {
             create_ability(
                 AbilityTrigger::AfterAnyAttack,
                 AbilityEffect::ModifyStats {
-                    health: 0,
-                    attack: 1,
+                    health: ValueExpr::Const(0),
+                    attack: ValueExpr::Const(1),
                     target: AbilityTarget::All {
                         scope: TargetScope::SelfUnit,
                     },
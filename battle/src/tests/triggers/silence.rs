@@ -0,0 +1,146 @@
+use crate::battle::{CombatEvent, CombatUnit, UnitId};
+use crate::limits::Team;
+use crate::tests::*;
+use crate::types::*;
+
+fn silence_front_enemy() -> AbilityEffect {
+    AbilityEffect::Silence {
+        target: AbilityTarget::Position {
+            scope: TargetScope::Enemies,
+            index: 0,
+        },
+    }
+}
+
+/// An enemy that pings every player unit for 1 at the start of battle.
+fn start_pinger(attack: i16) -> CombatUnit {
+    CombatUnit::from_card(
+        create_dummy_card(2, "Pinger", attack, 20).with_battle_ability(create_ability(
+            AbilityTrigger::OnStart,
+            AbilityEffect::Damage {
+                amount: ValueExpr::Const(1),
+                target: AbilityTarget::All {
+                    scope: TargetScope::Enemies,
+                },
+            },
+        )),
+    )
+}
+
+#[test]
+fn test_silence_stops_on_faint_ability() {
+    let silencer = create_dummy_card(1, "Silencer", 1, 30).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        silence_front_enemy(),
+    ));
+    let bomb = create_dummy_card(2, "Bomb", 1, 1).with_battle_ability(create_ability(
+        AbilityTrigger::OnFaint,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(10),
+            target: AbilityTarget::All {
+                scope: TargetScope::Enemies,
+            },
+        },
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(silencer)],
+        &[CombatUnit::from_card(bomb)],
+        42,
+    );
+
+    assert!(events.iter().any(|e| matches!(
+        e,
+        CombatEvent::UnitSilenced {
+            source_instance_id,
+            target_instance_id,
+        } if *source_instance_id == UnitId::player(1)
+            && *target_instance_id == UnitId::enemy(1)
+    )));
+    assert!(
+        events.iter().any(|e| matches!(
+            e,
+            CombatEvent::UnitDeath {
+                team: Team::Enemy,
+                ..
+            }
+        )),
+        "Bomb should still die in combat"
+    );
+    assert!(!has_ability_trigger(&events, UnitId::enemy(1), 0));
+}
+
+/// A trigger the target queued in the same pass is dropped when the silence
+/// resolves first.
+#[test]
+fn test_silence_drops_trigger_already_queued() {
+    // Higher attack: the silencer's OnStart resolves before the pinger's.
+    let silencer = create_dummy_card(1, "Silencer", 5, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        silence_front_enemy(),
+    ));
+
+    let events = run_battle(&[CombatUnit::from_card(silencer)], &[start_pinger(1)], 42);
+
+    assert!(has_ability_trigger(&events, UnitId::player(1), 0));
+    assert!(!has_ability_trigger(&events, UnitId::enemy(1), 0));
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, CombatEvent::AbilityDamage { .. })));
+}
+
+/// A trigger that already resolved is not undone by a later silence.
+#[test]
+fn test_silence_after_trigger_resolved_keeps_its_effect() {
+    let silencer = create_dummy_card(1, "Silencer", 1, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        silence_front_enemy(),
+    ));
+
+    let events = run_battle(&[CombatUnit::from_card(silencer)], &[start_pinger(5)], 42);
+
+    let trigger_pos = events.iter().position(|e| {
+        matches!(
+            e,
+            CombatEvent::AbilityTrigger { source_instance_id, .. }
+                if *source_instance_id == UnitId::enemy(1)
+        )
+    });
+    let silence_pos = events
+        .iter()
+        .position(|e| matches!(e, CombatEvent::UnitSilenced { .. }));
+    assert!(trigger_pos.expect("Pinger fires first") < silence_pos.expect("Pinger is silenced"));
+    assert_eq!(count_ability_triggers(&events, UnitId::enemy(1), 0), 1);
+}
+
+#[test]
+fn test_silenced_unit_mimics_stats_without_abilities() {
+    let shifter = create_dummy_card(1, "Shifter", 1, 3).with_battle_ability(Ability {
+        then: vec![AbilityEffect::Mimic {
+            target: AbilityTarget::Position {
+                scope: TargetScope::Enemies,
+                index: 0,
+            },
+        }],
+        ..create_ability(
+            AbilityTrigger::OnStart,
+            AbilityEffect::Silence {
+                target: AbilityTarget::All {
+                    scope: TargetScope::SelfUnit,
+                },
+            },
+        )
+    });
+
+    let events = run_battle(&[CombatUnit::from_card(shifter)], &[start_pinger(0)], 42);
+
+    let transformed = events.iter().find_map(|e| match e {
+        CombatEvent::UnitTransform {
+            transformed_unit, ..
+        } => Some(transformed_unit),
+        _ => None,
+    });
+    let transformed = transformed.expect("Shifter should transform");
+    assert_eq!(transformed.card_id, CardId(2));
+    assert!(transformed.battle_abilities.is_empty());
+}
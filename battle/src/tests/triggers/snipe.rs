@@ -7,7 +7,7 @@ fn test_snipe_lowest_health() {
     let headhunter_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::Standard {
                 scope: TargetScope::Enemies,
                 stat: StatType::Health,
@@ -55,7 +55,7 @@ fn test_snipe_highest_attack() {
     let giantslayer_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 3,
+            amount: ValueExpr::Const(3),
             target: AbilityTarget::Standard {
                 scope: TargetScope::Enemies,
                 stat: StatType::Attack,
@@ -96,7 +96,7 @@ fn test_snipe_highest_health() {
     let snipe_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::Standard {
                 scope: TargetScope::Enemies,
                 stat: StatType::Health,
@@ -133,7 +133,7 @@ fn test_snipe_lowest_attack() {
     let snipe_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::Standard {
                 scope: TargetScope::Enemies,
                 stat: StatType::Attack,
@@ -170,7 +170,7 @@ fn test_snipe_highest_mana() {
     let snipe_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::Standard {
                 scope: TargetScope::Enemies,
                 stat: StatType::Mana,
@@ -208,7 +208,7 @@ fn test_snipe_lowest_mana() {
     let snipe_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::Standard {
                 scope: TargetScope::Enemies,
                 stat: StatType::Mana,
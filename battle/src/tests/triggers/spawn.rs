@@ -1,5 +1,5 @@
-use crate::battle::{CombatEvent, UnitId};
-use crate::limits::LimitReason;
+use crate::battle::{CombatEvent, UnitId, UnitView};
+use crate::limits::{LimitReason, Team};
 use crate::tests::*;
 use crate::types::*;
 
@@ -8,13 +8,16 @@ fn test_warder_seal_fate() {
     let warder = create_dummy_card(1, "Warder", 2, 4).with_battle_ability(Ability {
         trigger: AbilityTrigger::OnEnemySpawn,
         effect: AbilityEffect::Damage {
-            amount: 1,
+            amount: ValueExpr::Const(1),
             target: AbilityTarget::All {
                 scope: TargetScope::TriggerSource,
             },
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     });
 
     let rat_swarm = create_dummy_card(2, "Rat Swarm", 1, 1).with_battle_ability(Ability {
@@ -22,9 +25,15 @@ fn test_warder_seal_fate() {
         effect: AbilityEffect::SpawnUnit {
             card_id: CardId(40), // rat_token
             spawn_location: SpawnLocation::DeathPosition,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     });
 
     let p_board = vec![CombatUnit::from_card(warder)];
@@ -71,22 +80,31 @@ fn test_necromancer_spawn_boost() {
         effect: AbilityEffect::SpawnUnit {
             card_id: CardId(40), // rat_token
             spawn_location: SpawnLocation::DeathPosition,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     });
 
     let necromancer = create_dummy_card(2, "Necromancer", 2, 3).with_battle_ability(Ability {
         trigger: AbilityTrigger::OnAllySpawn,
         effect: AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 2,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(2),
             target: AbilityTarget::All {
                 scope: TargetScope::TriggerSource,
             },
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
+        max_triggers_per_round: None,
+        cooldown: None,
     });
 
     let p_board = vec![
@@ -139,6 +157,9 @@ fn test_spawn_index_preservation() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42), // zombie_spawn
             spawn_location: SpawnLocation::DeathPosition,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     );
 
@@ -155,7 +176,7 @@ fn test_spawn_index_preservation() {
     let aoe_killer = create_dummy_card(4, "AoE", 5, 10).with_battle_ability(create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::All {
                 scope: TargetScope::Enemies,
             },
@@ -200,6 +221,9 @@ fn test_sacrifice_combo() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(43), // golem
             spawn_location: SpawnLocation::Front,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     );
     let lich =
@@ -208,8 +232,8 @@ fn test_sacrifice_combo() {
     let cart_ability = create_ability(
         AbilityTrigger::OnAllyFaint,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 2,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(2),
             target: AbilityTarget::All {
                 scope: TargetScope::SelfUnit,
             },
@@ -253,6 +277,9 @@ fn test_damage_taken_no_slide_trigger() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42), // zombie_spawn
             spawn_location: SpawnLocation::Front,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     );
 
@@ -283,6 +310,9 @@ fn test_spawn_id_uniqueness_and_buffs() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42), // zombie_spawn
             spawn_location: SpawnLocation::Front,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     );
     let spawner = create_dummy_card(1, "Spawner", 10, 10)
@@ -291,8 +321,8 @@ fn test_spawn_id_uniqueness_and_buffs() {
     let buff_ability = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 0,
-            attack: 2,
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(2),
             target: AbilityTarget::All {
                 scope: TargetScope::Allies,
             },
@@ -343,6 +373,9 @@ fn test_spawn_limit_logic() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42), // zombie_spawn
             spawn_location: SpawnLocation::DeathPosition,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     );
 
@@ -387,6 +420,9 @@ fn test_missing_spawn_card_fizzles_without_panic() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(65535),
             spawn_location: SpawnLocation::Front,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     ));
 
@@ -416,6 +452,9 @@ fn test_necromancer_rebirth_chain_spawns_once_without_looping() {
             AbilityEffect::SpawnUnit {
                 card_id: CardId(108),
                 spawn_location: SpawnLocation::DeathPosition,
+                count: 1,
+                side: SpawnSide::Own,
+                stats: SpawnStats::Base,
             },
         ));
 
@@ -428,7 +467,7 @@ fn test_necromancer_rebirth_chain_spawns_once_without_looping() {
         create_ability(
             AbilityTrigger::OnStart,
             AbilityEffect::Damage {
-                amount: 4,
+                amount: ValueExpr::Const(4),
                 target: AbilityTarget::All {
                     scope: TargetScope::Enemies,
                 },
@@ -465,8 +504,14 @@ fn test_necromancer_rebirth_chain_spawns_once_without_looping() {
                 AbilityEffect::SpawnUnit {
                     card_id: CardId(110),
                     spawn_location: SpawnLocation::DeathPosition,
+                    count: 1,
+                    side: SpawnSide::Own,
+                    stats: SpawnStats::Base,
                 },
             )],
+            base_statuses: StatusMask::empty(),
+            tags: TagMask::empty(),
+            keywords: KeywordMask::empty(),
         },
     );
     card_pool.insert(
@@ -529,6 +574,9 @@ fn test_full_board_spawn_spam_does_not_leak_recursion_depth() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42),
             spawn_location: SpawnLocation::Front,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     );
 
@@ -595,6 +643,9 @@ fn test_spawn_location_front_on_faint() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42),
             spawn_location: SpawnLocation::Front,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     ));
 
@@ -610,7 +661,7 @@ fn test_spawn_location_front_on_faint() {
     let aoe = create_dummy_card(4, "AoE", 5, 10).with_battle_ability(create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::All {
                 scope: TargetScope::Enemies,
             },
@@ -637,6 +688,9 @@ fn test_spawn_location_back_on_faint() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42),
             spawn_location: SpawnLocation::Back,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     ));
 
@@ -652,7 +706,7 @@ fn test_spawn_location_back_on_faint() {
     let aoe = create_dummy_card(4, "AoE", 5, 10).with_battle_ability(create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::All {
                 scope: TargetScope::Enemies,
             },
@@ -679,6 +733,9 @@ fn test_spawn_location_death_position_on_faint() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42),
             spawn_location: SpawnLocation::DeathPosition,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     ));
 
@@ -694,7 +751,7 @@ fn test_spawn_location_death_position_on_faint() {
     let aoe = create_dummy_card(4, "AoE", 5, 10).with_battle_ability(create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::All {
                 scope: TargetScope::Enemies,
             },
@@ -724,6 +781,9 @@ fn test_spawn_location_front_on_start() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42),
             spawn_location: SpawnLocation::Front,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     ));
 
@@ -747,6 +807,9 @@ fn test_spawn_location_back_on_start() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42),
             spawn_location: SpawnLocation::Back,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     ));
 
@@ -770,6 +833,9 @@ fn test_spawn_location_death_position_falls_back_to_front_on_start() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42),
             spawn_location: SpawnLocation::DeathPosition,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     ));
 
@@ -800,6 +866,9 @@ fn test_spawn_location_death_position_on_ally_faint() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42),
             spawn_location: SpawnLocation::DeathPosition,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     ));
     let unit_d = create_dummy_card(4, "D", 0, 1); // will die from AoE
@@ -814,7 +883,7 @@ fn test_spawn_location_death_position_on_ally_faint() {
     let aoe = create_dummy_card(5, "AoE", 5, 10).with_battle_ability(create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::Damage {
-            amount: 5,
+            amount: ValueExpr::Const(5),
             target: AbilityTarget::All {
                 scope: TargetScope::Enemies,
             },
@@ -849,6 +918,9 @@ fn test_spawn_location_death_position_front_unit_dies() {
         AbilityEffect::SpawnUnit {
             card_id: CardId(42),
             spawn_location: SpawnLocation::DeathPosition,
+            count: 1,
+            side: SpawnSide::Own,
+            stats: SpawnStats::Base,
         },
     ));
     let backline = create_dummy_card(2, "Back", 5, 10);
@@ -872,3 +944,214 @@ fn test_spawn_location_death_position_front_unit_dies() {
     );
     assert_eq!(board[1], CardId(2), "Back should stay at position 1");
 }
+
+fn spawn_effect(
+    card_id: u16,
+    spawn_location: SpawnLocation,
+    count: CountValue,
+    side: SpawnSide,
+    stats: SpawnStats,
+) -> AbilityEffect {
+    AbilityEffect::SpawnUnit {
+        card_id: CardId(card_id),
+        spawn_location,
+        count,
+        side,
+        stats,
+    }
+}
+
+fn spawns(events: &[CombatEvent]) -> Vec<(Team, UnitView)> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::UnitSpawn {
+                team, spawned_unit, ..
+            } => Some((*team, spawned_unit.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_spawn_count_spawns_every_copy_until_board_full() {
+    let hive = create_dummy_card(1, "Hive", 0, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        spawn_effect(40, SpawnLocation::Back, 6, SpawnSide::Own, SpawnStats::Base),
+    ));
+    let p_board = vec![CombatUnit::from_card(hive)];
+    let e_board = vec![create_board_unit(2, "Wall", 0, 50)];
+
+    let events = run_battle_with_pool(&p_board, &e_board, 42, &spawn_test_card_pool());
+
+    let spawned = spawns(&events);
+    assert_eq!(spawned.len(), 4, "Only four copies fit next to the Hive");
+    assert!(spawned.iter().all(|(_, u)| u.card_id == CardId(40)));
+}
+
+fn spawn_limit_hit(events: &[CombatEvent]) -> bool {
+    events.iter().any(|e| {
+        matches!(
+            e,
+            CombatEvent::LimitExceeded {
+                reason: LimitReason::SpawnLimit { .. },
+                ..
+            }
+        )
+    })
+}
+
+/// Every copy that spawns records a spawn against the battle's limit.
+#[test]
+fn test_spawn_count_records_each_copy_against_spawn_limit() {
+    let hive = create_dummy_card(1, "Hive", 0, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        spawn_effect(40, SpawnLocation::Back, 3, SpawnSide::Own, SpawnStats::Base),
+    ));
+    let rules = crate::rules::BattleRules {
+        max_spawns_per_battle: 2,
+        ..BattleRules::default()
+    };
+
+    let events = resolve_battle(
+        vec![CombatUnit::from_card(hive)],
+        vec![create_board_unit(2, "Wall", 0, 50)],
+        &mut XorShiftRng::seed_from_u64(42),
+        &spawn_test_card_pool(),
+        &rules,
+    );
+
+    assert_eq!(spawns(&events).len(), 2);
+    assert!(spawn_limit_hit(&events));
+}
+
+/// A full board stops a multi-copy spawn after one fizzled copy, so a large
+/// count cannot exhaust the spawn limit.
+#[test]
+fn test_spawn_count_on_full_board_charges_one_copy() {
+    let hive = create_dummy_card(1, "Hive", 0, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        spawn_effect(
+            40,
+            SpawnLocation::Back,
+            CountValue::MAX,
+            SpawnSide::Own,
+            SpawnStats::Base,
+        ),
+    ));
+    let e_board = vec![create_board_unit(2, "Wall", 0, 50)];
+
+    // The Hive fills the four free slots, then stops.
+    let events = run_battle_with_pool(
+        &[CombatUnit::from_card(hive.clone())],
+        &e_board,
+        42,
+        &spawn_test_card_pool(),
+    );
+    assert_eq!(spawns(&events).len(), 4);
+    assert!(!spawn_limit_hit(&events));
+
+    // Already full: nothing spawns and the team does not lose on the limit.
+    let mut p_board = vec![CombatUnit::from_card(hive)];
+    p_board.extend((0..4).map(|i| create_board_unit(10 + i, "Filler", 0, 10)));
+    let events = run_battle_with_pool(&p_board, &e_board, 42, &spawn_test_card_pool());
+    assert!(spawns(&events).is_empty());
+    assert!(!spawn_limit_hit(&events));
+}
+
+#[test]
+fn test_spawn_on_opposing_board() {
+    let hexer = create_dummy_card(1, "Hexer", 1, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        spawn_effect(
+            40,
+            SpawnLocation::Front,
+            1,
+            SpawnSide::Opposing,
+            SpawnStats::Fixed {
+                attack: 0,
+                health: 3,
+            },
+        ),
+    ));
+    let p_board = vec![CombatUnit::from_card(hexer)];
+    let e_board = vec![create_board_unit(2, "Brute", 3, 10)];
+
+    let events = run_battle_with_pool(&p_board, &e_board, 42, &spawn_test_card_pool());
+
+    let spawned = spawns(&events);
+    assert_eq!(spawned.len(), 1);
+    let (team, unit) = &spawned[0];
+    assert_eq!(*team, Team::Enemy);
+    assert_eq!(unit.instance_id, UnitId::enemy(2));
+    assert_eq!((unit.attack, unit.health), (0, 3));
+    assert!(
+        events.iter().any(|e| matches!(
+            e,
+            CombatEvent::UnitSpawn { team: Team::Enemy, new_board_state, .. }
+                if new_board_state[0].instance_id == UnitId::enemy(2)
+        )),
+        "The curse token blocks the front of the enemy board"
+    );
+}
+
+#[test]
+fn test_spawn_inherits_dying_source_buffs() {
+    let mother = create_dummy_card(1, "Mother", 1, 1).with_battle_ability(create_ability(
+        AbilityTrigger::OnFaint,
+        spawn_effect(
+            40,
+            SpawnLocation::DeathPosition,
+            1,
+            SpawnSide::Own,
+            SpawnStats::InheritBuffs,
+        ),
+    ));
+    let mut mother = CombatUnit::from_card(mother);
+    mother.attack_buff = 2;
+    mother.health_buff = 3;
+    mother.health = 4;
+    let e_board = vec![create_board_unit(2, "Brute", 10, 50)];
+
+    let events = run_battle_with_pool(&[mother], &e_board, 42, &spawn_test_card_pool());
+
+    let spawned = spawns(&events);
+    assert_eq!(spawned.len(), 1);
+    assert_eq!((spawned[0].1.attack, spawned[0].1.health), (3, 4));
+}
+
+#[test]
+fn test_spawn_adjacent_to_source() {
+    let summoner = create_dummy_card(1, "Summoner", 1, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        spawn_effect(
+            43,
+            SpawnLocation::AdjacentToSource,
+            1,
+            SpawnSide::Own,
+            SpawnStats::Base,
+        ),
+    ));
+    let p_board = vec![
+        create_board_unit(2, "Front", 1, 10),
+        CombatUnit::from_card(summoner),
+        create_board_unit(3, "Back", 1, 10),
+    ];
+    let e_board = vec![create_board_unit(4, "Wall", 0, 50)];
+
+    let events = run_battle_with_pool(&p_board, &e_board, 42, &spawn_test_card_pool());
+
+    let board = events
+        .iter()
+        .find_map(|e| match e {
+            CombatEvent::UnitSpawn {
+                new_board_state, ..
+            } => Some(new_board_state),
+            _ => None,
+        })
+        .expect("Summoner should spawn a Golem");
+    assert_eq!(
+        board.iter().map(|u| u.card_id).collect::<Vec<_>>(),
+        vec![CardId(2), CardId(1), CardId(43), CardId(3)]
+    );
+}
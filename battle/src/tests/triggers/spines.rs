@@ -7,7 +7,7 @@ fn test_spined_urchin_retribution() {
     let spines_ability = create_ability(
         AbilityTrigger::OnHurt,
         AbilityEffect::Damage {
-            amount: 1,
+            amount: ValueExpr::Const(1),
             target: AbilityTarget::All {
                 scope: TargetScope::TriggerSource,
             },
@@ -62,7 +62,7 @@ fn test_spined_urchin_self_harm_retribution() {
     let bomber_ability = create_ability(
         AbilityTrigger::OnFaint,
         AbilityEffect::Damage {
-            amount: 3,
+            amount: ValueExpr::Const(3),
             target: AbilityTarget::All {
                 scope: TargetScope::All,
             },
@@ -71,7 +71,7 @@ fn test_spined_urchin_self_harm_retribution() {
     let spines_ability = create_ability(
         AbilityTrigger::OnHurt,
         AbilityEffect::Damage {
-            amount: 1,
+            amount: ValueExpr::Const(1),
             target: AbilityTarget::All {
                 scope: TargetScope::TriggerSource,
             },
@@ -0,0 +1,376 @@
+use crate::battle::{
+    permanent_status_deltas_from_events, BattleResult, CombatEvent, CombatUnit, Team, UnitId,
+};
+use crate::tests::*;
+use crate::types::*;
+
+fn with_statuses(card: UnitCard, statuses: &[Status]) -> CombatUnit {
+    CombatUnit::from_card(card.with_base_statuses(StatusMask::from_statuses(statuses)))
+}
+
+fn first_damage_taken(events: &[CombatEvent], target: UnitId) -> Option<i16> {
+    events.iter().find_map(|e| match e {
+        CombatEvent::DamageTaken {
+            target_instance_id,
+            remaining_hp,
+            ..
+        } if *target_instance_id == target => Some(*remaining_hp),
+        _ => None,
+    })
+}
+
+fn count_consumed(events: &[CombatEvent], target: UnitId, status: Status) -> usize {
+    events
+        .iter()
+        .filter(|e| {
+            matches!(
+                e,
+                CombatEvent::StatusConsumed { target_instance_id, status: s }
+                    if *target_instance_id == target && *s == status
+            )
+        })
+        .count()
+}
+
+fn damaged_ids(events: &[CombatEvent]) -> Vec<UnitId> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::AbilityDamage {
+                target_instance_id, ..
+            } => Some(*target_instance_id),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Shield absorbs the first clash hit entirely and is consumed once.
+#[test]
+fn test_shield_blocks_first_clash_hit() {
+    let p_board = vec![with_statuses(
+        create_dummy_card(1, "Shielded", 1, 5),
+        &[Status::Shield],
+    )];
+    let e_board = vec![create_board_unit(2, "Hitter", 3, 20)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    assert_eq!(first_damage_taken(&events, UnitId::player(1)), Some(5));
+    assert_eq!(
+        count_consumed(&events, UnitId::player(1), Status::Shield),
+        1
+    );
+
+    // The second clash lands normally.
+    let second_hit = events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::DamageTaken {
+                target_instance_id,
+                remaining_hp,
+                ..
+            } if *target_instance_id == UnitId::player(1) => Some(*remaining_hp),
+            _ => None,
+        })
+        .nth(1);
+    assert_eq!(second_hit, Some(2));
+}
+
+/// Shield also blocks ability damage, and a blocked hit does not fire OnHurt.
+#[test]
+fn test_shield_blocks_ability_damage_without_on_hurt() {
+    let on_hurt = create_ability(
+        AbilityTrigger::OnHurt,
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Const(5),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    );
+    let sniper = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(3),
+            target: AbilityTarget::Position {
+                scope: TargetScope::Enemies,
+                index: 0,
+            },
+        },
+    );
+
+    let p_board = vec![with_statuses(
+        create_dummy_card(1, "Shielded", 1, 5).with_battle_ability(on_hurt),
+        &[Status::Shield],
+    )];
+    let e_board = vec![CombatUnit::from_card(
+        create_dummy_card(2, "Sniper", 0, 20).with_battle_ability(sniper),
+    )];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    assert_eq!(
+        count_consumed(&events, UnitId::player(1), Status::Shield),
+        1
+    );
+    assert!(
+        !has_ability_trigger(&events, UnitId::player(1), 0),
+        "a shield-blocked hit must not fire OnHurt"
+    );
+    assert!(
+        !damaged_ids(&events).contains(&UnitId::player(1)),
+        "a blocked hit should not be reported as ability damage"
+    );
+}
+
+/// Destroy is not damage, so Shield does not stop it.
+#[test]
+fn test_destroy_ignores_shield() {
+    let destroy = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Destroy {
+            target: AbilityTarget::Position {
+                scope: TargetScope::Enemies,
+                index: 0,
+            },
+        },
+    );
+
+    let p_board = vec![CombatUnit::from_card(
+        create_dummy_card(1, "Assassin", 1, 5).with_battle_ability(destroy),
+    )];
+    let e_board = vec![
+        with_statuses(create_dummy_card(2, "Shielded", 1, 50), &[Status::Shield]),
+        create_board_unit(3, "Backline", 1, 1),
+    ];
+
+    let events = run_battle(&p_board, &e_board, 42);
+
+    assert_eq!(count_consumed(&events, UnitId::enemy(1), Status::Shield), 0);
+    assert!(events.iter().any(|e| matches!(
+        e,
+        CombatEvent::AbilityDestroy { target_instance_id, .. }
+            if *target_instance_id == UnitId::enemy(1)
+    )));
+}
+
+/// Poison makes any positive clash damage lethal, unless a Shield absorbs it.
+#[test]
+fn test_poison_clash_damage_is_lethal_unless_shielded() {
+    let p_board = vec![with_statuses(
+        create_dummy_card(1, "Viper", 1, 10),
+        &[Status::Poison],
+    )];
+
+    let events = run_battle(&p_board, &[create_board_unit(2, "Tank", 1, 50)], 42);
+    assert_eq!(first_damage_taken(&events, UnitId::enemy(1)), Some(0));
+    assert!(events.iter().any(|e| matches!(
+        e,
+        CombatEvent::BattleEnd {
+            result: BattleResult::Victory
+        }
+    )));
+
+    let shielded_tank = with_statuses(create_dummy_card(2, "Tank", 1, 50), &[Status::Shield]);
+    let events = run_battle(&p_board, &[shielded_tank], 42);
+    assert_eq!(first_damage_taken(&events, UnitId::enemy(1)), Some(50));
+    assert_eq!(count_consumed(&events, UnitId::enemy(1), Status::Shield), 1);
+    // Shield is gone after the first hit, so the second poisoned clash kills.
+    let second_hit = events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::DamageTaken {
+                target_instance_id,
+                remaining_hp,
+                ..
+            } if *target_instance_id == UnitId::enemy(1) => Some(*remaining_hp),
+            _ => None,
+        })
+        .nth(1);
+    assert_eq!(second_hit, Some(0));
+}
+
+/// Poison does not apply to ability damage.
+#[test]
+fn test_poison_does_not_affect_ability_damage() {
+    let ping = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(1),
+            target: AbilityTarget::Position {
+                scope: TargetScope::Enemies,
+                index: 0,
+            },
+        },
+    );
+    let p_board = vec![with_statuses(
+        create_dummy_card(1, "Viper", 0, 10).with_battle_ability(ping),
+        &[Status::Poison],
+    )];
+    let e_board = vec![create_board_unit(2, "Tank", 0, 50)];
+
+    let events = run_battle(&p_board, &e_board, 42);
+    assert!(events.iter().any(|e| matches!(
+        e,
+        CombatEvent::AbilityDamage { target_instance_id, remaining_hp: 49, .. }
+            if *target_instance_id == UnitId::enemy(1)
+    )));
+}
+
+fn guard_test_enemies() -> Vec<CombatUnit> {
+    vec![
+        create_board_unit(10, "Front", 0, 10),
+        with_statuses(create_dummy_card(11, "Guardian", 0, 10), &[Status::Guard]),
+        create_board_unit(12, "Back", 0, 10),
+    ]
+}
+
+fn run_guard_selector(target: AbilityTarget) -> Vec<UnitId> {
+    let attacker = create_dummy_card(1, "Attacker", 0, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(1),
+            target,
+        },
+    ));
+    let events = run_battle(
+        &[CombatUnit::from_card(attacker)],
+        &guard_test_enemies(),
+        42,
+    );
+    damaged_ids(&events)
+}
+
+/// Enemy-scoped Position, Random and Standard selectors must pick a Guard unit.
+#[test]
+fn test_guard_redirects_enemy_selectors() {
+    let guardian = UnitId::enemy(2);
+
+    let position = run_guard_selector(AbilityTarget::Position {
+        scope: TargetScope::Enemies,
+        index: 0,
+    });
+    assert_eq!(position, vec![guardian]);
+
+    let random = run_guard_selector(AbilityTarget::Random {
+        scope: TargetScope::Enemies,
+        count: 2,
+    });
+    assert_eq!(random, vec![guardian]);
+
+    let standard = run_guard_selector(AbilityTarget::Standard {
+        scope: TargetScope::Enemies,
+        stat: StatType::Health,
+        order: SortOrder::Ascending,
+        count: 1,
+    });
+    assert_eq!(standard, vec![guardian]);
+}
+
+/// `All { scope: Enemies }` is not redirected by Guard.
+#[test]
+fn test_guard_does_not_filter_all_enemies() {
+    let all = run_guard_selector(AbilityTarget::All {
+        scope: TargetScope::Enemies,
+    });
+    assert_eq!(all.len(), 3);
+}
+
+/// Guard only restricts enemy scopes; allied selectors are unaffected.
+#[test]
+fn test_guard_ignored_for_ally_scopes() {
+    let buff = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(1),
+            attack: ValueExpr::Const(0),
+            target: AbilityTarget::Position {
+                scope: TargetScope::Allies,
+                index: 1,
+            },
+        },
+    );
+    let p_board = vec![
+        CombatUnit::from_card(create_dummy_card(1, "Buffer", 0, 10).with_battle_ability(buff)),
+        create_board_unit(2, "Plain", 0, 10),
+        with_statuses(create_dummy_card(3, "Guardian", 0, 10), &[Status::Guard]),
+    ];
+
+    let events = run_battle(&p_board, &[create_board_unit(4, "Dummy", 0, 10)], 42);
+    assert!(events.iter().any(|e| matches!(
+        e,
+        CombatEvent::AbilityModifyStats { target_instance_id, .. }
+            if *target_instance_id == UnitId::player(2)
+    )));
+}
+
+/// This-battle and permanent grants both activate the status; only permanent
+/// grants are reported as persistent deltas.
+#[test]
+fn test_grant_status_layers_and_permanent_deltas() {
+    let grant_battle = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::GrantStatusThisBattle {
+            status: Status::Shield,
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    );
+    let grant_perm = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::GrantStatusPermanent {
+            status: Status::Guard,
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    );
+
+    let unit =
+        create_dummy_card(1, "Blessed", 1, 5).with_battle_abilities(vec![grant_battle, grant_perm]);
+    let e_board = vec![create_board_unit(2, "Hitter", 2, 20)];
+
+    let events = run_battle(&[CombatUnit::from_card(unit)], &e_board, 42);
+
+    // The this-battle shield blocks the first clash.
+    assert_eq!(first_damage_taken(&events, UnitId::player(1)), Some(5));
+
+    let deltas = permanent_status_deltas_from_events(&events, Team::Player);
+    let (granted, removed) = deltas.get(&UnitId::player(1)).copied().unwrap();
+    assert!(granted.contains(Status::Guard));
+    assert!(!granted.contains(Status::Shield));
+    assert!(removed.is_empty());
+}
+
+/// RemoveStatusPermanent only reports a removal when the permanent layer had it.
+#[test]
+fn test_remove_status_permanent_reports_delta() {
+    let strip = create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::RemoveStatusPermanent {
+            status: Status::Guard,
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    );
+    let mut unit = CombatUnit::from_card(
+        create_dummy_card(1, "Veteran", 1, 5).with_battle_ability(strip.clone()),
+    );
+    unit.permanent_statuses.insert(Status::Guard);
+    let e_board = vec![create_board_unit(2, "Dummy", 0, 20)];
+
+    let events = run_battle(&[unit], &e_board, 42);
+    let deltas = permanent_status_deltas_from_events(&events, Team::Player);
+    let (granted, removed) = deltas.get(&UnitId::player(1)).copied().unwrap();
+    assert!(granted.is_empty());
+    assert!(removed.contains(Status::Guard));
+
+    // A unit without the permanent status reports nothing.
+    let plain =
+        CombatUnit::from_card(create_dummy_card(1, "Rookie", 1, 5).with_battle_ability(strip));
+    let events = run_battle(&[plain], &e_board, 42);
+    assert!(permanent_status_deltas_from_events(&events, Team::Player).is_empty());
+}
@@ -0,0 +1,147 @@
+use crate::battle::{CombatEvent, CombatUnit, UnitId};
+use crate::limits::Team;
+use crate::rng::XorShiftRng;
+use crate::tests::*;
+use crate::types::*;
+
+fn steal_front_enemy() -> AbilityEffect {
+    AbilityEffect::Steal {
+        target: AbilityTarget::Position {
+            scope: TargetScope::Enemies,
+            index: 0,
+        },
+    }
+}
+
+#[test]
+fn test_steal_moves_enemy_to_source_board() {
+    let thief = create_dummy_card(1, "Thief", 1, 10)
+        .with_battle_ability(create_ability(AbilityTrigger::OnStart, steal_front_enemy()));
+    let e_board = vec![
+        create_board_unit(2, "Brute", 4, 4),
+        create_board_unit(3, "Wall", 1, 20),
+    ];
+
+    let events = run_battle(&[CombatUnit::from_card(thief)], &e_board, 42);
+
+    let stolen = events.iter().find_map(|e| match e {
+        CombatEvent::UnitStolen {
+            source_instance_id,
+            old_instance_id,
+            new_instance_id,
+            team,
+            player_board_state,
+            enemy_board_state,
+        } => Some((
+            *source_instance_id,
+            *old_instance_id,
+            *new_instance_id,
+            *team,
+            player_board_state.clone(),
+            enemy_board_state.clone(),
+        )),
+        _ => None,
+    });
+    let (source, old_id, new_id, team, player_board, enemy_board) =
+        stolen.expect("Thief should steal the Brute");
+
+    assert_eq!(source, UnitId::player(1));
+    assert_eq!(old_id, UnitId::enemy(1));
+    assert_eq!(new_id, UnitId::player(2), "Stolen unit gets a player ID");
+    assert_eq!(team, Team::Player);
+    assert_eq!(
+        player_board
+            .iter()
+            .map(|u| (u.instance_id, u.card_id))
+            .collect::<Vec<_>>(),
+        vec![(UnitId::player(1), CardId(1)), (new_id, CardId(2))],
+        "Stolen unit joins the back of the thief's board"
+    );
+    assert_eq!(
+        enemy_board.iter().map(|u| u.card_id).collect::<Vec<_>>(),
+        vec![CardId(3)]
+    );
+}
+
+#[test]
+fn test_steal_respects_board_size() {
+    let thief = create_dummy_card(1, "Thief", 1, 10)
+        .with_battle_ability(create_ability(AbilityTrigger::OnStart, steal_front_enemy()));
+    let p_board = vec![
+        CombatUnit::from_card(thief),
+        create_board_unit(2, "Ally", 1, 10),
+    ];
+    let e_board = vec![create_board_unit(3, "Brute", 4, 4)];
+
+    let mut rng = XorShiftRng::seed_from_u64(42);
+    let events = resolve_battle(
+        p_board,
+        e_board,
+        &mut rng,
+        &empty_card_pool(),
+        &BattleRules::default().with_board_size(2),
+    );
+
+    assert!(has_ability_trigger(&events, UnitId::player(1), 0));
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, CombatEvent::UnitStolen { .. })));
+}
+
+#[test]
+fn test_steal_ignores_allies() {
+    let thief = create_dummy_card(1, "Thief", 1, 10).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Steal {
+            target: AbilityTarget::All {
+                scope: TargetScope::AlliesOther,
+            },
+        },
+    ));
+    let p_board = vec![
+        CombatUnit::from_card(thief),
+        create_board_unit(2, "Ally", 1, 10),
+    ];
+
+    let events = run_battle(&p_board, &[create_board_unit(3, "Wall", 1, 20)], 42);
+
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, CombatEvent::UnitStolen { .. })));
+}
+
+/// The stolen unit's abilities are re-registered under its new ID and
+/// resolve for its new team.
+#[test]
+fn test_stolen_unit_triggers_for_new_team() {
+    let thief = create_dummy_card(1, "Thief", 1, 10)
+        .with_battle_ability(create_ability(AbilityTrigger::OnStart, steal_front_enemy()));
+    let banker = create_dummy_card(2, "Banker", 1, 5).with_battle_ability(create_ability(
+        AbilityTrigger::BeforeAnyAttack,
+        AbilityEffect::GainMana {
+            amount: ValueExpr::Const(1),
+        },
+    ));
+    let e_board = vec![
+        CombatUnit::from_card(banker),
+        create_board_unit(3, "Wall", 1, 20),
+    ];
+
+    let events = run_battle(&[CombatUnit::from_card(thief)], &e_board, 42);
+
+    let mana_teams: Vec<(UnitId, Team)> = events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::AbilityGainMana {
+                source_instance_id,
+                team,
+                ..
+            } => Some((*source_instance_id, *team)),
+            _ => None,
+        })
+        .collect();
+    assert!(!mana_teams.is_empty(), "Stolen Banker should still fire");
+    assert!(mana_teams
+        .iter()
+        .all(|(id, team)| *id == UnitId::player(2) && *team == Team::Player));
+}
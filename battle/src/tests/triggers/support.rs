@@ -7,8 +7,8 @@ fn test_shield_squire_support() {
     let squire_ability = create_ability(
         AbilityTrigger::BeforeAnyAttack,
         AbilityEffect::ModifyStats {
-            health: 2,
-            attack: 0,
+            health: ValueExpr::Const(2),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::Position {
                 scope: TargetScope::SelfUnit,
                 index: -1,
@@ -41,8 +41,8 @@ fn test_nurse_goblin_heals_only_when_ally_hp_is_six_or_less() {
         create_dummy_card(2, "Nurse Goblin", 1, 3).with_battle_ability(Ability {
             trigger: AbilityTrigger::BeforeAnyAttack,
             effect: AbilityEffect::ModifyStats {
-                health: 2,
-                attack: 0,
+                health: ValueExpr::Const(2),
+                attack: ValueExpr::Const(0),
                 target: AbilityTarget::Position {
                     scope: TargetScope::Allies,
                     index: 0,
@@ -58,6 +58,9 @@ fn test_nurse_goblin_heals_only_when_ally_hp_is_six_or_less() {
                 value: 6,
             })],
             max_triggers: None,
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         })
     };
 
@@ -0,0 +1,173 @@
+use crate::battle::{CombatEvent, CombatUnit, UnitId};
+use crate::tests::*;
+use crate::types::*;
+
+fn tagged(id: u16, name: &str, atk: i16, hp: i16, tag: Tag) -> UnitCard {
+    create_dummy_card(id, name, atk, hp).with_tags(TagMask::from_tags(&[tag]))
+}
+
+fn modified_units(events: &[CombatEvent]) -> Vec<UnitId> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::AbilityModifyStats {
+                target_instance_id, ..
+            } => Some(*target_instance_id),
+            _ => None,
+        })
+        .collect()
+}
+
+fn self_buff() -> AbilityEffect {
+    AbilityEffect::ModifyStats {
+        health: ValueExpr::Const(1),
+        attack: ValueExpr::Const(1),
+        target: AbilityTarget::All {
+            scope: TargetScope::SelfUnit,
+        },
+    }
+}
+
+#[test]
+fn test_has_tag_condition() {
+    let mut ability = create_ability(AbilityTrigger::OnStart, self_buff());
+    ability.conditions = vec![Condition::Is(Matcher::HasTag {
+        scope: TargetScope::AlliesOther,
+        tag: Tag::Insect,
+    })];
+    let queen = create_dummy_card(1, "Queen", 1, 5).with_battle_ability(ability);
+    let enemies = vec![create_board_unit(9, "Dummy", 0, 10)];
+
+    let with_insect = vec![
+        CombatUnit::from_card(queen.clone()),
+        CombatUnit::from_card(tagged(2, "Ant", 1, 1, Tag::Insect)),
+    ];
+    let events = run_battle(&with_insect, &enemies, 42);
+    assert_eq!(modified_units(&events), vec![UnitId::player(1)]);
+
+    let with_beast = vec![
+        CombatUnit::from_card(queen),
+        CombatUnit::from_card(tagged(2, "Wolf", 1, 1, Tag::Beast)),
+    ];
+    let events = run_battle(&with_beast, &enemies, 42);
+    assert!(modified_units(&events).is_empty());
+}
+
+#[test]
+fn test_tag_count_condition() {
+    let mut ability = create_ability(AbilityTrigger::OnStart, self_buff());
+    ability.conditions = vec![Condition::Is(Matcher::TagCount {
+        scope: TargetScope::Allies,
+        tag: Tag::Undead,
+        op: CompareOp::GreaterThanOrEqual,
+        value: 2,
+    })];
+    let lich = tagged(1, "Lich", 1, 5, Tag::Undead).with_battle_ability(ability);
+    let enemies = vec![create_board_unit(9, "Dummy", 0, 10)];
+
+    let alone = vec![CombatUnit::from_card(lich.clone())];
+    assert!(modified_units(&run_battle(&alone, &enemies, 42)).is_empty());
+
+    let paired = vec![
+        CombatUnit::from_card(lich),
+        CombatUnit::from_card(tagged(2, "Skeleton", 1, 1, Tag::Undead)),
+    ];
+    assert_eq!(
+        modified_units(&run_battle(&paired, &enemies, 42)),
+        vec![UnitId::player(1)]
+    );
+}
+
+#[test]
+fn test_all_with_tag_targets_only_tagged_units() {
+    let hive = create_dummy_card(1, "Hive", 1, 5).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(1),
+            attack: ValueExpr::Const(1),
+            target: AbilityTarget::AllWithTag {
+                scope: TargetScope::Allies,
+                tag: Tag::Insect,
+            },
+        },
+    ));
+    let player = vec![
+        CombatUnit::from_card(hive),
+        CombatUnit::from_card(tagged(2, "Ant", 1, 1, Tag::Insect)),
+        CombatUnit::from_card(tagged(3, "Wolf", 1, 1, Tag::Beast)),
+        CombatUnit::from_card(tagged(4, "Bee", 1, 1, Tag::Insect)),
+    ];
+
+    let events = run_battle(&player, &[create_board_unit(9, "Dummy", 0, 10)], 42);
+    assert_eq!(
+        modified_units(&events),
+        vec![UnitId::player(2), UnitId::player(4)]
+    );
+}
+
+#[test]
+fn test_random_with_tag_never_picks_untagged_units() {
+    let hunter = create_dummy_card(1, "Hunter", 1, 5).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: ValueExpr::Const(1),
+            target: AbilityTarget::RandomWithTag {
+                scope: TargetScope::Enemies,
+                tag: Tag::Beast,
+                count: 2,
+            },
+        },
+    ));
+    let enemies = vec![
+        create_board_unit(2, "Knight", 0, 10),
+        CombatUnit::from_card(tagged(3, "Wolf", 0, 10, Tag::Beast)),
+        create_board_unit(4, "Mage", 0, 10),
+    ];
+
+    for seed in [1, 7, 42] {
+        let events = run_battle(&[CombatUnit::from_card(hunter.clone())], &enemies, seed);
+        let hits: Vec<UnitId> = events
+            .iter()
+            .filter_map(|e| match e {
+                CombatEvent::AbilityDamage {
+                    target_instance_id, ..
+                } => Some(*target_instance_id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(hits, vec![UnitId::enemy(2)], "seed {seed}");
+    }
+}
+
+#[test]
+fn test_value_scales_with_tag_count() {
+    let swarm = create_dummy_card(1, "Swarm", 1, 5).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(0),
+            attack: ValueExpr::Scaled {
+                term: ValueTerm::TagCount {
+                    scope: TargetScope::Allies,
+                    tag: Tag::Insect,
+                },
+                factor: 1,
+            },
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    ));
+    let player = vec![
+        CombatUnit::from_card(swarm),
+        CombatUnit::from_card(tagged(2, "Ant", 1, 1, Tag::Insect)),
+        CombatUnit::from_card(tagged(3, "Bee", 1, 1, Tag::Insect)),
+        CombatUnit::from_card(tagged(4, "Wolf", 1, 1, Tag::Beast)),
+    ];
+
+    let events = run_battle(&player, &[create_board_unit(9, "Dummy", 0, 10)], 42);
+    let change = events.iter().find_map(|e| match e {
+        CombatEvent::AbilityModifyStats { attack_change, .. } => Some(*attack_change),
+        _ => None,
+    });
+    assert_eq!(change, Some(2));
+}
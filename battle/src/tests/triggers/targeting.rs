@@ -7,8 +7,8 @@ fn test_targeting_logic_front_ally() {
     let buff = create_ability(
         AbilityTrigger::OnStart,
         AbilityEffect::ModifyStats {
-            health: 5,
-            attack: 0,
+            health: ValueExpr::Const(5),
+            attack: ValueExpr::Const(0),
             target: AbilityTarget::Position {
                 scope: TargetScope::Allies,
                 index: 0,
@@ -0,0 +1,179 @@
+use crate::battle::{CombatEvent, CombatUnit, UnitId};
+use crate::tests::*;
+use crate::types::*;
+
+fn scaled(term: ValueTerm, factor: StatValue) -> ValueExpr {
+    ValueExpr::Scaled { term, factor }
+}
+
+fn damage_dealt(events: &[CombatEvent], source: UnitId) -> Vec<StatValue> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::AbilityDamage {
+                source_instance_id,
+                damage,
+                ..
+            } if *source_instance_id == source => Some(*damage),
+            _ => None,
+        })
+        .collect()
+}
+
+fn attack_changes(events: &[CombatEvent]) -> Vec<StatValue> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            CombatEvent::AbilityModifyStats { attack_change, .. } => Some(*attack_change),
+            _ => None,
+        })
+        .collect()
+}
+
+fn front_enemy() -> AbilityTarget {
+    AbilityTarget::Position {
+        scope: TargetScope::Enemies,
+        index: 0,
+    }
+}
+
+#[test]
+fn test_damage_equal_to_own_attack() {
+    let striker = create_dummy_card(1, "Striker", 4, 5).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::Damage {
+            amount: scaled(
+                ValueTerm::Stat {
+                    scope: TargetScope::SelfUnit,
+                    stat: StatType::Attack,
+                },
+                1,
+            ),
+            target: front_enemy(),
+        },
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(striker)],
+        &[create_board_unit(2, "Dummy", 0, 10)],
+        42,
+    );
+    assert_eq!(damage_dealt(&events, UnitId::player(1)), vec![4]);
+}
+
+#[test]
+fn test_buff_scales_with_ally_count() {
+    let rallier = create_dummy_card(1, "Rallier", 1, 5).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::ModifyStats {
+            health: scaled(
+                ValueTerm::UnitCount {
+                    scope: TargetScope::AlliesOther,
+                },
+                1,
+            ),
+            attack: scaled(
+                ValueTerm::UnitCount {
+                    scope: TargetScope::AlliesOther,
+                },
+                2,
+            ),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    ));
+    let player = vec![
+        CombatUnit::from_card(rallier),
+        create_board_unit(2, "Ally", 1, 1),
+        create_board_unit(3, "Ally", 1, 1),
+    ];
+
+    let events = run_battle(&player, &[create_board_unit(4, "Dummy", 0, 10)], 42);
+    let modify = events
+        .iter()
+        .find_map(|e| match e {
+            CombatEvent::AbilityModifyStats {
+                health_change,
+                attack_change,
+                ..
+            } => Some((*health_change, *attack_change)),
+            _ => None,
+        })
+        .expect("buff should resolve");
+    assert_eq!(modify, (2, 4));
+}
+
+#[test]
+fn test_trigger_count_grows_each_activation() {
+    let grower = create_dummy_card(1, "Grower", 1, 50).with_battle_ability(create_ability(
+        AbilityTrigger::AfterUnitAttack,
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(0),
+            attack: scaled(ValueTerm::TriggerCount, 1),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(grower)],
+        &[create_board_unit(2, "Wall", 0, 12)],
+        42,
+    );
+    let changes = attack_changes(&events);
+    assert!(changes.len() >= 3);
+    assert_eq!(&changes[..3], &[1, 2, 3]);
+}
+
+#[test]
+fn test_fainted_source_reads_its_last_stats() {
+    let martyr = create_dummy_card(1, "Martyr", 3, 1).with_battle_ability(create_ability(
+        AbilityTrigger::OnFaint,
+        AbilityEffect::Damage {
+            amount: scaled(
+                ValueTerm::Stat {
+                    scope: TargetScope::SelfUnit,
+                    stat: StatType::Attack,
+                },
+                2,
+            ),
+            target: front_enemy(),
+        },
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(martyr)],
+        &[create_board_unit(2, "Brute", 5, 20)],
+        42,
+    );
+    assert_eq!(damage_dealt(&events, UnitId::player(1)), vec![6]);
+}
+
+#[test]
+fn test_scaled_values_saturate() {
+    let giant = create_dummy_card(1, "Giant", 1, 5).with_battle_ability(create_ability(
+        AbilityTrigger::OnStart,
+        AbilityEffect::ModifyStats {
+            health: ValueExpr::Const(0),
+            attack: scaled(
+                ValueTerm::Stat {
+                    scope: TargetScope::SelfUnit,
+                    stat: StatType::Health,
+                },
+                StatValue::MAX,
+            ),
+            target: AbilityTarget::All {
+                scope: TargetScope::SelfUnit,
+            },
+        },
+    ));
+
+    let events = run_battle(
+        &[CombatUnit::from_card(giant)],
+        &[create_board_unit(2, "Dummy", 0, 1)],
+        42,
+    );
+    assert_eq!(attack_changes(&events)[0], StatValue::MAX);
+}
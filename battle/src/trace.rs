@@ -0,0 +1,433 @@
+//! Trigger and condition tracing
+//!
+//! When a card does not behave as designed, the trace shows why: every
+//! trigger captured in battle with its priority, every matcher evaluated with
+//! the values it compared, the result of every `AnyOf`, `AllOf` and `Not`
+//! around them, and the reason each trigger that did not fire was
+//! skipped. Shop triggers are traced the same way.
+//!
+//! Tracing is compiled in with the `trace` feature and only records inside
+//! `capture`. Without the feature every hook is a no-op and the entries are
+//! never built.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::battle::UnitId;
+use crate::types::{CardId, CountValue, Matcher, ShopMatcher, ShopTrigger, StatType, StatValue};
+
+/// A unit a matcher looked at: a battle unit, or a shop board slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "type", content = "data"))]
+pub enum TraceUnit {
+    Unit(UnitId),
+    Slot(usize),
+}
+
+impl From<UnitId> for TraceUnit {
+    fn from(id: UnitId) -> Self {
+        Self::Unit(id)
+    }
+}
+
+/// A value a matcher compared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "type", content = "data"))]
+pub enum Operand {
+    Stat {
+        unit: TraceUnit,
+        stat: StatType,
+        value: StatValue,
+    },
+    MissingHealth {
+        unit: TraceUnit,
+        value: StatValue,
+    },
+    HasTag {
+        unit: TraceUnit,
+        has_tag: bool,
+    },
+    /// The unit at the position the matcher asked for, if any.
+    Position {
+        index: usize,
+        unit: Option<TraceUnit>,
+    },
+    Count(CountValue),
+}
+
+/// How a composite condition combines its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Combinator {
+    AnyOf,
+    AllOf,
+    Not,
+}
+
+/// Why a captured trigger did not fire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum SkipReason {
+    /// The source was silenced after the trigger was captured.
+    Silenced,
+    /// The source's abilities changed and the captured one is gone.
+    AbilityGone,
+    /// The ability already fired `max_triggers` times this battle.
+    MaxTriggers,
+    /// The ability already fired `max_triggers_per_round` times this round.
+    MaxTriggersPerRound,
+    /// The ability has conditions and its source is no longer in the battle.
+    SourceGone,
+    ConditionsFailed,
+    /// The ability's cooldown absorbed this activation.
+    Cooldown,
+}
+
+/// One traced step of trigger resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "type", content = "data"))]
+pub enum TraceEntry {
+    /// A battle trigger was captured. The triggers of one queue are listed
+    /// together, in the order they resolve.
+    TriggerQueued {
+        source: UnitId,
+        ability_index: usize,
+        attack: StatValue,
+        health: StatValue,
+        position: usize,
+        tiebreaker: u16,
+        ability_order: usize,
+    },
+    TriggerFired {
+        source: UnitId,
+        ability_index: usize,
+    },
+    TriggerSkipped {
+        source: UnitId,
+        ability_index: usize,
+        reason: SkipReason,
+    },
+    /// A battle matcher was evaluated. Operands are listed until the matcher
+    /// found a passing unit.
+    Condition {
+        source: UnitId,
+        matcher: Matcher,
+        operands: Vec<Operand>,
+        passed: bool,
+    },
+    /// A battle `AnyOf`, `AllOf` or `Not` condition was evaluated, after the
+    /// children it needed. `passed` is its own result, so a `Not` inverts
+    /// its child.
+    Combined {
+        source: UnitId,
+        combinator: Combinator,
+        passed: bool,
+    },
+    ShopTriggerQueued {
+        trigger: ShopTrigger,
+        slot: Option<usize>,
+        card_id: CardId,
+        ability_index: usize,
+        /// False for the abilities of a unit that was just sold.
+        on_board: bool,
+    },
+    ShopTriggerFired {
+        slot: Option<usize>,
+        card_id: CardId,
+        ability_index: usize,
+    },
+    ShopTriggerSkipped {
+        slot: Option<usize>,
+        card_id: CardId,
+        ability_index: usize,
+        reason: SkipReason,
+    },
+    ShopCondition {
+        slot: Option<usize>,
+        matcher: ShopMatcher,
+        operands: Vec<Operand>,
+        passed: bool,
+    },
+    ShopCombined {
+        slot: Option<usize>,
+        combinator: Combinator,
+        passed: bool,
+    },
+}
+
+/// The entries recorded by `capture`, in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Trace {
+    pub entries: Vec<TraceEntry>,
+}
+
+#[cfg(feature = "trace")]
+impl Trace {
+    pub fn to_json(&self) -> std::string::String {
+        serde_json::to_string_pretty(self).expect("trace entries always serialize")
+    }
+}
+
+#[cfg(feature = "trace")]
+std::thread_local! {
+    static ACTIVE: core::cell::RefCell<Option<Vec<TraceEntry>>> =
+        const { core::cell::RefCell::new(None) };
+}
+
+/// Run `f` and return its result with everything traced on this thread
+/// meanwhile. Battles that `f` hands to other threads are not traced.
+#[cfg(feature = "trace")]
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Trace) {
+    let outer = ACTIVE.with(|active| active.replace(Some(Vec::new())));
+    let result = f();
+    let entries = ACTIVE
+        .with(|active| active.replace(outer))
+        .unwrap_or_default();
+    (result, Trace { entries })
+}
+
+#[cfg(feature = "trace")]
+fn capturing() -> bool {
+    ACTIVE.with(|active| active.borrow().is_some())
+}
+
+/// Record an entry if a trace is being captured.
+#[inline(always)]
+pub(crate) fn record(entry: impl FnOnce() -> TraceEntry) {
+    #[cfg(feature = "trace")]
+    ACTIVE.with(|active| {
+        if let Some(entries) = active.borrow_mut().as_mut() {
+            entries.push(entry());
+        }
+    });
+    #[cfg(not(feature = "trace"))]
+    let _ = entry;
+}
+
+/// The operands a matcher compares, kept only while a trace is captured.
+pub(crate) struct Operands {
+    #[cfg(feature = "trace")]
+    operands: Option<Vec<Operand>>,
+}
+
+impl Operands {
+    #[inline(always)]
+    pub(crate) fn new() -> Self {
+        Self {
+            #[cfg(feature = "trace")]
+            operands: capturing().then(Vec::new),
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn push(&mut self, operand: impl FnOnce() -> Operand) {
+        #[cfg(feature = "trace")]
+        if let Some(operands) = self.operands.as_mut() {
+            operands.push(operand());
+        }
+        #[cfg(not(feature = "trace"))]
+        let _ = operand;
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Operand> {
+        #[cfg(feature = "trace")]
+        return self.operands.unwrap_or_default();
+        #[cfg(not(feature = "trace"))]
+        Vec::new()
+    }
+}
+
+impl fmt::Display for TraceUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unit(id) => {
+                let team = if id.is_player() { "player" } else { "enemy" };
+                write!(f, "{team}#{}", id.raw() & 0x7fff)
+            }
+            Self::Slot(slot) => write!(f, "slot {slot}"),
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stat { unit, stat, value } => write!(f, "{unit} {stat:?}={value}"),
+            Self::MissingHealth { unit, value } => write!(f, "{unit} missing health={value}"),
+            Self::HasTag { unit, has_tag } => write!(f, "{unit} has tag={has_tag}"),
+            Self::Position { index, unit } => match unit {
+                Some(unit) => write!(f, "position {index}={unit}"),
+                None => write!(f, "position {index} empty"),
+            },
+            Self::Count(count) => write!(f, "count={count}"),
+        }
+    }
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Silenced => "source silenced",
+            Self::AbilityGone => "ability gone",
+            Self::MaxTriggers => "max triggers reached",
+            Self::MaxTriggersPerRound => "max triggers per round reached",
+            Self::SourceGone => "source gone",
+            Self::ConditionsFailed => "conditions failed",
+            Self::Cooldown => "on cooldown",
+        })
+    }
+}
+
+/// Formats an optional shop slot; `None` is the shop itself.
+struct ShopSource(Option<usize>);
+
+impl fmt::Display for ShopSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(slot) => write!(f, "slot {slot}"),
+            None => f.write_str("shop"),
+        }
+    }
+}
+
+fn write_operands(f: &mut fmt::Formatter<'_>, operands: &[Operand]) -> fmt::Result {
+    f.write_str("[")?;
+    for (index, operand) in operands.iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{operand}")?;
+    }
+    f.write_str("]")
+}
+
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TriggerQueued {
+                source,
+                ability_index,
+                attack,
+                health,
+                position,
+                tiebreaker,
+                ability_order,
+            } => write!(
+                f,
+                "queued   {} ability {ability_index} (attack {attack}, health {health}, \
+                 position {position}, tiebreaker {tiebreaker}, order {ability_order})",
+                TraceUnit::from(*source)
+            ),
+            Self::TriggerFired {
+                source,
+                ability_index,
+            } => write!(
+                f,
+                "fired    {} ability {ability_index}",
+                TraceUnit::from(*source)
+            ),
+            Self::TriggerSkipped {
+                source,
+                ability_index,
+                reason,
+            } => write!(
+                f,
+                "skipped  {} ability {ability_index}: {reason}",
+                TraceUnit::from(*source)
+            ),
+            Self::Condition {
+                source,
+                matcher,
+                operands,
+                passed,
+            } => {
+                write!(f, "matcher  {} {matcher:?} ", TraceUnit::from(*source))?;
+                write_operands(f, operands)?;
+                write!(f, " -> {passed}")
+            }
+            Self::Combined {
+                source,
+                combinator,
+                passed,
+            } => write!(
+                f,
+                "combined {} {combinator:?} -> {passed}",
+                TraceUnit::from(*source)
+            ),
+            Self::ShopTriggerQueued {
+                trigger,
+                slot,
+                card_id,
+                ability_index,
+                on_board,
+            } => {
+                write!(
+                    f,
+                    "queued   {trigger:?} {} card {} ability {ability_index}",
+                    ShopSource(*slot),
+                    card_id.0
+                )?;
+                if !on_board {
+                    f.write_str(" (sold)")?;
+                }
+                Ok(())
+            }
+            Self::ShopTriggerFired {
+                slot,
+                card_id,
+                ability_index,
+            } => write!(
+                f,
+                "fired    {} card {} ability {ability_index}",
+                ShopSource(*slot),
+                card_id.0
+            ),
+            Self::ShopTriggerSkipped {
+                slot,
+                card_id,
+                ability_index,
+                reason,
+            } => write!(
+                f,
+                "skipped  {} card {} ability {ability_index}: {reason}",
+                ShopSource(*slot),
+                card_id.0
+            ),
+            Self::ShopCondition {
+                slot,
+                matcher,
+                operands,
+                passed,
+            } => {
+                write!(f, "matcher  {} {matcher:?} ", ShopSource(*slot))?;
+                write_operands(f, operands)?;
+                write!(f, " -> {passed}")
+            }
+            Self::ShopCombined {
+                slot,
+                combinator,
+                passed,
+            } => write!(
+                f,
+                "combined {} {combinator:?} -> {passed}",
+                ShopSource(*slot)
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            writeln!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
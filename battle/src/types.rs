@@ -2,6 +2,7 @@
 //!
 //! This module defines the fundamental types used throughout the game engine.
 
+use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -61,6 +62,347 @@ impl From<u16> for CardId {
     }
 }
 
+/// Unit statuses. Each variant owns one bit of a [`StatusMask`].
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Status {
+    /// Blocks the next instance of positive clash or ability damage, then is consumed.
+    Shield,
+    /// Positive clash damage dealt by this unit is lethal.
+    Poison,
+    /// Enemy single-target selectors must pick a guarded unit if one exists.
+    Guard,
+}
+
+impl Status {
+    /// Every status, in bit order.
+    pub const ALL: [Status; 3] = [Status::Shield, Status::Poison, Status::Guard];
+
+    /// Bit index of this status inside a [`StatusMask`].
+    pub fn bit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+/// Fixed 256-bit status set.
+///
+/// The storage shape never changes as statuses are added, so SCALE encoding
+/// stays a constant 32 bytes. Serializes to JSON as a list of status names.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct StatusMask(pub [u8; 32]);
+
+impl StatusMask {
+    pub const fn empty() -> Self {
+        Self([0; 32])
+    }
+
+    pub fn from_statuses(statuses: &[Status]) -> Self {
+        let mut mask = Self::empty();
+        for status in statuses {
+            mask.insert(*status);
+        }
+        mask
+    }
+
+    pub fn contains(&self, status: Status) -> bool {
+        let bit = status.bit();
+        self.0[(bit / 8) as usize] & (1 << (bit % 8)) != 0
+    }
+
+    pub fn insert(&mut self, status: Status) {
+        let bit = status.bit();
+        self.0[(bit / 8) as usize] |= 1 << (bit % 8);
+    }
+
+    pub fn remove(&mut self, status: Status) {
+        let bit = status.bit();
+        self.0[(bit / 8) as usize] &= !(1 << (bit % 8));
+    }
+
+    pub fn union(&self, other: &StatusMask) -> Self {
+        let mut out = *self;
+        for (byte, other) in out.0.iter_mut().zip(other.0.iter()) {
+            *byte |= *other;
+        }
+        out
+    }
+
+    /// Statuses in `self` that are not in `other`.
+    pub fn difference(&self, other: &StatusMask) -> Self {
+        let mut out = *self;
+        for (byte, other) in out.0.iter_mut().zip(other.0.iter()) {
+            *byte &= !*other;
+        }
+        out
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|byte| *byte == 0)
+    }
+
+    /// Known statuses present in this mask, in bit order.
+    pub fn iter(&self) -> impl Iterator<Item = Status> + '_ {
+        Status::ALL
+            .into_iter()
+            .filter(|status| self.contains(*status))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Serialize for StatusMask {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> Deserialize<'de> for StatusMask {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let statuses = Vec::<Status>::deserialize(deserializer)?;
+        Ok(Self::from_statuses(&statuses))
+    }
+}
+
+/// Unit tribes used to group cards into archetypes. Each variant owns one bit
+/// of a [`TagMask`].
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Tag {
+    Beast,
+    Insect,
+    Machine,
+    Undead,
+    Elemental,
+}
+
+impl Tag {
+    /// Every tag, in bit order.
+    pub const ALL: [Tag; 5] = [
+        Tag::Beast,
+        Tag::Insect,
+        Tag::Machine,
+        Tag::Undead,
+        Tag::Elemental,
+    ];
+
+    /// Bit index of this tag inside a [`TagMask`].
+    pub fn bit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+/// Fixed 16-bit tag set.
+///
+/// Cards carry only a handful of tags, so two bytes keep stored cards small
+/// while leaving room for new tribes. Serializes to JSON as a list of tag names.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct TagMask(pub u16);
+
+impl TagMask {
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub fn from_tags(tags: &[Tag]) -> Self {
+        let mut mask = Self::empty();
+        for tag in tags {
+            mask.insert(*tag);
+        }
+        mask
+    }
+
+    pub fn contains(&self, tag: Tag) -> bool {
+        self.0 & (1 << tag.bit()) != 0
+    }
+
+    pub fn insert(&mut self, tag: Tag) {
+        self.0 |= 1 << tag.bit();
+    }
+
+    pub fn remove(&mut self, tag: Tag) {
+        self.0 &= !(1 << tag.bit());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Known tags present in this mask, in bit order.
+    pub fn iter(&self) -> impl Iterator<Item = Tag> + '_ {
+        Tag::ALL.into_iter().filter(|tag| self.contains(*tag))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Serialize for TagMask {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> Deserialize<'de> for TagMask {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let tags = Vec::<Tag>::deserialize(deserializer)?;
+        Ok(Self::from_tags(&tags))
+    }
+}
+
+/// Combat keywords that change how a unit's clash attack lands. Each variant
+/// owns one bit of a [`KeywordMask`].
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Keyword {
+    /// Deals clash damage before the opposing front strikes back.
+    FirstStrike,
+    /// Clash damage beyond the opposing front's health carries into the next enemy.
+    Trample,
+    /// Attacks a second time each round while both fronts survive.
+    Windfury,
+    /// Also hits the opposing front when standing directly behind its own front (slot 1).
+    Reach,
+}
+
+impl Keyword {
+    /// Every keyword, in bit order.
+    pub const ALL: [Keyword; 4] = [
+        Keyword::FirstStrike,
+        Keyword::Trample,
+        Keyword::Windfury,
+        Keyword::Reach,
+    ];
+
+    /// Bit index of this keyword inside a [`KeywordMask`].
+    pub fn bit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+/// Fixed 8-bit keyword set. Serializes to JSON as a list of keyword names.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct KeywordMask(pub u8);
+
+impl KeywordMask {
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub fn from_keywords(keywords: &[Keyword]) -> Self {
+        let mut mask = Self::empty();
+        for keyword in keywords {
+            mask.insert(*keyword);
+        }
+        mask
+    }
+
+    pub fn contains(&self, keyword: Keyword) -> bool {
+        self.0 & (1 << keyword.bit()) != 0
+    }
+
+    pub fn insert(&mut self, keyword: Keyword) {
+        self.0 |= 1 << keyword.bit();
+    }
+
+    pub fn remove(&mut self, keyword: Keyword) {
+        self.0 &= !(1 << keyword.bit());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Known keywords present in this mask, in bit order.
+    pub fn iter(&self) -> impl Iterator<Item = Keyword> + '_ {
+        Keyword::ALL
+            .into_iter()
+            .filter(|keyword| self.contains(*keyword))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Serialize for KeywordMask {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> Deserialize<'de> for KeywordMask {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let keywords = Vec::<Keyword>::deserialize(deserializer)?;
+        Ok(Self::from_keywords(&keywords))
+    }
+}
+
 /// Battle scope for targeting and condition evaluation
 #[derive(
     Debug,
@@ -211,6 +553,25 @@ pub enum Matcher {
         scope: TargetScope,
         index: SignedIndex,
     },
+
+    /// Compare how much health a unit is missing (max health minus current
+    /// health) to a constant value. `GreaterThan 0` means "is damaged".
+    MissingHealthCompare {
+        scope: TargetScope,
+        op: CompareOp,
+        value: StatValue,
+    },
+
+    /// Check if any unit in a scope has a tag
+    HasTag { scope: TargetScope, tag: Tag },
+
+    /// Count units with a tag in a scope and compare to a value
+    TagCount {
+        scope: TargetScope,
+        tag: Tag,
+        op: CompareOp,
+        value: CountValue,
+    },
 }
 
 /// Shop matchers that must be met for an ability to activate.
@@ -238,8 +599,28 @@ pub enum ShopMatcher {
         scope: ShopScope,
         index: SignedIndex,
     },
+    /// Check if any unit in a scope has a tag.
+    HasTag { scope: ShopScope, tag: Tag },
+    /// Count units with a tag in a scope and compare to a value.
+    TagCount {
+        scope: ShopScope,
+        tag: Tag,
+        op: CompareOp,
+        value: CountValue,
+    },
 }
 
+/// Maximum nesting depth of a condition tree. A bare `Is` has depth 1, and
+/// every `AnyOf` / `AllOf` / `Not` adds one level on top of its children.
+/// Conditions nested deeper than this never pass.
+pub const MAX_CONDITION_DEPTH: usize = 4;
+
+/// SCALE nesting limit for decoding a `UnitCard`: the ability and condition
+/// lists, plus one level for each condition group within
+/// `MAX_CONDITION_DEPTH`. Pass it to `DecodeLimit::decode_with_depth_limit`
+/// so an over-nested payload is rejected before it can exhaust the stack.
+pub const MAX_CARD_DECODE_DEPTH: u32 = MAX_CONDITION_DEPTH as u32 + 1;
+
 /// Structural battle conditions that control the flow of evaluation.
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -248,10 +629,31 @@ pub enum Condition {
     /// A single mandatory requirement.
     Is(Matcher),
 
-    /// A "Shallow OR" list.
-    /// Returns true if ANY of the internal Matchers are true.
-    /// Stack Safe: Cannot contain nested AnyOfs.
-    AnyOf(Vec<Matcher>),
+    /// Returns true if ANY of the nested conditions are true.
+    AnyOf(Vec<Condition>),
+
+    /// Returns true if ALL of the nested conditions are true.
+    AllOf(Vec<Condition>),
+
+    /// Inverts the nested condition.
+    Not(Box<Condition>),
+}
+
+impl Condition {
+    /// Returns true if this condition is nested no deeper than `max_depth`.
+    /// Never descends further than `max_depth` levels.
+    pub fn within_depth(&self, max_depth: usize) -> bool {
+        if max_depth == 0 {
+            return false;
+        }
+        match self {
+            Condition::Is(_) => true,
+            Condition::AnyOf(children) | Condition::AllOf(children) => children
+                .iter()
+                .all(|child| child.within_depth(max_depth - 1)),
+            Condition::Not(child) => child.within_depth(max_depth - 1),
+        }
+    }
 }
 
 /// Structural shop conditions that control the flow of evaluation.
@@ -261,8 +663,29 @@ pub enum Condition {
 pub enum ShopCondition {
     /// A single mandatory requirement.
     Is(ShopMatcher),
-    /// A "Shallow OR" list. Stack-safe (cannot contain nested AnyOf).
-    AnyOf(Vec<ShopMatcher>),
+    /// Returns true if ANY of the nested conditions are true.
+    AnyOf(Vec<ShopCondition>),
+    /// Returns true if ALL of the nested conditions are true.
+    AllOf(Vec<ShopCondition>),
+    /// Inverts the nested condition.
+    Not(Box<ShopCondition>),
+}
+
+impl ShopCondition {
+    /// Returns true if this condition is nested no deeper than `max_depth`.
+    /// Never descends further than `max_depth` levels.
+    pub fn within_depth(&self, max_depth: usize) -> bool {
+        if max_depth == 0 {
+            return false;
+        }
+        match self {
+            ShopCondition::Is(_) => true,
+            ShopCondition::AnyOf(children) | ShopCondition::AllOf(children) => children
+                .iter()
+                .all(|child| child.within_depth(max_depth - 1)),
+            ShopCondition::Not(child) => child.within_depth(max_depth - 1),
+        }
+    }
 }
 
 /// Battle ability trigger conditions.
@@ -293,20 +716,94 @@ pub enum AbilityTrigger {
     AfterAnyAttack,
     AfterAllyAttack,
     AfterEnemyAttack,
+    /// This unit dealt the damage that killed an enemy. Trigger target: the fallen enemy.
+    OnKill,
+    /// Another ally was hurt. Trigger target: the hurt ally.
+    OnAllyHurt,
+    /// An enemy fainted. Trigger target: the fallen enemy.
+    OnEnemyFaint,
+    /// The battle ended with this unit still on the board. No trigger target.
+    OnBattleEnd,
+    /// This unit's health first dropped to or below `threshold` and it survived.
+    /// Fires once per crossing; healing back above the threshold re-arms it.
+    /// Trigger target: the unit that dealt the damage.
+    OnHealthBelow {
+        threshold: HealthThreshold,
+    },
 }
 
 /// Number of AbilityTrigger variants — must stay in sync with the enum.
-pub const TRIGGER_VARIANT_COUNT: usize = 13;
+pub const TRIGGER_VARIANT_COUNT: usize = 18;
 
 impl AbilityTrigger {
     /// Discriminant index for O(1) TriggerRegistry bucket lookup.
-    pub fn index(&self) -> usize {
-        *self as usize
+    pub const fn index(&self) -> usize {
+        match self {
+            Self::OnStart => 0,
+            Self::OnFaint => 1,
+            Self::OnAllyFaint => 2,
+            Self::OnHurt => 3,
+            Self::OnSpawn => 4,
+            Self::OnAllySpawn => 5,
+            Self::OnEnemySpawn => 6,
+            Self::BeforeUnitAttack => 7,
+            Self::AfterUnitAttack => 8,
+            Self::BeforeAnyAttack => 9,
+            Self::AfterAnyAttack => 10,
+            Self::AfterAllyAttack => 11,
+            Self::AfterEnemyAttack => 12,
+            Self::OnKill => 13,
+            Self::OnAllyHurt => 14,
+            Self::OnEnemyFaint => 15,
+            Self::OnBattleEnd => 16,
+            Self::OnHealthBelow { .. } => 17,
+        }
     }
 }
 
-// Compile-time check: last variant discriminant == TRIGGER_VARIANT_COUNT - 1
-const _: () = assert!(AbilityTrigger::AfterEnemyAttack as usize == TRIGGER_VARIANT_COUNT - 1);
+// Compile-time check: last variant index == TRIGGER_VARIANT_COUNT - 1
+const _: () = assert!(
+    AbilityTrigger::OnHealthBelow {
+        threshold: HealthThreshold::Absolute(0)
+    }
+    .index()
+        == TRIGGER_VARIANT_COUNT - 1
+);
+
+/// Health level for [`AbilityTrigger::OnHealthBelow`]. Health at or below the
+/// threshold counts as crossed.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "type", content = "data"))]
+pub enum HealthThreshold {
+    /// A fixed health value.
+    Absolute(StatValue),
+    /// A percentage of the unit's max health.
+    Percent(u8),
+}
+
+impl HealthThreshold {
+    /// Whether `health` is at or below this threshold for a unit with `max_health`.
+    pub fn is_reached(&self, health: StatValue, max_health: StatValue) -> bool {
+        match self {
+            Self::Absolute(value) => health <= *value,
+            Self::Percent(percent) => {
+                (health as i32) * 100 <= (max_health as i32) * (*percent as i32)
+            }
+        }
+    }
+}
 
 /// Shop ability trigger conditions.
 #[derive(
@@ -344,6 +841,217 @@ pub enum SpawnLocation {
     /// Spawn at the position where the death occurred.
     /// Falls back to Front if there is no death context (e.g. OnStart triggers).
     DeathPosition,
+    /// Spawn directly behind the ability's source.
+    /// Falls back to DeathPosition once the source has left the board.
+    AdjacentToSource,
+}
+
+/// Which board a battle spawn effect places units on.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum SpawnSide {
+    /// The ability source's own board.
+    #[default]
+    Own,
+    /// The opposing board (e.g. curse tokens). Spawn positions are measured as
+    /// on the source's board and clamped to the opposing one.
+    Opposing,
+}
+
+/// Stats a spawned unit starts with.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "type", content = "data"))]
+pub enum SpawnStats {
+    /// The spawned card's own stats.
+    #[default]
+    Base,
+    /// Replace the card's attack and health.
+    Fixed {
+        attack: StatValue,
+        health: StatValue,
+    },
+    /// The card's stats plus the source's stat buffs (permanent deltas in the shop).
+    InheritBuffs,
+}
+
+#[cfg(feature = "std")]
+fn default_spawn_count() -> CountValue {
+    1
+}
+
+/// Which fallen ally a resurrect effect brings back.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ResurrectPick {
+    /// The ally that fell last.
+    MostRecent,
+    /// The ally with the highest attack at death (ties go to the most recent).
+    Strongest,
+}
+
+/// Which stats a resurrected unit comes back with.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ResurrectStats {
+    /// Fresh from its card definition.
+    Base,
+    /// Keeps the buffs and statuses it had when it fell, at full max health.
+    Current,
+}
+
+/// A quantity read from the battle state when an effect resolves.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "type", content = "data"))]
+pub enum ValueTerm {
+    /// Sum of a stat over the units in scope. `SelfUnit` still reads a source
+    /// that has already fainted.
+    Stat { scope: TargetScope, stat: StatType },
+    /// Number of units in scope.
+    UnitCount { scope: TargetScope },
+    /// Number of units in scope with a tag.
+    TagCount { scope: TargetScope, tag: Tag },
+    /// How many times this ability has fired this battle, including this time.
+    TriggerCount,
+}
+
+/// A battle effect amount: a constant, or a state-derived term times a factor.
+/// Terms never nest, so evaluation is stack-safe and the encoding is bounded.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(untagged))]
+pub enum ValueExpr {
+    Const(StatValue),
+    Scaled { term: ValueTerm, factor: StatValue },
+}
+
+impl From<StatValue> for ValueExpr {
+    fn from(value: StatValue) -> Self {
+        ValueExpr::Const(value)
+    }
+}
+
+/// A quantity read from the shop board when a shop effect resolves.
+/// Shop abilities keep no trigger counter, so there is no `TriggerCount`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "type", content = "data"))]
+pub enum ShopValueTerm {
+    /// Sum of a stat over the board units in scope.
+    Stat { scope: ShopScope, stat: StatType },
+    /// Number of board units in scope.
+    UnitCount { scope: ShopScope },
+    /// Number of board units in scope with a tag.
+    TagCount { scope: ShopScope, tag: Tag },
+}
+
+/// A shop effect amount: a constant, or a board-derived term times a factor.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(untagged))]
+pub enum ShopValueExpr {
+    Const(StatValue),
+    Scaled {
+        term: ShopValueTerm,
+        factor: StatValue,
+    },
+}
+
+impl From<StatValue> for ShopValueExpr {
+    fn from(value: StatValue) -> Self {
+        ShopValueExpr::Const(value)
+    }
 }
 
 /// Battle ability effect types.
@@ -353,30 +1061,72 @@ pub enum SpawnLocation {
 pub enum AbilityEffect {
     /// Deal damage to target
     Damage {
-        amount: StatValue,
+        amount: ValueExpr,
         target: AbilityTarget,
     },
     /// Modify health and/or attack stats (positive = buff/heal, negative = debuff/damage)
     ModifyStats {
-        health: StatValue,
-        attack: StatValue,
+        health: ValueExpr,
+        attack: ValueExpr,
         target: AbilityTarget,
     },
     /// Permanently modify health and/or attack stats on board units (until sold)
     ModifyStatsPermanent {
-        health: StatValue,
-        attack: StatValue,
+        health: ValueExpr,
+        attack: ValueExpr,
         target: AbilityTarget,
     },
-    /// Spawn a new unit on the board
+    /// Spawn `count` new units on the source's or the opposing board.
+    /// Every copy counts toward the battle's spawn limit; once the board is
+    /// full, only the first fizzled copy does.
     SpawnUnit {
         card_id: CardId,
         spawn_location: SpawnLocation,
+        #[cfg_attr(feature = "std", serde(default = "default_spawn_count"))]
+        count: CountValue,
+        #[cfg_attr(feature = "std", serde(default))]
+        side: SpawnSide,
+        #[cfg_attr(feature = "std", serde(default))]
+        stats: SpawnStats,
     },
     /// Destroy a target directly
     Destroy { target: AbilityTarget },
     /// Add mana for next shop via battle event processing.
-    GainMana { amount: ManaDelta },
+    GainMana { amount: ValueExpr },
+    /// Grant a status for the rest of this battle only.
+    GrantStatusThisBattle {
+        status: Status,
+        target: AbilityTarget,
+    },
+    /// Grant a status that persists on board units after the battle.
+    GrantStatusPermanent {
+        status: Status,
+        target: AbilityTarget,
+    },
+    /// Remove a permanently granted status from board units.
+    RemoveStatusPermanent {
+        status: Status,
+        target: AbilityTarget,
+    },
+    /// Restore health to target, never above its max health.
+    Heal {
+        amount: ValueExpr,
+        target: AbilityTarget,
+    },
+    /// Bring a fallen ally back from this battle's graveyard. Counts as a spawn.
+    Resurrect {
+        pick: ResurrectPick,
+        stats: ResurrectStats,
+        spawn_location: SpawnLocation,
+    },
+    /// Turn this unit into a copy of the first target, abilities included.
+    /// A `TriggerSource` that already fell is copied from the graveyard.
+    Mimic { target: AbilityTarget },
+    /// Move enemy targets to the back of this unit's board for the rest of
+    /// the battle. Stops once the board is full.
+    Steal { target: AbilityTarget },
+    /// Remove every ability from the targets for the rest of the battle.
+    Silence { target: AbilityTarget },
 }
 
 /// Shop ability effect types.
@@ -386,19 +1136,36 @@ pub enum AbilityEffect {
 pub enum ShopEffect {
     /// Permanently modify health and/or attack stats on board units (until sold).
     ModifyStatsPermanent {
-        health: StatValue,
-        attack: StatValue,
+        health: ShopValueExpr,
+        attack: ShopValueExpr,
         target: ShopTarget,
     },
-    /// Spawn a new unit on the board.
+    /// Spawn `count` new units on the board, stopping once it is full.
     SpawnUnit {
         card_id: CardId,
         spawn_location: SpawnLocation,
+        #[cfg_attr(feature = "std", serde(default = "default_spawn_count"))]
+        count: CountValue,
+        #[cfg_attr(feature = "std", serde(default))]
+        stats: SpawnStats,
     },
     /// Destroy a target directly.
     Destroy { target: ShopTarget },
     /// Modify current shop mana.
-    GainMana { amount: ManaDelta },
+    GainMana { amount: ShopValueExpr },
+    /// Grant a status that persists on board units (until sold).
+    GrantStatusPermanent { status: Status, target: ShopTarget },
+    /// Remove a permanently granted status from board units.
+    RemoveStatusPermanent { status: Status, target: ShopTarget },
+    /// Restore health on board units lost to negative permanent health changes.
+    /// Board units have no separate max health, so this never raises a unit
+    /// above its card health (a negative `perm_health` is healed up to zero).
+    Heal {
+        amount: ShopValueExpr,
+        target: ShopTarget,
+    },
+    /// Permanently remove every shop and battle ability from board units (until sold).
+    Silence { target: ShopTarget },
 }
 
 /// Battle ability target specifications.
@@ -429,6 +1196,17 @@ pub enum AbilityTarget {
     },
     /// Everyone in scope.
     All { scope: TargetScope },
+    /// Units hit by the previous effect of the same ability that are still alive.
+    /// Resolves to nothing for an ability's first effect.
+    PreviousTargets,
+    /// Everyone in scope with a tag.
+    AllWithTag { scope: TargetScope, tag: Tag },
+    /// Random units with a tag from scope.
+    RandomWithTag {
+        scope: TargetScope,
+        tag: Tag,
+        count: CountValue,
+    },
 }
 
 /// Shop ability target specifications.
@@ -454,8 +1232,22 @@ pub enum ShopTarget {
     },
     /// Everyone in scope.
     All { scope: ShopScope },
+    /// Units hit by the previous effect of the same ability that are still on
+    /// the board. Resolves to nothing for an ability's first effect.
+    PreviousTargets,
+    /// Everyone in scope with a tag.
+    AllWithTag { scope: ShopScope, tag: Tag },
+    /// Random units with a tag from scope.
+    RandomWithTag {
+        scope: ShopScope,
+        tag: Tag,
+        count: CountValue,
+    },
 }
 
+/// Maximum number of effects one ability resolves (`effect` plus `then`).
+pub const MAX_ABILITY_EFFECTS: usize = 4;
+
 /// A battle ability.
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -470,6 +1262,18 @@ pub struct Ability {
     /// If None, the ability can trigger unlimited times.
     #[cfg_attr(feature = "std", serde(default))]
     pub max_triggers: Option<CountValue>,
+    /// Further effects resolved in order right after `effect`, under the same
+    /// trigger. Steps past `MAX_ABILITY_EFFECTS` in total are ignored.
+    #[cfg_attr(feature = "std", serde(default))]
+    pub then: Vec<AbilityEffect>,
+    /// Optional limit on how many times this ability can trigger per battle round.
+    /// The count resets as each round begins.
+    #[cfg_attr(feature = "std", serde(default))]
+    pub max_triggers_per_round: Option<CountValue>,
+    /// Optional cooldown: only every N-th activation fires. Activations that
+    /// pass the ability's conditions are counted; the skipped ones emit nothing.
+    #[cfg_attr(feature = "std", serde(default))]
+    pub cooldown: Option<CountValue>,
 }
 
 /// A shop ability.
@@ -486,6 +1290,10 @@ pub struct ShopAbility {
     /// If None, the ability can trigger unlimited times.
     #[cfg_attr(feature = "std", serde(default))]
     pub max_triggers: Option<CountValue>,
+    /// Further effects resolved in order right after `effect`, under the same
+    /// trigger. Steps past `MAX_ABILITY_EFFECTS` in total are ignored.
+    #[cfg_attr(feature = "std", serde(default))]
+    pub then: Vec<ShopEffect>,
 }
 
 /// Combat stats for a unit
@@ -520,6 +1328,15 @@ pub struct UnitCard {
     pub shop_abilities: Vec<ShopAbility>,
     #[cfg_attr(feature = "std", serde(default))]
     pub battle_abilities: Vec<Ability>,
+    /// Statuses every instance of this card starts each battle with.
+    #[cfg_attr(feature = "std", serde(default))]
+    pub base_statuses: StatusMask,
+    /// Tribes this card belongs to.
+    #[cfg_attr(feature = "std", serde(default))]
+    pub tags: TagMask,
+    /// Combat keywords honoured by the attack clash.
+    #[cfg_attr(feature = "std", serde(default))]
+    pub keywords: KeywordMask,
 }
 
 impl UnitCard {
@@ -541,6 +1358,9 @@ impl UnitCard {
             },
             shop_abilities: vec![],
             battle_abilities: vec![],
+            base_statuses: StatusMask::empty(),
+            tags: TagMask::empty(),
+            keywords: KeywordMask::empty(),
         }
     }
 
@@ -561,6 +1381,42 @@ impl UnitCard {
     pub fn with_battle_ability(self, ability: Ability) -> Self {
         self.with_battle_abilities(vec![ability])
     }
+
+    pub fn with_base_statuses(mut self, statuses: StatusMask) -> Self {
+        self.base_statuses = statuses;
+        self
+    }
+
+    pub fn with_tags(mut self, tags: TagMask) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn with_keywords(mut self, keywords: KeywordMask) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Returns true if every ability's conditions are nested no deeper than
+    /// `MAX_CONDITION_DEPTH` and it resolves at most `MAX_ABILITY_EFFECTS`
+    /// effects.
+    pub fn within_limits(&self) -> bool {
+        let battle = self.battle_abilities.iter().all(|ability| {
+            ability.then.len() < MAX_ABILITY_EFFECTS
+                && ability
+                    .conditions
+                    .iter()
+                    .all(|condition| condition.within_depth(MAX_CONDITION_DEPTH))
+        });
+        let shop = self.shop_abilities.iter().all(|ability| {
+            ability.then.len() < MAX_ABILITY_EFFECTS
+                && ability
+                    .conditions
+                    .iter()
+                    .all(|condition| condition.within_depth(MAX_CONDITION_DEPTH))
+        });
+        battle && shop
+    }
 }
 
 /// A unit instance on the board (tracks permanent stat deltas)
@@ -574,6 +1430,12 @@ pub struct BoardUnit {
     pub perm_attack: StatValue,
     /// Permanent health change applied to this unit while on board
     pub perm_health: StatValue,
+    /// Statuses granted to this unit while on board (on top of the card's base statuses)
+    #[cfg_attr(feature = "std", serde(default))]
+    pub perm_statuses: StatusMask,
+    /// Ignore the card's shop and battle abilities while on board
+    #[cfg_attr(feature = "std", serde(default))]
+    pub silenced: bool,
 }
 
 impl BoardUnit {
@@ -582,6 +1444,8 @@ impl BoardUnit {
             card_id,
             perm_attack: 0,
             perm_health: 0,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         }
     }
 }
@@ -629,7 +1493,7 @@ pub struct CommitTurnAction {
 // These are always available (no feature gate). The `bounded` module
 // wraps them in BoundedVec for pallet storage; contracts use plain Vec.
 
-/// A unit on a ghost board (CardId + permanent stat deltas).
+/// A unit on a ghost board (CardId + permanent stat and status changes).
 /// Stores minimal data needed to reconstruct buffed combat units.
 #[derive(
     Debug, Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen,
@@ -639,6 +1503,12 @@ pub struct GhostBoardUnit {
     pub card_id: CardId,
     pub perm_attack: StatValue,
     pub perm_health: StatValue,
+    /// Statuses granted in the shop, on top of the card's base statuses.
+    #[cfg_attr(feature = "std", serde(default))]
+    pub perm_statuses: StatusMask,
+    /// The unit was silenced in the shop and fights without abilities.
+    #[cfg_attr(feature = "std", serde(default))]
+    pub silenced: bool,
 }
 
 /// Matchmaking bracket for ghost opponent lookup.
@@ -8,8 +8,9 @@ use std::vec::Vec;
 
 use bounded_collections::ConstU32;
 use oab_battle::battle::{
-    player_permanent_stat_deltas_from_events, player_shop_mana_delta_from_events, resolve_battle,
-    CombatEvent, CombatUnit, UnitId, UnitView,
+    player_permanent_stat_deltas_from_events, player_permanent_status_deltas_from_events,
+    player_shop_mana_delta_from_events, resolve_battle_with_modifiers, CombatEvent, CombatUnit,
+    UnitId, UnitView,
 };
 use oab_battle::bounded::BoundedCardSet;
 use oab_battle::commit::{
@@ -17,9 +18,14 @@ use oab_battle::commit::{
     verify_and_apply_turn, ShopTurnContext,
 };
 use oab_battle::log;
-use oab_battle::rng::XorShiftRng;
+use oab_battle::modifiers::{select_modifiers, BattleModifier};
+use oab_battle::rng::{RngStream, VersionedRng};
+use oab_battle::rules::BattleRules;
 use oab_battle::state::*;
-use oab_battle::types::{BoardUnit, CardId, CommitTurnAction, TurnAction, UnitCard};
+use oab_battle::summary::BattleSummary;
+use oab_battle::types::{
+    BoardUnit, CardId, CommitTurnAction, MatchmakingBracket, StatusMask, TurnAction, UnitCard,
+};
 use oab_game::bounded::BoundedGameSession;
 use oab_game::view::{CardView, GameView};
 use oab_game::{GamePhase, GameSession, GameState};
@@ -41,6 +47,8 @@ pub struct BattleOutput {
     pub initial_player_units: Vec<UnitView>,
     pub initial_enemy_units: Vec<UnitView>,
     pub round: u8, // The round this battle was for (for display during animation)
+    pub modifiers: Vec<BattleModifier>, // Battlefield modifiers active for both teams
+    pub summary: BattleSummary, // Per-unit stats and MVP derived from `events`
 }
 
 /// Snapshot of turn state for undo functionality
@@ -791,6 +799,10 @@ impl GameEngine {
                 cu.attack_buff = u.perm_attack;
                 cu.health_buff = u.perm_health;
                 cu.health = cu.health.saturating_add(u.perm_health).max(0);
+                cu.permanent_statuses = u.perm_statuses;
+                if u.silenced {
+                    cu.silence();
+                }
                 cu
             })
             .collect();
@@ -805,22 +817,29 @@ impl GameEngine {
                 cu.attack_buff = u.perm_attack;
                 cu.health_buff = u.perm_health;
                 cu.health = cu.health.saturating_add(u.perm_health).max(0);
+                cu.permanent_statuses = u.perm_statuses;
+                if u.silenced {
+                    cu.silence();
+                }
                 cu
             })
             .collect();
 
         // Run the battle with deterministic RNG
-        let mut rng = XorShiftRng::seed_from_u64(seed);
-        let events = resolve_battle(
+        let rules = self.battle_rules();
+        let modifiers = self.select_battle_modifiers(seed);
+        let mut rng = VersionedRng::new(self.state.config.rng_version, seed, RngStream::Battle);
+        let events = resolve_battle_with_modifiers(
             player_units.clone(),
             enemy_units.clone(),
             &mut rng,
             &self.state.card_pool,
-            self.state.config.board_size as usize,
+            &rules,
+            &modifiers,
         );
 
         // Generate initial views for UI animation
-        let mut limits = oab_battle::limits::BattleLimits::new();
+        let mut limits = oab_battle::limits::BattleLimits::new(&rules);
         let initial_player_units: Vec<UnitView> = player_board
             .iter()
             .flatten()
@@ -832,7 +851,12 @@ impl GameEngine {
                     name: card.name.clone(),
                     attack: card.stats.attack.saturating_add(u.perm_attack),
                     health: card.stats.health.saturating_add(u.perm_health),
-                    battle_abilities: card.battle_abilities.clone(),
+                    battle_abilities: if u.silenced {
+                        Vec::new()
+                    } else {
+                        card.battle_abilities.clone()
+                    },
+                    statuses: card.base_statuses.union(&u.perm_statuses),
                 }
             })
             .collect();
@@ -849,7 +873,12 @@ impl GameEngine {
                     name: card.name.clone(),
                     attack: card.stats.attack.saturating_add(u.perm_attack),
                     health: card.stats.health.saturating_add(u.perm_health),
-                    battle_abilities: card.battle_abilities.clone(),
+                    battle_abilities: if u.silenced {
+                        Vec::new()
+                    } else {
+                        card.battle_abilities.clone()
+                    },
+                    statuses: card.base_statuses.union(&u.perm_statuses),
                 }
             })
             .collect();
@@ -868,6 +897,8 @@ impl GameEngine {
             log::info(&format!("P2P Battle Result: {:?}", result));
         }
         self.state.shop_mana = player_shop_mana_delta_from_events(&events).max(0) as u8;
+        let status_deltas = player_permanent_status_deltas_from_events(&events);
+        self.apply_player_permanent_status_deltas(&player_slots, &status_deltas);
         let permanent_deltas = player_permanent_stat_deltas_from_events(&events);
         self.apply_player_permanent_stat_deltas(&player_slots, &permanent_deltas);
 
@@ -875,10 +906,12 @@ impl GameEngine {
         // This keeps P2P flow consistent with single-player flow
 
         let output = BattleOutput {
+            summary: BattleSummary::from_events(&events),
             events,
             initial_player_units,
             initial_enemy_units,
             round: self.state.round,
+            modifiers,
         };
 
         self.last_battle_output = Some(output.clone());
@@ -1042,6 +1075,7 @@ impl GameEngine {
                 card_set,
                 self.state.game_seed,
                 self.state.config.bag_size as usize,
+                self.state.config.rng_version,
             );
         }
 
@@ -1069,6 +1103,25 @@ impl GameEngine {
         self.undo_history.clear();
     }
 
+    fn apply_player_permanent_status_deltas(
+        &mut self,
+        player_slots: &[usize],
+        deltas: &std::collections::BTreeMap<UnitId, (StatusMask, StatusMask)>,
+    ) {
+        for (unit_id, (granted, removed)) in deltas {
+            let unit_index = unit_id.raw() as usize;
+            if unit_index == 0 || unit_index > player_slots.len() {
+                continue;
+            }
+
+            let slot = player_slots[unit_index - 1];
+            if let Some(Some(board_unit)) = self.state.board.get_mut(slot) {
+                board_unit.perm_statuses =
+                    board_unit.perm_statuses.union(granted).difference(removed);
+            }
+        }
+    }
+
     fn apply_player_permanent_stat_deltas(
         &mut self,
         player_slots: &[usize],
@@ -1110,6 +1163,37 @@ impl GameEngine {
         }
     }
 
+    /// Battlefield modifiers for the current battle, picked the same way the
+    /// contract picks them: from the battle seed or the pre-battle bracket.
+    fn select_battle_modifiers(&self, battle_seed: u64) -> Vec<BattleModifier> {
+        let bracket = MatchmakingBracket {
+            set_id: self.set_id,
+            round: self.state.round,
+            wins: self.state.wins,
+            lives: self.state.lives,
+        };
+        let config = &self.state.config;
+        select_modifiers(
+            &config.battle_modifiers,
+            battle_seed,
+            &bracket,
+            config.rng_version,
+        )
+    }
+
+    /// Battle rules for the version this run was configured with. Unknown
+    /// versions fall back to the current rules so local play keeps working.
+    fn battle_rules(&self) -> BattleRules {
+        let config = &self.state.config;
+        config.battle_rules().unwrap_or_else(|| {
+            log::error(&format!(
+                "Unknown battle rules version {}, using current rules",
+                config.rules_version
+            ));
+            BattleRules::default().with_board_size(config.board_size)
+        })
+    }
+
     fn run_battle(&mut self) {
         log::info("=== BATTLE START ===");
         let board_before_battle = self.state.board.clone();
@@ -1128,6 +1212,10 @@ impl GameEngine {
                 cu.attack_buff = u.perm_attack;
                 cu.health_buff = u.perm_health;
                 cu.health = cu.health.saturating_add(u.perm_health).max(0);
+                cu.permanent_statuses = u.perm_statuses;
+                if u.silenced {
+                    cu.silence();
+                }
                 Some(cu)
             })
             .collect();
@@ -1135,15 +1223,24 @@ impl GameEngine {
         let battle_seed = self.state.round as u64;
         let enemy_units = Vec::new();
 
-        let mut rng = XorShiftRng::seed_from_u64(battle_seed);
-        let events = resolve_battle(
+        let rules = self.battle_rules();
+        let modifiers = self.select_battle_modifiers(battle_seed);
+        let mut rng = VersionedRng::new(
+            self.state.config.rng_version,
+            battle_seed,
+            RngStream::Battle,
+        );
+        let events = resolve_battle_with_modifiers(
             player_units,
             enemy_units,
             &mut rng,
             &self.state.card_pool,
-            self.state.config.board_size as usize,
+            &rules,
+            &modifiers,
         );
         self.state.shop_mana = player_shop_mana_delta_from_events(&events).max(0) as u8;
+        let status_deltas = player_permanent_status_deltas_from_events(&events);
+        self.apply_player_permanent_status_deltas(&player_slots, &status_deltas);
         let permanent_deltas = player_permanent_stat_deltas_from_events(&events);
         self.apply_player_permanent_stat_deltas(&player_slots, &permanent_deltas);
 
@@ -1161,7 +1258,7 @@ impl GameEngine {
         }
 
         // Generate initial views for UI
-        let mut limits = oab_battle::limits::BattleLimits::new();
+        let mut limits = oab_battle::limits::BattleLimits::new(&rules);
         let initial_player_units: Vec<UnitView> = board_before_battle
             .iter()
             .flatten()
@@ -1173,7 +1270,12 @@ impl GameEngine {
                     name: card.name.clone(),
                     attack: card.stats.attack.saturating_add(u.perm_attack),
                     health: card.stats.health.saturating_add(u.perm_health),
-                    battle_abilities: card.battle_abilities.clone(),
+                    battle_abilities: if u.silenced {
+                        Vec::new()
+                    } else {
+                        card.battle_abilities.clone()
+                    },
+                    statuses: card.base_statuses.union(&u.perm_statuses),
                 }
             })
             .collect();
@@ -1182,10 +1284,12 @@ impl GameEngine {
         let initial_enemy_units: Vec<UnitView> = Vec::new();
 
         self.last_battle_output = Some(BattleOutput {
+            summary: BattleSummary::from_events(&events),
             events,
             initial_player_units,
             initial_enemy_units,
             round: self.state.round,
+            modifiers,
         });
 
         log::info("=== BATTLE END ===");
@@ -8,8 +8,11 @@ use crate::engine::BattleOutput;
 use oab_assets::cards::build_pool;
 use oab_battle::battle::{resolve_battle, CombatUnit, UnitView};
 use oab_battle::log;
-use oab_battle::rng::XorShiftRng;
-use oab_battle::types::CardId;
+use oab_battle::matchup::{evaluate_matchup, MatchupConfig};
+use oab_battle::rng::{RngStream, RngVersion, VersionedRng};
+use oab_battle::rules::BattleRules;
+use oab_battle::summary::BattleSummary;
+use oab_battle::types::{BoardUnit, CardId};
 use oab_game::view::CardView;
 use serde::Deserialize;
 use wasm_bindgen::prelude::*;
@@ -50,17 +53,18 @@ pub fn run_sandbox_battle(player_units_js: JsValue, enemy_units_js: JsValue, see
         player_sandbox.iter().filter_map(make_combat_unit).collect();
     let enemy_board: Vec<CombatUnit> = enemy_sandbox.iter().filter_map(make_combat_unit).collect();
 
-    let mut rng = XorShiftRng::seed_from_u64(seed);
+    let rules = BattleRules::default();
+    let mut rng = VersionedRng::new(RngVersion::CURRENT, seed, RngStream::Battle);
 
     let events = resolve_battle(
         player_board.clone(),
         enemy_board.clone(),
         &mut rng,
         &card_pool,
-        5,
+        &rules,
     );
 
-    let mut limits = oab_battle::limits::BattleLimits::new();
+    let mut limits = oab_battle::limits::BattleLimits::new(&rules);
     let initial_player_units: Vec<UnitView> = player_board
         .iter()
         .map(|u| UnitView {
@@ -73,6 +77,7 @@ pub fn run_sandbox_battle(player_units_js: JsValue, enemy_units_js: JsValue, see
             attack: u.attack,
             health: u.health,
             battle_abilities: u.abilities.clone(),
+            statuses: u.active_statuses(),
         })
         .collect();
 
@@ -89,15 +94,50 @@ pub fn run_sandbox_battle(player_units_js: JsValue, enemy_units_js: JsValue, see
             attack: u.attack,
             health: u.health,
             battle_abilities: u.abilities.clone(),
+            statuses: u.active_statuses(),
         })
         .collect();
 
     let output = BattleOutput {
+        summary: BattleSummary::from_events(&events),
         events,
         initial_player_units,
         initial_enemy_units,
         round: 0, // Sandbox doesn't have rounds
+        modifiers: Vec::new(),
     };
 
     serde_wasm_bindgen::to_value(&output).unwrap_or(JsValue::NULL)
 }
+
+/// Run `battles` seeded sandbox battles from `first_seed` on and report the
+/// win/draw/loss odds for the player board
+#[wasm_bindgen]
+pub fn evaluate_sandbox_matchup(
+    player_units_js: JsValue,
+    enemy_units_js: JsValue,
+    battles: u32,
+    first_seed: u64,
+) -> JsValue {
+    log::action("evaluate_sandbox_matchup", "Evaluating custom matchup");
+
+    let to_board = |units_js: JsValue| -> Vec<BoardUnit> {
+        let sandbox: Vec<SandboxUnit> =
+            serde_wasm_bindgen::from_value(units_js).unwrap_or_default();
+        sandbox
+            .iter()
+            .map(|unit| BoardUnit::new(CardId(unit.card_id)))
+            .collect()
+    };
+    let player_board = to_board(player_units_js);
+    let enemy_board = to_board(enemy_units_js);
+
+    let config = MatchupConfig {
+        battles,
+        first_seed,
+        ..MatchupConfig::default()
+    };
+    let report = evaluate_matchup(&player_board, &enemy_board, &build_pool(), &config);
+
+    serde_wasm_bindgen::to_value(&report).unwrap_or(JsValue::NULL)
+}
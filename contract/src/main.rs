@@ -17,13 +17,16 @@ use alloc::collections::BTreeMap;
 #[cfg(any(test, feature = "std", feature = "abi-gen"))]
 use std::collections::BTreeMap;
 
-use parity_scale_codec::{Decode, Encode};
+use parity_scale_codec::{Decode, DecodeLimit, Encode};
 
-use oab_battle::battle::{resolve_battle, BattleResult, CombatUnit};
-use oab_battle::rng::{BattleRng, XorShiftRng};
+use oab_battle::battle::{resolve_battle_into, BattleResult, CombatUnit};
+use oab_battle::modifiers::{select_modifiers, ModifierSelection};
+use oab_battle::rng::{BattleRng, RngStream, RngVersion, VersionedRng};
+use oab_battle::rules::{BattleRules, RulesVersion, CURRENT_RULES_VERSION, LEGACY_RULES_VERSION};
 use oab_battle::state::CardSet;
+use oab_battle::summary::SummarySink;
 use oab_battle::types::*;
-use oab_battle::{apply_shop_start_triggers, verify_and_apply_turn};
+use oab_battle::{apply_shop_start_triggers, verify_and_apply_turn, BattleOutcome};
 use oab_game::GamePhase;
 
 // ── Game config + session types ───────────────────────────────────────────────
@@ -38,6 +41,9 @@ struct GameConfig {
     board_size: IndexValue,
     hand_size: IndexValue,
     bag_size: IndexValue,
+    battle_modifiers: ModifierSelection,
+    rules_version: RulesVersion,
+    rng_version: RngVersion,
 }
 
 impl GameConfig {
@@ -56,9 +62,20 @@ fn default_config() -> GameConfig {
         board_size: 5,
         hand_size: 5,
         bag_size: 50,
+        battle_modifiers: ModifierSelection::None,
+        rules_version: CURRENT_RULES_VERSION,
+        rng_version: RngVersion::CURRENT,
     }
 }
 
+/// Rules and generator versions a run started with, so later changes to
+/// either never replay an existing run differently.
+#[derive(Debug, Clone, Copy, Encode, Decode)]
+struct RunVersions {
+    rules: RulesVersion,
+    rng: RngVersion,
+}
+
 #[derive(Debug, Clone, Encode, Decode)]
 struct ArenaSession {
     bag: Vec<CardId>,
@@ -77,6 +94,8 @@ struct ArenaSession {
 
 type GhostPool = Vec<Vec<GhostBoardUnit>>;
 const MAX_GHOSTS_PER_BRACKET: usize = 10;
+/// Largest storage value `read_raw` reads back.
+const MAX_STORAGE_VALUE_LEN: usize = 16384;
 
 // ── Storage domain prefixes (keccak namespace separation) ─────────────────────
 
@@ -86,6 +105,9 @@ const DOM_SET: u8 = 2;
 const DOM_SESSION: u8 = 3;
 const DOM_SESSION_ACTIVE: u8 = 4;
 const DOM_GHOST: u8 = 5;
+/// RunVersions of a run. Kept beside the session so the ArenaSession layout
+/// the frontend decodes stays unchanged.
+const DOM_RUN_VERSIONS: u8 = 6;
 
 // keccak256("BattleReported(uint8,uint8,uint8,uint8,uint64,bytes)")
 const BATTLE_REPORTED_TOPIC: [u8; 32] = [
@@ -100,12 +122,17 @@ fn bracket_bytes(set_id: SetIdValue, round: RoundValue, wins: RoundValue, lives:
 
 // ── Pure helpers (no storage/host access) ─────────────────────────────────────
 
-fn create_starting_bag(set: &CardSet, seed: u64, bag_size: usize) -> Vec<CardId> {
+fn create_starting_bag(
+    set: &CardSet,
+    seed: u64,
+    bag_size: usize,
+    rng_version: RngVersion,
+) -> Vec<CardId> {
     if set.cards.is_empty() {
         return Vec::new();
     }
     let mut bag = Vec::with_capacity(bag_size);
-    let mut rng = XorShiftRng::seed_from_u64(seed);
+    let mut rng = VersionedRng::new(rng_version, seed, RngStream::StartingBag);
     let total_weight: u32 = set.cards.iter().map(|e| e.rarity as u32).sum();
     if total_weight == 0 {
         return Vec::new();
@@ -126,15 +153,17 @@ fn create_starting_bag(set: &CardSet, seed: u64, bag_size: usize) -> Vec<CardId>
     bag
 }
 
-fn draw_hand(session: &mut ArenaSession, hand_size: usize) {
+fn draw_hand(session: &mut ArenaSession, hand_size: usize, rng_version: RngVersion) {
     session.bag.append(&mut session.hand);
     let bag_len = session.bag.len();
     if bag_len == 0 {
         return;
     }
     let hand_count = hand_size.min(bag_len);
-    let seed = session.game_seed ^ (session.round as u64);
-    let mut rng = XorShiftRng::seed_from_u64(seed);
+    let stream = RngStream::Hand {
+        round: session.round,
+    };
+    let mut rng = VersionedRng::new(rng_version, session.game_seed, stream);
     let mut indices: Vec<usize> = (0..bag_len).collect();
     for i in 0..hand_count {
         let j = i + rng.gen_range(bag_len - i);
@@ -153,6 +182,7 @@ fn draw_hand(session: &mut ArenaSession, hand_size: usize) {
 fn make_shop_state(
     session: &ArenaSession,
     card_pool: &BTreeMap<CardId, UnitCard>,
+    rng_version: RngVersion,
 ) -> oab_battle::state::ShopState {
     oab_battle::state::ShopState {
         card_pool: card_pool.clone(),
@@ -163,6 +193,7 @@ fn make_shop_state(
         shop_mana: session.shop_mana,
         round: session.round,
         game_seed: session.game_seed,
+        rng_version,
     }
 }
 
@@ -180,6 +211,8 @@ fn create_ghost_from_board(board: &[Option<BoardUnit>]) -> Vec<GhostBoardUnit> {
             card_id: bu.card_id,
             perm_attack: bu.perm_attack,
             perm_health: bu.perm_health,
+            perm_statuses: bu.perm_statuses,
+            silenced: bu.silenced,
         })
         .collect()
 }
@@ -212,7 +245,7 @@ mod oab_arena {
         }
 
         fn read_raw(&self, key: &[u8; 32]) -> Option<Vec<u8>> {
-            let mut buf = vec![0u8; 16384];
+            let mut buf = vec![0u8; MAX_STORAGE_VALUE_LEN];
             let mut out = buf.as_mut_slice();
             match self.host().get_storage(StorageFlags::empty(), key, &mut out) {
                 Ok(_) => Some(out.to_vec()),
@@ -277,6 +310,17 @@ mod oab_arena {
         fn clear_session(&self, caller: &[u8; 20]) {
             self.write_scale(DOM_SESSION_ACTIVE, caller, &false);
             self.clear_scale(DOM_SESSION, caller);
+            self.clear_scale(DOM_RUN_VERSIONS, caller);
+        }
+
+        /// Runs started before versioning have none recorded and keep the
+        /// legacy rules and generator.
+        fn run_versions(&self, caller: &[u8; 20]) -> RunVersions {
+            self.read_scale(DOM_RUN_VERSIONS, caller)
+                .unwrap_or(RunVersions {
+                    rules: LEGACY_RULES_VERSION,
+                    rng: RngVersion::V1,
+                })
         }
 
         // ── ghost pools ──
@@ -309,13 +353,14 @@ mod oab_arena {
             lives: RoundValue,
             seed: u64,
             card_pool: &BTreeMap<CardId, UnitCard>,
+            rng_version: RngVersion,
         ) -> (Vec<CombatUnit>, Vec<GhostBoardUnit>) {
             let bk = bracket_bytes(set_id, round, wins, lives);
             let pool: GhostPool = self.read_scale(DOM_GHOST, &bk).unwrap_or_default();
             if pool.is_empty() {
                 return (Vec::new(), Vec::new());
             }
-            let mut rng = XorShiftRng::seed_from_u64(seed);
+            let mut rng = VersionedRng::new(rng_version, seed, RngStream::GhostPick);
             let index = rng.gen_range(pool.len());
             let ghost = &pool[index];
             let units = ghost
@@ -325,7 +370,11 @@ mod oab_arena {
                         let mut cu = CombatUnit::from_card(card.clone());
                         cu.attack_buff = unit.perm_attack;
                         cu.health_buff = unit.perm_health;
-                        cu.health = cu.health.saturating_add(unit.perm_health);
+                        cu.health = cu.health.saturating_add(unit.perm_health).max(0);
+                        cu.permanent_statuses = unit.perm_statuses;
+                        if unit.silenced {
+                            cu.silence();
+                        }
                         cu
                     })
                 })
@@ -361,17 +410,20 @@ mod oab_arena {
             self.write_scale(DOM_ADMIN, &[], &caller);
         }
 
-        /// Admin: store a SCALE-encoded card definition on-chain.
+        /// Admin: store a SCALE-encoded card definition on-chain. Cards with
+        /// conditions nested past `MAX_CONDITION_DEPTH` or too many effects
+        /// in one ability are rejected.
         #[pvm_contract_sdk::method]
         pub fn register_card(&mut self, data: Bytes) -> bool {
             if self.admin() != Some(self.caller_bytes()) {
                 return false;
             }
-            let card: UnitCard = match Decode::decode(&mut &data.0[..]) {
-                Ok(v) => v,
-                Err(_) => return false,
-            };
-            if self.load_card(card.id.0).is_some() {
+            let card =
+                match UnitCard::decode_with_depth_limit(MAX_CARD_DECODE_DEPTH, &mut &data.0[..]) {
+                    Ok(v) => v,
+                    Err(_) => return false,
+                };
+            if !card.within_limits() || self.load_card(card.id.0).is_some() {
                 return false;
             }
             self.write_scale(DOM_CARD, &card.id.0.to_le_bytes(), &card);
@@ -413,7 +465,12 @@ mod oab_arena {
 
             let config = default_config();
             let seed = self.derive_seed(&caller, b"start", seed_nonce);
-            let bag = create_starting_bag(&card_set, seed, config.bag_size as usize);
+            let bag = create_starting_bag(
+                &card_set,
+                seed,
+                config.bag_size as usize,
+                config.rng_version,
+            );
 
             let mut session = ArenaSession {
                 bag,
@@ -430,12 +487,17 @@ mod oab_arena {
                 set_id,
             };
 
-            draw_hand(&mut session, config.hand_size as usize);
-            let mut shop = make_shop_state(&session, &card_pool);
+            draw_hand(&mut session, config.hand_size as usize, config.rng_version);
+            let mut shop = make_shop_state(&session, &card_pool, config.rng_version);
             apply_shop_start_triggers(&mut shop);
             sync_from_shop_state(&mut session, &shop);
 
             self.store_session(&caller, &session);
+            let versions = RunVersions {
+                rules: config.rules_version,
+                rng: config.rng_version,
+            };
+            self.write_scale(DOM_RUN_VERSIONS, &caller, &versions);
             seed
         }
 
@@ -464,8 +526,13 @@ mod oab_arena {
             };
             let card_pool = self.build_card_pool_from_storage(&card_set);
             let config = default_config();
+            let versions = self.run_versions(&caller);
+            let rules = match BattleRules::for_version(versions.rules) {
+                Some(rules) => rules.with_board_size(config.board_size),
+                None => return 0,
+            };
 
-            let mut shop_state = make_shop_state(&session, &card_pool);
+            let mut shop_state = make_shop_state(&session, &card_pool, versions.rng);
             if verify_and_apply_turn(&mut shop_state, &action).is_err() {
                 return 0;
             }
@@ -485,6 +552,10 @@ mod oab_arena {
                         cu.attack_buff = bu.perm_attack;
                         cu.health_buff = bu.perm_health;
                         cu.health = cu.health.saturating_add(bu.perm_health).max(0);
+                        cu.permanent_statuses = bu.perm_statuses;
+                        if bu.silenced {
+                            cu.silence();
+                        }
                         cu
                     })
                 })
@@ -499,6 +570,7 @@ mod oab_arena {
                 session.lives,
                 battle_seed,
                 &card_pool,
+                versions.rng,
             );
 
             // Store player's board as ghost for future opponents
@@ -511,40 +583,55 @@ mod oab_arena {
                 player_ghost,
             );
 
-            let mut rng = XorShiftRng::seed_from_u64(battle_seed);
-            let events = resolve_battle(
+            let bracket = MatchmakingBracket {
+                set_id: session.set_id,
+                round: session.round,
+                wins: session.wins,
+                lives: session.lives,
+            };
+            let modifiers = select_modifiers(
+                &config.battle_modifiers,
+                battle_seed,
+                &bracket,
+                versions.rng,
+            );
+            let mut rng = VersionedRng::new(versions.rng, battle_seed, RngStream::Battle);
+            // The summary is built alongside the outcome; neither keeps an
+            // event log or builds board snapshots.
+            let mut report = (BattleOutcome::default(), SummarySink::default());
+            resolve_battle_into(
                 player_units,
                 enemy_units,
                 &mut rng,
                 &card_pool,
-                config.board_size as usize,
+                &rules,
+                &modifiers,
+                &mut report,
             );
+            let (outcome, summary) = report;
+            let summary = summary.into_summary();
 
-            let result = events
-                .iter()
-                .rev()
-                .find_map(|e| {
-                    if let oab_battle::battle::CombatEvent::BattleEnd { result } = e {
-                        Some(result.clone())
-                    } else {
-                        None
-                    }
-                })
-                .unwrap_or(BattleResult::Draw);
+            let result = outcome.result.clone();
 
-            let mana_delta: ManaValue =
-                oab_battle::battle::player_shop_mana_delta_from_events(&events).max(0) as ManaValue;
-            let permanent_deltas =
-                oab_battle::battle::player_permanent_stat_deltas_from_events(&events);
-            for (unit_id, (attack_delta, health_delta)) in &permanent_deltas {
+            let mana_delta: ManaValue = outcome.player_shop_mana_delta.max(0) as ManaValue;
+            for (unit_id, (granted, removed)) in outcome.player_permanent_status_deltas() {
+                let idx = unit_id.raw() as usize;
+                if idx == 0 || idx > player_slots.len() {
+                    continue;
+                }
+                if let Some(Some(bu)) = shop_state.board.get_mut(player_slots[idx - 1]) {
+                    bu.perm_statuses = bu.perm_statuses.union(&granted).difference(&removed);
+                }
+            }
+            for (unit_id, (attack_delta, health_delta)) in outcome.player_permanent_stat_deltas() {
                 let idx = unit_id.raw() as usize;
                 if idx == 0 || idx > player_slots.len() {
                     continue;
                 }
                 let slot = player_slots[idx - 1];
                 let remove = if let Some(Some(bu)) = shop_state.board.get_mut(slot) {
-                    bu.perm_attack = bu.perm_attack.saturating_add(*attack_delta);
-                    bu.perm_health = bu.perm_health.saturating_add(*health_delta);
+                    bu.perm_attack = bu.perm_attack.saturating_add(attack_delta);
+                    bu.perm_health = bu.perm_health.saturating_add(health_delta);
                     card_pool
                         .get(&bu.card_id)
                         .map(|c| c.stats.health.saturating_add(bu.perm_health) <= 0)
@@ -583,8 +670,8 @@ mod oab_arena {
                 session.hand = Vec::new();
                 session.phase = GamePhase::Shop;
 
-                draw_hand(&mut session, config.hand_size as usize);
-                let mut shop = make_shop_state(&session, &card_pool);
+                draw_hand(&mut session, config.hand_size as usize, versions.rng);
+                let mut shop = make_shop_state(&session, &card_pool, versions.rng);
                 oab_battle::apply_shop_start_triggers_with_result(&mut shop, Some(result.clone()));
                 sync_from_shop_state(&mut session, &shop);
             } else {
@@ -596,9 +683,12 @@ mod oab_arena {
 
             self.store_session(&caller, &session);
 
-            // Emit BattleReported event with opponent board for frontend replay
+            // Emit BattleReported event with opponent board for frontend replay,
+            // followed by the battle summary
             let ghost_encoded = opponent_ghost.encode();
-            let mut event_data = Vec::with_capacity(12 + ghost_encoded.len());
+            let summary_encoded = summary.encode();
+            let mut event_data =
+                Vec::with_capacity(12 + ghost_encoded.len() + summary_encoded.len());
             let result_byte = match result {
                 BattleResult::Victory => 0u8,
                 BattleResult::Defeat => 1,
@@ -610,6 +700,7 @@ mod oab_arena {
             event_data.push(completed_round);
             event_data.extend_from_slice(&battle_seed.to_be_bytes());
             event_data.extend_from_slice(&ghost_encoded);
+            event_data.extend_from_slice(&summary_encoded);
             self.host()
                 .deposit_event(&[BATTLE_REPORTED_TOPIC], &event_data);
 
@@ -844,6 +935,35 @@ mod tests {
         assert!(!c.register_set(0, Bytes(set.encode())), "SetAlreadyExists => false");
     }
 
+    #[test]
+    fn register_card_rejects_cards_over_limits() {
+        use oab_battle::types::{Condition, MAX_ABILITY_EFFECTS, MAX_CONDITION_DEPTH};
+
+        let mock = MockHostBuilder::new().caller(ADMIN).build();
+        let mut c = OabArena::with_host(mock);
+        c.new();
+        let template = oab_assets::cards::get_all()
+            .into_iter()
+            .find(|card| !card.battle_abilities.is_empty())
+            .expect("an asset card with a battle ability");
+
+        let mut too_deep = template.clone();
+        let ability = &mut too_deep.battle_abilities[0];
+        let mut condition = Condition::AllOf(ability.conditions.clone());
+        for _ in 0..MAX_CONDITION_DEPTH {
+            condition = Condition::Not(Box::new(condition));
+        }
+        ability.conditions = vec![condition];
+        assert!(!c.register_card(Bytes(too_deep.encode())), "too deep => false");
+
+        let mut too_long = template.clone();
+        let ability = &mut too_long.battle_abilities[0];
+        ability.then = vec![ability.effect.clone(); MAX_ABILITY_EFFECTS];
+        assert!(!c.register_card(Bytes(too_long.encode())), "too many effects => false");
+
+        assert!(c.register_card(Bytes(template.encode())), "rejections store nothing");
+    }
+
     #[test]
     fn register_requires_admin() {
         // Fresh contract WITHOUT running the constructor => admin unset =>
@@ -927,7 +1047,7 @@ mod tests {
 
     #[test]
     fn forced_loss_to_zero_lives_ends_game() {
-        use oab_battle::types::GhostBoardUnit;
+        use oab_battle::types::{GhostBoardUnit, StatusMask};
         let (mut c, mock) = setup();
         c.start_game(0, 1);
 
@@ -943,6 +1063,8 @@ mod tests {
             card_id: cid,
             perm_attack: 50,
             perm_health: 50,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         }]];
         mock.set_raw_storage(
             skey(super::DOM_GHOST, &super::bracket_bytes(0, round, wins, 1)).to_vec(),
@@ -964,7 +1086,7 @@ mod tests {
     /// reaches its natural lives==0 game-over, then finalize with end_game.
     #[test]
     fn e2e_full_game_lifecycle_to_defeat() {
-        use oab_battle::types::GhostBoardUnit;
+        use oab_battle::types::{GhostBoardUnit, StatusMask};
         let (mut c, mock) = setup();
         let cid = oab_assets::sets::get_all()[0].clone().cards[0].card_id;
 
@@ -975,6 +1097,8 @@ mod tests {
                 card_id: cid,
                 perm_attack: 50,
                 perm_health: 50,
+                perm_statuses: StatusMask::empty(),
+                silenced: false,
             }]];
             mock.set_raw_storage(
                 skey(super::DOM_GHOST, &super::bracket_bytes(0, round, 0, lives)).to_vec(),
@@ -7,15 +7,22 @@
 mod tests {
 
     use oab_assets::{cards, sets};
-    use oab_battle::battle::{resolve_battle, BattleResult, CombatUnit};
-    use oab_battle::rng::{BattleRng, XorShiftRng};
+    use oab_battle::battle::{
+        resolve_battle, resolve_battle_into, resolve_battle_outcome, resolve_battle_with_modifiers,
+        BattleResult, CombatUnit,
+    };
+    use oab_battle::modifiers::{select_modifiers, ModifierSelection};
+    use oab_battle::rng::{BattleRng, RngStream, RngVersion, VersionedRng, XorShiftRng};
+    use oab_battle::rules::{BattleRules, RulesVersion, CURRENT_RULES_VERSION};
+    use oab_battle::sink::BattleOutcome;
     use oab_battle::state::{CardSet, ShopState};
+    use oab_battle::summary::{BattleSummary, SummarySink};
     use oab_battle::types::*;
     use oab_battle::{
         apply_shop_start_triggers, apply_shop_start_triggers_with_result, verify_and_apply_turn,
     };
     use oab_game::{GamePhase, GameSession};
-    use parity_scale_codec::{Decode, Encode};
+    use parity_scale_codec::{Decode, DecodeLimit, Encode};
     use std::collections::BTreeMap;
 
     // ── Types mirroring the contract ─────────────────────────────────────────────
@@ -49,6 +56,9 @@ mod tests {
         board_size: IndexValue,
         hand_size: IndexValue,
         bag_size: IndexValue,
+        battle_modifiers: ModifierSelection,
+        rules_version: RulesVersion,
+        rng_version: RngVersion,
     }
 
     impl GameConfig {
@@ -67,6 +77,9 @@ mod tests {
             board_size: 5,
             hand_size: 5,
             bag_size: 50,
+            battle_modifiers: ModifierSelection::None,
+            rules_version: CURRENT_RULES_VERSION,
+            rng_version: RngVersion::CURRENT,
         }
     }
 
@@ -116,12 +129,17 @@ mod tests {
 
     // ── Game logic (mirroring contract) ──────────────────────────────────────────
 
-    fn create_starting_bag(set: &CardSet, seed: u64, bag_size: usize) -> Vec<CardId> {
+    fn create_starting_bag(
+        set: &CardSet,
+        seed: u64,
+        bag_size: usize,
+        rng_version: RngVersion,
+    ) -> Vec<CardId> {
         if set.cards.is_empty() {
             return Vec::new();
         }
         let mut bag = Vec::with_capacity(bag_size);
-        let mut rng = XorShiftRng::seed_from_u64(seed);
+        let mut rng = VersionedRng::new(rng_version, seed, RngStream::StartingBag);
         let total_weight: u32 = set.cards.iter().map(|e| e.rarity as u32).sum();
         if total_weight == 0 {
             return Vec::new();
@@ -142,15 +160,17 @@ mod tests {
         bag
     }
 
-    fn draw_hand(session: &mut ArenaSession, hand_size: usize) {
+    fn draw_hand(session: &mut ArenaSession, hand_size: usize, rng_version: RngVersion) {
         session.bag.append(&mut session.hand);
         let bag_len = session.bag.len();
         if bag_len == 0 {
             return;
         }
         let hand_count = hand_size.min(bag_len);
-        let seed = session.game_seed ^ (session.round as u64);
-        let mut rng = XorShiftRng::seed_from_u64(seed);
+        let stream = RngStream::Hand {
+            round: session.round,
+        };
+        let mut rng = VersionedRng::new(rng_version, session.game_seed, stream);
         let mut indices: Vec<usize> = (0..bag_len).collect();
         for i in 0..hand_count {
             let j = i + rng.gen_range(bag_len - i);
@@ -170,7 +190,7 @@ mod tests {
         let card_set = registry.load_set(set_id).unwrap();
         let card_pool = registry.build_card_pool(card_set);
         let config = default_config();
-        let bag = create_starting_bag(card_set, seed, config.bag_size as usize);
+        let bag = create_starting_bag(card_set, seed, config.bag_size as usize, config.rng_version);
         let mut session = ArenaSession {
             bag,
             hand: Vec::new(),
@@ -185,7 +205,7 @@ mod tests {
             game_seed: seed,
             set_id,
         };
-        draw_hand(&mut session, config.hand_size as usize);
+        draw_hand(&mut session, config.hand_size as usize, config.rng_version);
         let mut shop = ShopState {
             card_pool,
             set_id: 0,
@@ -195,6 +215,7 @@ mod tests {
             shop_mana: session.shop_mana,
             round: session.round,
             game_seed: session.game_seed,
+            rng_version: config.rng_version,
         };
         apply_shop_start_triggers(&mut shop);
         session.hand = shop.hand;
@@ -212,6 +233,9 @@ mod tests {
         let card_set = registry.load_set(session.set_id).unwrap();
         let card_pool = registry.build_card_pool(card_set);
         let config = default_config();
+        let rules = BattleRules::for_version(config.rules_version)
+            .expect("rules version is published")
+            .with_board_size(config.board_size);
         let mut shop_state = ShopState {
             card_pool: card_pool.clone(),
             set_id: 0,
@@ -221,6 +245,7 @@ mod tests {
             shop_mana: session.shop_mana,
             round: session.round,
             game_seed: session.game_seed,
+            rng_version: config.rng_version,
         };
         verify_and_apply_turn(&mut shop_state, action).expect("Turn should be valid");
         shop_state.shop_mana = 0;
@@ -238,6 +263,7 @@ mod tests {
                     cu.attack_buff = bu.perm_attack;
                     cu.health_buff = bu.perm_health;
                     cu.health = cu.health.saturating_add(bu.perm_health).max(0);
+                    cu.permanent_statuses = bu.perm_statuses;
                     cu
                 })
             })
@@ -247,39 +273,49 @@ mod tests {
             .game_seed
             .wrapping_mul(6364136223846793005)
             .wrapping_add(1);
-        let mut rng = XorShiftRng::seed_from_u64(battle_seed);
-        let events = resolve_battle(
+        let bracket = MatchmakingBracket {
+            set_id: session.set_id,
+            round: session.round,
+            wins: session.wins,
+            lives: session.lives,
+        };
+        let modifiers = select_modifiers(
+            &config.battle_modifiers,
+            battle_seed,
+            &bracket,
+            config.rng_version,
+        );
+        let mut rng = VersionedRng::new(config.rng_version, battle_seed, RngStream::Battle);
+        let outcome = resolve_battle_outcome(
             player_units,
             enemy_units,
             &mut rng,
             &card_pool,
-            config.board_size as usize,
+            &rules,
+            &modifiers,
         );
 
-        let result = events
-            .iter()
-            .rev()
-            .find_map(|e| {
-                if let oab_battle::battle::CombatEvent::BattleEnd { result } = e {
-                    Some(result.clone())
-                } else {
-                    None
-                }
-            })
-            .unwrap_or(BattleResult::Draw);
+        let result = outcome.result.clone();
 
-        let mana_delta: ManaValue =
-            oab_battle::battle::player_shop_mana_delta_from_events(&events).max(0) as ManaValue;
-        let deltas = oab_battle::battle::player_permanent_stat_deltas_from_events(&events);
-        for (uid, (ad, hd)) in &deltas {
+        let mana_delta: ManaValue = outcome.player_shop_mana_delta.max(0) as ManaValue;
+        for (uid, (granted, removed)) in outcome.player_permanent_status_deltas() {
+            let idx = uid.raw() as usize;
+            if idx == 0 || idx > player_slots.len() {
+                continue;
+            }
+            if let Some(Some(bu)) = shop_state.board.get_mut(player_slots[idx - 1]) {
+                bu.perm_statuses = bu.perm_statuses.union(&granted).difference(&removed);
+            }
+        }
+        for (uid, (ad, hd)) in outcome.player_permanent_stat_deltas() {
             let idx = uid.raw() as usize;
             if idx == 0 || idx > player_slots.len() {
                 continue;
             }
             let slot = player_slots[idx - 1];
             let remove = if let Some(Some(bu)) = shop_state.board.get_mut(slot) {
-                bu.perm_attack = bu.perm_attack.saturating_add(*ad);
-                bu.perm_health = bu.perm_health.saturating_add(*hd);
+                bu.perm_attack = bu.perm_attack.saturating_add(ad);
+                bu.perm_health = bu.perm_health.saturating_add(hd);
                 card_pool
                     .get(&bu.card_id)
                     .map(|c| c.stats.health.saturating_add(bu.perm_health) <= 0)
@@ -312,7 +348,7 @@ mod tests {
             session.bag = bag;
             session.hand = Vec::new();
             session.phase = PHASE_SHOP;
-            draw_hand(session, config.hand_size as usize);
+            draw_hand(session, config.hand_size as usize, config.rng_version);
             let mut ss = ShopState {
                 card_pool,
                 set_id: 0,
@@ -322,6 +358,7 @@ mod tests {
                 shop_mana: session.shop_mana,
                 round: session.round,
                 game_seed: session.game_seed,
+                rng_version: config.rng_version,
             };
             apply_shop_start_triggers_with_result(&mut ss, Some(result.clone()));
             session.hand = ss.hand;
@@ -378,6 +415,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn individual_cards_pass_registration_limits() {
+        for card in cards::get_all() {
+            let decoded =
+                UnitCard::decode_with_depth_limit(MAX_CARD_DECODE_DEPTH, &mut &card.encode()[..]);
+            assert_eq!(
+                decoded.as_ref(),
+                Ok(&card),
+                "Card {} ({})",
+                card.id.0,
+                card.name
+            );
+            assert!(card.within_limits(), "Card {} ({})", card.id.0, card.name);
+        }
+    }
+
     #[test]
     fn all_card_sets_fit_storage() {
         let metas = sets::get_all_metas();
@@ -434,6 +487,8 @@ mod tests {
                     card_id: CardId(10 + i),
                     perm_attack: 10,
                     perm_health: 15,
+                    perm_statuses: StatusMask::empty(),
+                    silenced: false,
                 })
             })
             .collect();
@@ -452,7 +507,9 @@ mod tests {
                     Some(BoardUnit {
                         card_id: CardId(10),
                         perm_attack: 99,
-                        perm_health: 99
+                        perm_health: 99,
+                        perm_statuses: StatusMask::empty(),
+                        silenced: false
                     });
                     5
                 ],
@@ -478,22 +535,25 @@ mod tests {
     #[test]
     fn bag_correct_size() {
         let cs = &sets::get_all()[0];
-        assert_eq!(create_starting_bag(cs, 42, 50).len(), 50);
+        assert_eq!(
+            create_starting_bag(cs, 42, 50, RngVersion::CURRENT).len(),
+            50
+        );
     }
     #[test]
     fn bag_deterministic() {
         let cs = &sets::get_all()[0];
         assert_eq!(
-            create_starting_bag(cs, 42, 50),
-            create_starting_bag(cs, 42, 50)
+            create_starting_bag(cs, 42, 50, RngVersion::CURRENT),
+            create_starting_bag(cs, 42, 50, RngVersion::CURRENT)
         );
     }
     #[test]
     fn bag_varies_with_seed() {
         let cs = &sets::get_all()[0];
         assert_ne!(
-            create_starting_bag(cs, 42, 50),
-            create_starting_bag(cs, 43, 50)
+            create_starting_bag(cs, 42, 50, RngVersion::CURRENT),
+            create_starting_bag(cs, 43, 50, RngVersion::CURRENT)
         );
     }
     #[test]
@@ -505,19 +565,21 @@ mod tests {
             .filter(|e| e.rarity > 0)
             .map(|e| e.card_id)
             .collect();
-        for id in create_starting_bag(cs, 42, 50) {
+        for id in create_starting_bag(cs, 42, 50, RngVersion::CURRENT) {
             assert!(ok.contains(&id));
         }
     }
     #[test]
     fn bag_matches_pallet() {
         let cs = &sets::get_all()[0];
-        for seed in [1, 42, 999, u64::MAX] {
-            assert_eq!(
-                create_starting_bag(cs, seed, 50),
-                oab_game::sealed::create_starting_bag(cs, seed, 50),
-                "seed {seed}"
-            );
+        for version in [RngVersion::V1, RngVersion::V2] {
+            for seed in [1, 42, 999, u64::MAX] {
+                assert_eq!(
+                    create_starting_bag(cs, seed, 50, version),
+                    oab_game::sealed::create_starting_bag(cs, seed, 50, version),
+                    "{version:?} seed {seed}"
+                );
+            }
         }
     }
 
@@ -559,7 +621,7 @@ mod tests {
             game_seed: 42,
             set_id: 0,
         };
-        draw_hand(&mut s, 5);
+        draw_hand(&mut s, 5, RngVersion::CURRENT);
         assert!(s.hand.is_empty());
     }
     #[test]
@@ -578,26 +640,31 @@ mod tests {
             game_seed: 42,
             set_id: 0,
         };
-        draw_hand(&mut s, 5);
+        draw_hand(&mut s, 5, RngVersion::CURRENT);
         assert_eq!(s.hand.len(), 2);
         assert!(s.bag.is_empty());
     }
     #[test]
     fn hand_indices_match_pallet() {
-        for (n, s, r) in [(45, 42u64, 1u8), (40, 100, 3), (10, 7, 8), (3, 1, 1)] {
-            let contract: Vec<usize> = {
-                let hand_count = 5usize.min(n);
-                let seed = s ^ (r as u64);
-                let mut rng = XorShiftRng::seed_from_u64(seed);
-                let mut indices: Vec<usize> = (0..n).collect();
-                for i in 0..hand_count {
-                    let j = i + rng.gen_range(n - i);
-                    indices.swap(i, j);
-                }
-                indices.truncate(hand_count);
-                indices
-            };
-            assert_eq!(contract, oab_game::derive_hand_indices_logic(n, s, r, 5));
+        let cases = [(45, 42u64, 1u8), (40, 100, 3), (10, 7, 8), (3, 1, 1)];
+        for version in [RngVersion::V1, RngVersion::V2] {
+            for (n, s, r) in cases {
+                let contract: Vec<usize> = {
+                    let hand_count = 5usize.min(n);
+                    let mut rng = VersionedRng::new(version, s, RngStream::Hand { round: r });
+                    let mut indices: Vec<usize> = (0..n).collect();
+                    for i in 0..hand_count {
+                        let j = i + rng.gen_range(n - i);
+                        indices.swap(i, j);
+                    }
+                    indices.truncate(hand_count);
+                    indices
+                };
+                assert_eq!(
+                    contract,
+                    oab_game::derive_hand_indices_logic(n, s, r, 5, version)
+                );
+            }
         }
     }
 
@@ -665,6 +732,7 @@ mod tests {
             shop_mana: s.shop_mana,
             round: s.round,
             game_seed: s.game_seed,
+            rng_version: default_config().rng_version,
         }
     }
 
@@ -748,6 +816,8 @@ mod tests {
             card_id: CardId(20),
             perm_attack: 0,
             perm_health: 0,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         });
         if submit_turn(
             &mut s,
@@ -800,6 +870,8 @@ mod tests {
             card_id: CardId(20),
             perm_attack: 50,
             perm_health: 50,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         });
         if submit_turn(
             &mut s,
@@ -861,13 +933,163 @@ mod tests {
                 e.clone(),
                 &mut XorShiftRng::seed_from_u64(42),
                 &pool,
-                5
+                &BattleRules::default()
             )
             .len(),
-            resolve_battle(p, e, &mut XorShiftRng::seed_from_u64(42), &pool, 5).len()
+            resolve_battle(
+                p,
+                e,
+                &mut XorShiftRng::seed_from_u64(42),
+                &pool,
+                &BattleRules::default()
+            )
+            .len()
         );
     }
 
+    #[test]
+    fn battle_outcome_matches_event_log() {
+        let reg = setup_registry();
+        let cards: Vec<&UnitCard> = reg.cards.values().collect();
+        for seed in 0..300u64 {
+            let mut picker = XorShiftRng::seed_from_u64(seed);
+            let board = |picker: &mut XorShiftRng| -> Vec<CombatUnit> {
+                let size = 1 + picker.gen_range(5);
+                (0..size)
+                    .map(|_| CombatUnit::from_card(cards[picker.gen_range(cards.len())].clone()))
+                    .collect()
+            };
+            let p = board(&mut picker);
+            let e = board(&mut picker);
+            let bracket = MatchmakingBracket {
+                set_id: 0,
+                round: 1 + (seed % 10) as RoundValue,
+                wins: 0,
+                lives: 3,
+            };
+            let modifiers = select_modifiers(
+                &ModifierSelection::FromSeed,
+                seed,
+                &bracket,
+                RngVersion::CURRENT,
+            );
+            let rules = BattleRules::default();
+
+            let mut log_rng = VersionedRng::new(RngVersion::CURRENT, seed, RngStream::Battle);
+            let events = resolve_battle_with_modifiers(
+                p.clone(),
+                e.clone(),
+                &mut log_rng,
+                &reg.cards,
+                &rules,
+                &modifiers,
+            );
+            let mut outcome_rng = VersionedRng::new(RngVersion::CURRENT, seed, RngStream::Battle);
+            let outcome = resolve_battle_outcome(
+                p.clone(),
+                e.clone(),
+                &mut outcome_rng,
+                &reg.cards,
+                &rules,
+                &modifiers,
+            );
+            // The contract's report: outcome and summary from one pass.
+            let mut report_rng = VersionedRng::new(RngVersion::CURRENT, seed, RngStream::Battle);
+            let mut report = (BattleOutcome::default(), SummarySink::default());
+            resolve_battle_into(
+                p,
+                e,
+                &mut report_rng,
+                &reg.cards,
+                &rules,
+                &modifiers,
+                &mut report,
+            );
+            let (report_outcome, summary) = report;
+
+            assert_eq!(outcome, BattleOutcome::from_events(&events), "seed {seed}");
+            assert_eq!(report_outcome, outcome, "seed {seed}");
+            assert_eq!(
+                summary.into_summary(),
+                BattleSummary::from_events(&events),
+                "seed {seed}"
+            );
+            assert_eq!(
+                outcome_rng.next_u64(),
+                log_rng.next_u64(),
+                "seed {seed}: RNG draws diverged"
+            );
+        }
+    }
+
+    /// FNV-1a hashes of the SCALE-encoded event logs of 3000 seeded asset
+    /// battles, in buckets of 300 seeds. Engine refactors must leave these
+    /// unchanged; a deliberate rules or asset change updates them.
+    const GOLDEN_EVENT_LOG_HASHES: [u64; 10] = [
+        0x12228d4e22f8ae8d,
+        0xad2b09a1fb83f3c4,
+        0x7e7c41120b8513f6,
+        0x640f384dec3cec97,
+        0xabcb9e1549b628be,
+        0x4d04954b5726a237,
+        0xb7a5b02af7397f94,
+        0x7755a0b5816edfbe,
+        0x15fccda7a53a2b27,
+        0xee8e9267f2a2cb72,
+    ];
+
+    fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+        bytes.iter().fold(hash, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+        })
+    }
+
+    #[test]
+    fn event_logs_match_golden_hashes() {
+        let reg = setup_registry();
+        let cards: Vec<&UnitCard> = reg.cards.values().collect();
+        for (bucket, expected) in GOLDEN_EVENT_LOG_HASHES.iter().enumerate() {
+            let seeds = bucket as u64 * 300..(bucket as u64 + 1) * 300;
+            let mut hash = 0xcbf29ce484222325;
+            for seed in seeds.clone() {
+                let mut picker = XorShiftRng::seed_from_u64(seed);
+                let board = |picker: &mut XorShiftRng| -> Vec<CombatUnit> {
+                    let size = 1 + picker.gen_range(5);
+                    (0..size)
+                        .map(|_| {
+                            CombatUnit::from_card(cards[picker.gen_range(cards.len())].clone())
+                        })
+                        .collect()
+                };
+                let p = board(&mut picker);
+                let e = board(&mut picker);
+                let bracket = MatchmakingBracket {
+                    set_id: 0,
+                    round: 1 + (seed % 10) as RoundValue,
+                    wins: 0,
+                    lives: 3,
+                };
+                let modifiers = select_modifiers(
+                    &ModifierSelection::FromSeed,
+                    seed,
+                    &bracket,
+                    RngVersion::CURRENT,
+                );
+                let mut rng = VersionedRng::new(RngVersion::CURRENT, seed, RngStream::Battle);
+                let events = resolve_battle_with_modifiers(
+                    p,
+                    e,
+                    &mut rng,
+                    &reg.cards,
+                    &BattleRules::default(),
+                    &modifiers,
+                );
+                hash = fnv1a(hash, &events.encode());
+            }
+            assert_eq!(hash, *expected, "event logs changed for seeds {seeds:?}");
+        }
+    }
+
     #[test]
     fn combat_unit_round_trip() {
         let u = CombatUnit::from_card(UnitCard::new(CardId(42), "T", 5, 10, 3, 1));
@@ -882,9 +1104,15 @@ mod tests {
             effect: AbilityEffect::SpawnUnit {
                 card_id: CardId(100),
                 spawn_location: SpawnLocation::DeathPosition,
+                count: 1,
+                side: SpawnSide::Own,
+                stats: SpawnStats::Base,
             },
             conditions: vec![],
             max_triggers: Some(1),
+            then: vec![],
+            max_triggers_per_round: None,
+            cooldown: None,
         });
         assert_eq!(
             CombatUnit::decode(&mut &CombatUnit::from_card(card).encode()[..])
@@ -907,6 +1135,8 @@ mod tests {
                 card_id: bu.card_id,
                 perm_attack: bu.perm_attack,
                 perm_health: bu.perm_health,
+                perm_statuses: bu.perm_statuses,
+                silenced: bu.silenced,
             })
             .collect()
     }
@@ -924,12 +1154,16 @@ mod tests {
                 card_id: CardId(10),
                 perm_attack: 2,
                 perm_health: 3,
+                perm_statuses: StatusMask::empty(),
+                silenced: false,
             }),
             None,
             Some(BoardUnit {
                 card_id: CardId(20),
                 perm_attack: 0,
                 perm_health: -1,
+                perm_statuses: StatusMask::empty(),
+                silenced: false,
             }),
             None,
             None,
@@ -950,6 +1184,8 @@ mod tests {
                     card_id: CardId(10 + i),
                     perm_attack: i as StatValue,
                     perm_health: (i * 2) as StatValue,
+                    perm_statuses: StatusMask::empty(),
+                    silenced: false,
                 })
             })
             .collect();
@@ -966,17 +1202,73 @@ mod tests {
             card_id: CardId(10),
             perm_attack: 99,
             perm_health: -50,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         })];
         let ghost = create_ghost_from_board(&board);
         assert_eq!(ghost[0].perm_attack, 99);
         assert_eq!(ghost[0].perm_health, -50);
     }
 
+    #[test]
+    fn ghost_keeps_silence() {
+        let reg = setup_registry();
+        let card = reg
+            .cards
+            .values()
+            .find(|card| !card.battle_abilities.is_empty())
+            .unwrap();
+        let mut silenced = BoardUnit::new(card.id);
+        silenced.silenced = true;
+        let ghost = create_ghost_from_board(&[Some(silenced), Some(BoardUnit::new(card.id))]);
+        assert!(ghost[0].silenced);
+        assert!(!ghost[1].silenced);
+
+        let mut store = GhostPoolStore::new();
+        store.push(0, 1, 0, 3, ghost);
+        let (units, _) = store.select(0, 1, 0, 3, 7, &reg.cards);
+        assert!(units[0].abilities.is_empty());
+        assert!(!units[1].abilities.is_empty());
+    }
+
+    #[test]
+    fn ghost_keeps_permanent_statuses() {
+        let reg = setup_registry();
+        let card = reg.cards.values().next().unwrap();
+        let statuses = StatusMask::from_statuses(&[Status::Shield, Status::Guard]);
+        let mut shielded = BoardUnit::new(card.id);
+        shielded.perm_statuses = statuses;
+        let ghost = create_ghost_from_board(&[Some(shielded)]);
+        assert_eq!(ghost[0].perm_statuses, statuses);
+
+        let mut store = GhostPoolStore::new();
+        store.push(0, 1, 0, 3, ghost);
+        let (units, _) = store.select(0, 1, 0, 3, 7, &reg.cards);
+        assert!(units[0].has_status(Status::Shield));
+        assert!(units[0].has_status(Status::Guard));
+    }
+
+    #[test]
+    fn ghost_health_never_starts_negative() {
+        let reg = setup_registry();
+        let card = reg.cards.values().next().unwrap();
+        let mut drained = BoardUnit::new(card.id);
+        drained.perm_health = -(card.stats.health + 50);
+        let ghost = create_ghost_from_board(&[Some(drained)]);
+
+        let mut store = GhostPoolStore::new();
+        store.push(0, 1, 0, 3, ghost);
+        let (units, _) = store.select(0, 1, 0, 3, 7, &reg.cards);
+        assert_eq!(units[0].health, 0);
+    }
+
     // ═════════════════════════════════════════════════════════════════════════════
     // Ghost pool FIFO logic
     // ═════════════════════════════════════════════════════════════════════════════
 
     const MAX_GHOSTS_PER_BRACKET: usize = 10;
+    /// Largest storage value the contract reads back.
+    const MAX_STORAGE_VALUE_LEN: usize = 16384;
 
     /// Simulated ghost pool (mirrors contract storage)
     struct GhostPoolStore {
@@ -1044,7 +1336,11 @@ mod tests {
                         let mut cu = CombatUnit::from_card(card.clone());
                         cu.attack_buff = unit.perm_attack;
                         cu.health_buff = unit.perm_health;
-                        cu.health = cu.health.saturating_add(unit.perm_health);
+                        cu.health = cu.health.saturating_add(unit.perm_health).max(0);
+                        cu.permanent_statuses = unit.perm_statuses;
+                        if unit.silenced {
+                            cu.silence();
+                        }
                         cu
                     })
                 })
@@ -1066,6 +1362,8 @@ mod tests {
             card_id: CardId(10),
             perm_attack: 0,
             perm_health: 0,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         }];
         store.push(0, 1, 0, 3, ghost.clone());
         let pool = store.load(0, 1, 0, 3);
@@ -1094,6 +1392,8 @@ mod tests {
                     card_id: CardId(i as u16),
                     perm_attack: 0,
                     perm_health: 0,
+                    perm_statuses: StatusMask::empty(),
+                    silenced: false,
                 }],
             );
         }
@@ -1111,6 +1411,8 @@ mod tests {
                 card_id: CardId(99),
                 perm_attack: 0,
                 perm_health: 0,
+                perm_statuses: StatusMask::empty(),
+                silenced: false,
             }],
         );
         let pool = store.load(0, 1, 0, 3);
@@ -1126,11 +1428,15 @@ mod tests {
             card_id: CardId(1),
             perm_attack: 0,
             perm_health: 0,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         }];
         let ghost_b = vec![GhostBoardUnit {
             card_id: CardId(2),
             perm_attack: 0,
             perm_health: 0,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         }];
         store.push(0, 1, 0, 3, ghost_a);
         store.push(0, 2, 1, 3, ghost_b);
@@ -1148,17 +1454,23 @@ mod tests {
                     card_id: CardId(10),
                     perm_attack: 5,
                     perm_health: -2,
+                    perm_statuses: StatusMask::empty(),
+                    silenced: false,
                 },
                 GhostBoardUnit {
                     card_id: CardId(20),
                     perm_attack: 0,
                     perm_health: 10,
+                    perm_statuses: StatusMask::empty(),
+                    silenced: false,
                 },
             ],
             vec![GhostBoardUnit {
                 card_id: CardId(30),
                 perm_attack: 99,
                 perm_health: 0,
+                perm_statuses: StatusMask::empty(),
+                silenced: false,
             }],
         ];
         let encoded = pool.encode();
@@ -1168,20 +1480,27 @@ mod tests {
 
     #[test]
     fn ghost_pool_fits_storage() {
-        // Max pool: 10 ghosts, each with 5 units (6 bytes each) + SCALE overhead
-        let full_pool: Vec<Vec<GhostBoardUnit>> = (0..10)
+        // Max pool: 10 ghosts, each with a full board of 39-byte units + SCALE overhead
+        let full_pool: Vec<Vec<GhostBoardUnit>> = (0..MAX_GHOSTS_PER_BRACKET)
             .map(|_| {
-                (0..5)
+                (0..oab_battle::rules::MAX_BOARD_SIZE)
                     .map(|i| GhostBoardUnit {
-                        card_id: CardId(i),
+                        card_id: CardId(i.into()),
                         perm_attack: 99,
                         perm_health: 99,
+                        perm_statuses: StatusMask::empty(),
+                        silenced: false,
                     })
                     .collect()
             })
             .collect();
         let size = full_pool.encode().len();
-        assert!(size <= 416, "Full ghost pool is {} bytes > 416", size);
+        assert!(
+            size <= MAX_STORAGE_VALUE_LEN,
+            "Full ghost pool is {} bytes > {}",
+            size,
+            MAX_STORAGE_VALUE_LEN
+        );
     }
 
     // ═════════════════════════════════════════════════════════════════════════════
@@ -1207,6 +1526,8 @@ mod tests {
             card_id: card_set.cards[0].card_id,
             perm_attack: 0,
             perm_health: 0,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         }];
         store.push(0, 1, 0, 3, ghost.clone());
         let (units, returned_ghost) = store.select(0, 1, 0, 3, 42, &card_pool);
@@ -1230,6 +1551,8 @@ mod tests {
                     card_id: CardId(card_set.cards[i as usize].card_id.0),
                     perm_attack: 0,
                     perm_health: 0,
+                    perm_statuses: StatusMask::empty(),
+                    silenced: false,
                 }],
             );
         }
@@ -1254,6 +1577,8 @@ mod tests {
                     card_id: CardId(card_set.cards[i as usize].card_id.0),
                     perm_attack: 0,
                     perm_health: 0,
+                    perm_statuses: StatusMask::empty(),
+                    silenced: false,
                 }],
             );
         }
@@ -1285,6 +1610,8 @@ mod tests {
                 card_id,
                 perm_attack: 5,
                 perm_health: 10,
+                perm_statuses: StatusMask::empty(),
+                silenced: false,
             }],
         );
         let (units, _) = store.select(0, 1, 0, 3, 42, &card_pool);
@@ -1472,11 +1799,15 @@ mod tests {
             card_id: CardId(10),
             perm_attack: 5,
             perm_health: 3,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         });
         session.board[2] = Some(BoardUnit {
             card_id: CardId(20),
             perm_attack: 0,
             perm_health: 0,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         });
         session.wins = 10;
         session.phase = PHASE_COMPLETED;
@@ -1511,6 +1842,8 @@ mod tests {
             card_id: CardId(10),
             perm_attack: 0,
             perm_health: 0,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         });
         session.round = 7;
         session.wins = 10;
@@ -1534,6 +1867,8 @@ mod tests {
             card_id: CardId(20),
             perm_attack: 50,
             perm_health: 50,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         });
         session.wins = 9;
         let result = submit_turn(
@@ -1586,6 +1921,8 @@ mod tests {
             card_id: CardId(10),
             perm_attack: 5,
             perm_health: 3,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         });
         // Abandon during shop phase — should NOT archive ghost
         let ghost_store = GhostPoolStore::new();
@@ -1604,6 +1941,8 @@ mod tests {
             card_id: CardId(10),
             perm_attack: 0,
             perm_health: 0,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         });
         session.phase = PHASE_COMPLETED;
         session.wins = 10;
@@ -1630,6 +1969,8 @@ mod tests {
             card_id,
             perm_attack: 2,
             perm_health: 1,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         });
         // Create the ghost the same way the contract does (before battle)
         let mut shop = make_shop(&session, &reg);
@@ -1675,6 +2016,8 @@ mod tests {
             card_id: card_set.cards[0].card_id,
             perm_attack: 0,
             perm_health: 0,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         });
 
         // Play through several rounds, archiving ghosts each turn (like the contract does)
@@ -1724,6 +2067,8 @@ mod tests {
             card_id: card_set.cards[0].card_id,
             perm_attack: 10,
             perm_health: 5,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         });
         session.wins = 10;
         session.round = 5;
@@ -1759,6 +2104,8 @@ mod tests {
             card_id: CardId(20),
             perm_attack: 3,
             perm_health: 7,
+            perm_statuses: StatusMask::empty(),
+            silenced: false,
         });
         // Submit a turn — if the unit survives, stats should persist
         let initial_perm_attack = 3;
@@ -1798,6 +2145,8 @@ mod tests {
                     card_id: CardId(10),
                     perm_attack: 1,
                     perm_health: 2,
+                    perm_statuses: StatusMask::empty(),
+                    silenced: false,
                 }),
                 None,
                 None,
@@ -1836,6 +2185,8 @@ mod tests {
                     card_id: CardId(10),
                     perm_attack: 20,
                     perm_health: 15,
+                    perm_statuses: StatusMask::empty(),
+                    silenced: false,
                 }),
                 None,
                 None,
@@ -1869,6 +2220,8 @@ mod tests {
                     card_id: CardId(10),
                     perm_attack: 20,
                     perm_health: 15,
+                    perm_statuses: StatusMask::empty(),
+                    silenced: false,
                 }),
                 None,
                 None,
@@ -1894,6 +2247,20 @@ mod tests {
         assert_eq!(decoded.state.wins, 10);
     }
 
+    #[test]
+    fn frontend_config_bytes_decode_as_contract_config() {
+        // web/src/contract/index.ts appends these to ArenaSession bytes.
+        let bytes = [3u8, 10, 3, 10, 0, 5, 5, 50, 0, 2, 0, 1];
+        let decoded = oab_game::GameConfig::decode(&mut &bytes[..]).unwrap();
+        let config = default_config();
+        assert_eq!(decoded.starting_lives, config.starting_lives);
+        assert_eq!(decoded.board_size, config.board_size);
+        assert_eq!(decoded.bag_size, config.bag_size);
+        assert_eq!(decoded.battle_modifiers, config.battle_modifiers);
+        assert_eq!(decoded.rules_version, config.rules_version);
+        assert_eq!(decoded.rng_version, config.rng_version);
+    }
+
     #[test]
     fn ghost_board_unit_encoding_matches_types_crate() {
         // Verify GhostBoardUnit encoding is the same between test and battle crate
@@ -1901,13 +2268,18 @@ mod tests {
             card_id: CardId(42),
             perm_attack: -3,
             perm_health: 10,
+            perm_statuses: StatusMask::from_statuses(&[Status::Shield]),
+            silenced: true,
         };
         let encoded = unit.encode();
         let decoded = GhostBoardUnit::decode(&mut &encoded[..]).unwrap();
         assert_eq!(decoded.card_id, CardId(42));
         assert_eq!(decoded.perm_attack, -3);
         assert_eq!(decoded.perm_health, 10);
-        // CardId(u16) = 2 bytes, StatValue(i16) = 2 bytes each, total = 6 bytes
-        assert_eq!(encoded.len(), 6);
+        assert!(decoded.perm_statuses.contains(Status::Shield));
+        assert!(decoded.silenced);
+        // CardId(u16) = 2 bytes, StatValue(i16) = 2 bytes each,
+        // StatusMask = 32 bytes, bool = 1 byte, total = 39 bytes
+        assert_eq!(encoded.len(), 39);
     }
 } // mod tests
@@ -12,6 +12,7 @@ use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
 use oab_battle::bounded::BoundedUnitCard;
+use oab_battle::rng::RngVersion;
 use oab_battle::types::{BoardUnit, CardId, ManaValue, RoundValue, SetIdValue};
 
 use crate::state::{derive_hand_indices_logic, GamePhase, GameSession, GameState, LocalGameState};
@@ -36,15 +37,20 @@ where
     MaxConditions: Get<u32>,
 {
     /// Populate the hand by drawing from the bag.
-    pub fn draw_hand(&mut self, hand_size: usize) {
+    pub fn draw_hand(&mut self, hand_size: usize, rng_version: RngVersion) {
         // Return unused hand cards to the bag
         let hand_cards: Vec<_> = self.hand.drain(..).collect();
         for card_id in hand_cards {
             let _ = self.bag.try_push(card_id);
         }
 
-        let indices =
-            derive_hand_indices_logic(self.bag.len(), self.game_seed, self.round, hand_size);
+        let indices = derive_hand_indices_logic(
+            self.bag.len(),
+            self.game_seed,
+            self.round,
+            hand_size,
+            rng_version,
+        );
         if indices.is_empty() {
             return;
         }
@@ -4,6 +4,9 @@ use alloc::collections::BTreeMap;
 use alloc::format;
 use alloc::string::String;
 
+use oab_battle::modifiers::ModifierSelection;
+use oab_battle::rng::RngVersion;
+use oab_battle::rules::CURRENT_RULES_VERSION;
 use oab_battle::state::CardSet;
 
 use oab_battle::types::CardId;
@@ -27,6 +30,9 @@ pub fn default_config() -> GameConfig {
         board_size: 5,
         hand_size: 5,
         bag_size: 50,
+        battle_modifiers: ModifierSelection::None,
+        rules_version: CURRENT_RULES_VERSION,
+        rng_version: RngVersion::CURRENT,
     }
 }
 
@@ -49,6 +49,22 @@ pub struct GameConfig {
     pub hand_size: oab_battle::types::IndexValue,
     /// Number of cards in the starting bag/deck.
     pub bag_size: oab_battle::types::IndexValue,
+    /// How battlefield modifiers are chosen for each battle.
+    #[cfg_attr(feature = "std", serde(default))]
+    pub battle_modifiers: oab_battle::modifiers::ModifierSelection,
+    /// Battle rules version this run was started with. Runs saved before
+    /// versioning load as the legacy rules.
+    #[cfg_attr(feature = "std", serde(default = "legacy_rules_version"))]
+    pub rules_version: oab_battle::rules::RulesVersion,
+    /// Generator this run was started with. Runs saved before versioning
+    /// load as `RngVersion::V1`.
+    #[cfg_attr(feature = "std", serde(default))]
+    pub rng_version: oab_battle::rng::RngVersion,
+}
+
+#[cfg(feature = "std")]
+fn legacy_rules_version() -> oab_battle::rules::RulesVersion {
+    oab_battle::rules::LEGACY_RULES_VERSION
 }
 
 impl GameConfig {
@@ -61,6 +77,13 @@ impl GameConfig {
             .saturating_add(round.saturating_sub(1))
             .min(self.max_mana_limit)
     }
+
+    /// Battle rules for this run: its recorded rules version on its board size.
+    /// None if the version is unknown to this build.
+    pub fn battle_rules(&self) -> Option<oab_battle::rules::BattleRules> {
+        oab_battle::rules::BattleRules::for_version(self.rules_version)
+            .map(|rules| rules.with_board_size(self.board_size))
+    }
 }
 
 #[cfg(feature = "bounded")]
@@ -2,7 +2,9 @@
 
 use alloc::vec::Vec;
 
-use oab_battle::rng::{BattleRng, XorShiftRng};
+use oab_battle::modifiers::ModifierSelection;
+use oab_battle::rng::{BattleRng, RngStream, RngVersion, VersionedRng};
+use oab_battle::rules::CURRENT_RULES_VERSION;
 use oab_battle::state::CardSet;
 
 use oab_battle::types::CardId;
@@ -20,17 +22,25 @@ pub fn default_config() -> GameConfig {
         board_size: 5,
         hand_size: 5,
         bag_size: 50,
+        battle_modifiers: ModifierSelection::None,
+        rules_version: CURRENT_RULES_VERSION,
+        rng_version: RngVersion::CURRENT,
     }
 }
 
 /// Create a starting bag of random CardIds from a card set, weighted by rarity.
-pub fn create_starting_bag(set: &CardSet, seed: u64, bag_size: usize) -> Vec<CardId> {
+pub fn create_starting_bag(
+    set: &CardSet,
+    seed: u64,
+    bag_size: usize,
+    rng_version: RngVersion,
+) -> Vec<CardId> {
     if set.cards.is_empty() {
         return Vec::new();
     }
 
     let mut bag = Vec::with_capacity(bag_size);
-    let mut rng = XorShiftRng::seed_from_u64(seed);
+    let mut rng = VersionedRng::new(rng_version, seed, RngStream::StartingBag);
 
     // Calculate total weight for weighted selection
     let total_weight: u16 = set.cards.iter().map(|entry| entry.rarity as u16).sum();
@@ -65,7 +75,7 @@ mod tests {
     #[test]
     fn empty_set_returns_empty() {
         let set = CardSet { cards: vec![] };
-        let bag = create_starting_bag(&set, 123, 50);
+        let bag = create_starting_bag(&set, 123, 50, RngVersion::CURRENT);
         assert!(bag.is_empty());
     }
 
@@ -83,7 +93,7 @@ mod tests {
                 },
             ],
         };
-        let bag = create_starting_bag(&set, 999, 50);
+        let bag = create_starting_bag(&set, 999, 50, RngVersion::CURRENT);
         assert!(bag.is_empty());
     }
 
@@ -105,9 +115,9 @@ mod tests {
                 },
             ],
         };
-        let a = create_starting_bag(&set, 77, 50);
-        let b = create_starting_bag(&set, 77, 50);
-        let c = create_starting_bag(&set, 78, 50);
+        let a = create_starting_bag(&set, 77, 50, RngVersion::CURRENT);
+        let b = create_starting_bag(&set, 77, 50, RngVersion::CURRENT);
+        let c = create_starting_bag(&set, 78, 50, RngVersion::CURRENT);
         assert_eq!(a, b, "same seed must produce same bag");
         assert_ne!(a, c, "different seed should produce different bag");
     }
@@ -127,7 +137,7 @@ mod tests {
             ],
         };
         let config = default_config();
-        let bag = create_starting_bag(&set, 321, config.bag_size as usize);
+        let bag = create_starting_bag(&set, 321, config.bag_size as usize, config.rng_version);
         assert_eq!(bag.len(), config.bag_size as usize);
         assert!(bag.iter().all(|id| *id == CardId(42)));
     }
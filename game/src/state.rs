@@ -9,7 +9,7 @@ use alloc::vec::Vec;
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
-use oab_battle::rng::{BattleRng, XorShiftRng};
+use oab_battle::rng::{BattleRng, RngStream, RngVersion, VersionedRng};
 use oab_battle::state::{find_empty_board_slot, ShopState};
 use oab_battle::types::{BoardUnit, CardId, ManaValue, RoundValue, SetIdValue, UnitCard};
 
@@ -120,6 +120,7 @@ impl GameState {
                 shop_mana: 0,
                 round: 1,
                 game_seed,
+                rng_version: config.rng_version,
             },
             config,
             bag: Vec::new(),
@@ -142,6 +143,7 @@ impl GameState {
                 shop_mana: 0,
                 round: 0,
                 game_seed: 0,
+                rng_version: RngVersion::CURRENT,
             },
             config: crate::sealed::default_config(),
             bag: Vec::new(),
@@ -169,6 +171,7 @@ impl GameState {
                 shop_mana: local.shop_mana,
                 round: local.round,
                 game_seed: local.game_seed,
+                rng_version: config.rng_version,
             },
             config,
             bag: local.bag,
@@ -213,6 +216,7 @@ impl GameState {
             self.shop.game_seed,
             self.shop.round,
             hand_size,
+            self.shop.rng_version,
         );
         if indices.is_empty() {
             return;
@@ -254,14 +258,14 @@ pub fn derive_hand_indices_logic(
     game_seed: u64,
     round: RoundValue,
     hand_size: usize,
+    rng_version: RngVersion,
 ) -> Vec<usize> {
     if bag_len == 0 {
         return Vec::new();
     }
 
     let hand_count = hand_size.min(bag_len);
-    let seed = game_seed ^ (round as u64);
-    let mut rng = XorShiftRng::seed_from_u64(seed);
+    let mut rng = VersionedRng::new(rng_version, game_seed, RngStream::Hand { round });
 
     let mut indices: Vec<usize> = (0..bag_len).collect();
     for i in 0..hand_count {
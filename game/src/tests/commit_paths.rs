@@ -209,14 +209,15 @@ fn test_on_buy_damage_trigger_source_removes_bought_unit() {
     let support = UnitCard::new(support_id, "Bomber", 1, 2, 1, 1).with_shop_ability(ShopAbility {
         trigger: ShopTrigger::OnBuy,
         effect: ShopEffect::ModifyStatsPermanent {
-            health: -3,
-            attack: 0,
+            health: ShopValueExpr::Const(-3),
+            attack: ShopValueExpr::Const(0),
             target: ShopTarget::All {
                 scope: ShopScope::TriggerSource,
             },
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
     });
 
     let bought_id = add_card(&mut state, "Fragile", 1, 1, 0, 0);
@@ -259,6 +260,7 @@ fn test_on_buy_destroy_standard_target_selects_highest_attack() {
             },
             conditions: vec![],
             max_triggers: None,
+            then: vec![],
         });
 
     let high_id = add_card(&mut state, "High", 6, 3, 0, 0);
@@ -296,8 +298,8 @@ fn test_on_sell_self_position_zero_fizzles_when_source_is_removed() {
     let seller = UnitCard::new(seller_id, "Seller", 1, 2, 1, 1).with_shop_ability(ShopAbility {
         trigger: ShopTrigger::OnSell,
         effect: ShopEffect::ModifyStatsPermanent {
-            health: 10,
-            attack: 0,
+            health: ShopValueExpr::Const(10),
+            attack: ShopValueExpr::Const(0),
             target: ShopTarget::Position {
                 scope: ShopScope::SelfUnit,
                 index: 0,
@@ -305,6 +307,7 @@ fn test_on_sell_self_position_zero_fizzles_when_source_is_removed() {
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
     });
 
     state.card_pool.insert(seller_id, seller);
@@ -331,14 +334,15 @@ fn test_on_sell_allies_other_targets_survivors_when_source_is_removed() {
     let seller = UnitCard::new(seller_id, "Seller", 1, 2, 1, 1).with_shop_ability(ShopAbility {
         trigger: ShopTrigger::OnSell,
         effect: ShopEffect::ModifyStatsPermanent {
-            health: 0,
-            attack: 2,
+            health: ShopValueExpr::Const(0),
+            attack: ShopValueExpr::Const(2),
             target: ShopTarget::All {
                 scope: ShopScope::AlliesOther,
             },
         },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
     });
 
     state.card_pool.insert(seller_id, seller);
@@ -367,13 +371,16 @@ fn test_shop_condition_gate_can_prevent_effects() {
 
     let gated = UnitCard::new(gate_id, "Gate", 3, 3, 0, 0).with_shop_ability(ShopAbility {
         trigger: ShopTrigger::OnBuy,
-        effect: ShopEffect::GainMana { amount: 2 },
+        effect: ShopEffect::GainMana {
+            amount: ShopValueExpr::Const(2),
+        },
         conditions: vec![ShopCondition::Is(ShopMatcher::UnitCount {
             scope: ShopScope::Allies,
             op: CompareOp::GreaterThan,
             value: 10,
         })],
         max_triggers: None,
+        then: vec![],
     });
 
     state.card_pool.insert(gate_id, gated);
@@ -407,24 +414,25 @@ fn test_shop_condition_anyof_path_applies_with_valid_targets() {
     let source = UnitCard::new(source_id, "Source", 1, 2, 0, 0).with_shop_ability(ShopAbility {
         trigger: ShopTrigger::OnShopStart,
         effect: ShopEffect::ModifyStatsPermanent {
-            health: 0,
-            attack: 5,
+            health: ShopValueExpr::Const(0),
+            attack: ShopValueExpr::Const(5),
             target: ShopTarget::All {
                 scope: ShopScope::AlliesOther,
             },
         },
         conditions: vec![ShopCondition::AnyOf(vec![
-            ShopMatcher::UnitCount {
+            ShopCondition::Is(ShopMatcher::UnitCount {
                 scope: ShopScope::Allies,
                 op: CompareOp::GreaterThanOrEqual,
                 value: 1,
-            },
-            ShopMatcher::IsPosition {
+            }),
+            ShopCondition::Is(ShopMatcher::IsPosition {
                 scope: ShopScope::SelfUnit,
                 index: 99,
-            },
+            }),
         ])],
         max_triggers: None,
+        then: vec![],
     });
 
     state.card_pool.insert(source_id, source);
@@ -438,3 +446,396 @@ fn test_shop_condition_anyof_path_applies_with_valid_targets() {
         "AnyOf should pass and buff allies-other via valid shop target"
     );
 }
+
+#[test]
+fn test_on_buy_grant_and_remove_status_permanent() {
+    let mut state = GameState::new(12, crate::sealed::default_config());
+    state.mana_limit = 10;
+    state.shop_mana = 10;
+
+    let blesser_id = state.generate_card_id();
+    let blesser = UnitCard::new(blesser_id, "Blesser", 1, 2, 1, 1).with_shop_ability(ShopAbility {
+        trigger: ShopTrigger::OnBuy,
+        effect: ShopEffect::GrantStatusPermanent {
+            status: Status::Shield,
+            target: ShopTarget::All {
+                scope: ShopScope::TriggerSource,
+            },
+        },
+        conditions: vec![],
+        max_triggers: None,
+        then: vec![],
+    });
+    let cleanser_id = state.generate_card_id();
+    let cleanser =
+        UnitCard::new(cleanser_id, "Cleanser", 1, 2, 1, 1).with_shop_ability(ShopAbility {
+            trigger: ShopTrigger::OnBuy,
+            effect: ShopEffect::RemoveStatusPermanent {
+                status: Status::Guard,
+                target: ShopTarget::All {
+                    scope: ShopScope::Allies,
+                },
+            },
+            conditions: vec![],
+            max_triggers: None,
+            then: vec![],
+        });
+
+    let guarded_id = add_card(&mut state, "Guarded", 1, 3, 0, 0);
+    let bought_id = add_card(&mut state, "Bought", 2, 2, 0, 0);
+    state.card_pool.insert(blesser_id, blesser);
+    state.card_pool.insert(cleanser_id, cleanser);
+    state.board[0] = Some(BoardUnit::new(blesser_id));
+    state.board[1] = Some(BoardUnit::new(cleanser_id));
+    let mut guarded = BoardUnit::new(guarded_id);
+    guarded.perm_statuses.insert(Status::Guard);
+    state.board[2] = Some(guarded);
+    state.hand = vec![bought_id];
+
+    let action = CommitTurnAction {
+        actions: vec![TurnAction::PlayFromHand {
+            hand_index: 0,
+            board_slot: 3,
+        }],
+    };
+
+    let result = verify_and_apply_turn(&mut state, &action);
+    assert!(result.is_ok(), "buy action should succeed: {:?}", result);
+    let bought = state.board[3].as_ref().unwrap();
+    assert_eq!(bought.card_id, bought_id);
+    assert!(bought.perm_statuses.contains(Status::Shield));
+    assert!(
+        state.board[2].as_ref().unwrap().perm_statuses.is_empty(),
+        "permanent Guard should be removed from allies"
+    );
+}
+
+#[test]
+fn test_shop_heal_restores_lost_health_without_overhealing() {
+    let mut state = GameState::new(13, crate::sealed::default_config());
+    state.mana_limit = 10;
+    state.shop_mana = 10;
+
+    let medic_id = state.generate_card_id();
+    let medic = UnitCard::new(medic_id, "Medic", 1, 2, 1, 1).with_shop_ability(ShopAbility {
+        trigger: ShopTrigger::OnBuy,
+        effect: ShopEffect::Heal {
+            amount: ShopValueExpr::Const(3),
+            target: ShopTarget::All {
+                scope: ShopScope::AlliesOther,
+            },
+        },
+        conditions: vec![],
+        max_triggers: None,
+        then: vec![],
+    });
+
+    let wounded_id = add_card(&mut state, "Wounded", 1, 5, 0, 0);
+    let buffed_id = add_card(&mut state, "Buffed", 1, 5, 0, 0);
+    let bought_id = add_card(&mut state, "Bought", 1, 1, 0, 0);
+    state.card_pool.insert(medic_id, medic);
+    state.board[0] = Some(BoardUnit::new(medic_id));
+    let mut wounded = BoardUnit::new(wounded_id);
+    wounded.perm_health = -2;
+    state.board[1] = Some(wounded);
+    let mut buffed = BoardUnit::new(buffed_id);
+    buffed.perm_health = 4;
+    state.board[2] = Some(buffed);
+    state.hand = vec![bought_id];
+
+    let action = CommitTurnAction {
+        actions: vec![TurnAction::PlayFromHand {
+            hand_index: 0,
+            board_slot: 3,
+        }],
+    };
+
+    let result = verify_and_apply_turn(&mut state, &action);
+    assert!(result.is_ok(), "buy action should succeed: {:?}", result);
+    assert_eq!(state.board[1].as_ref().unwrap().perm_health, 0);
+    assert_eq!(state.board[2].as_ref().unwrap().perm_health, 4);
+    assert_eq!(state.board[3].as_ref().unwrap().perm_health, 0);
+}
+
+#[test]
+fn test_shop_composite_ability_follows_up_on_previous_targets() {
+    let mut state = GameState::new(17, crate::sealed::default_config());
+    state.mana_limit = 10;
+    state.shop_mana = 10;
+
+    let drill_id = state.generate_card_id();
+    let drill =
+        UnitCard::new(drill_id, "Drill Sergeant", 1, 2, 1, 1).with_shop_ability(ShopAbility {
+            trigger: ShopTrigger::OnBuy,
+            effect: ShopEffect::ModifyStatsPermanent {
+                health: ShopValueExpr::Const(0),
+                attack: ShopValueExpr::Const(1),
+                target: ShopTarget::Standard {
+                    scope: ShopScope::AlliesOther,
+                    stat: StatType::Health,
+                    order: SortOrder::Ascending,
+                    count: 1,
+                },
+            },
+            conditions: vec![],
+            max_triggers: None,
+            then: vec![ShopEffect::GrantStatusPermanent {
+                status: Status::Shield,
+                target: ShopTarget::PreviousTargets,
+            }],
+        });
+
+    let sturdy_id = add_card(&mut state, "Sturdy", 1, 5, 0, 0);
+    let frail_id = add_card(&mut state, "Frail", 1, 3, 0, 0);
+    let bought_id = add_card(&mut state, "Bought", 1, 9, 0, 0);
+    state.card_pool.insert(drill_id, drill);
+    state.board[0] = Some(BoardUnit::new(drill_id));
+    state.board[1] = Some(BoardUnit::new(sturdy_id));
+    state.board[2] = Some(BoardUnit::new(frail_id));
+    state.hand = vec![bought_id];
+
+    let action = CommitTurnAction {
+        actions: vec![TurnAction::PlayFromHand {
+            hand_index: 0,
+            board_slot: 3,
+        }],
+    };
+
+    let result = verify_and_apply_turn(&mut state, &action);
+    assert!(result.is_ok(), "buy action should succeed: {:?}", result);
+    let sturdy = state.board[1].as_ref().unwrap();
+    let frail = state.board[2].as_ref().unwrap();
+    assert_eq!(sturdy.perm_attack, 0);
+    assert!(!sturdy.perm_statuses.contains(Status::Shield));
+    assert_eq!(frail.perm_attack, 1);
+    assert!(frail.perm_statuses.contains(Status::Shield));
+}
+
+#[test]
+fn test_shop_effect_amount_scales_with_board() {
+    let mut state = GameState::new(19, crate::sealed::default_config());
+    state.mana_limit = 10;
+    state.shop_mana = 10;
+
+    let captain_id = state.generate_card_id();
+    let captain = UnitCard::new(captain_id, "Captain", 1, 2, 1, 1).with_shop_ability(ShopAbility {
+        trigger: ShopTrigger::OnBuy,
+        effect: ShopEffect::ModifyStatsPermanent {
+            health: ShopValueExpr::Scaled {
+                term: ShopValueTerm::UnitCount {
+                    scope: ShopScope::AlliesOther,
+                },
+                factor: 1,
+            },
+            attack: ShopValueExpr::Scaled {
+                term: ShopValueTerm::Stat {
+                    scope: ShopScope::AlliesOther,
+                    stat: StatType::Attack,
+                },
+                factor: 1,
+            },
+            target: ShopTarget::Position {
+                scope: ShopScope::SelfUnit,
+                index: 0,
+            },
+        },
+        conditions: vec![],
+        max_triggers: None,
+        then: vec![],
+    });
+
+    let first_id = add_card(&mut state, "First", 2, 5, 0, 0);
+    let second_id = add_card(&mut state, "Second", 3, 5, 0, 0);
+    let bought_id = add_card(&mut state, "Bought", 0, 1, 0, 0);
+    state.card_pool.insert(captain_id, captain);
+    state.board[0] = Some(BoardUnit::new(captain_id));
+    state.board[1] = Some(BoardUnit::new(first_id));
+    state.board[2] = Some(BoardUnit::new(second_id));
+    state.hand = vec![bought_id];
+
+    let action = CommitTurnAction {
+        actions: vec![TurnAction::PlayFromHand {
+            hand_index: 0,
+            board_slot: 3,
+        }],
+    };
+
+    let result = verify_and_apply_turn(&mut state, &action);
+    assert!(result.is_ok(), "buy action should succeed: {:?}", result);
+    let captain = state.board[0].as_ref().unwrap();
+    assert_eq!(captain.perm_attack, 5);
+    assert_eq!(captain.perm_health, 3);
+}
+
+#[test]
+fn test_shop_tag_target_and_matcher() {
+    let mut state = GameState::new(23, crate::sealed::default_config());
+    state.mana_limit = 10;
+    state.shop_mana = 10;
+
+    let keeper_id = state.generate_card_id();
+    let keeper =
+        UnitCard::new(keeper_id, "Hive Keeper", 1, 2, 1, 1).with_shop_ability(ShopAbility {
+            trigger: ShopTrigger::OnBuy,
+            effect: ShopEffect::ModifyStatsPermanent {
+                health: ShopValueExpr::Const(1),
+                attack: ShopValueExpr::Const(1),
+                target: ShopTarget::AllWithTag {
+                    scope: ShopScope::AlliesOther,
+                    tag: Tag::Insect,
+                },
+            },
+            conditions: vec![ShopCondition::Is(ShopMatcher::TagCount {
+                scope: ShopScope::Allies,
+                tag: Tag::Insect,
+                op: CompareOp::GreaterThanOrEqual,
+                value: 2,
+            })],
+            max_triggers: None,
+            then: vec![],
+        });
+
+    let ant_id = add_card(&mut state, "Ant", 1, 1, 0, 0);
+    let wolf_id = add_card(&mut state, "Wolf", 1, 1, 0, 0);
+    let bee_id = add_card(&mut state, "Bee", 1, 1, 0, 0);
+    let insect = TagMask::from_tags(&[Tag::Insect]);
+    for id in [ant_id, bee_id] {
+        let card = state.card_pool.remove(&id).unwrap().with_tags(insect);
+        state.card_pool.insert(id, card);
+    }
+    let wolf = state.card_pool.remove(&wolf_id).unwrap();
+    state
+        .card_pool
+        .insert(wolf_id, wolf.with_tags(TagMask::from_tags(&[Tag::Beast])));
+    state.card_pool.insert(keeper_id, keeper);
+    state.board[0] = Some(BoardUnit::new(keeper_id));
+    state.board[1] = Some(BoardUnit::new(ant_id));
+    state.board[2] = Some(BoardUnit::new(wolf_id));
+    state.hand = vec![bee_id];
+
+    let action = CommitTurnAction {
+        actions: vec![TurnAction::PlayFromHand {
+            hand_index: 0,
+            board_slot: 3,
+        }],
+    };
+
+    let result = verify_and_apply_turn(&mut state, &action);
+    assert!(result.is_ok(), "buy action should succeed: {:?}", result);
+    assert_eq!(state.board[1].as_ref().unwrap().perm_attack, 1);
+    assert_eq!(state.board[2].as_ref().unwrap().perm_attack, 0);
+    assert_eq!(state.board[3].as_ref().unwrap().perm_attack, 1);
+}
+
+#[test]
+fn test_shop_silence_removes_abilities_until_sold() {
+    let mut state = GameState::new(24, crate::sealed::default_config());
+    state.mana_limit = 20;
+    state.shop_mana = 10;
+
+    let hush_id = state.generate_card_id();
+    let hush = UnitCard::new(hush_id, "Hush", 1, 2, 1, 1).with_shop_ability(ShopAbility {
+        trigger: ShopTrigger::OnBuy,
+        effect: ShopEffect::Silence {
+            target: ShopTarget::All {
+                scope: ShopScope::AlliesOther,
+            },
+        },
+        conditions: vec![],
+        max_triggers: None,
+        then: vec![],
+    });
+    let seller_id = state.generate_card_id();
+    let seller = UnitCard::new(seller_id, "Seller", 1, 2, 1, 1).with_shop_ability(ShopAbility {
+        trigger: ShopTrigger::OnSell,
+        effect: ShopEffect::GainMana {
+            amount: ShopValueExpr::Const(3),
+        },
+        conditions: vec![],
+        max_triggers: None,
+        then: vec![],
+    });
+    state.card_pool.insert(hush_id, hush);
+    state.card_pool.insert(seller_id, seller);
+    state.board[0] = Some(BoardUnit::new(seller_id));
+    state.hand = vec![hush_id];
+
+    let action = CommitTurnAction {
+        actions: vec![
+            TurnAction::PlayFromHand {
+                hand_index: 0,
+                board_slot: 1,
+            },
+            TurnAction::BurnFromBoard { board_slot: 0 },
+        ],
+    };
+
+    let result = verify_and_apply_turn(&mut state, &action);
+    assert!(result.is_ok(), "turn should succeed: {:?}", result);
+    assert!(
+        !state.board[1].as_ref().unwrap().silenced,
+        "Hush does not silence itself"
+    );
+    assert_eq!(
+        state.shop_mana, 10,
+        "silenced seller should only refund its burn value"
+    );
+}
+
+#[test]
+fn test_on_sell_spawns_copies_with_inherited_and_fixed_stats() {
+    let mut state = GameState::new(25, crate::sealed::default_config());
+    state.mana_limit = 10;
+
+    let pup_id = add_card(&mut state, "Pup", 1, 1, 0, 0);
+    let brood_id = state.generate_card_id();
+    let brood = UnitCard::new(brood_id, "Brood", 2, 2, 1, 1).with_shop_ability(ShopAbility {
+        trigger: ShopTrigger::OnSell,
+        effect: ShopEffect::SpawnUnit {
+            card_id: pup_id,
+            spawn_location: SpawnLocation::AdjacentToSource,
+            count: 2,
+            stats: SpawnStats::InheritBuffs,
+        },
+        conditions: vec![],
+        max_triggers: None,
+        then: vec![ShopEffect::SpawnUnit {
+            card_id: pup_id,
+            spawn_location: SpawnLocation::Back,
+            count: 5,
+            stats: SpawnStats::Fixed {
+                attack: 2,
+                health: 2,
+            },
+        }],
+    });
+    state.card_pool.insert(brood_id, brood);
+    let mut buffed = BoardUnit::new(brood_id);
+    buffed.perm_attack = 3;
+    buffed.perm_health = 1;
+    state.board[1] = Some(buffed);
+    state.board[3] = Some(BoardUnit::new(pup_id));
+
+    let action = CommitTurnAction {
+        actions: vec![TurnAction::BurnFromBoard { board_slot: 1 }],
+    };
+
+    let result = verify_and_apply_turn(&mut state, &action);
+    assert!(result.is_ok(), "sell action should succeed: {:?}", result);
+    let perms: Vec<Option<(StatValue, StatValue)>> = state
+        .board
+        .iter()
+        .map(|slot| slot.as_ref().map(|u| (u.perm_attack, u.perm_health)))
+        .collect();
+    assert_eq!(
+        perms,
+        vec![
+            Some((1, 1)),
+            Some((3, 1)),
+            Some((3, 1)),
+            Some((0, 0)),
+            Some((1, 1)),
+        ],
+        "inherited copies fill the sold slot and its neighbour; fixed 2/2 copies fill the rest"
+    );
+}
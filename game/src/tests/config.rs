@@ -1,4 +1,7 @@
 use crate::{sealed, GameConfig};
+use oab_battle::modifiers::ModifierSelection;
+use oab_battle::rng::RngVersion;
+use oab_battle::rules::{CURRENT_RULES_VERSION, LEGACY_RULES_VERSION};
 
 #[test]
 fn sealed_mana_limit_progression() {
@@ -20,9 +23,28 @@ fn custom_config_mana_limit() {
         board_size: 5,
         hand_size: 5,
         bag_size: 50,
+        battle_modifiers: ModifierSelection::None,
+        rules_version: CURRENT_RULES_VERSION,
+        rng_version: RngVersion::CURRENT,
     };
     assert_eq!(config.mana_limit_for_round(1), 5);
     assert_eq!(config.mana_limit_for_round(6), 10);
     assert_eq!(config.mana_limit_for_round(11), 15);
     assert_eq!(config.mana_limit_for_round(20), 15);
 }
+
+#[test]
+fn battle_rules_follow_recorded_version_and_board_size() {
+    let mut config = sealed::default_config();
+    config.board_size = 3;
+    let rules = config.battle_rules().expect("current version is known");
+    assert_eq!(rules.version, CURRENT_RULES_VERSION);
+    assert_eq!(rules.board_size, 3);
+
+    config.rules_version = LEGACY_RULES_VERSION;
+    let legacy = config.battle_rules().expect("legacy version is known");
+    assert_eq!(legacy.fatigue.damage_for_round(99), None);
+
+    config.rules_version = u16::MAX;
+    assert!(config.battle_rules().is_none());
+}
@@ -1,5 +1,6 @@
 use crate::state::derive_hand_indices_logic;
 use crate::GameState;
+use oab_battle::rng::RngVersion;
 use oab_battle::types::*;
 
 #[test]
@@ -13,19 +14,33 @@ fn test_hand_derivation_deterministic() {
     }
 
     // Same seed + round should always produce same hand
-    let hand1 = derive_hand_indices_logic(state.bag.len(), state.game_seed, state.round, 5);
-    let hand2 = derive_hand_indices_logic(state.bag.len(), state.game_seed, state.round, 5);
+    let hand1 = derive_hand_indices_logic(
+        state.bag.len(),
+        state.game_seed,
+        state.round,
+        5,
+        state.rng_version,
+    );
+    let hand2 = derive_hand_indices_logic(
+        state.bag.len(),
+        state.game_seed,
+        state.round,
+        5,
+        state.rng_version,
+    );
     assert_eq!(hand1, hand2, "Same state should produce same hand");
 
     // Different round should produce different hand
-    let hand3 = derive_hand_indices_logic(state.bag.len(), state.game_seed, 2, 5);
+    let hand3 =
+        derive_hand_indices_logic(state.bag.len(), state.game_seed, 2, 5, state.rng_version);
     assert_ne!(
         hand1, hand3,
         "Different round should produce different hand"
     );
 
     // Different seed should produce different hand
-    let hand4 = derive_hand_indices_logic(state.bag.len(), 99999, state.round, 5);
+    let hand4 =
+        derive_hand_indices_logic(state.bag.len(), 99999, state.round, 5, state.rng_version);
     assert_ne!(hand1, hand4, "Different seed should produce different hand");
 }
 
@@ -39,7 +54,13 @@ fn test_hand_derivation_unique_indices() {
         state.bag.push(id);
     }
 
-    let hand = derive_hand_indices_logic(state.bag.len(), state.game_seed, state.round, 5);
+    let hand = derive_hand_indices_logic(
+        state.bag.len(),
+        state.game_seed,
+        state.round,
+        5,
+        state.rng_version,
+    );
     assert_eq!(hand.len(), 5, "Hand should have 5 cards");
 
     let mut sorted = hand.clone();
@@ -54,3 +75,16 @@ fn test_hand_derivation_unique_indices() {
         );
     }
 }
+
+#[test]
+fn test_state_carries_config_rng_version() {
+    let mut config = crate::sealed::default_config();
+    config.rng_version = RngVersion::V1;
+    let state = GameState::new(7, config.clone());
+    assert_eq!(state.rng_version, RngVersion::V1);
+
+    let (card_pool, set_id, _, local) = state.decompose();
+    config.rng_version = RngVersion::V2;
+    let state = GameState::reconstruct(card_pool, set_id, config, local);
+    assert_eq!(state.rng_version, RngVersion::V2);
+}
@@ -302,14 +302,15 @@ fn test_on_buy_trigger_applies_in_shop() {
         UnitCard::new(support_id, "Support", 1, 2, 1, 1).with_shop_ability(ShopAbility {
             trigger: ShopTrigger::OnBuy,
             effect: ShopEffect::ModifyStatsPermanent {
-                health: 2,
-                attack: 0,
+                health: ShopValueExpr::Const(2),
+                attack: ShopValueExpr::Const(0),
                 target: ShopTarget::All {
                     scope: ShopScope::SelfUnit,
                 },
             },
             conditions: vec![],
             max_triggers: None,
+            then: vec![],
         });
     let buyer_card = UnitCard::new(buyer_id, "Buyer", 1, 1, 0, 1);
 
@@ -350,8 +351,8 @@ fn test_on_sell_trigger_applies_in_shop() {
         UnitCard::new(seller_id, "Seller", 1, 2, 1, 1).with_shop_ability(ShopAbility {
             trigger: ShopTrigger::OnSell,
             effect: ShopEffect::ModifyStatsPermanent {
-                health: 3,
-                attack: 0,
+                health: ShopValueExpr::Const(3),
+                attack: ShopValueExpr::Const(0),
                 target: ShopTarget::Position {
                     scope: ShopScope::SelfUnit,
                     index: 1,
@@ -359,6 +360,7 @@ fn test_on_sell_trigger_applies_in_shop() {
             },
             conditions: vec![],
             max_triggers: None,
+            then: vec![],
         });
     let ally_card = UnitCard::new(ally_id, "Ally", 2, 4, 1, 1);
 
@@ -397,9 +399,12 @@ fn test_on_buy_gain_mana_enables_extra_play() {
     let booster_card =
         UnitCard::new(booster_id, "Booster", 1, 2, 1, 1).with_shop_ability(ShopAbility {
             trigger: ShopTrigger::OnBuy,
-            effect: ShopEffect::GainMana { amount: 1 },
+            effect: ShopEffect::GainMana {
+                amount: ShopValueExpr::Const(1),
+            },
             conditions: vec![],
             max_triggers: None,
+            then: vec![],
         });
     let free_buy_card = UnitCard::new(free_buy_id, "FreeBuy", 1, 1, 0, 1);
     let paid_buy_card = UnitCard::new(paid_buy_id, "PaidBuy", 2, 2, 1, 1);
@@ -448,9 +453,12 @@ fn test_on_sell_gain_mana_enables_extra_play() {
     let seller_card =
         UnitCard::new(seller_id, "Seller", 1, 2, 1, 1).with_shop_ability(ShopAbility {
             trigger: ShopTrigger::OnSell,
-            effect: ShopEffect::GainMana { amount: 1 },
+            effect: ShopEffect::GainMana {
+                amount: ShopValueExpr::Const(1),
+            },
             conditions: vec![],
             max_triggers: None,
+            then: vec![],
         });
     let paid_buy_card = UnitCard::new(paid_buy_id, "PaidBuy", 2, 2, 1, 1);
 
@@ -495,8 +503,8 @@ fn test_on_shop_start_random_is_deterministic_with_seed() {
             UnitCard::new(trigger_id, "Starter", 1, 2, 1, 1).with_shop_ability(ShopAbility {
                 trigger: ShopTrigger::OnShopStart,
                 effect: ShopEffect::ModifyStatsPermanent {
-                    health: 1,
-                    attack: 0,
+                    health: ShopValueExpr::Const(1),
+                    attack: ShopValueExpr::Const(0),
                     target: ShopTarget::Random {
                         scope: ShopScope::AlliesOther,
                         count: 1,
@@ -504,6 +512,7 @@ fn test_on_shop_start_random_is_deterministic_with_seed() {
                 },
                 conditions: vec![],
                 max_triggers: None,
+                then: vec![],
             });
 
         let ally_a = UnitCard::new(ally_a_id, "AllyA", 2, 3, 1, 1);
@@ -558,9 +567,12 @@ fn test_on_shop_start_gain_mana_sets_turn_starting_mana() {
 
     let starter = UnitCard::new(starter_id, "Starter", 1, 2, 1, 1).with_shop_ability(ShopAbility {
         trigger: ShopTrigger::OnShopStart,
-        effect: ShopEffect::GainMana { amount: 1 },
+        effect: ShopEffect::GainMana {
+            amount: ShopValueExpr::Const(1),
+        },
         conditions: vec![],
         max_triggers: None,
+        then: vec![],
     });
     let paid_buy = UnitCard::new(paid_buy_id, "PaidBuy", 2, 2, 1, 1);
 
@@ -621,32 +633,37 @@ fn test_after_battle_shop_triggers_fire_by_result() {
             ShopAbility {
                 trigger: ShopTrigger::AfterLoss,
                 effect: ShopEffect::ModifyStatsPermanent {
-                    health: 1,
-                    attack: 0,
+                    health: ShopValueExpr::Const(1),
+                    attack: ShopValueExpr::Const(0),
                     target: ShopTarget::All {
                         scope: ShopScope::SelfUnit,
                     },
                 },
                 conditions: vec![],
                 max_triggers: None,
+                then: vec![],
             },
             ShopAbility {
                 trigger: ShopTrigger::AfterWin,
                 effect: ShopEffect::ModifyStatsPermanent {
-                    health: 0,
-                    attack: 1,
+                    health: ShopValueExpr::Const(0),
+                    attack: ShopValueExpr::Const(1),
                     target: ShopTarget::All {
                         scope: ShopScope::SelfUnit,
                     },
                 },
                 conditions: vec![],
                 max_triggers: None,
+                then: vec![],
             },
             ShopAbility {
                 trigger: ShopTrigger::AfterDraw,
-                effect: ShopEffect::GainMana { amount: 1 },
+                effect: ShopEffect::GainMana {
+                    amount: ShopValueExpr::Const(1),
+                },
                 conditions: vec![],
                 max_triggers: None,
+                then: vec![],
             },
         ]);
 
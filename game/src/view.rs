@@ -8,7 +8,10 @@ use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
 use crate::state::{GamePhase, GameState};
-use oab_battle::types::{CardId, IndexValue, ManaValue, RoundValue, StatValue, UnitCard};
+use oab_battle::types::{
+    CardId, IndexValue, KeywordMask, ManaValue, RoundValue, StatValue, StatusMask, TagMask,
+    UnitCard,
+};
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -25,6 +28,9 @@ pub struct CardView {
     pub burn_value: ManaValue,
     pub shop_abilities: Vec<oab_battle::types::ShopAbility>,
     pub battle_abilities: Vec<oab_battle::types::Ability>,
+    pub base_statuses: StatusMask,
+    pub tags: TagMask,
+    pub keywords: KeywordMask,
 }
 
 impl From<&UnitCard> for CardView {
@@ -38,6 +44,9 @@ impl From<&UnitCard> for CardView {
             burn_value: card.economy.burn_value,
             shop_abilities: card.shop_abilities.clone(),
             battle_abilities: card.battle_abilities.clone(),
+            base_statuses: card.base_statuses,
+            tags: card.tags,
+            keywords: card.keywords,
         }
     }
 }
@@ -52,8 +61,14 @@ pub struct BoardUnitView {
     pub health: StatValue,
     pub play_cost: ManaValue,
     pub burn_value: ManaValue,
+    /// Empty while the unit is silenced.
     pub shop_abilities: Vec<oab_battle::types::ShopAbility>,
+    /// Empty while the unit is silenced.
     pub battle_abilities: Vec<oab_battle::types::Ability>,
+    /// Base card statuses plus permanently granted ones.
+    pub statuses: StatusMask,
+    /// Abilities were permanently removed in the shop.
+    pub silenced: bool,
 }
 
 /// The complete game view sent to React (Hot Path - lightweight)
@@ -134,8 +149,18 @@ impl GameView {
                                 health: card.stats.health.saturating_add(unit.perm_health),
                                 play_cost: card.economy.play_cost,
                                 burn_value: card.economy.burn_value,
-                                shop_abilities: card.shop_abilities.clone(),
-                                battle_abilities: card.battle_abilities.clone(),
+                                shop_abilities: if unit.silenced {
+                                    Vec::new()
+                                } else {
+                                    card.shop_abilities.clone()
+                                },
+                                battle_abilities: if unit.silenced {
+                                    Vec::new()
+                                } else {
+                                    card.battle_abilities.clone()
+                                },
+                                statuses: card.base_statuses.union(&unit.perm_statuses),
+                                silenced: unit.silenced,
                             })
                     })
                 })
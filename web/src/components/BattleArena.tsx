@@ -112,6 +112,14 @@ function buildUnitMap(output: BattleOutput): Map<number, UnitView> {
   for (const event of output.events || []) {
     if (event.type === 'UnitSpawn') {
       map.set(event.payload.spawned_unit.instance_id, event.payload.spawned_unit);
+    } else if (event.type === 'UnitTransform') {
+      map.set(event.payload.transformed_unit.instance_id, event.payload.transformed_unit);
+    } else if (event.type === 'UnitStolen') {
+      const { new_instance_id, player_board_state, enemy_board_state } = event.payload;
+      const stolen = [...player_board_state, ...enemy_board_state].find(
+        (u) => u.instance_id === new_instance_id
+      );
+      if (stolen) map.set(new_instance_id, stolen);
     }
   }
   return map;
@@ -158,7 +166,10 @@ function computeBoardState(
         else enemy = new_board_state || [];
         break;
       }
-      case 'AbilityDamage': {
+      case 'AbilityDamage':
+      case 'FatigueDamage':
+      case 'Trample':
+      case 'Reach': {
         const { target_instance_id, remaining_hp } = event.payload;
         const update = (board: UnitView[]) =>
           board.map((u) =>
@@ -176,6 +187,16 @@ function computeBoardState(
         enemy = update(enemy);
         break;
       }
+      case 'AbilityHeal': {
+        const { target_instance_id, new_health } = event.payload;
+        const update = (board: UnitView[]) =>
+          board.map((u) =>
+            u.instance_id === target_instance_id ? { ...u, health: new_health } : u
+          );
+        player = update(player);
+        enemy = update(enemy);
+        break;
+      }
       case 'AbilityModifyStats': {
         const { target_instance_id, new_attack, new_health } = event.payload;
         const update = (board: UnitView[]) =>
@@ -200,12 +221,38 @@ function computeBoardState(
         enemy = update(enemy);
         break;
       }
-      case 'UnitSpawn': {
+      case 'UnitSpawn':
+      case 'UnitTransform': {
         const { team, new_board_state } = event.payload;
         if (String(team).toUpperCase() === 'PLAYER') player = new_board_state;
         else enemy = new_board_state;
         break;
       }
+      case 'UnitStolen': {
+        player = event.payload.player_board_state;
+        enemy = event.payload.enemy_board_state;
+        break;
+      }
+      case 'UnitSilenced': {
+        const { target_instance_id } = event.payload;
+        const update = (board: UnitView[]) =>
+          board.map((u) =>
+            u.instance_id === target_instance_id ? { ...u, battle_abilities: [] } : u
+          );
+        player = update(player);
+        enemy = update(enemy);
+        break;
+      }
+      case 'ModifierActive': {
+        const { modifier } = event.payload;
+        if (modifier.type === 'BloodMoon') {
+          const update = (board: UnitView[]) =>
+            board.map((u) => ({ ...u, attack: u.attack + modifier.data.attack }));
+          player = update(player);
+          enemy = update(enemy);
+        }
+        break;
+      }
     }
   }
 
@@ -230,13 +277,21 @@ function getEventDelay(events: CombatEvent[], index: number, playbackSpeed: numb
       return 1000 / playbackSpeed;
     case 'AbilityDamage':
     case 'AbilityDestroy':
+    case 'FatigueDamage':
       return 400 / playbackSpeed;
+    case 'AbilityHeal':
     case 'AbilityModifyStats':
     case 'AbilityModifyStatsPermanent':
       return 400 / playbackSpeed;
     case 'AbilityGainMana':
       return 300 / playbackSpeed;
+    case 'UnitSilenced':
+      return 400 / playbackSpeed;
+    case 'ModifierActive':
+      return 800 / playbackSpeed;
     case 'UnitSpawn':
+    case 'UnitTransform':
+    case 'UnitStolen':
       return 600 / playbackSpeed;
     case 'PhaseStart':
     case 'PhaseEnd':
@@ -510,6 +565,26 @@ export function BattleArena({
         break;
       }
 
+      case 'FatigueDamage': {
+        const { target_instance_id, damage, remaining_hp } = event.payload;
+        setTargetHighlightIds((prev) => new Map(prev).set(target_instance_id, 'negative'));
+        setTimeout(() => {
+          setTargetHighlightIds((prev) => {
+            const next = new Map(prev);
+            next.delete(target_instance_id);
+            return next;
+          });
+        }, 600);
+        setDamageNumbers((prev) => new Map(prev).set(target_instance_id, damage));
+        const fatigue = (board: UnitView[]) =>
+          board.map((u) =>
+            u.instance_id === target_instance_id ? { ...u, health: remaining_hp } : u
+          );
+        setPlayerBoard(fatigue);
+        setEnemyBoard(fatigue);
+        break;
+      }
+
       case 'AbilityDestroy': {
         const { target_instance_id: destroyTarget, source_instance_id: destroySource } =
           event.payload;
@@ -613,6 +688,37 @@ export function BattleArena({
         break;
       }
 
+      case 'AbilityHeal': {
+        const { target_instance_id: healTarget, new_health, amount } = event.payload;
+
+        setTargetHighlightIds((prev) => {
+          const next = new Map(prev);
+          next.delete(healTarget);
+          return next;
+        });
+        requestAnimationFrame(() => {
+          requestAnimationFrame(() => {
+            setTargetHighlightIds((prev) => new Map(prev).set(healTarget, 'positive'));
+          });
+        });
+        setTimeout(() => {
+          setTargetHighlightIds((prev) => {
+            const next = new Map(prev);
+            next.delete(healTarget);
+            return next;
+          });
+        }, 600);
+
+        setStatChanges((prevStats) =>
+          new Map(prevStats).set(healTarget, { health: amount, attack: 0 })
+        );
+        const updateHealed = (board: UnitView[]) =>
+          board.map((u) => (u.instance_id === healTarget ? { ...u, health: new_health } : u));
+        setPlayerBoard(updateHealed);
+        setEnemyBoard(updateHealed);
+        break;
+      }
+
       case 'AbilityModifyStatsPermanent': {
         const {
           target_instance_id: statsTarget,
@@ -680,7 +786,8 @@ export function BattleArena({
         break;
       }
 
-      case 'UnitSpawn': {
+      case 'UnitSpawn':
+      case 'UnitTransform': {
         const { team, new_board_state } = event.payload;
         const isPlayerTeam = String(team).toUpperCase() === 'PLAYER';
         if (isPlayerTeam) {
@@ -690,6 +797,34 @@ export function BattleArena({
         }
         break;
       }
+
+      case 'UnitStolen': {
+        setPlayerBoard(event.payload.player_board_state);
+        setEnemyBoard(event.payload.enemy_board_state);
+        break;
+      }
+
+      case 'UnitSilenced': {
+        const { target_instance_id } = event.payload;
+        const silence = (board: UnitView[]) =>
+          board.map((u) =>
+            u.instance_id === target_instance_id ? { ...u, battle_abilities: [] } : u
+          );
+        setPlayerBoard(silence);
+        setEnemyBoard(silence);
+        break;
+      }
+
+      case 'ModifierActive': {
+        const { modifier } = event.payload;
+        if (modifier.type === 'BloodMoon') {
+          const empower = (board: UnitView[]) =>
+            board.map((u) => ({ ...u, attack: u.attack + modifier.data.attack }));
+          setPlayerBoard(empower);
+          setEnemyBoard(empower);
+        }
+        break;
+      }
     }
   }, [
     battleOutput,
@@ -3,7 +3,7 @@ import { useGameStore } from '../store/gameStore';
 import { BattleArena } from './BattleArena';
 import { UI_LAYERS } from '../constants/uiLayers';
 import type { BattleAbility, BattleOutput, CombatEvent } from '../types';
-import { formatAbilitySummary } from '../utils/abilityText';
+import { formatAbilitySummary, formatBattleModifier } from '../utils/abilityText';
 
 // --- Battle Log Helpers ---
 
@@ -24,6 +24,24 @@ function buildUnitNameMap(
         team: String(event.payload.team),
         battle_abilities: event.payload.spawned_unit.battle_abilities,
       });
+    } else if (event.type === 'UnitTransform') {
+      map.set(event.payload.transformed_unit.instance_id, {
+        name: event.payload.transformed_unit.name,
+        team: String(event.payload.team),
+        battle_abilities: event.payload.transformed_unit.battle_abilities,
+      });
+    } else if (event.type === 'UnitStolen') {
+      const { new_instance_id, team, player_board_state, enemy_board_state } = event.payload;
+      const stolen = [...player_board_state, ...enemy_board_state].find(
+        (u) => u.instance_id === new_instance_id
+      );
+      if (stolen) {
+        map.set(new_instance_id, {
+          name: stolen.name,
+          team: String(team),
+          battle_abilities: stolen.battle_abilities,
+        });
+      }
     }
   }
   return map;
@@ -78,11 +96,21 @@ function formatEvent(
         text: `${getName(event.payload.source_instance_id)} deals ${event.payload.damage} to ${getName(event.payload.target_instance_id)}`,
         color: 'text-card-burn',
       };
+    case 'FatigueDamage':
+      return {
+        text: `Fatigue deals ${event.payload.damage} to ${getName(event.payload.target_instance_id)}`,
+        color: 'text-card-burn',
+      };
     case 'AbilityDestroy':
       return {
         text: `${getName(event.payload.source_instance_id)} destroys ${getName(event.payload.target_instance_id)}`,
         color: 'text-card-burn',
       };
+    case 'AbilityHeal':
+      return {
+        text: `${getName(event.payload.source_instance_id)} heals ${getName(event.payload.target_instance_id)} for ${event.payload.amount}`,
+        color: 'text-positive',
+      };
     case 'AbilityModifyStats':
     case 'AbilityModifyStatsPermanent': {
       const { attack_change, health_change } = event.payload;
@@ -104,6 +132,26 @@ function formatEvent(
         text: `${event.payload.spawned_unit.name} spawns (${event.payload.team})`,
         color: 'text-special',
       };
+    case 'UnitTransform':
+      return {
+        text: `A unit becomes ${event.payload.transformed_unit.name} (${event.payload.team})`,
+        color: 'text-special',
+      };
+    case 'UnitStolen':
+      return {
+        text: `${getName(event.payload.source_instance_id)} takes control of ${getName(event.payload.old_instance_id)}`,
+        color: 'text-special',
+      };
+    case 'UnitSilenced':
+      return {
+        text: `${getName(event.payload.source_instance_id)} silences ${getName(event.payload.target_instance_id)}`,
+        color: 'text-special',
+      };
+    case 'ModifierActive':
+      return {
+        text: formatBattleModifier(event.payload.modifier),
+        color: 'text-accent',
+      };
     case 'BattleEnd':
       return {
         text: `Battle ends: ${event.payload.result}`,
@@ -44,6 +44,8 @@ export interface GhostBoardUnit {
   card_id: number;
   perm_attack: number;
   perm_health: number;
+  perm_statuses: string[];
+  silenced: boolean;
 }
 
 export interface GameStateRaw {
@@ -329,7 +331,9 @@ export function createContractBackend(deps: {
       // The WASM engine expects BoundedGameSession = { state, set_id, config }.
       // The contract returns ArenaSession = { state_fields..., set_id }, so we
       // append the default config bytes to make it a full BoundedGameSession.
-      const DEFAULT_CONFIG_SCALE = new Uint8Array([3, 10, 3, 10, 0, 5, 5, 50]);
+      // Trailing bytes: battle_modifiers = None, rules_version = 2 (u16 LE),
+      // rng_version = V2.
+      const DEFAULT_CONFIG_SCALE = new Uint8Array([3, 10, 3, 10, 0, 5, 5, 50, 0, 2, 0, 1]);
       const stateBytes = new Uint8Array(arenaSessionBytes.length + DEFAULT_CONFIG_SCALE.length);
       stateBytes.set(arenaSessionBytes);
       stateBytes.set(DEFAULT_CONFIG_SCALE, arenaSessionBytes.length);
@@ -461,6 +465,11 @@ function binaryToBytes(data: unknown): Uint8Array {
   return new Uint8Array();
 }
 
+/** Status names in `StatusMask` bit order. */
+const STATUS_NAMES = ['Shield', 'Poison', 'Guard'];
+/** SCALE size of one GhostBoardUnit. */
+const GHOST_UNIT_BYTES = 39;
+
 /**
  * Decode the OAB BattleReported event payload. The contract emits a packed
  * byte layout:
@@ -470,7 +479,10 @@ function binaryToBytes(data: unknown): Uint8Array {
  *   byte 3:    round
  *   bytes 4-11: battleSeed (uint64, big-endian)
  *   bytes 12+:  SCALE-encoded Vec<GhostBoardUnit> (1-byte compact prefix, then
- *               6 bytes per unit: u16 card_id LE, i16 attack LE, i16 health LE)
+ *               39 bytes per unit: u16 card_id LE, i16 attack LE, i16 health LE,
+ *               32-byte status mask, bool silenced)
+ *   then:       SCALE-encoded BattleSummary. Not decoded here: the battle is
+ *               replayed locally, which rebuilds the summary with its events.
  */
 function decodeBattleReported(bytes: Uint8Array): TurnResult {
   if (bytes.length < 12) {
@@ -491,14 +503,18 @@ function decodeBattleReported(bytes: Uint8Array): TurnResult {
   if (ghostBytes.length > 0) {
     const count = (ghostBytes[0] ?? 0) / 4;
     let offset = 1;
-    for (let i = 0; i < count && offset + 6 <= ghostBytes.length; i++) {
+    for (let i = 0; i < count && offset + GHOST_UNIT_BYTES <= ghostBytes.length; i++) {
       const card_id = (ghostBytes[offset] ?? 0) | ((ghostBytes[offset + 1] ?? 0) << 8);
       const perm_attack =
         (((ghostBytes[offset + 2] ?? 0) | ((ghostBytes[offset + 3] ?? 0) << 8)) << 16) >> 16;
       const perm_health =
         (((ghostBytes[offset + 4] ?? 0) | ((ghostBytes[offset + 5] ?? 0) << 8)) << 16) >> 16;
-      opponentBoard.push({ card_id, perm_attack, perm_health });
-      offset += 6;
+      const perm_statuses = STATUS_NAMES.filter(
+        (_, bit) => ((ghostBytes[offset + 6 + (bit >> 3)] ?? 0) >> (bit & 7)) & 1
+      );
+      const silenced = ghostBytes[offset + 38] === 1;
+      opponentBoard.push({ card_id, perm_attack, perm_health, perm_statuses, silenced });
+      offset += GHOST_UNIT_BYTES;
     }
   }
 
@@ -162,6 +162,8 @@ export const useContractStore = create<ContractStore>((set, get) => ({
             initial_player_units: [],
             initial_enemy_units: [],
             round: turnResult.round || 1,
+            modifiers: [],
+            summary: { units: [], mvp: null },
           },
           selection: null,
           showBattleOverlay: true,
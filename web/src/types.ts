@@ -10,6 +10,8 @@ export type BattleScope =
 export type ShopScope = 'SelfUnit' | 'Allies' | 'All' | 'AlliesOther' | 'TriggerSource';
 export type StatType = 'Health' | 'Attack' | 'Mana';
 export type SortOrder = 'Ascending' | 'Descending';
+export type Tag = 'Beast' | 'Insect' | 'Machine' | 'Undead' | 'Elemental';
+export type Keyword = 'FirstStrike' | 'Trample' | 'Windfury' | 'Reach';
 export type CompareOp =
   | 'GreaterThan'
   | 'LessThan'
@@ -37,11 +39,16 @@ export type BattleMatcher =
       };
     }
   | { type: 'UnitCount'; data: { scope: BattleScope; op: CompareOp; value: number } }
-  | { type: 'IsPosition'; data: { scope: BattleScope; index: number } };
+  | { type: 'IsPosition'; data: { scope: BattleScope; index: number } }
+  | { type: 'MissingHealthCompare'; data: { scope: BattleScope; op: CompareOp; value: number } }
+  | { type: 'HasTag'; data: { scope: BattleScope; tag: Tag } }
+  | { type: 'TagCount'; data: { scope: BattleScope; tag: Tag; op: CompareOp; value: number } };
 
 export type BattleCondition =
   | { type: 'Is'; data: BattleMatcher }
-  | { type: 'AnyOf'; data: BattleMatcher[] };
+  | { type: 'AnyOf'; data: BattleCondition[] }
+  | { type: 'AllOf'; data: BattleCondition[] }
+  | { type: 'Not'; data: BattleCondition };
 
 // Shop condition types
 export type ShopMatcher =
@@ -50,11 +57,15 @@ export type ShopMatcher =
       data: { scope: ShopScope; stat: StatType; op: CompareOp; value: number };
     }
   | { type: 'UnitCount'; data: { scope: ShopScope; op: CompareOp; value: number } }
-  | { type: 'IsPosition'; data: { scope: ShopScope; index: number } };
+  | { type: 'IsPosition'; data: { scope: ShopScope; index: number } }
+  | { type: 'HasTag'; data: { scope: ShopScope; tag: Tag } }
+  | { type: 'TagCount'; data: { scope: ShopScope; tag: Tag; op: CompareOp; value: number } };
 
 export type ShopCondition =
   | { type: 'Is'; data: ShopMatcher }
-  | { type: 'AnyOf'; data: ShopMatcher[] };
+  | { type: 'AnyOf'; data: ShopCondition[] }
+  | { type: 'AllOf'; data: ShopCondition[] }
+  | { type: 'Not'; data: ShopCondition };
 
 export type BattleTrigger =
   | 'OnStart'
@@ -67,7 +78,16 @@ export type BattleTrigger =
   | 'BeforeUnitAttack'
   | 'AfterUnitAttack'
   | 'BeforeAnyAttack'
-  | 'AfterAnyAttack';
+  | 'AfterAnyAttack'
+  | 'OnKill'
+  | 'OnAllyHurt'
+  | 'OnEnemyFaint'
+  | 'OnBattleEnd'
+  | { OnHealthBelow: { threshold: HealthThreshold } };
+
+export type HealthThreshold =
+  | { type: 'Absolute'; data: number }
+  | { type: 'Percent'; data: number };
 
 export type ShopTrigger =
   | 'OnBuy'
@@ -85,7 +105,10 @@ export type BattleTarget =
       type: 'Standard';
       data: { scope: BattleScope; stat: StatType; order: SortOrder; count: number };
     }
-  | { type: 'All'; data: { scope: BattleScope } };
+  | { type: 'All'; data: { scope: BattleScope } }
+  | { type: 'PreviousTargets' }
+  | { type: 'AllWithTag'; data: { scope: BattleScope; tag: Tag } }
+  | { type: 'RandomWithTag'; data: { scope: BattleScope; tag: Tag; count: number } };
 
 export type ShopTarget =
   | { type: 'Position'; data: { scope: ShopScope; index: number } }
@@ -94,29 +117,86 @@ export type ShopTarget =
       type: 'Standard';
       data: { scope: ShopScope; stat: StatType; order: SortOrder; count: number };
     }
-  | { type: 'All'; data: { scope: ShopScope } };
+  | { type: 'All'; data: { scope: ShopScope } }
+  | { type: 'PreviousTargets' }
+  | { type: 'AllWithTag'; data: { scope: ShopScope; tag: Tag } }
+  | { type: 'RandomWithTag'; data: { scope: ShopScope; tag: Tag; count: number } };
+
+export type ValueTerm =
+  | { type: 'Stat'; data: { scope: BattleScope; stat: StatType } }
+  | { type: 'UnitCount'; data: { scope: BattleScope } }
+  | { type: 'TagCount'; data: { scope: BattleScope; tag: Tag } }
+  | { type: 'TriggerCount' };
+
+export type ShopValueTerm =
+  | { type: 'Stat'; data: { scope: ShopScope; stat: StatType } }
+  | { type: 'UnitCount'; data: { scope: ShopScope } }
+  | { type: 'TagCount'; data: { scope: ShopScope; tag: Tag } };
+
+// Effect amounts: a plain number, or a state-derived term times a factor.
+export type ValueExpr = number | { term: ValueTerm; factor: number };
+export type ShopValueExpr = number | { term: ShopValueTerm; factor: number };
 
 export type BattleEffect =
-  | { type: 'Damage'; amount: number; target: BattleTarget }
-  | { type: 'ModifyStats'; health: number; attack: number; target: BattleTarget }
-  | { type: 'ModifyStatsPermanent'; health: number; attack: number; target: BattleTarget }
-  | { type: 'SpawnUnit'; card_id: number; spawn_location: SpawnLocation }
+  | { type: 'Damage'; amount: ValueExpr; target: BattleTarget }
+  | { type: 'Heal'; amount: ValueExpr; target: BattleTarget }
+  | { type: 'ModifyStats'; health: ValueExpr; attack: ValueExpr; target: BattleTarget }
+  | { type: 'ModifyStatsPermanent'; health: ValueExpr; attack: ValueExpr; target: BattleTarget }
+  | {
+      type: 'SpawnUnit';
+      card_id: number;
+      spawn_location: SpawnLocation;
+      count: number;
+      side: SpawnSide;
+      stats: SpawnStats;
+    }
   | { type: 'Destroy'; target: BattleTarget }
-  | { type: 'GainMana'; amount: number };
+  | { type: 'GainMana'; amount: ValueExpr }
+  | {
+      type: 'Resurrect';
+      pick: ResurrectPick;
+      stats: ResurrectStats;
+      spawn_location: SpawnLocation;
+    }
+  | { type: 'Mimic'; target: BattleTarget }
+  | { type: 'Steal'; target: BattleTarget }
+  | { type: 'Silence'; target: BattleTarget };
+
+export type SpawnLocation = 'Front' | 'Back' | 'DeathPosition' | 'AdjacentToSource';
+
+export type SpawnSide = 'Own' | 'Opposing';
 
-export type SpawnLocation = 'Front' | 'Back' | 'DeathPosition';
+export type SpawnStats =
+  | { type: 'Base' }
+  | { type: 'Fixed'; data: { attack: number; health: number } }
+  | { type: 'InheritBuffs' };
+
+export type ResurrectPick = 'MostRecent' | 'Strongest';
+
+export type ResurrectStats = 'Base' | 'Current';
 
 export type ShopEffect =
-  | { type: 'ModifyStatsPermanent'; health: number; attack: number; target: ShopTarget }
-  | { type: 'SpawnUnit'; card_id: number; spawn_location: SpawnLocation }
+  | { type: 'ModifyStatsPermanent'; health: ShopValueExpr; attack: ShopValueExpr; target: ShopTarget }
+  | {
+      type: 'SpawnUnit';
+      card_id: number;
+      spawn_location: SpawnLocation;
+      count: number;
+      stats: SpawnStats;
+    }
   | { type: 'Destroy'; target: ShopTarget }
-  | { type: 'GainMana'; amount: number };
+  | { type: 'GainMana'; amount: ShopValueExpr }
+  | { type: 'Heal'; amount: ShopValueExpr; target: ShopTarget }
+  | { type: 'Silence'; target: ShopTarget };
 
 export interface BattleAbility {
   trigger: BattleTrigger;
   effect: BattleEffect;
   conditions: BattleCondition[];
   max_triggers?: number;
+  then?: BattleEffect[];
+  max_triggers_per_round?: number;
+  cooldown?: number;
 }
 
 export interface ShopAbility {
@@ -124,6 +204,7 @@ export interface ShopAbility {
   effect: ShopEffect;
   conditions: ShopCondition[];
   max_triggers?: number;
+  then?: ShopEffect[];
 }
 
 export type AnyAbility = BattleAbility | ShopAbility;
@@ -139,6 +220,8 @@ export interface CardView {
   burn_value: number;
   shop_abilities: ShopAbility[];
   battle_abilities: BattleAbility[];
+  tags?: Tag[];
+  keywords?: Keyword[];
 }
 
 export interface BoardUnitView {
@@ -190,14 +273,34 @@ export type LimitReason =
   | { type: 'TriggerLimit'; payload: { current: number; max: number } }
   | { type: 'TriggerDepthLimit'; payload: { current: number; max: number } };
 
+export type BattleModifier =
+  | { type: 'BloodMoon'; data: { attack: number } }
+  | { type: 'Fog' }
+  | { type: 'SuddenDeath'; data: { from_round: number; multiplier: number } }
+  | { type: 'Fatigue'; data: { from_round: number; base_damage: number } };
+
 export type CombatEvent =
   | { type: 'PhaseStart'; payload: { phase: BattlePhase } }
   | { type: 'PhaseEnd'; payload: { phase: BattlePhase } }
   | { type: 'AbilityTrigger'; payload: { source_instance_id: number; ability_index: number } }
-  | { type: 'Clash'; payload: { p_dmg: number; e_dmg: number } }
+  | {
+      type: 'Clash';
+      payload: {
+        p_dmg: number;
+        e_dmg: number;
+        player_instance_id: number;
+        enemy_instance_id: number;
+      };
+    }
   | {
       type: 'DamageTaken';
-      payload: { target_instance_id: number; team: Team; remaining_hp: number };
+      payload: {
+        target_instance_id: number;
+        team: Team;
+        remaining_hp: number;
+        source_instance_id: number;
+        damage: number;
+      };
     }
   | { type: 'UnitDeath'; payload: { team: Team; new_board_state: UnitView[] } }
   | { type: 'BattleEnd'; payload: { result: BattleResult } }
@@ -210,6 +313,15 @@ export type CombatEvent =
         remaining_hp: number;
       };
     }
+  | {
+      type: 'AbilityHeal';
+      payload: {
+        source_instance_id: number;
+        target_instance_id: number;
+        amount: number;
+        new_health: number;
+      };
+    }
   | {
       type: 'AbilityModifyStats';
       payload: {
@@ -255,6 +367,57 @@ export type CombatEvent =
         new_board_state: UnitView[];
       };
     }
+  | {
+      type: 'UnitTransform';
+      payload: {
+        team: Team;
+        transformed_unit: UnitView;
+        new_board_state: UnitView[];
+      };
+    }
+  | {
+      type: 'UnitStolen';
+      payload: {
+        source_instance_id: number;
+        old_instance_id: number;
+        new_instance_id: number;
+        team: Team;
+        player_board_state: UnitView[];
+        enemy_board_state: UnitView[];
+      };
+    }
+  | {
+      type: 'UnitSilenced';
+      payload: { source_instance_id: number; target_instance_id: number };
+    }
+  | { type: 'ModifierActive'; payload: { modifier: BattleModifier } }
+  | {
+      type: 'FatigueDamage';
+      payload: { target_instance_id: number; damage: number; remaining_hp: number };
+    }
+  | {
+      type: 'FirstStrike';
+      payload: { source_instance_id: number; target_instance_id: number };
+    }
+  | {
+      type: 'Trample';
+      payload: {
+        source_instance_id: number;
+        target_instance_id: number;
+        damage: number;
+        remaining_hp: number;
+      };
+    }
+  | { type: 'Windfury'; payload: { source_instance_id: number } }
+  | {
+      type: 'Reach';
+      payload: {
+        source_instance_id: number;
+        target_instance_id: number;
+        damage: number;
+        remaining_hp: number;
+      };
+    }
   | {
       type: 'LimitExceeded';
       payload: {
@@ -263,11 +426,28 @@ export type CombatEvent =
       };
     };
 
+export interface UnitSummary {
+  instance_id: number;
+  damage_dealt: number;
+  damage_taken: number;
+  kills: number;
+  abilities_triggered: number;
+  units_spawned: number;
+  mana_generated: number;
+}
+
+export interface BattleSummary {
+  units: UnitSummary[]; // Units that acted or were hit, by instance id
+  mvp: number | null; // Instance id of the winning side's top damage dealer
+}
+
 export interface BattleOutput {
   events: CombatEvent[];
   initial_player_units: UnitView[];
   initial_enemy_units: UnitView[];
   round: number; // The round this battle was for
+  modifiers: BattleModifier[]; // Battlefield modifiers active for both teams
+  summary: BattleSummary; // Per-unit stats derived from the events
 }
 
 // Selection state for UI
@@ -2,14 +2,21 @@ import type {
   AnyAbility,
   BattleCondition,
   BattleMatcher,
+  BattleModifier,
   BattleScope,
   BattleTarget,
+  BattleTrigger,
   CompareOp,
+  HealthThreshold,
   ShopAbility,
   ShopCondition,
   ShopMatcher,
   ShopScope,
   ShopTarget,
+  ShopTrigger,
+  ShopValueExpr,
+  SpawnLocation,
+  ValueExpr,
 } from '../types';
 
 export interface AbilityTextOptions {
@@ -80,7 +87,14 @@ function describeScopeSingular(scope: BattleScope | ShopScope): string {
   }
 }
 
-export function formatAbilityTrigger(trigger: string): string {
+function formatHealthThreshold(threshold: HealthThreshold): string {
+  return threshold.type === 'Percent' ? `${threshold.data}% health` : `${threshold.data} health`;
+}
+
+export function formatAbilityTrigger(trigger: BattleTrigger | ShopTrigger): string {
+  if (typeof trigger === 'object') {
+    return `At ${formatHealthThreshold(trigger.OnHealthBelow.threshold)}`;
+  }
   switch (trigger) {
     case 'OnStart':
       return 'Battle Start';
@@ -116,6 +130,14 @@ export function formatAbilityTrigger(trigger: string): string {
       return 'Before Any Attack';
     case 'AfterAnyAttack':
       return 'After Any Attack';
+    case 'OnKill':
+      return 'On Kill';
+    case 'OnAllyHurt':
+      return 'When Ally Hurt';
+    case 'OnEnemyFaint':
+      return 'When Enemy Dies';
+    case 'OnBattleEnd':
+      return 'Battle End';
     default:
       return trigger;
   }
@@ -147,36 +169,102 @@ export function formatAbilityTarget(target: BattleTarget | ShopTarget): string {
     }
     case 'Adjacent':
       return `units adjacent to ${describeScope(target.data.scope)}`;
+    case 'PreviousTargets':
+      return 'them';
+    case 'AllWithTag':
+      return `${target.data.tag} ${describeScope(target.data.scope)}`;
+    case 'RandomWithTag': {
+      const { count, scope, tag } = target.data;
+      return `${count === 1 ? 'a random' : `${count} random`} ${tag} ${describeScopeSingular(scope)}`;
+    }
     default:
       return 'unknown target';
   }
 }
 
+export function formatValueExpr(value: ValueExpr | ShopValueExpr): string {
+  if (typeof value === 'number') return `${value}`;
+  const { term, factor } = value;
+  let termText: string;
+  switch (term.type) {
+    case 'Stat':
+      termText = `${String(term.data.stat).toLowerCase()} of ${describeScope(term.data.scope)}`;
+      break;
+    case 'UnitCount':
+      termText = `the number of ${describeScope(term.data.scope)}`;
+      break;
+    case 'TagCount':
+      termText = `the number of ${term.data.tag} ${describeScope(term.data.scope)}`;
+      break;
+    case 'TriggerCount':
+      termText = 'times triggered';
+      break;
+    default:
+      termText = 'unknown value';
+  }
+  return factor === 1 ? `(${termText})` : `${factor}× (${termText})`;
+}
+
+function formatSignedValue(value: ValueExpr | ShopValueExpr): string {
+  if (typeof value === 'number') return `${value >= 0 ? '+' : ''}${value}`;
+  return `+${formatValueExpr(value)}`;
+}
+
+function formatSpawnLocation(location: SpawnLocation): string {
+  switch (location) {
+    case 'Back':
+      return ' at the back';
+    case 'DeathPosition':
+      return ' in its place';
+    case 'AdjacentToSource':
+      return ' behind this unit';
+    default:
+      return ' at the front';
+  }
+}
+
 export function formatAbilityEffect(
   effect: AnyAbility['effect'],
   options: AbilityTextOptions = {}
 ): string {
   switch (effect.type) {
     case 'Damage':
-      return `Deal ${effect.amount} damage to ${formatAbilityTarget(effect.target)}`;
+      return `Deal ${formatValueExpr(effect.amount)} damage to ${formatAbilityTarget(effect.target)}`;
+    case 'Heal':
+      return `Heal ${formatAbilityTarget(effect.target)} for ${formatValueExpr(effect.amount)}`;
     case 'ModifyStats':
-      return `Give ${effect.attack >= 0 ? '+' : ''}${effect.attack}/${effect.health >= 0 ? '+' : ''}${effect.health} to ${formatAbilityTarget(effect.target)}`;
+      return `Give ${formatSignedValue(effect.attack)}/${formatSignedValue(effect.health)} to ${formatAbilityTarget(effect.target)}`;
     case 'ModifyStatsPermanent':
-      return `Give ${effect.attack >= 0 ? '+' : ''}${effect.attack}/${effect.health >= 0 ? '+' : ''}${effect.health} permanently to ${formatAbilityTarget(effect.target)}`;
+      return `Give ${formatSignedValue(effect.attack)}/${formatSignedValue(effect.health)} permanently to ${formatAbilityTarget(effect.target)}`;
     case 'SpawnUnit': {
       const name = options.resolveCardName?.(effect.card_id) ?? `card #${effect.card_id}`;
-      const loc =
-        effect.spawn_location === 'Back'
-          ? ' at the back'
-          : effect.spawn_location === 'DeathPosition'
-            ? ' in its place'
-            : ' at the front';
-      return `Spawn ${name}${loc}`;
+      const loc = formatSpawnLocation(effect.spawn_location);
+      const count = effect.count > 1 ? `${effect.count}× ` : '';
+      const stats =
+        effect.stats.type === 'Fixed'
+          ? ` as a ${effect.stats.data.attack}/${effect.stats.data.health}`
+          : effect.stats.type === 'InheritBuffs'
+            ? " with this unit's buffs"
+            : '';
+      const side = 'side' in effect && effect.side === 'Opposing' ? ' on the enemy board' : '';
+      return `Spawn ${count}${name}${stats}${loc}${side}`;
     }
     case 'Destroy':
       return `Destroy ${formatAbilityTarget(effect.target)}`;
     case 'GainMana':
-      return `Gain ${effect.amount} mana`;
+      return `Gain ${formatValueExpr(effect.amount)} mana`;
+    case 'Resurrect': {
+      const who = effect.pick === 'Strongest' ? 'the strongest fallen ally' : 'the last fallen ally';
+      const stats = effect.stats === 'Current' ? ' with its buffs' : '';
+      const loc = formatSpawnLocation(effect.spawn_location);
+      return `Resurrect ${who}${stats}${loc}`;
+    }
+    case 'Mimic':
+      return `Become a copy of ${formatAbilityTarget(effect.target)}`;
+    case 'Steal':
+      return `Take control of ${formatAbilityTarget(effect.target)}`;
+    case 'Silence':
+      return `Remove all abilities from ${formatAbilityTarget(effect.target)}`;
     default:
       return 'Unknown effect';
   }
@@ -194,6 +282,12 @@ function formatBattleMatcher(matcher: BattleMatcher): string {
       return `${describeScope(matcher.data.scope)} count ${formatCompareOp(matcher.data.op)} ${matcher.data.value}`;
     case 'IsPosition':
       return `this unit is at position ${matcher.data.index} within ${describeScope(matcher.data.scope)}`;
+    case 'MissingHealthCompare':
+      return `any ${describeScopeSingular(matcher.data.scope)} is missing health ${formatCompareOp(matcher.data.op)} ${matcher.data.value}`;
+    case 'HasTag':
+      return `any ${describeScopeSingular(matcher.data.scope)} is a ${matcher.data.tag}`;
+    case 'TagCount':
+      return `${matcher.data.tag} count in ${describeScope(matcher.data.scope)} ${formatCompareOp(matcher.data.op)} ${matcher.data.value}`;
     default:
       return 'Unknown battle matcher';
   }
@@ -207,23 +301,43 @@ function formatShopMatcher(matcher: ShopMatcher): string {
       return `${describeScope(matcher.data.scope)} count ${formatCompareOp(matcher.data.op)} ${matcher.data.value}`;
     case 'IsPosition':
       return `this unit is at position ${matcher.data.index} within ${describeScope(matcher.data.scope)}`;
+    case 'HasTag':
+      return `any ${describeScopeSingular(matcher.data.scope)} is a ${matcher.data.tag}`;
+    case 'TagCount':
+      return `${matcher.data.tag} count in ${describeScope(matcher.data.scope)} ${formatCompareOp(matcher.data.op)} ${matcher.data.value}`;
     default:
       return 'Unknown shop matcher';
   }
 }
 
 function formatBattleCondition(condition: BattleCondition): string {
-  if (condition.type === 'Is') {
-    return formatBattleMatcher(condition.data);
+  switch (condition.type) {
+    case 'Is':
+      return formatBattleMatcher(condition.data);
+    case 'AnyOf':
+      return `(${condition.data.map(formatBattleCondition).join(' or ')})`;
+    case 'AllOf':
+      return `(${condition.data.map(formatBattleCondition).join(' and ')})`;
+    case 'Not':
+      return `not ${formatBattleCondition(condition.data)}`;
+    default:
+      return 'Unknown battle condition';
   }
-  return condition.data.map(formatBattleMatcher).join(' or ');
 }
 
 function formatShopCondition(condition: ShopCondition): string {
-  if (condition.type === 'Is') {
-    return formatShopMatcher(condition.data);
+  switch (condition.type) {
+    case 'Is':
+      return formatShopMatcher(condition.data);
+    case 'AnyOf':
+      return `(${condition.data.map(formatShopCondition).join(' or ')})`;
+    case 'AllOf':
+      return `(${condition.data.map(formatShopCondition).join(' and ')})`;
+    case 'Not':
+      return `not ${formatShopCondition(condition.data)}`;
+    default:
+      return 'Unknown shop condition';
   }
-  return condition.data.map(formatShopMatcher).join(' or ');
 }
 
 function isShopAbility(ability: AnyAbility): ability is ShopAbility {
@@ -249,7 +363,10 @@ function formatAbilityConditions(ability: AnyAbility): string {
   return ` if ${text}`;
 }
 
-function formatTriggerClause(trigger: string): string {
+function formatTriggerClause(trigger: BattleTrigger | ShopTrigger): string {
+  if (typeof trigger === 'object') {
+    return `When this drops to ${formatHealthThreshold(trigger.OnHealthBelow.threshold)} or less`;
+  }
   switch (trigger) {
     case 'OnStart':
       return 'At battle start';
@@ -277,6 +394,14 @@ function formatTriggerClause(trigger: string): string {
       return 'When a friendly unit is spawned';
     case 'OnEnemySpawn':
       return 'When an enemy is spawned';
+    case 'OnKill':
+      return 'When this kills an enemy';
+    case 'OnAllyHurt':
+      return 'When a friendly unit is hurt';
+    case 'OnEnemyFaint':
+      return 'When an enemy dies';
+    case 'OnBattleEnd':
+      return 'At battle end, if alive';
     case 'BeforeUnitAttack':
       return 'Before attacking';
     case 'AfterUnitAttack':
@@ -290,17 +415,56 @@ function formatTriggerClause(trigger: string): string {
   }
 }
 
+function formatAbilityEffects(ability: AnyAbility, options: AbilityTextOptions): string {
+  const steps: AnyAbility['effect'][] = [ability.effect, ...(ability.then ?? [])];
+  return steps
+    .map((effect, index) => {
+      const text = formatAbilityEffect(effect, options);
+      return index === 0 ? text : lowercaseFirst(text);
+    })
+    .join(', then ');
+}
+
+function ordinal(n: number): string {
+  const mod100 = n % 100;
+  if (mod100 >= 11 && mod100 <= 13) return `${n}th`;
+  switch (n % 10) {
+    case 1:
+      return `${n}st`;
+    case 2:
+      return `${n}nd`;
+    case 3:
+      return `${n}rd`;
+    default:
+      return `${n}th`;
+  }
+}
+
+function formatBattleLimits(ability: AnyAbility): string[] {
+  const limits: string[] = [];
+  if ('max_triggers_per_round' in ability && ability.max_triggers_per_round != null) {
+    const n = ability.max_triggers_per_round;
+    limits.push(`Max ${n} trigger${n === 1 ? '' : 's'} per round`);
+  }
+  if ('cooldown' in ability && ability.cooldown != null && ability.cooldown > 1) {
+    limits.push(`Every ${ordinal(ability.cooldown)} activation`);
+  }
+  return limits;
+}
+
 export function formatAbilitySummary(
   ability: AnyAbility,
   options: AbilityTextOptions = {}
 ): string {
-  const effect = formatAbilityEffect(ability.effect, options);
+  const effect = formatAbilityEffects(ability, options);
   const conditionText = formatAbilityConditions(ability);
   const triggerLimit =
     ability.max_triggers == null
       ? ''
       : ` Max ${ability.max_triggers} trigger${ability.max_triggers === 1 ? '' : 's'}.`;
-  return `${effect}${conditionText}.${triggerLimit}`.trim();
+  const battleLimits = formatBattleLimits(ability);
+  const battleLimitText = battleLimits.length === 0 ? '' : ` ${battleLimits.join('. ')}.`;
+  return `${effect}${conditionText}.${triggerLimit}${battleLimitText}`.trim();
 }
 
 export function formatAbilitySentence(
@@ -308,12 +472,30 @@ export function formatAbilitySentence(
   options: AbilityTextOptions = {}
 ): string {
   const trigger = formatTriggerClause(ability.trigger);
-  const effect = lowercaseFirst(formatAbilityEffect(ability.effect, options));
+  const effect = lowercaseFirst(formatAbilityEffects(ability, options));
   const conditionText = formatAbilityConditions(ability);
   const triggerLimit =
     ability.max_triggers == null
       ? ''
       : ` (up to ${ability.max_triggers} trigger${ability.max_triggers === 1 ? '' : 's'})`;
+  const battleLimits = formatBattleLimits(ability)
+    .map((limit) => ` (${lowercaseFirst(limit)})`)
+    .join('');
+
+  return `${trigger}, ${effect}${conditionText}${triggerLimit}${battleLimits}.`;
+}
 
-  return `${trigger}, ${effect}${conditionText}${triggerLimit}.`;
+export function formatBattleModifier(modifier: BattleModifier): string {
+  switch (modifier.type) {
+    case 'BloodMoon':
+      return `Blood Moon: all units +${modifier.data.attack} attack`;
+    case 'Fog':
+      return 'Fog: abilities pick random targets';
+    case 'SuddenDeath':
+      return `Sudden Death: clash damage x${modifier.data.multiplier} from round ${modifier.data.from_round}`;
+    case 'Fatigue':
+      return modifier.data.base_damage > 0
+        ? `Fatigue: fronts take ${modifier.data.base_damage}+ damage from round ${modifier.data.from_round}`
+        : 'Fatigue: off';
+  }
 }